@@ -1,23 +1,4 @@
-use fontconfig_parser::{FontConfig, Result};
-use std::path::PathBuf;
-
-fn visit_dir(dir: PathBuf, fonts: &mut Vec<PathBuf>) -> Result<()> {
-    let dir = std::fs::read_dir(dir)?;
-
-    for entry in dir {
-        if let Ok(entry) = entry {
-            if let Ok(ty) = entry.file_type() {
-                if ty.is_dir() {
-                    visit_dir(entry.path(), fonts).ok();
-                } else if ty.is_file() || ty.is_symlink() {
-                    fonts.push(entry.path());
-                }
-            }
-        }
-    }
-
-    Ok(())
-}
+use fontconfig_parser::{FontConfig, PropertyKind, Result};
 
 fn main() -> Result<()> {
     let mut config = FontConfig::default();
@@ -25,15 +6,19 @@ fn main() -> Result<()> {
 
     println!("dirs: {:#?}", config.dirs);
 
-    let mut fonts = Vec::new();
-
-    for dir in config.dirs {
-        visit_dir(dir.path, &mut fonts).ok();
-    }
+    let fonts = config.scan_fonts();
 
     println!("Find all {} fonts!", fonts.len());
 
-    println!("fonts: {:#?}", fonts);
+    for font in fonts {
+        println!(
+            "{}: {}:style={}",
+            font.get_string(&PropertyKind::File, 0).unwrap_or_default(),
+            font.get_string(&PropertyKind::Family, 0)
+                .unwrap_or_default(),
+            font.get_string(&PropertyKind::Style, 0).unwrap_or_default(),
+        );
+    }
 
     Ok(())
 }
@@ -9,7 +9,12 @@ fn main() {
     let parts =
         fontconfig_parser::parse_config_parts(&std::fs::read_to_string(&args[1]).unwrap()).unwrap();
 
-    serde_yaml::to_writer(std::io::stdout(), &parts).unwrap();
+    // enums are single key maps since YAML tags can't be nested
+    serde_yaml::with::singleton_map_recursive::serialize(
+        &parts,
+        &mut serde_yaml::Serializer::new(std::io::stdout()),
+    )
+    .unwrap();
 }
 
 #[cfg(not(feature = "serde"))]
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+# Generate src/scan/orth.rs from orthographies built into libfontconfig
+#
+# Languages are read from `LANGS` of src/cache.rs, which is the order of fontconfig's orthography list.
+
+import ctypes
+import re
+
+fc = ctypes.CDLL("libfontconfig.so.1")
+
+Page = ctypes.c_uint32 * 8
+fc.FcGetVersion.restype = ctypes.c_int
+fc.FcLangGetCharSet.restype = ctypes.c_void_p
+fc.FcLangGetCharSet.argtypes = [ctypes.c_char_p]
+for name in ["FcCharSetFirstPage", "FcCharSetNextPage"]:
+    func = getattr(fc, name)
+    func.restype = ctypes.c_uint32
+    func.argtypes = [ctypes.c_void_p, ctypes.POINTER(Page), ctypes.POINTER(ctypes.c_uint32)]
+
+CHARSET_DONE = 0xFFFFFFFF
+
+
+def chars(charset):
+    page = Page()
+    next_page = ctypes.c_uint32()
+    base = fc.FcCharSetFirstPage(charset, ctypes.byref(page), ctypes.byref(next_page))
+
+    while base != CHARSET_DONE:
+        for idx, bits in enumerate(page):
+            for bit in range(32):
+                if bits >> bit & 1:
+                    yield base + idx * 32 + bit
+        base = fc.FcCharSetNextPage(charset, ctypes.byref(page), ctypes.byref(next_page))
+
+
+def ranges(charset):
+    ranges = []
+    for c in chars(charset):
+        if ranges and ranges[-1][1] + 1 == c:
+            ranges[-1][1] = c
+        else:
+            ranges.append([c, c])
+    return ranges
+
+
+cache = open("src/cache.rs").read()
+langs = re.findall(r'"([^"]+)"', re.search(r"const LANGS: &\[&str\] = &\[(.*?)\];", cache, re.S)[1])
+
+version = fc.FcGetVersion()
+out = [
+    "//! Orthography of each language in order of `LANGS` of the cache module, generated by `gen-orth.py`",
+    "//! from libfontconfig %d.%d.%d" % (version // 10000, version // 100 % 100, version % 100),
+    "",
+    "use crate::Int;",
+    "",
+    "/// Language and its code points, languages with the same orthography share a table",
+    "pub(crate) const ORTHOGRAPHIES: &[(&str, &[(Int, Int)])] = &[",
+]
+tables = {}
+items = []
+
+for lang in langs:
+    charset = fc.FcLangGetCharSet(lang.encode())
+    assert charset, lang
+    table = tuple(map(tuple, ranges(charset)))
+    name = tables.setdefault(table, lang.upper().replace("-", "_"))
+    out.append('    ("%s", %s),' % (lang, name))
+
+out.append("];")
+
+for table, name in tables.items():
+    out += ["", "#[rustfmt::skip]", "const %s: &[(Int, Int)] = &[" % name]
+    line = "   "
+    for start, end in table:
+        item = " (0x%x, 0x%x)," % (start, end)
+        if len(line) + len(item) > 100:
+            out.append(line)
+            line = "   "
+        line += item
+    out += [line, "];"]
+
+open("src/scan/orth.rs", "w").write("\n".join(out) + "\n")
@@ -0,0 +1,250 @@
+//! Option parsing and loading shared by the fontconfig tools
+
+#![allow(dead_code)]
+
+use fontconfig_parser::{FontConfig, Pattern, PropertyKind};
+
+use std::io::Write;
+use std::process::exit;
+
+/// Option which is given like `-f FORMAT` or `--format=FORMAT`
+pub struct Opt {
+    pub short: char,
+    pub long: &'static str,
+    /// Name of the value, `None` for flags
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+pub const VERSION: Opt = Opt {
+    short: 'V',
+    long: "version",
+    value: None,
+    help: "display font config version and exit",
+};
+
+pub const HELP: Opt = Opt {
+    short: 'h',
+    long: "help",
+    value: None,
+    help: "display this help and exit",
+};
+
+pub const FORMAT: Opt = Opt {
+    short: 'f',
+    long: "format",
+    value: Some("FORMAT"),
+    help: "use the given output format",
+};
+
+pub struct Command {
+    pub name: &'static str,
+    /// Usage after options
+    pub args: &'static str,
+    pub about: &'static str,
+    pub opts: &'static [Opt],
+}
+
+#[derive(Default)]
+pub struct Matches {
+    flags: Vec<char>,
+    values: Vec<(char, String)>,
+    pub free: Vec<String>,
+}
+
+impl Matches {
+    pub fn flag(&self, short: char) -> bool {
+        self.flags.contains(&short)
+    }
+
+    pub fn value(&self, short: char) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(c, _)| *c == short)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Command {
+    fn usage(&self) -> String {
+        let shorts = self
+            .opts
+            .iter()
+            .filter(|opt| opt.value.is_none())
+            .map(|opt| opt.short)
+            .collect::<String>();
+        let mut usage = format!("usage: {} [-{}]", self.name, shorts);
+
+        for opt in self.opts.iter().filter(|opt| opt.value.is_some()) {
+            usage += &format!(" [-{} {}]", opt.short, opt.value.unwrap_or_default());
+        }
+        for opt in self.opts.iter() {
+            match opt.value {
+                Some(value) => usage += &format!(" [--{}={}]", opt.long, value),
+                None => usage += &format!(" [--{}]", opt.long),
+            }
+        }
+
+        format!("{} {}\n{}\n\n", usage, self.args, self.about)
+            + &self
+                .opts
+                .iter()
+                .map(|opt| {
+                    let long = match opt.value {
+                        Some(value) => format!("{}={}", opt.long, value),
+                        None => opt.long.to_string(),
+                    };
+                    format!("  -{}, --{:<16} {}\n", opt.short, long, opt.help)
+                })
+                .collect::<String>()
+    }
+
+    /// Parse arguments like `getopt_long`, exits for `--help`, `--version` and bad options
+    pub fn parse(&self) -> Matches {
+        match self.try_parse(std::env::args().skip(1)) {
+            Ok(matches) if matches.flag(HELP.short) => {
+                print!("{}", self.usage());
+                exit(0);
+            }
+            Ok(matches) if matches.flag(VERSION.short) => {
+                println!("fontconfig-parser version {}", env!("CARGO_PKG_VERSION"));
+                exit(0);
+            }
+            Ok(matches) => matches,
+            Err(msg) => {
+                eprintln!("{}: {}", self.name, msg);
+                eprint!("{}", self.usage());
+                exit(1);
+            }
+        }
+    }
+
+    fn try_parse(&self, args: impl Iterator<Item = String>) -> Result<Matches, String> {
+        let mut matches = Matches::default();
+        let mut args = args.peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                matches.free.extend(args);
+                break;
+            }
+
+            let (opt, inline) = if let Some(long) = arg.strip_prefix("--") {
+                let (name, value) = match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (long, None),
+                };
+                let opt = self
+                    .opts
+                    .iter()
+                    .find(|opt| opt.long == name)
+                    .ok_or_else(|| format!("unrecognized option '--{}'", name))?;
+                (opt, value)
+            } else if arg.len() > 1 && arg.starts_with('-') {
+                let mut found = None;
+
+                for (idx, c) in arg[1..].char_indices() {
+                    let opt = self
+                        .opts
+                        .iter()
+                        .find(|opt| opt.short == c)
+                        .ok_or_else(|| format!("invalid option -- '{}'", c))?;
+
+                    if opt.value.is_some() {
+                        let rest = &arg[1 + idx + c.len_utf8()..];
+                        found = Some((opt, Some(rest.to_string()).filter(|s| !s.is_empty())));
+                        break;
+                    }
+
+                    matches.flags.push(c);
+                }
+
+                match found {
+                    Some(found) => found,
+                    None => continue,
+                }
+            } else {
+                matches.free.push(arg);
+                continue;
+            };
+
+            match opt.value {
+                Some(_) => {
+                    let value = match inline {
+                        Some(value) => value,
+                        None => args.next().ok_or_else(|| {
+                            format!("option '--{}' requires an argument", opt.long)
+                        })?,
+                    };
+                    matches.values.push((opt.short, value));
+                }
+                None if inline.is_some() => {
+                    return Err(format!("option '--{}' doesn't allow an argument", opt.long))
+                }
+                None => matches.flags.push(opt.short),
+            }
+        }
+
+        Ok(matches)
+    }
+}
+
+/// Load config like libfontconfig, see [`FontConfig::from_env`]
+///
+/// Without a config file nothing is configured.
+pub fn load_config(name: &str) -> FontConfig {
+    FontConfig::from_env().unwrap_or_else(|e| {
+        eprintln!("{}: Cannot load config file: {}", name, e);
+        FontConfig::default()
+    })
+}
+
+/// Pattern given like fontconfig tools, `fc-list` uses an empty pattern by default
+pub fn parse_pattern(name: &str, pattern: Option<&String>) -> Pattern {
+    match pattern.map_or(Ok(Pattern::new()), |p| p.parse()) {
+        Ok(pattern) => pattern,
+        Err(e) => {
+            eprintln!("{}: Unable to parse the pattern: {}", name, e);
+            exit(1);
+        }
+    }
+}
+
+/// Properties given after the pattern
+pub fn parse_objects(elements: &[String]) -> Option<Vec<PropertyKind>> {
+    if elements.is_empty() {
+        return None;
+    }
+
+    Some(
+        elements
+            .iter()
+            .map(|element| element.parse().unwrap_or_default())
+            .collect(),
+    )
+}
+
+/// Format `font` with `format` or print it verbosely, `brief` drops charset and lang
+///
+/// A bad format is reported like libfontconfig and gives nothing for the font.
+pub fn format_font(font: &Pattern, format: Option<&str>, brief: bool) -> String {
+    match format {
+        Some(format) => font.format(format).unwrap_or_else(|e| {
+            eprintln!("Fontconfig: {}.", e);
+            String::new()
+        }),
+        None if brief => {
+            let mut font = font.clone();
+            font.delete(&PropertyKind::Charset);
+            font.delete(&PropertyKind::Lang);
+            font.to_verbose_string()
+        }
+        None => font.to_verbose_string(),
+    }
+}
+
+/// Write all output at once, a closed pipe isn't an error
+pub fn output(out: &str) {
+    std::io::stdout().write_all(out.as_bytes()).ok();
+}
@@ -0,0 +1,40 @@
+//! `fc-conflist`, show merged config files and their description
+
+mod common;
+
+use common::*;
+use fontconfig_parser::MergeOptions;
+
+const COMMAND: Command = Command {
+    name: "fc-conflist",
+    args: "",
+    about: "Show the ruleset files information on the system",
+    opts: &[VERSION, HELP],
+};
+
+fn main() {
+    COMMAND.parse();
+
+    let mut config = load_config(COMMAND.name);
+    // templates which aren't enabled are listed too, missing templates are fine
+    config
+        .parse_templates(MergeOptions::TEMPLATE_DIR, &MergeOptions::from_env())
+        .ok();
+
+    output(
+        &config
+            .config_files
+            .iter()
+            .map(|file| {
+                format!(
+                    "{} {}: {}\n",
+                    if file.enabled { '+' } else { '-' },
+                    file.path.display(),
+                    file.description
+                        .as_ref()
+                        .map_or("No description", |d| d.text.as_str())
+                )
+            })
+            .collect::<String>(),
+    );
+}
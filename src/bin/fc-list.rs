@@ -0,0 +1,71 @@
+//! `fc-list`, list fonts matching a pattern
+
+mod common;
+
+use common::*;
+use fontconfig_parser::{font_list, PropertyKind};
+
+const COMMAND: Command = Command {
+    name: "fc-list",
+    args: "[pattern] {element ...}",
+    about: "List fonts matching [pattern]",
+    opts: &[
+        Opt {
+            short: 'v',
+            long: "verbose",
+            value: None,
+            help: "display entire font pattern verbosely",
+        },
+        Opt {
+            short: 'b',
+            long: "brief",
+            value: None,
+            help: "display entire font pattern briefly",
+        },
+        FORMAT,
+        Opt {
+            short: 'q',
+            long: "quiet",
+            value: None,
+            help: "suppress all normal output, exit 1 if no fonts matched",
+        },
+        VERSION,
+        HELP,
+    ],
+};
+
+fn main() {
+    let matches = COMMAND.parse();
+    let (verbose, brief, quiet) = (matches.flag('v'), matches.flag('b'), matches.flag('q'));
+
+    let pattern = parse_pattern(COMMAND.name, matches.free.first());
+    let mut objects = parse_objects(matches.free.get(1..).unwrap_or_default());
+
+    if !verbose && !brief && !quiet && matches.value('f').is_none() && objects.is_none() {
+        objects = Some(vec![
+            PropertyKind::Family,
+            PropertyKind::Style,
+            PropertyKind::File,
+        ]);
+    }
+
+    let config = load_config(COMMAND.name);
+    let fonts = font_list(&pattern, objects.as_deref(), &config.scan_fonts_cached());
+
+    if quiet {
+        std::process::exit(fonts.is_empty() as i32);
+    }
+
+    let format = match matches.value('f') {
+        Some(format) => Some(format),
+        None if verbose || brief => None,
+        None => Some("%{=fclist}\n"),
+    };
+
+    output(
+        &fonts
+            .iter()
+            .map(|font| format_font(font, format, brief))
+            .collect::<String>(),
+    );
+}
@@ -0,0 +1,87 @@
+//! `fc-match`, show the best fonts for a pattern
+
+mod common;
+
+use common::*;
+
+const COMMAND: Command = Command {
+    name: "fc-match",
+    args: "[pattern] {element...}",
+    about: "List best font matching [pattern]",
+    opts: &[
+        Opt {
+            short: 's',
+            long: "sort",
+            value: None,
+            help: "display sorted list of matches",
+        },
+        Opt {
+            short: 'a',
+            long: "all",
+            value: None,
+            help: "display unpruned sorted list of matches",
+        },
+        Opt {
+            short: 'v',
+            long: "verbose",
+            value: None,
+            help: "display entire font pattern verbosely",
+        },
+        Opt {
+            short: 'b',
+            long: "brief",
+            value: None,
+            help: "display entire font pattern briefly",
+        },
+        FORMAT,
+        VERSION,
+        HELP,
+    ],
+};
+
+fn main() {
+    let matches = COMMAND.parse();
+    let (sort, all) = (matches.flag('s'), matches.flag('a'));
+    let (verbose, brief) = (matches.flag('v'), matches.flag('b'));
+
+    let mut pattern = parse_pattern(COMMAND.name, matches.free.first());
+    let objects = parse_objects(matches.free.get(1..).unwrap_or_default());
+
+    let config = load_config(COMMAND.name);
+    config.config_substitute(&mut pattern);
+    pattern.default_substitute();
+
+    let fonts = config.scan_fonts_cached();
+    let matched = if sort || all {
+        config
+            .font_sort(&pattern, &fonts, !all)
+            .into_iter()
+            .map(|font| config.render_prepare(&pattern, font))
+            .collect()
+    } else {
+        config
+            .font_match(&pattern, &fonts)
+            .into_iter()
+            .collect::<Vec<_>>()
+    };
+
+    let format = match (matches.value('f'), &objects) {
+        (Some(format), _) => Some(format),
+        _ if verbose || brief => None,
+        (None, Some(_)) => Some("%{=unparse}\n"),
+        (None, None) => Some("%{=fcmatch}\n"),
+    };
+
+    output(
+        &matched
+            .iter()
+            .map(|font| {
+                let font = match &objects {
+                    Some(objects) => font.filter(objects),
+                    None => font.clone(),
+                };
+                format_font(&font, format, brief)
+            })
+            .collect::<String>(),
+    );
+}
@@ -0,0 +1,49 @@
+//! `fc-pattern`, show a pattern after config and default substitution
+
+mod common;
+
+use common::*;
+
+const COMMAND: Command = Command {
+    name: "fc-pattern",
+    args: "[pattern] {element...}",
+    about: "List best font matching [pattern]",
+    opts: &[
+        Opt {
+            short: 'c',
+            long: "config",
+            value: None,
+            help: "perform config substitution on pattern",
+        },
+        Opt {
+            short: 'd',
+            long: "default",
+            value: None,
+            help: "perform default substitution on pattern",
+        },
+        FORMAT,
+        VERSION,
+        HELP,
+    ],
+};
+
+fn main() {
+    let matches = COMMAND.parse();
+
+    let mut pattern = parse_pattern(COMMAND.name, matches.free.first());
+    let objects = parse_objects(matches.free.get(1..).unwrap_or_default());
+
+    if matches.flag('c') {
+        load_config(COMMAND.name).config_substitute(&mut pattern);
+    }
+
+    if matches.flag('d') {
+        pattern.default_substitute();
+    }
+
+    if let Some(objects) = objects {
+        pattern = pattern.filter(&objects);
+    }
+
+    output(&format_font(&pattern, matches.value('f'), false));
+}
@@ -0,0 +1,575 @@
+//! Reading libfontconfig binary caches like `FcDirCacheLoadFile` and finding them like `FcDirCacheBasenameMD5`
+//!
+//! Only caches written for little endian 64 bit machines (`*-le64.cache-*`) are supported.
+//! Offsets in the file are relative to the structure which holds them, pointers to values
+//! are stored as offsets with the lowest bit set.
+
+use crate::eval::number;
+use crate::*;
+
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+
+/// `FC_CACHE_MAGIC_MMAP`
+const MAGIC: u32 = 0xFC02FC04;
+
+/// Names of `FcObject` ids starting from 1, later versions only append to it
+pub(crate) const OBJECTS: &[&str] = &[
+    "family",
+    "familylang",
+    "style",
+    "stylelang",
+    "fullname",
+    "fullnamelang",
+    "slant",
+    "weight",
+    "width",
+    "size",
+    "aspect",
+    "pixelsize",
+    "spacing",
+    "foundry",
+    "antialias",
+    "hintstyle",
+    "hinting",
+    "verticallayout",
+    "autohint",
+    "globaladvance",
+    "file",
+    "index",
+    "rasterizer",
+    "outline",
+    "scalable",
+    "dpi",
+    "rgba",
+    "scale",
+    "minspace",
+    "charwidth",
+    "charheight",
+    "matrix",
+    "charset",
+    "lang",
+    "fontversion",
+    "capability",
+    "fontformat",
+    "embolden",
+    "embeddedbitmap",
+    "decorative",
+    "lcdfilter",
+    "namelang",
+    "fontfeatures",
+    "prgname",
+    "hash",
+    "postscriptname",
+    "color",
+    "symbol",
+    "fontvariations",
+    "variable",
+    "fonthashint",
+    "order",
+];
+
+/// Languages of each bit of a cached `FcLangSet`, in order of fontconfig's orthography list
+pub(crate) const LANGS: &[&str] = &[
+    "aa", "ab", "af", "am", "ar", "as", "ast", "av", "ay", "az-az", "az-ir", "ba", "bm", "be",
+    "bg", "bh", "bho", "bi", "bin", "bn", "bo", "br", "bs", "bua", "ca", "ce", "ch", "chm", "chr",
+    "co", "cs", "cu", "cv", "cy", "da", "de", "dz", "el", "en", "eo", "es", "et", "eu", "fa", "fi",
+    "fj", "fo", "fr", "ff", "fur", "fy", "ga", "gd", "gez", "gl", "gn", "gu", "gv", "ha", "haw",
+    "he", "hi", "ho", "hr", "hu", "hy", "ia", "ig", "id", "ie", "ik", "io", "is", "it", "iu", "ja",
+    "ka", "kaa", "ki", "kk", "kl", "km", "kn", "ko", "kok", "ks", "ku-am", "ku-ir", "kum", "kv",
+    "kw", "ky", "la", "lb", "lez", "ln", "lo", "lt", "lv", "mg", "mh", "mi", "mk", "ml", "mn-cn",
+    "mo", "mr", "mt", "my", "nb", "nds", "ne", "nl", "nn", "no", "nr", "nso", "ny", "oc", "om",
+    "or", "os", "pa", "pl", "ps-af", "ps-pk", "pt", "rm", "ro", "ru", "sa", "sah", "sco", "se",
+    "sel", "sh", "shs", "si", "sk", "sl", "sm", "sma", "smj", "smn", "sms", "so", "sq", "sr", "ss",
+    "st", "sv", "sw", "syr", "ta", "te", "tg", "th", "ti-er", "ti-et", "tig", "tk", "tl", "tn",
+    "to", "tr", "ts", "tt", "tw", "tyv", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "vot", "wa",
+    "wen", "wo", "xh", "yap", "yi", "yo", "zh-cn", "zh-hk", "zh-mo", "zh-sg", "zh-tw", "zu", "ak",
+    "an", "ber-dz", "ber-ma", "byn", "crh", "csb", "dv", "ee", "fat", "fil", "hne", "hsb", "ht",
+    "hz", "ii", "jv", "kab", "kj", "kr", "ku-iq", "ku-tr", "kwm", "lg", "li", "mai", "mn-mn", "ms",
+    "na", "ng", "nv", "ota", "pa-pk", "pap-an", "pap-aw", "qu", "quz", "rn", "rw", "sc", "sd",
+    "sg", "sid", "sn", "su", "ty", "wal", "za", "lah", "nqo", "brx", "sat", "doi", "mni",
+    "und-zsye", "und-zmth",
+];
+
+/// Contents of a libfontconfig cache file, see [`FcCache::read`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FcCache {
+    /// `FC_CACHE_VERSION_NUMBER` of fontconfig which wrote the cache
+    pub version: u32,
+    /// Font directory of the cache
+    pub dir: PathBuf,
+    /// Subdirectories of [`dir`](Self::dir) which have their own cache
+    pub subdirs: Vec<PathBuf>,
+    /// Every font found in [`dir`](Self::dir)
+    pub fonts: Vec<Pattern>,
+    /// Seconds part of modification time of [`dir`](Self::dir) when the cache was written
+    pub checksum: i32,
+    /// Nanoseconds part of modification time of [`dir`](Self::dir)
+    pub checksum_nano: i64,
+}
+
+impl FcCache {
+    /// Cache versions which can be read, fontconfig 2.12 to 2.14
+    pub const SUPPORTED_VERSIONS: &'static [u32] = &[7, 8];
+
+    /// Cache version written by fontconfig 2.13.91 to 2.14, used by [`DirData::cache_basename`]
+    pub const VERSION: u32 = 8;
+
+    /// Read a cache file
+    pub fn read<P: AsRef<Path> + ?Sized>(path: &P) -> Result<Self> {
+        Self::parse(&std::fs::read(path)?)
+    }
+
+    /// Parse cache file contents
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let reader = Reader(data);
+
+        if reader.u32(0)? != MAGIC {
+            return Err(invalid("magic number doesn't match"));
+        }
+
+        let version = reader.u32(4)?;
+
+        if !Self::SUPPORTED_VERSIONS.contains(&version) {
+            return Err(invalid(&format!("version {} isn't supported", version)));
+        }
+
+        if reader.i64(8)? != data.len() as i64 {
+            return Err(invalid("size doesn't match file length"));
+        }
+
+        let dir = reader.path(reader.offset(0, 16)?)?;
+
+        let dirs = reader.offset(0, 24)?;
+        let subdirs = (0..reader.count(32)?)
+            .map(|idx| reader.path(reader.offset(dirs, dirs + idx * 8)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        let set = reader.offset(0, 40)?;
+        let font_offsets = reader.encoded(set, set + 8)?;
+        let fonts = (0..reader.count(set)?)
+            .map(|idx| reader.pattern(reader.encoded(set, font_offsets + idx * 8)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            version,
+            dir,
+            subdirs,
+            fonts,
+            checksum: reader.i32(48)?,
+            checksum_nano: reader.i64(56)?,
+        })
+    }
+
+    /// Whether modification time of [`dir`](Self::dir) still matches the cache like `FcCacheTimeValid`
+    ///
+    /// A cache of a directory which can't be read is outdated.
+    pub fn is_up_to_date(&self) -> bool {
+        let modified = match std::fs::metadata(&self.dir).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return false,
+        };
+
+        let (secs, nanos) = match modified.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() as i64, since.subsec_nanos() as i64),
+            Err(before) => (-(before.duration().as_secs() as i64), 0),
+        };
+
+        self.checksum == secs as i32 && self.checksum_nano == nanos
+    }
+}
+
+impl FontConfig {
+    /// Read every little endian 64 bit cache of a supported version found in [`cache_dirs`](Self::cache_dirs)
+    ///
+    /// Files which can't be read or parsed are skipped.
+    pub fn read_fc_caches(&self) -> Vec<FcCache> {
+        let mut caches = Vec::new();
+
+        for dir in self.cache_dirs.iter() {
+            let mut files = match std::fs::read_dir(dir) {
+                Ok(entries) => entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| is_cache_file_name(path))
+                    .collect::<Vec<_>>(),
+                Err(_) => continue,
+            };
+
+            files.sort();
+
+            caches.extend(files.iter().filter_map(|file| FcCache::read(file).ok()));
+        }
+
+        caches
+    }
+}
+
+#[cfg(feature = "cache")]
+impl FontConfig {
+    /// Paths where libfontconfig looks for the cache of `dir`, for each of [`cache_dirs`](Self::cache_dirs)
+    /// and each of [`FcCache::SUPPORTED_VERSIONS`] from the newest
+    ///
+    /// `dir` is mapped by [`remap_dirs`](Self::remap_dirs), see [`DirData::cache_basename`].
+    pub fn cache_files(&self, dir: &DirData) -> Vec<PathBuf> {
+        let hash = dir.cache_hash(&self.remap_dirs);
+
+        self.cache_dirs
+            .iter()
+            .flat_map(|cache_dir| {
+                let hash = &hash;
+
+                FcCache::SUPPORTED_VERSIONS
+                    .iter()
+                    .rev()
+                    .map(move |version| cache_dir.join(format!("{}-le64.cache-{}", hash, version)))
+            })
+            .collect()
+    }
+
+    /// First cache of [`cache_files`](Self::cache_files) which can be read and is
+    /// [up to date](FcCache::is_up_to_date)
+    pub fn read_fc_cache(&self, dir: &DirData) -> Option<FcCache> {
+        self.cache_files(dir)
+            .iter()
+            .filter_map(|file| FcCache::read(file).ok())
+            .find(FcCache::is_up_to_date)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl DirData {
+    /// Name of libfontconfig cache file of this directory like `FcDirCacheBasenameMD5`
+    ///
+    /// The name is MD5 of the path followed by [`salt`](Self::salt). When the path is in one of `remap_dirs`,
+    /// it's replaced by [`as_path`](RemapDirData::as_path) first and salt of the remap dir is used if this
+    /// directory has none. Subdirectories of a `<dir>` should have the salt of that `<dir>`.
+    pub fn cache_basename(&self, remap_dirs: &[RemapDirData]) -> String {
+        format!(
+            "{}-le64.cache-{}",
+            self.cache_hash(remap_dirs),
+            FcCache::VERSION
+        )
+    }
+
+    pub(crate) fn cache_hash(&self, remap_dirs: &[RemapDirData]) -> String {
+        let path = canonical_path(&self.path);
+        let remapped = remap_dirs.iter().find_map(|remap| {
+            let rest = path.strip_prefix(canonical_path(&remap.path)).ok()?;
+            Some((canonical_path(&Path::new(&remap.as_path).join(rest)), remap))
+        });
+
+        let (path, salt) = match remapped {
+            Some((path, remap)) if self.salt.is_empty() => (path, remap.salt.as_str()),
+            Some((path, _)) => (path, self.salt.as_str()),
+            None => (path, self.salt.as_str()),
+        };
+
+        let mut key = path_bytes(&path);
+        key.extend_from_slice(salt.as_bytes());
+
+        format!("{:x}", md5::compute(key))
+    }
+}
+
+/// Path without `.`, `..`, repeated and trailing separators like `FcStrCanonFilename`
+#[cfg(feature = "cache")]
+fn canonical_path(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut canonical = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                canonical.pop();
+            }
+            component => canonical.push(component),
+        }
+    }
+
+    canonical
+}
+
+#[cfg(all(feature = "cache", unix))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(all(feature = "cache", not(unix)))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+fn is_cache_file_name(path: &Path) -> bool {
+    let name = match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name,
+        None => return false,
+    };
+
+    FcCache::SUPPORTED_VERSIONS
+        .iter()
+        .any(|version| name.ends_with(&format!("-le64.cache-{}", version)))
+}
+
+fn invalid(msg: &str) -> Error {
+    Error::InvalidCache(msg.into())
+}
+
+// `FcType`
+const TYPE_INTEGER: i32 = 1;
+const TYPE_DOUBLE: i32 = 2;
+const TYPE_STRING: i32 = 3;
+const TYPE_BOOL: i32 = 4;
+const TYPE_MATRIX: i32 = 5;
+const TYPE_CHARSET: i32 = 6;
+const TYPE_LANGSET: i32 = 8;
+const TYPE_RANGE: i32 = 9;
+
+/// Bounds checked reads of little endian values
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N]> {
+        offset
+            .checked_add(N)
+            .and_then(|end| self.0.get(offset..end))
+            .map(|b| {
+                let mut bytes = [0; N];
+                bytes.copy_from_slice(b);
+                bytes
+            })
+            .ok_or_else(|| invalid("offset is out of bounds"))
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32> {
+        self.bytes(offset).map(u32::from_le_bytes)
+    }
+
+    fn i32(&self, offset: usize) -> Result<i32> {
+        self.bytes(offset).map(i32::from_le_bytes)
+    }
+
+    fn i64(&self, offset: usize) -> Result<i64> {
+        self.bytes(offset).map(i64::from_le_bytes)
+    }
+
+    fn f64(&self, offset: usize) -> Result<f64> {
+        self.bytes(offset).map(f64::from_le_bytes)
+    }
+
+    /// Non negative `int` used as count of items
+    fn count(&self, offset: usize) -> Result<usize> {
+        usize::try_from(self.i32(offset)?).map_err(|_| invalid("count is negative"))
+    }
+
+    /// Position of `base` moved by the `intptr_t` offset at `at`
+    fn offset(&self, base: usize, at: usize) -> Result<usize> {
+        let offset = self.i64(at)?;
+
+        i64::try_from(base)
+            .ok()
+            .and_then(|base| base.checked_add(offset))
+            .and_then(|pos| usize::try_from(pos).ok())
+            .ok_or_else(|| invalid("offset is out of bounds"))
+    }
+
+    /// Same as [`offset`](Self::offset) for a pointer stored as offset with lowest bit set
+    fn encoded(&self, base: usize, at: usize) -> Result<usize> {
+        if self.i64(at)? & 1 == 0 {
+            return Err(invalid("pointer isn't an offset"));
+        }
+
+        self.offset(base, at).map(|pos| pos & !1)
+    }
+
+    /// Bytes of NUL terminated string
+    fn c_str(&self, offset: usize) -> Result<&'a [u8]> {
+        let rest = self
+            .0
+            .get(offset..)
+            .ok_or_else(|| invalid("offset is out of bounds"))?;
+        let len = rest
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| invalid("string isn't terminated"))?;
+
+        Ok(&rest[..len])
+    }
+
+    fn str(&self, offset: usize) -> Result<String> {
+        Ok(String::from_utf8_lossy(self.c_str(offset)?).into_owned())
+    }
+
+    #[cfg(unix)]
+    fn path(&self, offset: usize) -> Result<PathBuf> {
+        use std::os::unix::ffi::OsStrExt;
+
+        Ok(std::ffi::OsStr::from_bytes(self.c_str(offset)?).into())
+    }
+
+    #[cfg(not(unix))]
+    fn path(&self, offset: usize) -> Result<PathBuf> {
+        self.str(offset).map(Into::into)
+    }
+
+    /// `FcPattern`
+    fn pattern(&self, offset: usize) -> Result<Pattern> {
+        let mut pattern = Pattern::new();
+        let elts = self.offset(offset, offset + 8)?;
+
+        for idx in 0..self.count(offset)? {
+            let elt = elts + idx * 16;
+            let object = self.i32(elt)?;
+            let kind = match object
+                .checked_sub(1)
+                .and_then(|id| usize::try_from(id).ok())
+                .and_then(|id| OBJECTS.get(id))
+            {
+                Some(name) => name.parse::<PropertyKind>()?,
+                // objects added by newer fontconfig
+                None => continue,
+            };
+
+            let mut list = self.encoded(elt, elt + 8)?;
+
+            loop {
+                if let Some(value) = self.value(list + 8)? {
+                    let binding = match self.i32(list + 24)? {
+                        0 => EditBinding::Weak,
+                        1 => EditBinding::Strong,
+                        _ => EditBinding::Same,
+                    };
+                    pattern.add_with_binding(kind.clone(), value, binding);
+                }
+
+                if self.i64(list)? == 0 {
+                    break;
+                }
+
+                let next = self.encoded(list, list)?;
+                // a corrupt list could loop forever
+                if next <= list {
+                    return Err(invalid("value list doesn't move forward"));
+                }
+                list = next;
+            }
+        }
+
+        Ok(pattern)
+    }
+
+    /// `FcValue`, values of types which the crate doesn't have are skipped
+    fn value(&self, offset: usize) -> Result<Option<Value>> {
+        let u = offset + 8;
+
+        let value = match self.i32(offset)? {
+            TYPE_INTEGER => Value::Int(self.i32(u)? as Int),
+            TYPE_DOUBLE => number(self.f64(u)?),
+            TYPE_STRING => Value::String(self.str(self.encoded(offset, u)?)?),
+            TYPE_BOOL => Value::Bool(self.i32(u)? != 0),
+            TYPE_MATRIX => {
+                let matrix = self.encoded(offset, u)?;
+                Value::Matrix([
+                    self.f64(matrix)?,
+                    self.f64(matrix + 8)?,
+                    self.f64(matrix + 16)?,
+                    self.f64(matrix + 24)?,
+                ])
+            }
+            TYPE_CHARSET => Value::CharSet(self.charset(self.encoded(offset, u)?)?),
+            TYPE_LANGSET => Value::LangSet(self.langset(self.encoded(offset, u)?)?),
+            TYPE_RANGE => {
+                let range = self.encoded(offset, u)?;
+                Value::Range(
+                    self.f64(range)?.round() as Int,
+                    self.f64(range + 8)?.round() as Int,
+                )
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(value))
+    }
+
+    /// `FcCharSet`, each leaf has 256 bits and the upper bits of its characters are in `numbers`
+    fn charset(&self, offset: usize) -> Result<CharSet> {
+        let leaves = self.offset(offset, offset + 8)?;
+        let numbers = self.offset(offset, offset + 16)?;
+        let mut ranges: Vec<(Int, Int)> = Vec::new();
+
+        for idx in 0..self.count(offset + 4)? {
+            let leaf = self.offset(leaves, leaves + idx * 8)?;
+            let high = u16::from_le_bytes(self.bytes(numbers + idx * 2)?) as Int;
+
+            for word in 0..8 {
+                let bits = self.u32(leaf + word * 4)?;
+
+                for bit in 0..32 {
+                    if bits & (1 << bit) == 0 {
+                        continue;
+                    }
+
+                    let c = (high << 8) | (word as Int * 32 + bit);
+
+                    match ranges.last_mut() {
+                        Some((_, end)) if *end + 1 == c => *end = c,
+                        _ => ranges.push((c, c)),
+                    }
+                }
+            }
+        }
+
+        Ok(CharSet::from_ranges(ranges))
+    }
+
+    /// `FcLangSet`, languages which aren't in fontconfig's table aren't cached
+    fn langset(&self, offset: usize) -> Result<LangSet> {
+        let map_size = self.u32(offset + 8)? as usize;
+        let mut langs = LangSet::new();
+
+        for word in 0..map_size {
+            let bits = self.u32(offset + 12 + word * 4)?;
+
+            for bit in 0..32 {
+                if bits & (1 << bit) != 0 {
+                    if let Some(lang) = LANGS.get(word * 32 + bit) {
+                        langs.insert(lang);
+                    }
+                }
+            }
+        }
+
+        Ok(langs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn canonical_paths() {
+        k9::assert_equal!(
+            canonical_path(Path::new("/usr//share/./fonts/../fonts/")),
+            Path::new("/usr/share/fonts")
+        );
+    }
+
+    #[test]
+    fn cache_file_names() {
+        assert!(is_cache_file_name(Path::new(
+            "/var/cache/fontconfig/3830d5c3ddfd5cd38a049b759396e72e-le64.cache-8"
+        )));
+        assert!(is_cache_file_name(Path::new(
+            "3830d5c3ddfd5cd38a049b759396e72e-le64.cache-7"
+        )));
+        assert!(!is_cache_file_name(Path::new(
+            "3830d5c3ddfd5cd38a049b759396e72e-be32d4.cache-8"
+        )));
+        assert!(!is_cache_file_name(Path::new("CACHEDIR.TAG")));
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 use std::num::ParseFloatError;
 use std::num::ParseIntError;
+use std::path::PathBuf;
+
+use crate::Span;
 use std::str::ParseBoolError;
 
 #[derive(Debug)]
@@ -13,6 +16,53 @@ pub enum Error {
     ParseIntError(ParseIntError),
     ParseFloatError(ParseFloatError),
     ParseBoolError(ParseBoolError),
+    /// Include chain which loads same file again, last path is the repeated file
+    IncludeCycle(Vec<PathBuf>),
+    /// Include chain which is longer than [`MergeOptions::max_include_depth`](crate::MergeOptions::max_include_depth)
+    IncludeTooDeep(Vec<PathBuf>),
+    /// Element which isn't part of fontconfig format, only returned in strict mode
+    UnknownElement(String),
+    /// Element name and attribute which the element doesn't have, only returned in strict mode
+    UnknownAttribute(String, String),
+    /// Font file which can't be scanned
+    InvalidFont(String),
+    /// libfontconfig cache file which can't be read
+    InvalidCache(String),
+    /// Pattern format string which can't be interpreted
+    InvalidPatternFormat(String),
+    /// Error caused by the element at [`Span`]
+    Located(Span, Box<Error>),
+}
+
+impl Error {
+    /// Whether error is caused by include chain itself rather than an included file
+    pub(crate) fn is_include_error(&self) -> bool {
+        matches!(self, Error::IncludeCycle(_) | Error::IncludeTooDeep(_))
+    }
+
+    /// Whether error is only returned in strict mode, these aren't ignored in included files either
+    pub(crate) fn is_strict_error(&self) -> bool {
+        matches!(
+            self.inner(),
+            Error::UnknownElement(_) | Error::UnknownAttribute(..)
+        )
+    }
+
+    /// Location of the element which caused this error
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Error::Located(span, _) => Some(span),
+            _ => None,
+        }
+    }
+
+    /// Error without location
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Located(_, e) => e.inner(),
+            e => e,
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -56,8 +106,35 @@ impl fmt::Display for Error {
             Error::ParseIntError(e) => e.fmt(f),
             Error::ParseFloatError(e) => e.fmt(f),
             Error::ParseBoolError(e) => e.fmt(f),
+            Error::IncludeCycle(chain) => {
+                write!(f, "Include cycle detected: ")?;
+                write_chain(f, chain)
+            }
+            Error::IncludeTooDeep(chain) => {
+                write!(f, "Include depth exceeded: ")?;
+                write_chain(f, chain)
+            }
+            Error::UnknownElement(name) => write!(f, "Unknown element <{}>", name),
+            Error::UnknownAttribute(name, attr) => {
+                write!(f, "Unknown attribute {} of <{}>", attr, name)
+            }
+            Error::InvalidFont(msg) => write!(f, "Font file is invalid: {}", msg),
+            Error::InvalidCache(msg) => write!(f, "Cache file is invalid: {}", msg),
+            Error::InvalidPatternFormat(msg) => write!(f, "Pattern format error: {}", msg),
+            Error::Located(span, e) => write!(f, "{}: {}", span, e),
         }
     }
 }
 
+fn write_chain(f: &mut fmt::Formatter<'_>, chain: &[PathBuf]) -> fmt::Result {
+    for (idx, path) in chain.iter().enumerate() {
+        if idx != 0 {
+            write!(f, " -> ")?;
+        }
+        write!(f, "{}", path.display())?;
+    }
+
+    Ok(())
+}
+
 impl std::error::Error for Error {}
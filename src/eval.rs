@@ -0,0 +1,522 @@
+use crate::*;
+
+use std::borrow::Cow;
+
+/// Patterns which an [`Expression`] is evaluated against
+#[derive(Clone, Copy, Debug)]
+pub struct EvalContext<'a> {
+    /// Target of the running `<match>`
+    pub target: MatchTarget,
+    /// Pattern being edited
+    pub pattern: &'a Pattern,
+    /// Original query pattern, only exists for `<match target="font">`
+    pub original: Option<&'a Pattern>,
+    /// Property which the result is used for, needed to resolve `<const>`
+    pub kind: &'a PropertyKind,
+}
+
+impl<'a> EvalContext<'a> {
+    fn property(&self, target: PropertyTarget, kind: &PropertyKind) -> Option<&'a Value> {
+        let pattern = match (self.target, target) {
+            (MatchTarget::Font, PropertyTarget::Pattern) => self.original?,
+            // `<name target="font">` has no meaning in a pattern match
+            (MatchTarget::Pattern, PropertyTarget::Font) => return None,
+            _ => self.pattern,
+        };
+
+        pattern.get(kind)?.first().map(|v| &v.value)
+    }
+}
+
+impl Expression {
+    /// Evaluate expression like `FcConfigEvaluate`, `None` means the result is void
+    ///
+    /// Integers are promoted to double for arithmetic, and results without fraction become integer again.
+    /// List operators fold from the right like fontconfig does, so `<minus>a b c</minus>` is `a - (b - c)`.
+    pub fn evaluate(&self, ctx: &EvalContext) -> Option<Value> {
+        match self {
+            Expression::Simple(value) => value.evaluate(ctx),
+            Expression::Unary(op, expr) => match (op, expr.evaluate(ctx)?) {
+                (UnaryOp::Not, Value::Bool(b)) => Some(Value::Bool(!b)),
+                (UnaryOp::Not, _) => None,
+                (_, Value::Int(i)) => Some(Value::Int(i)),
+                (op, Value::Double(d)) => Some(number(match op {
+                    UnaryOp::Cecil => d.ceil(),
+                    UnaryOp::Floor => d.floor(),
+                    UnaryOp::Round => (d + 0.5).floor(),
+                    UnaryOp::Trunc | UnaryOp::Not => d.trunc(),
+                })),
+                _ => None,
+            },
+            Expression::Binary(op, exprs) => {
+                let [left, right] = &**exprs;
+                let left = left.evaluate(ctx);
+                let right = right.evaluate(ctx);
+
+                Some(Value::Bool(compare_value(
+                    left.as_ref(),
+                    test_compare(*op),
+                    right.as_ref(),
+                )))
+            }
+            Expression::Ternary(TernaryOp::If, exprs) => {
+                let [cond, then, otherwise] = &**exprs;
+
+                match cond.evaluate(ctx)? {
+                    Value::Bool(true) => then.evaluate(ctx),
+                    Value::Bool(false) => otherwise.evaluate(ctx),
+                    _ => None,
+                }
+            }
+            Expression::List(op, exprs) => {
+                let mut values = exprs.iter().rev().map(|expr| expr.evaluate(ctx));
+                let last = values.next()?;
+
+                values.fold(last, |right, left| list_op(*op, left, right))
+            }
+            Expression::Matrix(exprs) => {
+                if exprs.len() != 4 {
+                    return None;
+                }
+
+                let mut matrix = IDENTITY_MATRIX;
+
+                for (expr, v) in exprs.iter().zip(matrix.iter_mut()) {
+                    *v = match expr.evaluate(ctx)? {
+                        Value::Int(i) => i as Double,
+                        Value::Double(d) => d,
+                        _ => return None,
+                    };
+                }
+
+                Some(Value::Matrix(matrix))
+            }
+        }
+    }
+}
+
+impl Value {
+    fn evaluate(&self, ctx: &EvalContext) -> Option<Value> {
+        match self {
+            Value::Constant(c) => c.get_value(ctx.kind.clone()).map(Value::Int),
+            Value::Property(target, kind) => ctx.property(*target, kind).cloned(),
+            value => Some(value.clone()),
+        }
+    }
+}
+
+fn test_compare(op: BinaryOp) -> TestCompare {
+    match op {
+        BinaryOp::Eq => TestCompare::Eq,
+        BinaryOp::NotEq => TestCompare::NotEq,
+        BinaryOp::Less => TestCompare::Less,
+        BinaryOp::LessEq => TestCompare::LessEq,
+        BinaryOp::More => TestCompare::More,
+        BinaryOp::MoreEq => TestCompare::MoreEq,
+        BinaryOp::Contains => TestCompare::Contains,
+        BinaryOp::NotContains => TestCompare::NotContains,
+    }
+}
+
+/// Arithmetic result, integer when it has no fraction
+pub(crate) fn number(d: Double) -> Value {
+    if d.fract() == 0.0 && d >= 0.0 && d <= Int::MAX as Double {
+        Value::Int(d as Int)
+    } else {
+        Value::Double(d)
+    }
+}
+
+fn list_op(op: ListOp, left: Option<Value>, right: Option<Value>) -> Option<Value> {
+    let left = Operand::new(left.as_ref());
+    let right = Operand::new(right.as_ref());
+
+    match (left.promote(&right), right.promote(&left)) {
+        (Operand::Double(l), Operand::Double(r)) => match op {
+            ListOp::Plus => Some(number(l + r)),
+            ListOp::Minus => Some(number(l - r)),
+            ListOp::Times => Some(number(l * r)),
+            ListOp::Divide => Some(number(l / r)),
+            ListOp::Or | ListOp::And => None,
+        },
+        (Operand::Bool(l), Operand::Bool(r)) => match op {
+            ListOp::Or => Some(Value::Bool(l || r)),
+            ListOp::And => Some(Value::Bool(l && r)),
+            _ => None,
+        },
+        (Operand::String(l), Operand::String(r)) => match op {
+            ListOp::Plus => Some(Value::String(format!("{}{}", l, r))),
+            _ => None,
+        },
+        (Operand::Matrix(l), Operand::Matrix(r)) => match op {
+            ListOp::Times => Some(Value::Matrix([
+                l[0] * r[0] + l[1] * r[2],
+                l[0] * r[1] + l[1] * r[3],
+                l[2] * r[0] + l[3] * r[2],
+                l[2] * r[1] + l[3] * r[3],
+            ])),
+            _ => None,
+        },
+        (Operand::LangSet(l), Operand::LangSet(r)) => match op {
+            ListOp::Plus => Some(Value::LangSet(l.union(&r))),
+            ListOp::Minus => Some(Value::LangSet(l.subtract(&r))),
+            _ => None,
+        },
+        (Operand::CharSet(l), Operand::CharSet(r)) => match op {
+            ListOp::Plus => Some(Value::CharSet(l.union(&r))),
+            ListOp::Minus => Some(Value::CharSet(l.subtract(&r))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+const IDENTITY_MATRIX: Matrix = [1.0, 0.0, 0.0, 1.0];
+
+/// Values after `FcConfigPromote`
+#[derive(Clone)]
+enum Operand<'a> {
+    Void,
+    Double(Double),
+    Bool(Bool),
+    String(&'a str),
+    Range(Double, Double),
+    LangSet(Cow<'a, LangSet>),
+    CharSet(Cow<'a, CharSet>),
+    Matrix(&'a Matrix),
+}
+
+impl<'a> Operand<'a> {
+    fn new(value: Option<&'a Value>) -> Self {
+        match value {
+            Some(Value::Int(i)) => Operand::Double(*i as Double),
+            Some(Value::Double(d)) => Operand::Double(*d),
+            Some(Value::Bool(b)) => Operand::Bool(*b),
+            Some(Value::String(s)) => Operand::String(s),
+            Some(Value::Range(start, end)) => Operand::Range(*start as Double, *end as Double),
+            Some(Value::LangSet(l)) => Operand::LangSet(Cow::Borrowed(l)),
+            Some(Value::CharSet(c)) => Operand::CharSet(Cow::Borrowed(c)),
+            Some(Value::Matrix(m)) => Operand::Matrix(m),
+            Some(Value::Constant(_)) | Some(Value::Property(..)) | None => Operand::Void,
+        }
+    }
+
+    fn promote(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Operand::Double(d), Operand::Range(..)) => Operand::Range(*d, *d),
+            (Operand::String(s), Operand::LangSet(_)) => {
+                Operand::LangSet(Cow::Owned(LangSet::from(*s)))
+            }
+            (Operand::Void, Operand::LangSet(_)) => Operand::LangSet(Cow::Owned(LangSet::new())),
+            (Operand::Void, Operand::CharSet(_)) => Operand::CharSet(Cow::Owned(CharSet::new())),
+            (Operand::Void, Operand::Matrix(_)) => Operand::Matrix(&IDENTITY_MATRIX),
+            (v, _) => v.clone(),
+        }
+    }
+}
+
+/// Compare a pattern value with a test value like `FcConfigCompareValue`
+pub(crate) fn compare_value(
+    left: Option<&Value>,
+    compare: TestCompare,
+    right: Option<&Value>,
+) -> bool {
+    compare_value_with(left, compare, right, false)
+}
+
+/// Same as [`compare_value`], `ignore_blanks` makes string equality ignore blanks like `ignore-blanks`
+pub(crate) fn compare_value_with(
+    left: Option<&Value>,
+    compare: TestCompare,
+    right: Option<&Value>,
+    ignore_blanks: bool,
+) -> bool {
+    let str_eq = if ignore_blanks {
+        str_eq_ignore_blanks_and_case
+    } else {
+        str_eq_ignore_case
+    };
+
+    let left = Operand::new(left);
+    let right = Operand::new(right);
+
+    let (left, right) = (left.promote(&right), right.promote(&left));
+
+    match (left, right) {
+        (Operand::Void, Operand::Void) => {
+            matches!(compare, TestCompare::Eq | TestCompare::Contains)
+        }
+        (Operand::Double(l), Operand::Double(r)) => compare_ord(l, compare, r),
+        (Operand::Bool(l), Operand::Bool(r)) => compare_ord(l, compare, r),
+        (Operand::Range(ls, le), Operand::Range(rs, re)) => {
+            compare_range((ls, le), compare, (rs, re))
+        }
+        (Operand::String(l), Operand::String(r)) => match compare {
+            TestCompare::Eq => str_eq(l, r),
+            TestCompare::NotEq => !str_eq(l, r),
+            TestCompare::Contains => str_contains_ignore_case(l, r),
+            TestCompare::NotContains => !str_contains_ignore_case(l, r),
+            _ => false,
+        },
+        (Operand::LangSet(l), Operand::LangSet(r)) => match compare {
+            TestCompare::Eq => l == r,
+            TestCompare::NotEq => l != r,
+            TestCompare::Contains => l.contains(&r),
+            TestCompare::NotContains => !l.contains(&r),
+            _ => false,
+        },
+        (Operand::CharSet(l), Operand::CharSet(r)) => match compare {
+            TestCompare::Eq => l == r,
+            TestCompare::NotEq => l != r,
+            TestCompare::Contains => l.is_superset(&r),
+            TestCompare::NotContains => !l.is_superset(&r),
+            _ => false,
+        },
+        (Operand::Matrix(l), Operand::Matrix(r)) => match compare {
+            TestCompare::Eq | TestCompare::Contains => l == r,
+            TestCompare::NotEq | TestCompare::NotContains => l != r,
+            _ => false,
+        },
+        _ => matches!(compare, TestCompare::NotEq | TestCompare::NotContains),
+    }
+}
+
+fn compare_ord<T: PartialOrd>(l: T, compare: TestCompare, r: T) -> bool {
+    match compare {
+        TestCompare::Eq | TestCompare::Contains => l == r,
+        TestCompare::NotEq | TestCompare::NotContains => l != r,
+        TestCompare::Less => l < r,
+        TestCompare::LessEq => l <= r,
+        TestCompare::More => l > r,
+        TestCompare::MoreEq => l >= r,
+    }
+}
+
+/// Same as `FcRangeCompare`
+fn compare_range(l: (Double, Double), compare: TestCompare, r: (Double, Double)) -> bool {
+    match compare {
+        TestCompare::Eq => l.0 == r.0 && l.1 == r.1,
+        TestCompare::NotEq => l.0 != r.0 || l.1 != r.1,
+        TestCompare::Contains => l.0 <= r.0 && l.1 >= r.1,
+        TestCompare::NotContains => l.0 > r.0 || l.1 < r.1,
+        TestCompare::Less => l.1 < r.0,
+        TestCompare::LessEq => l.1 <= r.0,
+        TestCompare::More => l.0 > r.1,
+        TestCompare::MoreEq => l.0 >= r.1,
+    }
+}
+
+fn fold_case(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+fn str_eq_ignore_case(l: &str, r: &str) -> bool {
+    fold_case(l).eq(fold_case(r))
+}
+
+/// Same as `FcStrCmpIgnoreBlanksAndCase`, only spaces are ignored
+pub(crate) fn str_eq_ignore_blanks_and_case(l: &str, r: &str) -> bool {
+    fold_case(l)
+        .filter(|c| *c != ' ')
+        .eq(fold_case(r).filter(|c| *c != ' '))
+}
+
+fn str_contains_ignore_case(l: &str, r: &str) -> bool {
+    fold_case(l)
+        .collect::<String>()
+        .contains(&fold_case(r).collect::<String>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    #[test]
+    fn promote_int_to_double() {
+        assert!(compare_value(
+            Some(&Value::Int(80)),
+            TestCompare::Eq,
+            Some(&Value::Double(80.0))
+        ));
+        assert!(!compare_value(
+            Some(&Value::Int(12)),
+            TestCompare::Contains,
+            Some(&Value::Range(10, 14))
+        ));
+        assert!(compare_value(
+            Some(&Value::Range(10, 14)),
+            TestCompare::Contains,
+            Some(&Value::Int(12))
+        ));
+    }
+
+    fn eval(expr: &str, pattern: &Pattern) -> Option<Value> {
+        let doc = roxmltree::Document::parse(expr).unwrap();
+        let expr = crate::parser::parse_expr(doc.root_element()).unwrap();
+        let ctx = EvalContext {
+            target: MatchTarget::Pattern,
+            pattern,
+            original: None,
+            kind: &PropertyKind::PixelSize,
+        };
+
+        expr.evaluate(&ctx)
+    }
+
+    #[test]
+    fn evaluate_operators() {
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Size, 12.0);
+        pattern.add(PropertyKind::Dpi, 96);
+
+        k9::assert_equal!(
+            eval("<times><name>size</name><name>dpi</name></times>", &pattern),
+            Some(Value::Int(1152))
+        );
+        k9::assert_equal!(
+            eval(
+                "<divide><name>size</name><double>8</double></divide>",
+                &pattern
+            ),
+            Some(Value::Double(1.5))
+        );
+        k9::assert_equal!(
+            eval(
+                "<minus><int>10</int><int>4</int><int>3</int></minus>",
+                &pattern
+            ),
+            Some(Value::Int(9))
+        );
+        k9::assert_equal!(
+            eval("<round><double>1.5</double></round>", &pattern),
+            Some(Value::Int(2))
+        );
+        k9::assert_equal!(
+            eval(
+                "<if><less><name>size</name><int>10</int></less><string>small</string><string>large</string></if>",
+                &pattern
+            ),
+            Some(Value::from("large"))
+        );
+        k9::assert_equal!(
+            eval(
+                "<and><more><name>dpi</name><int>72</int></more><not><bool>false</bool></not></and>",
+                &pattern
+            ),
+            Some(Value::Bool(true))
+        );
+        k9::assert_equal!(
+            eval(
+                "<plus><name>size</name><string>pt</string></plus>",
+                &pattern
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn evaluate_matrix() {
+        let pattern = Pattern::new();
+
+        k9::assert_equal!(
+            eval(
+                "<times><name>matrix</name><matrix><double>1</double><double>0.2</double><double>0</double><double>1</double></matrix></times>",
+                &pattern
+            ),
+            Some(Value::Matrix([1.0, 0.2, 0.0, 1.0]))
+        );
+        // a matrix without 4 elements is void
+        k9::assert_equal!(
+            eval(
+                "<matrix><int>1</int><int>0</int><int>1</int></matrix>",
+                &pattern
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn evaluate_charset() {
+        let pattern = Pattern::new();
+
+        k9::assert_equal!(
+            eval(
+                "<minus><charset><range><int>0</int><int>20</int></range></charset><charset><int>5</int><range><int>10</int><int>30</int></range></charset></minus>",
+                &pattern
+            ),
+            Some(Value::CharSet(CharSet::from_ranges(vec![(0, 4), (6, 9)])))
+        );
+    }
+
+    #[test]
+    fn compare_string() {
+        let sans = Value::from("DejaVu Sans");
+
+        assert!(compare_value(
+            Some(&sans),
+            TestCompare::Eq,
+            Some(&"dejavu sans".into())
+        ));
+        assert!(compare_value(
+            Some(&sans),
+            TestCompare::Contains,
+            Some(&"vu s".into())
+        ));
+        assert!(compare_value(
+            Some(&sans),
+            TestCompare::NotEq,
+            Some(&Value::Int(1))
+        ));
+        assert!(!compare_value(Some(&sans), TestCompare::Eq, None));
+    }
+
+    #[test]
+    fn compare_langset() {
+        let ja = Value::LangSet("ja".into());
+
+        assert!(compare_value(
+            Some(&ja),
+            TestCompare::Contains,
+            Some(&"ja-JP".into())
+        ));
+        assert!(!compare_value(
+            Some(&ja),
+            TestCompare::Contains,
+            Some(&"ko".into())
+        ));
+        assert!(compare_value(
+            Some(&Value::LangSet(LangSet::from_iter(["ja", "en_US"]))),
+            TestCompare::Eq,
+            Some(&Value::LangSet(LangSet::from_iter(["en-us", "JA"])))
+        ));
+    }
+
+    #[test]
+    fn evaluate_langset() {
+        k9::assert_equal!(
+            eval(
+                "<plus><langset><string>ja</string></langset><langset><string>en</string><string>ja</string></langset></plus>",
+                &Pattern::new()
+            ),
+            Some(Value::LangSet(LangSet::from_iter(["en", "ja"])))
+        );
+    }
+
+    #[test]
+    fn compare_charset() {
+        let charset = Value::CharSet(CharSet::from_ranges(vec![(0, 10), (11, 20)]));
+
+        assert!(compare_value(
+            Some(&charset),
+            TestCompare::Contains,
+            Some(&Value::CharSet(CharSet::from_ranges(vec![(5, 15)])))
+        ));
+        assert!(compare_value(
+            Some(&charset),
+            TestCompare::NotContains,
+            Some(&Value::CharSet(CharSet::from_ranges(vec![(21, 21)])))
+        ));
+    }
+}
@@ -0,0 +1,841 @@
+//! Pattern format strings like `FcPatternFormat` and pattern dumps like `FcPatternPrint`
+
+use crate::name::{object_order, UnescapedValues};
+use crate::*;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Output of `fc-list`
+const FCLIST: &str = "%{?file{%{file}: }}%{-file{%{=unparse}}}";
+/// Output of `fc-match`
+const FCMATCH: &str =
+    "%{file|basename}: \"%{family[0]:-<unknown family>}\" \"%{style[0]:-<unknown style>}\"";
+/// Output of `fc-cat`
+const FCCAT: &str = "\"%{file|basename|cescape}\" %{index} \"%{-file{%{=unparse|cescape}}}\"";
+/// Font provides for PackageKit
+const PKGKIT: &str = "%{[]family{font(%{family|downcase|delete( )})\n}}\
+                      %{[]lang{font(:lang=%{lang|downcase|translate(_,-)})\n}}";
+
+impl Pattern {
+    /// Format the pattern like `FcPatternFormat`
+    ///
+    /// `%{family}` gives every value of a property, `%{family[0]}` one of them and
+    /// `%{family:-default}` falls back to `default`. Subexpressions can be filtered with `%{+family{...}}`,
+    /// `%{-family{...}}`, `%{?family{then}{else}}` and `%{[]family,style{...}}`,
+    /// output can be converted like `%{file|basename}` and aligned like `%-20{family}`.
+    /// Builtins are `%{=unparse}`, `%{=fclist}`, `%{=fcmatch}`, `%{=fccat}` and `%{=pkgkit}`.
+    pub fn format(&self, format: &str) -> Result<String> {
+        let mut out = String::new();
+        FormatContext::new(format).interpret_expr(self, &mut out, None)?;
+        Ok(out)
+    }
+
+    /// Every value with its type and binding like `FcPatternPrint`
+    pub fn to_verbose_string(&self) -> String {
+        let mut out = String::new();
+        // elements are allocated 16 at a time
+        let size = (self.len() + 15) / 16 * 16;
+        writeln!(out, "Pattern has {} elts (size {})", self.len(), size).ok();
+
+        let mut props = self.iter().collect::<Vec<_>>();
+        props.sort_by_key(|&(kind, _)| (object_order(kind), kind));
+
+        for (kind, values) in props {
+            write!(out, "\t{}:", kind.name()).ok();
+
+            for value in values {
+                out.push(' ');
+                write_verbose_value(&mut out, &value.value);
+                out.push_str(match value.binding {
+                    EditBinding::Strong => "(s)",
+                    EditBinding::Weak => "(w)",
+                    EditBinding::Same => "(=)",
+                });
+            }
+
+            out.push('\n');
+        }
+
+        out.push('\n');
+        out
+    }
+}
+
+fn write_verbose_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Int(i) => write!(out, "{}(i)", i),
+        Value::Double(d) => write!(out, "{}(f)", format_g(*d)),
+        Value::String(s) => write!(out, "\"{}\"", s),
+        Value::Bool(b) => write!(out, "{}", if *b { "True" } else { "False" }),
+        Value::Range(start, end) => write!(
+            out,
+            "[{} {}]",
+            format_g(*start as Double),
+            format_g(*end as Double)
+        ),
+        Value::Matrix([xx, xy, yx, yy]) => write!(
+            out,
+            "[{} {}; {} {}]",
+            format_g(*xx),
+            format_g(*xy),
+            format_g(*yx),
+            format_g(*yy)
+        ),
+        Value::LangSet(langs) => write!(out, "{}", langs),
+        Value::CharSet(charset) => {
+            // bitmap of each 256 chars page like `FcCharSetPrint`
+            let mut pages = BTreeMap::<Int, [u32; 8]>::new();
+            for &(start, end) in charset.ranges() {
+                for c in start..=end {
+                    pages.entry(c >> 8).or_default()[(c as usize >> 5) & 7] |= 1 << (c & 31);
+                }
+            }
+
+            out.push('\n');
+            for (page, bits) in pages {
+                write!(out, "\t{:04x}:", page).ok();
+                for bits in bits.iter() {
+                    write!(out, " {:08x}", bits).ok();
+                }
+                out.push('\n');
+            }
+
+            Ok(())
+        }
+        // patterns never hold unevaluated values
+        Value::Constant(_) | Value::Property(..) => Ok(()),
+    }
+    .ok();
+}
+
+/// Same as `printf("%g")`
+fn format_g(d: Double) -> String {
+    if d == 0.0 || !d.is_finite() {
+        return d.to_string();
+    }
+
+    // exponent after rounding to 6 significant digits
+    let sci = format!("{:.5e}", d);
+    let (mantissa, exp) = sci.split_at(sci.find('e').unwrap_or(sci.len()));
+    let exp = exp[1..].parse::<i32>().unwrap_or(0);
+
+    let trim = |s: &str| {
+        if s.contains('.') {
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            s.to_string()
+        }
+    };
+
+    if !(-4..6).contains(&exp) {
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", trim(mantissa), sign, exp.abs())
+    } else {
+        trim(&format!("{:.*}", (5 - exp) as usize, d))
+    }
+}
+
+/// Same as `escaped_char` of fcformat.c
+fn escaped_char(c: char) -> char {
+    match c {
+        'a' => '\x07',
+        'b' => '\x08',
+        'f' => '\x0c',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'v' => '\x0b',
+        c => c,
+    }
+}
+
+/// Interpreter of a format string, it formats while parsing like fcformat.c
+struct FormatContext<'a> {
+    format: &'a str,
+    pos: usize,
+}
+
+impl<'a> FormatContext<'a> {
+    fn new(format: &'a str) -> Self {
+        Self { format, pos: 0 }
+    }
+
+    /// Error located at the current position
+    fn error(&self, msg: impl std::fmt::Display) -> Error {
+        Error::InvalidPatternFormat(format!("{} at {}", msg, self.pos + 1))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.format[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.consume(c) {
+            Ok(())
+        } else if self.peek().is_none() {
+            Err(Error::InvalidPatternFormat(format!(
+                "format ended while expecting '{}'",
+                c
+            )))
+        } else {
+            Err(self.error(format_args!("expected '{}'", c)))
+        }
+    }
+
+    /// Read until punctuation, used for names
+    fn read_word(&mut self) -> Result<String> {
+        let mut word = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.bump();
+                if let Some(c) = self.bump() {
+                    word.push(escaped_char(c));
+                }
+            } else if c.is_ascii_punctuation() {
+                break;
+            } else {
+                word.push(c);
+                self.bump();
+            }
+        }
+
+        if word.is_empty() {
+            return Err(self.error("expected identifier"));
+        }
+
+        Ok(word)
+    }
+
+    /// Read until `term` or the end of current expression
+    fn read_chars(&mut self, term: char) -> Result<String> {
+        let mut chars = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '}' || c == term {
+                break;
+            }
+
+            self.bump();
+            if c == '\\' {
+                if let Some(c) = self.bump() {
+                    chars.push(escaped_char(c));
+                }
+            } else {
+                chars.push(c);
+            }
+        }
+
+        if chars.is_empty() {
+            return Err(self.error("expected character data"));
+        }
+
+        Ok(chars)
+    }
+
+    /// Comma separated property names
+    fn read_kinds(&mut self) -> Result<Vec<PropertyKind>> {
+        let mut kinds = vec![self.read_word()?.parse()?];
+
+        while self.consume(',') {
+            kinds.push(self.read_word()?.parse()?);
+        }
+
+        Ok(kinds)
+    }
+
+    fn read_number(&mut self) -> Option<i64> {
+        let start = self.pos;
+        let negative = self.consume('-');
+        if !negative {
+            self.consume('+');
+        }
+
+        let digits = self.format[self.pos..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.format.len() - self.pos);
+
+        match self.format[self.pos..self.pos + digits].parse::<i64>() {
+            Ok(number) => {
+                self.pos += digits;
+                Some(if negative { -number } else { number })
+            }
+            Err(_) => {
+                self.pos = start;
+                None
+            }
+        }
+    }
+
+    fn interpret_expr(
+        &mut self,
+        pattern: &Pattern,
+        out: &mut String,
+        term: Option<char>,
+    ) -> Result<()> {
+        while let Some(c) = self.peek() {
+            if Some(c) == term {
+                break;
+            }
+
+            match c {
+                '\\' => {
+                    self.bump();
+                    if let Some(c) = self.bump() {
+                        out.push(escaped_char(c));
+                    }
+                }
+                '%' => self.interpret_percent(pattern, out)?,
+                c => {
+                    self.bump();
+                    out.push(c);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn interpret_percent(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('%')?;
+
+        if self.consume('%') {
+            out.push('%');
+            return Ok(());
+        }
+
+        let width = self.read_number().unwrap_or(0);
+        self.expect('{')?;
+        let start = out.len();
+
+        match self.peek() {
+            Some('=') => self.interpret_builtin(pattern, out)?,
+            Some('{') => self.interpret_subexpr(pattern, out)?,
+            Some('+') => self.interpret_filter(pattern, out, true)?,
+            Some('-') => self.interpret_filter(pattern, out, false)?,
+            Some('?') => self.interpret_cond(pattern, out)?,
+            Some('#') => self.interpret_count(pattern, out)?,
+            Some('[') => self.interpret_enumerate(pattern, out)?,
+            _ => self.interpret_simple(pattern, out)?,
+        }
+
+        self.interpret_converts(out, start)?;
+
+        // negative width aligns to left
+        let len = out[start..].chars().count();
+        let pad = (width.unsigned_abs() as usize).saturating_sub(len);
+        if width < 0 {
+            out.push_str(&" ".repeat(pad));
+        } else {
+            out.insert_str(start, &" ".repeat(pad));
+        }
+
+        self.expect('}')
+    }
+
+    fn interpret_builtin(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('=')?;
+        let name = self.read_word()?;
+
+        let format = match name.as_str() {
+            "unparse" => {
+                write!(out, "{}", pattern).ok();
+                return Ok(());
+            }
+            "fclist" => FCLIST,
+            "fcmatch" => FCMATCH,
+            "fccat" => FCCAT,
+            "pkgkit" => PKGKIT,
+            _ => {
+                return Err(Error::InvalidPatternFormat(format!(
+                    "unknown builtin \"{}\"",
+                    name
+                )))
+            }
+        };
+
+        FormatContext::new(format).interpret_expr(pattern, out, None)
+    }
+
+    fn interpret_subexpr(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('{')?;
+        self.interpret_expr(pattern, out, Some('}'))?;
+        self.expect('}')
+    }
+
+    /// `%{+family{...}}` keeps only listed properties, `%{-family{...}}` drops them
+    fn interpret_filter(&mut self, pattern: &Pattern, out: &mut String, keep: bool) -> Result<()> {
+        self.bump();
+        let kinds = self.read_kinds()?;
+
+        let filtered = if keep {
+            pattern.filter(&kinds)
+        } else {
+            let mut filtered = pattern.clone();
+            for kind in kinds.iter() {
+                filtered.delete(kind);
+            }
+            filtered
+        };
+
+        self.interpret_subexpr(&filtered, out)
+    }
+
+    /// `%{?family,!style{then}{else}}` where `!` tests a property doesn't exist
+    fn interpret_cond(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('?')?;
+        let mut pass = true;
+
+        loop {
+            let negate = self.consume('!');
+            let kind: PropertyKind = self.read_word()?.parse()?;
+            pass &= pattern.get(&kind).is_some() != negate;
+
+            if !self.consume(',') {
+                break;
+            }
+        }
+
+        if pass {
+            self.interpret_subexpr(pattern, out)?;
+            if self.peek() == Some('{') {
+                self.skip_subexpr()?;
+            }
+        } else {
+            self.skip_subexpr()?;
+            if self.peek() == Some('{') {
+                self.interpret_subexpr(pattern, out)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `%{#family}` is the number of values
+    fn interpret_count(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('#')?;
+        let kind: PropertyKind = self.read_word()?.parse()?;
+        write!(
+            out,
+            "{}",
+            pattern.get(&kind).map_or(0, |values| values.len())
+        )
+        .ok();
+        Ok(())
+    }
+
+    /// `%{[]family,style{...}}` formats the subexpression for each index with `idx`th values
+    ///
+    /// Languages of a langset are enumerated one by one.
+    fn interpret_enumerate(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        self.expect('[')?;
+        self.expect(']')?;
+        let kinds = self.read_kinds()?;
+
+        let lists = kinds
+            .iter()
+            .map(|kind| {
+                let mut list = Vec::new();
+                for value in pattern.get(kind).unwrap_or_default() {
+                    match &value.value {
+                        Value::LangSet(langs) => list.extend(
+                            langs
+                                .iter()
+                                .map(|lang| PatternValue::new(lang.into(), value.binding)),
+                        ),
+                        _ => list.push(value.clone()),
+                    }
+                }
+                list
+            })
+            .collect::<Vec<_>>();
+
+        let start = self.pos;
+        let mut idx = 0;
+
+        loop {
+            let mut subpattern = pattern.clone();
+            let mut found = false;
+
+            for (kind, list) in kinds.iter().zip(lists.iter()) {
+                subpattern.delete(kind);
+                if let Some(value) = list.get(idx) {
+                    subpattern.values_mut(kind.clone()).push(value.clone());
+                    found = true;
+                }
+            }
+
+            if !found {
+                break;
+            }
+
+            self.pos = start;
+            self.interpret_subexpr(&subpattern, out)?;
+            idx += 1;
+        }
+
+        if idx == 0 {
+            self.skip_subexpr()?;
+        }
+
+        Ok(())
+    }
+
+    /// `%{:family[0]=:-default}`, `:` prefixes a colon and `=` prefixes the name
+    fn interpret_simple(&mut self, pattern: &Pattern, out: &mut String) -> Result<()> {
+        let add_colon = self.consume(':');
+        let name = self.read_word()?;
+
+        let idx = if self.consume('[') {
+            // `strtol` gives 0 without digits
+            let idx = self.read_number().unwrap_or(0);
+            if idx < 0 {
+                // located at the last char of the number like fontconfig
+                return Err(Error::InvalidPatternFormat(format!(
+                    "expected non-negative number at {}",
+                    self.pos
+                )));
+            }
+            self.expect(']')?;
+            Some(idx as usize)
+        } else {
+            None
+        };
+
+        let add_name = self.consume('=');
+
+        let default = if self.consume(':') {
+            self.expect('-')?;
+            Some(self.read_chars('|')?)
+        } else {
+            None
+        };
+
+        let kind: PropertyKind = name.parse()?;
+        let values = pattern.get(&kind).map(|values| {
+            if add_colon {
+                out.push(':');
+            }
+            if add_name {
+                write!(out, "{}=", name).ok();
+            }
+
+            match idx {
+                Some(idx) => values.get(idx..=idx),
+                None => Some(values),
+            }
+        });
+
+        match values.flatten() {
+            Some(values) => write!(out, "{}", UnescapedValues(values)).ok(),
+            None => default.map(|default| out.push_str(&default)),
+        };
+
+        Ok(())
+    }
+
+    /// `|basename`, `|translate(from,to)` and other converters applied to output from `start`
+    fn interpret_converts(&mut self, out: &mut String, start: usize) -> Result<()> {
+        while self.consume('|') {
+            let name = self.read_word()?;
+            let s = out.split_off(start);
+
+            let converted = match name.as_str() {
+                "basename" => s.rsplit('/').next().unwrap_or_default().to_string(),
+                "dirname" => match s.rfind('/') {
+                    Some(0) => "/".to_string(),
+                    Some(idx) => s[..idx].to_string(),
+                    None => ".".to_string(),
+                },
+                "downcase" => s.to_lowercase(),
+                "shescape" => format!("'{}'", s.replace('\'', "'\\''")),
+                "cescape" => s.chars().fold(String::new(), |mut out, c| {
+                    if c == '\\' || c == '"' {
+                        out.push('\\');
+                    }
+                    out.push(c);
+                    out
+                }),
+                "xmlescape" => s
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;"),
+                "delete" => {
+                    let chars = self.read_arg()?;
+                    s.chars().filter(|c| !chars.contains(*c)).collect()
+                }
+                "escape" => {
+                    // first char escapes every char of the argument
+                    let chars = self.read_arg()?;
+                    let escape = chars.chars().next().unwrap_or('\\');
+                    s.chars().fold(String::new(), |mut out, c| {
+                        if chars.contains(c) {
+                            out.push(escape);
+                        }
+                        out.push(c);
+                        out
+                    })
+                }
+                "translate" => {
+                    self.expect('(')?;
+                    let from = self.read_chars(',')?.chars().collect::<Vec<_>>();
+                    self.expect(',')?;
+                    let to = self.read_chars(')')?.chars().collect::<Vec<_>>();
+                    self.expect(')')?;
+
+                    // the last char of `to` is repeated when it's shorter
+                    s.chars()
+                        .map(|c| match from.iter().position(|f| *f == c) {
+                            Some(idx) => to[idx.min(to.len() - 1)],
+                            None => c,
+                        })
+                        .collect()
+                }
+                _ => {
+                    return Err(Error::InvalidPatternFormat(format!(
+                        "unknown converter \"{}\"",
+                        name
+                    )))
+                }
+            };
+
+            out.push_str(&converted);
+        }
+
+        Ok(())
+    }
+
+    /// Single argument in parentheses like `(chars)`
+    fn read_arg(&mut self) -> Result<String> {
+        self.expect('(')?;
+        let arg = self.read_chars(')')?;
+        self.expect(')')?;
+        Ok(arg)
+    }
+
+    fn skip_subexpr(&mut self) -> Result<()> {
+        self.expect('{')?;
+        self.skip_expr()?;
+        self.expect('}')
+    }
+
+    /// Skip until the end of current expression, `%{...}` may contain any braces
+    fn skip_expr(&mut self) -> Result<()> {
+        while let Some(c) = self.peek() {
+            match c {
+                '}' => break,
+                '\\' => {
+                    self.bump();
+                    self.bump();
+                }
+                '%' => self.skip_percent()?,
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn skip_percent(&mut self) -> Result<()> {
+        self.expect('%')?;
+
+        if self.consume('%') {
+            return Ok(());
+        }
+
+        self.read_number();
+        self.expect('{')?;
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' => break,
+                '\\' => {
+                    self.bump();
+                    self.bump();
+                }
+                '{' => self.skip_subexpr()?,
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+
+        self.expect('}')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(name: &str, format: &str) -> String {
+        name.parse::<Pattern>().unwrap().format(format).unwrap()
+    }
+
+    #[test]
+    fn simple() {
+        let name = "A\"<B,B-12:weight=200:file=/x/y.ttf:lang=en|ja";
+
+        k9::assert_equal!(format(name, "%{family}|%{family[1]}"), "A\"<B,B|B");
+        k9::assert_equal!(
+            format(name, "%{:family}|%{family=}|%{#family}"),
+            ":A\"<B,B|family=A\"<B,B|2"
+        );
+        k9::assert_equal!(
+            format(name, "%{foo:-none}|%{family[2]:-none}|%%\\t"),
+            "none|none|%\t"
+        );
+        k9::assert_equal!(
+            format(name, "%-10{size}|%10{size}|"),
+            "12        |        12|"
+        );
+    }
+
+    #[test]
+    fn subexpressions() {
+        let name = "A\"<B,B-12:weight=200:file=/x/y.ttf:lang=en|ja";
+
+        k9::assert_equal!(
+            format(name, "%{?foo{y}{n}}|%{?family,!foo{y}{n}}|%{?foo{y}}"),
+            "n|y|"
+        );
+        k9::assert_equal!(
+            format(name, "%{-family{%{=unparse}}}|%{+family{%{=unparse}}}"),
+            "-12:weight=200:file=/x/y.ttf:lang=en|ja|A\"<B,B"
+        );
+        k9::assert_equal!(
+            format(name, "%{[]family{%{family} }}%{[]foo{x}}"),
+            "A\"<B B "
+        );
+    }
+
+    #[test]
+    fn converters() {
+        let name = "A\"<B,B:file=/x/y.ttf";
+
+        k9::assert_equal!(format(name, "%{file|basename}|%{file|dirname}"), "y.ttf|/x");
+        k9::assert_equal!(
+            format(
+                name,
+                "%{family|downcase}|%{family|shescape}|%{family|cescape}"
+            ),
+            "a\"<b,b|'A\"<B,B'|A\\\"<B,B"
+        );
+        k9::assert_equal!(
+            format(name, "%{family|xmlescape}|%{family|translate(AB,ab)}"),
+            "A\"&lt;B,B|a\"<b,b"
+        );
+        k9::assert_equal!(
+            format(name, "%{family|delete(A)}|%{family|escape(A)}"),
+            "\"<B,B|AA\"<B,B"
+        );
+    }
+
+    #[test]
+    fn builtins() {
+        let name = "A,B-12:weight=200:file=/x/y.ttf:lang=en|ja";
+
+        k9::assert_equal!(
+            format(name, "%{=fclist}"),
+            "/x/y.ttf: A,B-12:weight=200:lang=en|ja"
+        );
+        k9::assert_equal!(
+            format(name, "%{=fcmatch}"),
+            "y.ttf: \"A\" \"<unknown style>\""
+        );
+        k9::assert_equal!(
+            format(name, "%{=fccat}"),
+            "\"y.ttf\"  \"A,B-12:weight=200:lang=en|ja\""
+        );
+        k9::assert_equal!(
+            format(name, "%{=pkgkit}"),
+            "font(a)\nfont(b)\nfont(:lang=en)\nfont(:lang=ja)\n"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let pattern = Pattern::new();
+
+        assert!(pattern.format("%{=verbose}").is_err());
+        assert!(pattern.format("%{family|upcase}").is_err());
+        assert!(pattern.format("%{family").is_err());
+        assert!(pattern.format("%{}").is_err());
+
+        let message = |format| pattern.format(format).unwrap_err().to_string();
+        k9::assert_equal!(
+            message("%{family"),
+            "Pattern format error: format ended while expecting '}'"
+        );
+        k9::assert_equal!(
+            message("%{family|upcase}"),
+            "Pattern format error: unknown converter \"upcase\""
+        );
+        k9::assert_equal!(
+            message("%{"),
+            "Pattern format error: expected identifier at 3"
+        );
+        k9::assert_equal!(
+            message("%{family[-1]}"),
+            "Pattern format error: expected non-negative number at 11"
+        );
+    }
+
+    #[test]
+    fn verbose() {
+        let pattern: Pattern =
+            "A,B-12.5:weight=[50 100]:charset=20-7e 1f600:lang=en|ja:matrix=1 0.5 0 1"
+                .parse()
+                .unwrap();
+
+        k9::assert_equal!(
+            pattern.to_verbose_string(),
+            [
+                "Pattern has 6 elts (size 16)",
+                "\tfamily: \"A\"(s) \"B\"(s)",
+                "\tweight: [50 100](s)",
+                "\tsize: 12.5(f)(s)",
+                "\tmatrix: [1 0.5; 0 1](s)",
+                "\tcharset: ",
+                "\t0000: 00000000 ffffffff ffffffff 7fffffff 00000000 00000000 00000000 00000000",
+                "\t01f6: 00000001 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+                "(s)",
+                "\tlang: en|ja(s)",
+                "",
+                "",
+            ]
+            .join("\n")
+        );
+        k9::assert_equal!(
+            Pattern::new().to_verbose_string(),
+            "Pattern has 0 elts (size 0)\n\n"
+        );
+    }
+
+    #[test]
+    fn printf_g() {
+        k9::assert_equal!(format_g(12.0), "12");
+        k9::assert_equal!(format_g(0.5), "0.5");
+        k9::assert_equal!(format_g(1.0 / 3.0), "0.333333");
+        k9::assert_equal!(format_g(1234567.0), "1.23457e+06");
+        k9::assert_equal!(format_g(0.00001), "1e-05");
+    }
+}
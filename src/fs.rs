@@ -0,0 +1,165 @@
+//! File system used to load config files, see [`MergeOptions::fs`](crate::MergeOptions::fs)
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Type of a file system entry, symlinks are already followed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileKind {
+    File,
+    Dir,
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirEntry {
+    pub path: PathBuf,
+    pub kind: FileKind,
+}
+
+/// Operations needed to load config files
+pub trait FileSystem: fmt::Debug {
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Kind of `path` after following symlinks
+    fn metadata(&self, path: &Path) -> io::Result<FileKind>;
+
+    /// Entries of directory in any order
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>>;
+
+    /// Path which is same for every link to the file, used to detect include cycles
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(path.to_path_buf())
+    }
+}
+
+/// [`FileSystem`] backed by `std::fs`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdFileSystem;
+
+fn file_kind(ty: std::fs::FileType) -> FileKind {
+    if ty.is_file() {
+        FileKind::File
+    } else if ty.is_dir() {
+        FileKind::Dir
+    } else {
+        FileKind::Other
+    }
+}
+
+impl FileSystem for StdFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileKind> {
+        std::fs::metadata(path).map(|meta| file_kind(meta.file_type()))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        std::fs::read_dir(path)?
+            .map(|entry| {
+                let path = entry?.path();
+                // broken symlinks are kept and fail when they're read
+                let kind = std::fs::metadata(&path)
+                    .map(|meta| file_kind(meta.file_type()))
+                    .unwrap_or(FileKind::File);
+                Ok(DirEntry { path, kind })
+            })
+            .collect()
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+}
+
+/// In-memory [`FileSystem`], directories are implied by file paths
+///
+/// ```
+/// use fontconfig_parser::{FontConfig, MemoryFileSystem, MergeOptions};
+/// use std::sync::Arc;
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert("/etc/fonts/fonts.conf", "<fontconfig><dir>/fonts</dir></fontconfig>");
+///
+/// let options = MergeOptions {
+///     fs: Arc::new(fs),
+///     ..Default::default()
+/// };
+/// let mut config = FontConfig::default();
+/// config.merge_config_with_options("/etc/fonts/fonts.conf", &options).unwrap();
+///
+/// assert_eq!(config.dirs[0].path, std::path::Path::new("/fonts"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFileSystem {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, path: impl Into<PathBuf>, content: impl Into<String>) {
+        self.files.insert(path.into(), content.into());
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} is not found", path.display()),
+    )
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files.get(path).cloned().ok_or_else(|| not_found(path))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileKind> {
+        if self.files.contains_key(path) {
+            Ok(FileKind::File)
+        } else if self.files.keys().any(|file| file.starts_with(path)) {
+            Ok(FileKind::Dir)
+        } else {
+            Err(not_found(path))
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        let mut entries: Vec<DirEntry> = Vec::new();
+
+        for file in self.files.keys() {
+            let name = match file.strip_prefix(path).ok().and_then(|p| p.iter().next()) {
+                Some(name) => name,
+                None => continue,
+            };
+            let entry_path = path.join(name);
+
+            if entries.iter().any(|e| e.path == entry_path) {
+                continue;
+            }
+
+            let kind = if *file == entry_path {
+                FileKind::File
+            } else {
+                FileKind::Dir
+            };
+            entries.push(DirEntry {
+                path: entry_path,
+                kind,
+            });
+        }
+
+        if entries.is_empty() {
+            Err(not_found(path))
+        } else {
+            Ok(entries)
+        }
+    }
+}
@@ -11,24 +11,82 @@
 //!
 //! config.merge_config("/etc/fonts/fonts.conf").unwrap();
 //! ```
+//!
+//! Use [`FontConfig::from_env`] to find the main config like libfontconfig,
+//! honoring `FONTCONFIG_FILE`, `FONTCONFIG_PATH` and `FONTCONFIG_SYSROOT`.
+//!
+//! The `cache` feature finds libfontconfig cache files of font directories, which needs MD5 of their path.
+//!
+//! The `cli` feature builds `fc-list`, `fc-match`, `fc-pattern` and `fc-conflist` on top of this crate.
 
 #[macro_use]
 mod util;
 
+mod cache;
 mod error;
+mod eval;
+mod format;
+mod fs;
+mod matching;
+mod name;
 mod parser;
+#[cfg(feature = "scan")]
+mod scan;
+mod select;
+mod substitute;
 mod types;
+mod validate;
+mod writer;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+pub use crate::cache::FcCache;
 pub use crate::error::Error;
+pub use crate::eval::EvalContext;
+pub use crate::fs::{DirEntry, FileKind, FileSystem, MemoryFileSystem, StdFileSystem};
+pub use crate::matching::{font_list, match_score, MatchScore};
+#[cfg(feature = "scan")]
+pub use crate::scan::{scan_font_data, ScannedDir};
 pub use crate::types::*;
+pub use crate::validate::validate_config_parts;
+pub use crate::writer::{config_to_xml, write_config, WriteXml, XML_HEADER};
 
 /// Parse as raw config parts use this when you want custom handling config file
 ///
 /// Otherwise, you may want [`FontConfig::merge_config`]
 pub fn parse_config_parts(s: &str) -> Result<Vec<ConfigPart>> {
-    crate::parser::parse_config(&roxmltree::Document::parse(s)?)?.collect()
+    let parts =
+        parse_config_parts_with_options(s, None, &ParseOptions::default(), &mut Vec::new())?;
+
+    Ok(parts.into_iter().map(|part| part.value).collect())
+}
+
+/// Same as [`parse_config_parts`] but with custom options, keeping source location of each part
+///
+/// `file` is recorded in every [`Span`] and error location.
+/// With [`ParseOptions::strict`], unknown elements and attributes are located errors,
+/// otherwise they are pushed to `diagnostics` with other problems which don't stop parsing
+/// like dropped `<patelt>` of `<selectfont>`
+pub fn parse_config_parts_with_options(
+    s: &str,
+    file: Option<&std::path::Path>,
+    options: &ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<Spanned<ConfigPart>>> {
+    crate::parser::parse_config(
+        &roxmltree::Document::parse(s)?,
+        file.map(Into::into),
+        *options,
+        diagnostics,
+    )?
+    .collect()
+}
+
+/// Parse fontconfig font name syntax like `DejaVu Sans-12:weight=bold:lang=ja`
+///
+/// Constants are resolved into numbers, use `str::parse` to get a [`Pattern`] and `to_string` to format it back
+pub fn parse_font_name(s: &str) -> Result<Vec<Property>> {
+    crate::name::parse_name(s)
 }
 
 #[cfg(test)]
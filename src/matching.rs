@@ -0,0 +1,1013 @@
+//! Font matching like `FcFontMatch` and `FcFontSort`, listing like `FcFontList`
+
+use crate::eval::{compare_value_with, str_eq_ignore_blanks_and_case};
+use crate::*;
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Priority of each property in the score, ordered like `FcMatcherPriority`
+///
+/// Only family has different priorities for strong and weak values.
+#[derive(Copy, Clone)]
+enum Priority {
+    File,
+    Fontformat,
+    Variable,
+    Scalable,
+    Color,
+    Foundry,
+    Charset,
+    FamilyStrong,
+    PostscriptnameStrong,
+    Lang,
+    FamilyWeak,
+    PostscriptnameWeak,
+    Symbol,
+    Spacing,
+    Size,
+    PixelSize,
+    Style,
+    Slant,
+    Weight,
+    Width,
+    Fonthashint,
+    Decorative,
+    Antialias,
+    Rasterizer,
+    Outline,
+    Order,
+    Fontversion,
+    End,
+}
+
+/// Distance of a font value from a pattern value and the value a matched font takes
+///
+/// `None` means values can't be compared
+type Compare = fn(&Value, &Value) -> Option<(Double, Value)>;
+
+struct Matcher {
+    compare: Compare,
+    strong: Priority,
+    weak: Priority,
+}
+
+/// Same as `FcObjectToMatcher`, properties without matcher don't affect score
+fn matcher(kind: &PropertyKind) -> Option<Matcher> {
+    use Priority::*;
+
+    let (compare, strong, weak): (Compare, _, _) = match kind {
+        PropertyKind::File => (compare_filename, File, File),
+        PropertyKind::Fontformat => (compare_string, Fontformat, Fontformat),
+        PropertyKind::Variable => (compare_bool, Variable, Variable),
+        PropertyKind::Scalable => (compare_bool, Scalable, Scalable),
+        PropertyKind::Color => (compare_bool, Color, Color),
+        PropertyKind::Foundry => (compare_string, Foundry, Foundry),
+        PropertyKind::Charset => (compare_charset, Charset, Charset),
+        PropertyKind::Family => (compare_family, FamilyStrong, FamilyWeak),
+        PropertyKind::Postscriptname => {
+            (compare_postscript, PostscriptnameStrong, PostscriptnameWeak)
+        }
+        PropertyKind::Lang => (compare_lang, Lang, Lang),
+        PropertyKind::Symbol => (compare_bool, Symbol, Symbol),
+        PropertyKind::Spacing => (compare_number, Spacing, Spacing),
+        PropertyKind::Size => (compare_size, Size, Size),
+        PropertyKind::PixelSize => (compare_size, PixelSize, PixelSize),
+        PropertyKind::Style => (compare_string, Style, Style),
+        PropertyKind::Slant => (compare_number, Slant, Slant),
+        PropertyKind::Weight => (compare_range, Weight, Weight),
+        PropertyKind::Width => (compare_range, Width, Width),
+        PropertyKind::Fonthashint => (compare_bool, Fonthashint, Fonthashint),
+        PropertyKind::Decorative => (compare_bool, Decorative, Decorative),
+        PropertyKind::Antialias => (compare_bool, Antialias, Antialias),
+        PropertyKind::Rasterizer => (compare_string, Rasterizer, Rasterizer),
+        PropertyKind::Outline => (compare_bool, Outline, Outline),
+        PropertyKind::Order => (compare_number, Order, Order),
+        PropertyKind::Fontversion => (compare_number, Fontversion, Fontversion),
+        _ => return None,
+    };
+
+    Some(Matcher {
+        compare,
+        strong,
+        weak,
+    })
+}
+
+/// Distance of a font from a pattern, compared property by property in priority order
+///
+/// Smaller is better.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchScore([Double; Priority::End as usize]);
+
+impl MatchScore {
+    fn new() -> Self {
+        Self([0.0; Priority::End as usize])
+    }
+}
+
+impl PartialOrd for MatchScore {
+    /// Same as `FcSortCompare`, the first different priority decides
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(l, r)| l.partial_cmp(r))
+            .find(|ord| *ord != Some(Ordering::Equal))
+            .unwrap_or(Some(Ordering::Equal))
+    }
+}
+
+/// Score of `font` against `pattern` like `FcCompare`
+///
+/// Only properties which exist in both patterns are compared.
+/// Returns `None` when a property has values which can't be compared, such font never matches.
+pub fn match_score(pattern: &Pattern, font: &Pattern) -> Option<MatchScore> {
+    let mut score = MatchScore::new();
+
+    for (kind, values) in pattern.iter() {
+        let (matcher, font_values) = match (matcher(kind), font.get(kind)) {
+            (Some(matcher), Some(font_values)) => (matcher, font_values),
+            _ => continue,
+        };
+
+        compare_value_list(&matcher, values, font_values, Some(&mut score))?;
+    }
+
+    Some(score)
+}
+
+/// Fonts of `fonts` which have every value of `pattern` like `FcFontList`
+///
+/// Strings are compared ignoring case and blanks, font ranges, langsets and charsets must contain
+/// the pattern value. Fonts are reduced to `objects` unless it's `None`, then duplicates are dropped.
+pub fn font_list(
+    pattern: &Pattern,
+    objects: Option<&[PropertyKind]>,
+    fonts: &[Pattern],
+) -> Vec<Pattern> {
+    let mut seen = HashSet::new();
+
+    fonts
+        .iter()
+        .filter(|font| list_match(pattern, font))
+        .map(|font| match objects {
+            Some(objects) => font.filter(objects),
+            None => font.clone(),
+        })
+        // patterns can't be hashed, equal patterns have same canonical name
+        .filter(|font| seen.insert(font.to_string()))
+        .collect()
+}
+
+/// Same as `FcListPatternMatchAny`
+fn list_match(pattern: &Pattern, font: &Pattern) -> bool {
+    pattern.iter().all(|(kind, values)| {
+        let font_values = match font.get(kind) {
+            Some(font_values) => font_values,
+            None => return false,
+        };
+
+        values.iter().all(|value| {
+            font_values.iter().any(|font_value| {
+                // `FcOpListing` tests containment except that strings must be equal
+                let compare = match value.value {
+                    Value::String(_) => TestCompare::Eq,
+                    _ => TestCompare::Contains,
+                };
+
+                compare_value_with(Some(&font_value.value), compare, Some(&value.value), true)
+            })
+        })
+    })
+}
+
+/// Best pair of values like `FcCompareValueList`
+///
+/// Earlier pattern values are preferred, each position adds a small penalty to the distance.
+/// Returns the font value to use for the best pair and position of the font value.
+fn compare_value_list(
+    matcher: &Matcher,
+    values: &[PatternValue],
+    font_values: &[PatternValue],
+    score: Option<&mut MatchScore>,
+) -> Option<(Value, usize)> {
+    const WORST: Double = 1e99;
+
+    let mut best = WORST;
+    let mut best_strong = WORST;
+    let mut best_weak = WORST;
+    let mut best_value = None;
+    let split = matcher.strong as usize != matcher.weak as usize;
+
+    for (idx, value) in values.iter().enumerate() {
+        for (font_idx, font_value) in font_values.iter().enumerate() {
+            let (distance, matched) = (matcher.compare)(&value.value, &font_value.value)?;
+            let distance = distance * 1000.0 + idx as Double;
+
+            if distance < best {
+                best = distance;
+                best_value = Some((matched, font_idx));
+            }
+
+            if split {
+                if value.binding == EditBinding::Strong {
+                    best_strong = best_strong.min(distance);
+                } else {
+                    best_weak = best_weak.min(distance);
+                }
+            }
+        }
+    }
+
+    if let Some(score) = score {
+        if split {
+            score.0[matcher.strong as usize] += best_strong;
+            score.0[matcher.weak as usize] += best_weak;
+        } else {
+            score.0[matcher.strong as usize] += best;
+        }
+    }
+
+    best_value
+}
+
+fn number(value: &Value) -> Option<Double> {
+    match value {
+        Value::Int(i) => Some(*i as Double),
+        Value::Double(d) => Some(*d),
+        _ => None,
+    }
+}
+
+/// Numbers and ranges as `(begin, end)`
+fn range(value: &Value) -> Option<(Double, Double)> {
+    match value {
+        Value::Range(begin, end) => Some((*begin as Double, *end as Double)),
+        value => number(value).map(|n| (n, n)),
+    }
+}
+
+fn compare_number(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    Some(((number(l)? - number(r)?).abs(), r.clone()))
+}
+
+fn compare_string(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    match (l, r) {
+        (Value::String(l), Value::String(s)) => {
+            Some((!l.eq_ignore_ascii_case(s) as u8 as Double, r.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Same as `FcCompareFilename`, case and glob matches are closer than other files
+fn compare_filename(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    match (l, r) {
+        (Value::String(l), Value::String(s)) => {
+            let distance = if l == s {
+                0.0
+            } else if l.eq_ignore_ascii_case(s) {
+                1.0
+            } else if glob_match(l, s) {
+                2.0
+            } else {
+                3.0
+            };
+
+            Some((distance, r.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn compare_family(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    match (l, r) {
+        (Value::String(l), Value::String(s)) => Some((
+            !str_eq_ignore_blanks_and_case(l, s) as u8 as Double,
+            r.clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// Same as `FcComparePostScript`, the distance is the ratio of unmatched prefix
+///
+/// Case, spaces and `-` are ignored while matching, but the prefix and length count them.
+fn compare_postscript(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    let (l, s) = match (l, r) {
+        (Value::String(l), Value::String(s)) => (l.as_bytes(), s.as_bytes()),
+        _ => return None,
+    };
+
+    if l.is_empty() {
+        return Some((0.0, r.clone()));
+    }
+
+    let first = |s: &[u8]| s.first().map(u8::to_ascii_lowercase);
+    if first(l) != first(s) && l[0] != b' ' && s.first() != Some(&b' ') {
+        return Some((1.0, r.clone()));
+    }
+
+    let is_delim = |c: &&u8| **c == b' ' || **c == b'-';
+    let mut l_chars = l.iter().enumerate().filter(|(_, c)| !is_delim(c));
+    let mut s_chars = s.iter().filter(|c| !is_delim(c));
+    // position of the first different char like `FcStrMatchIgnoreCaseAndDelims`
+    let matched = loop {
+        match (l_chars.next(), s_chars.next()) {
+            (Some((_, lc)), Some(sc)) if lc.eq_ignore_ascii_case(sc) => {}
+            (Some((idx, _)), _) => break idx,
+            (None, _) => break l.len(),
+        }
+    };
+
+    Some(((l.len() - matched) as Double / l.len() as Double, r.clone()))
+}
+
+fn compare_bool(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    match (l, r) {
+        (Value::Bool(l), Value::Bool(b)) => Some(((l != b) as u8 as Double, r.clone())),
+        _ => None,
+    }
+}
+
+/// Number of characters of the pattern which the font doesn't cover
+fn compare_charset(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    match (l, r) {
+        (Value::CharSet(l), Value::CharSet(c)) => Some((c.missing(l) as Double, r.clone())),
+        _ => None,
+    }
+}
+
+/// Same as `FcCompareLang`, one for another territory and two for another language
+fn compare_lang(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    let result = match (l, r) {
+        (Value::LangSet(l), Value::LangSet(langs)) => l.compare(langs),
+        (Value::LangSet(langs), Value::String(lang))
+        | (Value::String(lang), Value::LangSet(langs)) => langs.compare_lang(lang),
+        (Value::String(l), Value::String(s)) => {
+            crate::compare_lang(&normalize_lang(l), &normalize_lang(s))
+        }
+        _ => return None,
+    };
+
+    Some((result as u8 as Double, r.clone()))
+}
+
+/// Same as `FcCompareSize`, overlapping sizes have no distance
+fn compare_size(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    let (l_begin, l_end) = range(l)?;
+    let (r_begin, r_end) = range(r)?;
+
+    let distance = if r_begin > l_end {
+        r_begin - l_end
+    } else if l_begin > r_end {
+        l_begin - r_end
+    } else {
+        0.0
+    };
+
+    Some((distance, r.clone()))
+}
+
+/// Same as `FcCompareRange`, overlapping ranges take the middle of the overlap
+///
+/// A font range like a variable weight takes the closest requested value.
+fn compare_range(l: &Value, r: &Value) -> Option<(Double, Value)> {
+    let (l_begin, l_end) = range(l)?;
+    let (r_begin, r_end) = range(r)?;
+
+    let (distance, matched) = if l_end < r_begin {
+        (r_begin - l_end, r_begin)
+    } else if r_end < l_begin {
+        (l_begin - r_end, r_end)
+    } else {
+        (0.0, (l_begin.max(r_begin) + l_end.min(r_end)) / 2.0)
+    };
+
+    Some((distance, Value::Double(matched)))
+}
+
+/// Shell glob with `*` and `?` like `FcStrGlobMatch`
+pub(crate) fn glob_match(glob: &str, s: &str) -> bool {
+    let glob = glob.as_bytes();
+    let s = s.as_bytes();
+    let (mut g, mut i) = (0, 0);
+    // position after last `*` and where its match started
+    let mut star = None;
+
+    while i < s.len() {
+        match glob.get(g) {
+            Some(b'*') => {
+                star = Some((g + 1, i));
+                g += 1;
+            }
+            Some(c) if *c == b'?' || *c == s[i] => {
+                g += 1;
+                i += 1;
+            }
+            _ => match star {
+                Some((star_g, star_i)) => {
+                    g = star_g;
+                    i = star_i + 1;
+                    star = Some((star_g, star_i + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    glob[g..].iter().all(|c| *c == b'*')
+}
+
+impl Pattern {
+    /// Fill values which matching needs like `FcDefaultSubstitute`
+    ///
+    /// Missing weight, slant, width, rendering options, scale and dpi get their default and
+    /// `pixelsize` is computed from them. `namelang` defaults to the first of [`default_langs`],
+    /// which is also the preferred language of names followed by a weak `en-us`.
+    pub fn default_substitute(&mut self) {
+        let defaults = vec![
+            (PropertyKind::Weight, Value::Int(80)),
+            (PropertyKind::Slant, Value::Int(0)),
+            (PropertyKind::Width, Value::Int(100)),
+            (PropertyKind::Hinting, Value::Bool(true)),
+            (PropertyKind::VerticalLayout, Value::Bool(false)),
+            (PropertyKind::AutoHint, Value::Bool(false)),
+            (PropertyKind::GlobalAdvance, Value::Bool(true)),
+            (PropertyKind::Embeddedbitmap, Value::Bool(true)),
+            (PropertyKind::Decorative, Value::Bool(false)),
+            (PropertyKind::Symbol, Value::Bool(false)),
+            (PropertyKind::Variable, Value::Bool(false)),
+        ];
+
+        for (kind, value) in defaults {
+            if self.get(&kind).is_none() {
+                self.add(kind, value);
+            }
+        }
+
+        let mut size = match self.get_value(&PropertyKind::Size, 0).and_then(range) {
+            Some((begin, end)) => (begin + end) / 2.0,
+            None => 12.0,
+        };
+        let scale = self.get_double(&PropertyKind::Scale, 0).unwrap_or(1.0);
+        let dpi = self.get_double(&PropertyKind::Dpi, 0).unwrap_or(75.0);
+
+        match self.get_double(&PropertyKind::PixelSize, 0) {
+            Some(pixel_size) => size = pixel_size / dpi * 72.0 / scale,
+            None => {
+                self.delete(&PropertyKind::Scale);
+                self.add(PropertyKind::Scale, scale);
+                self.delete(&PropertyKind::Dpi);
+                self.add(PropertyKind::Dpi, dpi);
+                self.add(PropertyKind::PixelSize, size * scale * dpi / 72.0);
+            }
+        }
+
+        self.delete(&PropertyKind::Size);
+        self.add(PropertyKind::Size, size);
+
+        if self.get(&PropertyKind::Fontversion).is_none() {
+            self.add(PropertyKind::Fontversion, Value::Int(0x7fff_ffff));
+        }
+
+        if self.get(&PropertyKind::HintStyle).is_none() {
+            self.add(PropertyKind::HintStyle, Value::Int(3));
+        }
+
+        if self.get(&PropertyKind::Namelang).is_none() {
+            let lang = default_langs().into_iter().next().unwrap_or_default();
+            self.add(PropertyKind::Namelang, lang);
+        }
+
+        let namelang = self.get_value(&PropertyKind::Namelang, 0).cloned();
+
+        for kind in [
+            PropertyKind::FamilyLang,
+            PropertyKind::StyleLang,
+            PropertyKind::FullNameLang,
+        ] {
+            if self.get(&kind).is_none() {
+                if let Some(namelang) = namelang.clone() {
+                    self.add(kind.clone(), namelang);
+                }
+                // english names when the preferred language isn't available
+                self.add_weak(kind, "en-us");
+            }
+        }
+
+        if self.get(&PropertyKind::Prgname).is_none() {
+            if let Some(name) = crate::substitute::prgname() {
+                self.add(PropertyKind::Prgname, name);
+            }
+        }
+
+        if self.get(&PropertyKind::Order).is_none() {
+            self.add(PropertyKind::Order, Value::Int(0));
+        }
+    }
+}
+
+impl FontConfig {
+    /// Best font of `fonts` for `pattern` like `FcFontMatch`
+    ///
+    /// `pattern` should already be prepared with [`substitute`](Self::substitute) and
+    /// [`Pattern::default_substitute`]. Ties are won by the earlier font.
+    /// The result is prepared with [`render_prepare`](Self::render_prepare).
+    pub fn font_match(&self, pattern: &Pattern, fonts: &[Pattern]) -> Option<Pattern> {
+        let mut best: Option<(MatchScore, &Pattern)> = None;
+
+        for font in fonts {
+            let score = match match_score(pattern, font) {
+                Some(score) => score,
+                None => continue,
+            };
+
+            match &best {
+                Some((best_score, _)) if score >= *best_score => {}
+                _ => best = Some((score, font)),
+            }
+        }
+
+        best.map(|(_, font)| self.render_prepare(pattern, font))
+    }
+
+    /// Fonts of `fonts` ordered from the best match like `FcFontSort`
+    ///
+    /// For each language of `pattern` only the best font covering it keeps its language score,
+    /// so fallback fonts for other languages come right after it.
+    /// With `trim`, fonts whose charset doesn't add coverage to earlier fonts are dropped and so are
+    /// fonts without charset.
+    pub fn font_sort<'a>(
+        &self,
+        pattern: &Pattern,
+        fonts: &'a [Pattern],
+        trim: bool,
+    ) -> Vec<&'a Pattern> {
+        let mut nodes = fonts
+            .iter()
+            .filter_map(|font| Some((match_score(pattern, font)?, font)))
+            .collect::<Vec<_>>();
+
+        // `sort_by` is stable so ties keep the order of `fonts`
+        let sort = |nodes: &mut Vec<(MatchScore, &Pattern)>| {
+            nodes.sort_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(Ordering::Equal))
+        };
+
+        sort(&mut nodes);
+
+        let langs = pattern.get(&PropertyKind::Lang).unwrap_or_default();
+        let mut satisfied = vec![false; langs.len()];
+
+        for (score, font) in nodes.iter_mut() {
+            let mut satisfies = false;
+
+            if score.0[Priority::Lang as usize] < 2000.0 {
+                if let Some(font_lang) = font.get_value(&PropertyKind::Lang, 0) {
+                    for (idx, lang) in langs.iter().enumerate() {
+                        if satisfied[idx] {
+                            continue;
+                        }
+
+                        if let Some((distance, _)) = compare_lang(&lang.value, font_lang) {
+                            if distance < 2.0 {
+                                satisfied[idx] = true;
+                                satisfies = true;
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !satisfies {
+                score.0[Priority::Lang as usize] = 10000.0;
+            }
+        }
+
+        sort(&mut nodes);
+
+        let mut coverage: Option<CharSet> = None;
+
+        nodes
+            .into_iter()
+            .map(|(_, font)| font)
+            .filter(|font| {
+                let charset = match font.get_value(&PropertyKind::Charset, 0) {
+                    Some(Value::CharSet(charset)) => charset,
+                    _ => return !trim,
+                };
+
+                match &mut coverage {
+                    Some(coverage) if trim && charset.is_subset(coverage) => false,
+                    Some(coverage) => {
+                        *coverage = coverage.union(charset);
+                        true
+                    }
+                    None => {
+                        coverage = Some(charset.clone());
+                        true
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Combine a matched `font` with the query `pattern` like `FcFontRenderPrepare`
+    ///
+    /// Properties compared by matching take the best font value, other font properties are kept
+    /// and properties only in `pattern` are copied. Names with languages keep every value, the name
+    /// in the best language for `familylang`, `stylelang` or `fullnamelang` of `pattern` comes first.
+    /// Then every `<match target="font">` rule runs.
+    pub fn render_prepare(&self, pattern: &Pattern, font: &Pattern) -> Pattern {
+        let mut prepared = Pattern::new();
+
+        for (kind, font_values) in font.iter() {
+            let lang_kind = match kind {
+                PropertyKind::Family => Some(PropertyKind::FamilyLang),
+                PropertyKind::Style => Some(PropertyKind::StyleLang),
+                PropertyKind::FullName => Some(PropertyKind::FullNameLang),
+                // added together with their names
+                PropertyKind::FamilyLang | PropertyKind::StyleLang | PropertyKind::FullNameLang => {
+                    continue
+                }
+                _ => None,
+            };
+            let font_langs =
+                lang_kind.and_then(|lang_kind| Some((font.get(&lang_kind)?, lang_kind)));
+
+            if let Some((font_langs, lang_kind)) = font_langs {
+                let best = pattern
+                    .get(&lang_kind)
+                    .zip(matcher(&PropertyKind::Lang))
+                    .and_then(|(langs, matcher)| {
+                        compare_value_list(&matcher, langs, font_langs, None)
+                    })
+                    .map_or(0, |(_, idx)| idx);
+                let to_front = |values: &[PatternValue]| {
+                    let mut values = values.to_vec();
+                    if best < values.len() {
+                        let value = values.remove(best);
+                        values.insert(0, value);
+                    }
+                    values
+                };
+
+                prepared
+                    .values_mut(kind.clone())
+                    .extend(to_front(font_values));
+                prepared.values_mut(lang_kind).extend(to_front(font_langs));
+                continue;
+            }
+
+            match pattern.get(kind) {
+                Some(values) => {
+                    let best = matcher(kind)
+                        .and_then(|matcher| compare_value_list(&matcher, values, font_values, None))
+                        .map_or_else(|| font_values[0].value.clone(), |(value, _)| value);
+                    prepared.add(kind.clone(), best);
+                }
+                None => prepared
+                    .values_mut(kind.clone())
+                    .extend(font_values.iter().cloned()),
+            }
+        }
+
+        for (kind, values) in pattern.iter() {
+            let lang_kind = matches!(
+                kind,
+                PropertyKind::FamilyLang | PropertyKind::StyleLang | PropertyKind::FullNameLang
+            );
+
+            if font.get(kind).is_none() && !lang_kind {
+                prepared
+                    .values_mut(kind.clone())
+                    .extend(values.iter().cloned());
+            }
+        }
+
+        self.substitute_with(MatchTarget::Font, &mut prepared, Some(pattern));
+        prepared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font(family: &str, props: Vec<(PropertyKind, Value)>) -> Pattern {
+        let mut font = Pattern::new();
+        font.add(PropertyKind::Family, family);
+        font.extend(props);
+        font
+    }
+
+    fn family(pattern: &Pattern) -> &str {
+        pattern.get_string(&PropertyKind::Family, 0).unwrap()
+    }
+
+    #[test]
+    fn list() {
+        let fonts = [
+            font(
+                "DejaVu Sans",
+                vec![
+                    (PropertyKind::Style, "Bold".into()),
+                    (PropertyKind::Weight, Value::Int(200)),
+                    (
+                        PropertyKind::Lang,
+                        LangSet::from(vec!["en".into(), "ja".into()]).into(),
+                    ),
+                ],
+            ),
+            font(
+                "DejaVu Sans",
+                vec![
+                    (PropertyKind::Style, "Book".into()),
+                    (PropertyKind::Weight, Value::Range(80, 200)),
+                ],
+            ),
+            font("DejaVu Serif", vec![]),
+        ];
+        let styles = |pattern: &str| {
+            font_list(&pattern.parse().unwrap(), None, &fonts)
+                .iter()
+                .map(|font| {
+                    font.get_string(&PropertyKind::Style, 0)
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        k9::assert_equal!(styles(""), ["Bold", "Book", ""]);
+        k9::assert_equal!(styles("dejavusans"), ["Bold", "Book"]);
+        k9::assert_equal!(styles("DejaVu"), Vec::<String>::new());
+        // range contains the weight
+        k9::assert_equal!(styles(":weight=100"), ["Book"]);
+        k9::assert_equal!(styles(":lang=ja"), ["Bold"]);
+        k9::assert_equal!(styles(":lang=en|ja:weight=200"), ["Bold"]);
+
+        // duplicates of the reduced fonts are dropped
+        let families = font_list(&Pattern::new(), Some(&[PropertyKind::Family]), &fonts);
+        k9::assert_equal!(families.len(), 2);
+        k9::assert_equal!(families[0].len(), 1);
+    }
+
+    #[test]
+    fn strong_and_weak_family() {
+        let fonts = [
+            font(
+                "Bar",
+                vec![(PropertyKind::Lang, LangSet::from("en").into())],
+            ),
+            font(
+                "Baz",
+                vec![(PropertyKind::Lang, LangSet::from("ja").into())],
+            ),
+        ];
+        let c = FontConfig::default();
+
+        // language is more important than a weak family
+        let mut pattern = Pattern::new();
+        pattern.add_weak(PropertyKind::Family, "Bar");
+        pattern.add(PropertyKind::Lang, LangSet::from("ja"));
+        k9::assert_equal!(family(&c.font_match(&pattern, &fonts).unwrap()), "Baz");
+
+        // but not than a strong one
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "bar");
+        pattern.add(PropertyKind::Lang, LangSet::from("ja"));
+        k9::assert_equal!(family(&c.font_match(&pattern, &fonts).unwrap()), "Bar");
+    }
+
+    #[test]
+    fn style_distance() {
+        let fonts = [
+            font("A", vec![(PropertyKind::Weight, Value::Int(80))]),
+            font("B", vec![(PropertyKind::Weight, Value::Int(200))]),
+            font("C", vec![(PropertyKind::Weight, Value::Range(100, 210))]),
+        ];
+        let c = FontConfig::default();
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Weight, 180);
+        let matched = c.font_match(&pattern, &fonts).unwrap();
+        // variable font takes the requested weight
+        k9::assert_equal!(family(&matched), "C");
+        k9::assert_equal!(matched.get_double(&PropertyKind::Weight, 0), Some(180.0));
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Weight, 50);
+        k9::assert_equal!(family(&c.font_match(&pattern, &fonts).unwrap()), "A");
+    }
+
+    #[test]
+    fn variable_and_symbol() {
+        let c = FontConfig::default();
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Variable, false);
+        pattern.add(PropertyKind::Scalable, true);
+        pattern.add(PropertyKind::Symbol, false);
+        pattern.add(PropertyKind::Spacing, 0);
+
+        // static fonts are preferred over scalable ones
+        let fonts = [
+            font("Variable", vec![(PropertyKind::Variable, true.into())]),
+            font("Bitmap", vec![(PropertyKind::Scalable, false.into())]),
+        ];
+        k9::assert_equal!(family(&c.font_match(&pattern, &fonts).unwrap()), "Bitmap");
+
+        // and text fonts over spacing
+        let fonts = [
+            font("Symbol", vec![(PropertyKind::Symbol, true.into())]),
+            font("Mono", vec![(PropertyKind::Spacing, 100.into())]),
+        ];
+        k9::assert_equal!(family(&c.font_match(&pattern, &fonts).unwrap()), "Mono");
+    }
+
+    #[test]
+    fn value_order() {
+        let fonts = [font("B", vec![]), font("A", vec![])];
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "A");
+        pattern.add(PropertyKind::Family, "B");
+
+        let a = match_score(&pattern, &fonts[1]).unwrap();
+        let b = match_score(&pattern, &fonts[0]).unwrap();
+        assert!(a < b);
+        k9::assert_equal!(
+            family(&FontConfig::default().font_match(&pattern, &fonts).unwrap()),
+            "A"
+        );
+    }
+
+    #[test]
+    fn localized_names() {
+        let mut localized = font("Test", vec![(PropertyKind::Family, "テスト".into())]);
+        localized.add(PropertyKind::FamilyLang, "en");
+        localized.add(PropertyKind::FamilyLang, "ja");
+        let c = FontConfig::default();
+        let names = |pattern: &Pattern, kind| {
+            c.render_prepare(pattern, &localized)
+                .get(&kind)
+                .unwrap()
+                .iter()
+                .map(|value| value.value.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Namelang, "ja");
+        pattern.default_substitute();
+        k9::assert_equal!(
+            names(&pattern, PropertyKind::Family),
+            vec![Value::from("テスト"), Value::from("Test")]
+        );
+        k9::assert_equal!(
+            names(&pattern, PropertyKind::FamilyLang),
+            vec![Value::from("ja"), Value::from("en")]
+        );
+
+        // names are kept in order without preferred languages
+        k9::assert_equal!(
+            names(&Pattern::new(), PropertyKind::Family),
+            vec![Value::from("Test"), Value::from("テスト")]
+        );
+
+        // without languages of names only the best name is kept
+        let plain = font("A", vec![(PropertyKind::Family, "B".into())]);
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "b");
+        let prepared = c.render_prepare(&pattern, &plain);
+        k9::assert_equal!(prepared.get(&PropertyKind::Family).unwrap().len(), 1);
+        k9::assert_equal!(family(&prepared), "B");
+    }
+
+    #[test]
+    fn sort_trim() {
+        let charset = |ranges: Vec<(Int, Int)>| Value::CharSet(CharSet::from_ranges(ranges));
+        let fonts = [
+            font(
+                "Latin",
+                vec![(PropertyKind::Charset, charset(vec![(0x20, 0x7e)]))],
+            ),
+            font(
+                "Subset",
+                vec![(PropertyKind::Charset, charset(vec![(0x41, 0x5a)]))],
+            ),
+            font(
+                "Kana",
+                vec![(PropertyKind::Charset, charset(vec![(0x3040, 0x30ff)]))],
+            ),
+            font("Empty", vec![]),
+        ];
+        let c = FontConfig::default();
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "Latin");
+
+        let families = |trim| {
+            c.font_sort(&pattern, &fonts, trim)
+                .into_iter()
+                .map(family)
+                .collect::<Vec<_>>()
+        };
+
+        k9::assert_equal!(families(true), vec!["Latin", "Kana"]);
+        k9::assert_equal!(families(false), vec!["Latin", "Subset", "Kana", "Empty"]);
+    }
+
+    #[test]
+    fn sort_lang_fallback() {
+        let fonts = [
+            font(
+                "Sans",
+                vec![(PropertyKind::Lang, LangSet::from("en").into())],
+            ),
+            font(
+                "Sans Mono",
+                vec![(PropertyKind::Lang, LangSet::from("en").into())],
+            ),
+            font(
+                "Sans JP",
+                vec![(PropertyKind::Lang, LangSet::from("ja").into())],
+            ),
+        ];
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Lang, "en");
+        pattern.add(PropertyKind::Lang, "ja");
+
+        let families = FontConfig::default()
+            .font_sort(&pattern, &fonts, false)
+            .into_iter()
+            .map(family)
+            .collect::<Vec<_>>();
+        // second font for english comes after the one covering japanese
+        k9::assert_equal!(families, vec!["Sans", "Sans JP", "Sans Mono"]);
+    }
+
+    #[test]
+    fn default_substitute() {
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Size, 12.0);
+        pattern.add(PropertyKind::Dpi, 96.0);
+        pattern.default_substitute();
+
+        k9::assert_equal!(pattern.get_double(&PropertyKind::PixelSize, 0), Some(16.0));
+        k9::assert_equal!(pattern.get_int(&PropertyKind::Weight, 0), Some(80));
+        k9::assert_equal!(
+            pattern.get(&PropertyKind::Slant).unwrap()[0].binding,
+            EditBinding::Strong
+        );
+        k9::assert_equal!(pattern.get(&PropertyKind::Antialias), None);
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Namelang, "ja");
+        pattern.add(PropertyKind::StyleLang, "de");
+        pattern.default_substitute();
+
+        let langs = pattern.get(&PropertyKind::FamilyLang).unwrap();
+        k9::assert_equal!(langs[0].value, Value::String("ja".into()));
+        k9::assert_equal!(langs[1].value, Value::String("en-us".into()));
+        k9::assert_equal!(langs[1].binding, EditBinding::Weak);
+        k9::assert_equal!(pattern.get(&PropertyKind::StyleLang).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn compare_functions() {
+        let distance = |compare: Compare, l: &str, r: &str| compare(&l.into(), &r.into()).unwrap();
+
+        // delimiters count in the length
+        k9::assert_equal!(
+            distance(compare_postscript, "Foo-Bold", "FooBar"),
+            (3.0 / 8.0, "FooBar".into())
+        );
+        k9::assert_equal!(distance(compare_postscript, "Foo Bold", "foo-bold").0, 0.0);
+        k9::assert_equal!(distance(compare_postscript, "Foo", "Bar").0, 1.0);
+
+        let range = |l: Value, r: Value| compare_range(&l, &r).unwrap();
+        k9::assert_equal!(
+            range(Value::Range(80, 200), Value::Range(100, 300)),
+            (0.0, Value::Double(150.0))
+        );
+        k9::assert_equal!(
+            range(Value::Int(80), Value::Range(100, 300)),
+            (20.0, Value::Double(100.0))
+        );
+        k9::assert_equal!(
+            range(Value::Int(120), Value::Int(200)),
+            (80.0, Value::Double(200.0))
+        );
+    }
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("/usr/share/fonts/*", "/usr/share/fonts/a/b.ttf"));
+        assert!(glob_match("*.pc?", "foo.pcf"));
+        assert!(!glob_match("*.ttf", "foo.otf"));
+    }
+}
@@ -0,0 +1,364 @@
+//! Font name syntax like `DejaVu Sans-12:weight=bold`, see `FcNameParse` and `FcNameUnparse`
+
+use crate::*;
+
+use std::fmt::{self, Write};
+
+/// Chars escaped in family and size
+const ESCAPE_FIXED: &str = "\\-:,";
+/// Chars escaped in other property values
+const ESCAPE_VARIABLE: &str = "\\=_:,";
+
+/// Find next token delimited by one of `delims` like `FcNameFindNext`
+///
+/// Returns unescaped token, the delimiter and the rest of input
+fn find_next<'a>(s: &'a str, delims: &str) -> (String, Option<char>, &'a str) {
+    let s = s.trim_start();
+    let mut token = String::new();
+    let mut chars = s.char_indices();
+
+    while let Some((idx, c)) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some((_, c)) => token.push(c),
+                None => break,
+            }
+        } else if delims.contains(c) {
+            return (token, Some(c), &s[idx + c.len_utf8()..]);
+        } else {
+            token.push(c);
+        }
+    }
+
+    (token, None, "")
+}
+
+/// Same as `FcNameParse`
+pub fn parse_name(name: &str) -> Result<Vec<Property>> {
+    let mut props = Vec::new();
+    let mut rest = name;
+    let mut delim;
+
+    loop {
+        let (family, d, r) = find_next(rest, "-,:");
+        delim = d;
+        rest = r;
+
+        if !family.is_empty() {
+            props.push(Property::Family(family.into()));
+        }
+
+        if delim != Some(',') {
+            break;
+        }
+    }
+
+    if delim == Some('-') {
+        loop {
+            let (size, d, r) = find_next(rest, "-,:");
+            delim = d;
+            rest = r;
+
+            // `sans-serif` has no size, libfontconfig ignores anything `strtod` can't parse
+            if let Ok(size) = size.parse::<Double>() {
+                props.push(Property::Size(size.into()));
+            }
+
+            if delim != Some(',') {
+                break;
+            }
+        }
+    }
+
+    while delim == Some(':') {
+        let (name, d, r) = find_next(rest, "=_:");
+        delim = d;
+        rest = r;
+
+        if name.is_empty() {
+            continue;
+        }
+
+        if let Some('=') | Some('_') = delim {
+            let kind: PropertyKind = name.parse()?;
+
+            loop {
+                let (value, d, r) = find_next(rest, ":,");
+                delim = d;
+                rest = r;
+
+                let value = parse_value(&kind, &value)?;
+                props.push(kind.clone().make_property(value.into()));
+
+                if delim != Some(',') {
+                    break;
+                }
+            }
+        } else if let Ok(constant) = name.parse::<Constant>() {
+            // `:bold` is same as `:weight=bold`
+            let kind = constant.property_kind();
+            let value = constant.get_value(kind.clone()).unwrap_or_default();
+            props.push(kind.make_property(Value::Int(value).into()));
+        }
+    }
+
+    Ok(props)
+}
+
+/// Convert a value by type of `kind` like `FcNameConvert`
+fn parse_value(kind: &PropertyKind, s: &str) -> Result<Value> {
+    let int = |s: &str| -> Result<Int> {
+        match s
+            .parse::<Constant>()
+            .ok()
+            .and_then(|c| c.get_value(kind.clone()))
+        {
+            Some(value) => Ok(value),
+            None => Ok(s.parse()?),
+        }
+    };
+
+    match kind.value_type() {
+        Some(ValueType::Int) => Ok(Value::Int(int(s)?)),
+        Some(ValueType::Double) => Ok(Value::Double(s.parse()?)),
+        Some(ValueType::Range) => {
+            if let Some(range) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                let mut bounds = range.split_whitespace();
+                let start = int(try_next!(bounds, "Expect range start"))?;
+                let end = int(try_next!(bounds, "Expect range end"))?;
+                Ok(Value::Range(start, end))
+            } else if let Ok(int) = int(s) {
+                Ok(Value::Int(int))
+            } else {
+                Ok(Value::Double(s.parse()?))
+            }
+        }
+        Some(ValueType::Bool) => parse_bool(s).map(Value::Bool),
+        Some(ValueType::LangSet) => Ok(Value::LangSet(s.split('|').collect())),
+        Some(ValueType::CharSet) => parse_charset(s).map(Value::CharSet),
+        Some(ValueType::Matrix) => {
+            let mut m = s.split_whitespace();
+            let mut next = || -> Result<Double> { Ok(try_next!(m, "Expect 4 numbers").parse()?) };
+            Ok(Value::Matrix([next()?, next()?, next()?, next()?]))
+        }
+        Some(ValueType::String) | None => Ok(Value::String(s.into())),
+    }
+}
+
+/// Same as `FcNameBool`
+pub(crate) fn parse_bool(s: &str) -> Result<Bool> {
+    let mut chars = s.chars().map(|c| c.to_ascii_lowercase());
+
+    match (chars.next(), chars.next()) {
+        (Some('t' | 'y' | '1'), _) | (Some('o'), Some('n')) => Ok(true),
+        (Some('f' | 'n' | '0'), _) | (Some('o'), Some('f')) => Ok(false),
+        _ => Err(Error::ParseEnumError("bool", s.into())),
+    }
+}
+
+/// Parse space separated hex code points and ranges like `20-7e a0`
+fn parse_charset(s: &str) -> Result<CharSet> {
+    s.split_whitespace()
+        .map(|range| {
+            let hex = |s: &str| Int::from_str_radix(s, 16);
+            Ok(match range.split_once('-') {
+                Some((start, end)) => IntOrRange::Range(hex(start)?, hex(end)?),
+                None => IntOrRange::Int(hex(range)?),
+            })
+        })
+        .collect()
+}
+
+impl core::str::FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut pattern = Pattern::new();
+
+        for prop in parse_name(s)? {
+            if let Expression::Simple(value) = prop.expression() {
+                pattern.add(prop.kind(), value.clone());
+            }
+        }
+
+        Ok(pattern)
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter, s: &str, escape: &str) -> fmt::Result {
+    for c in s.chars() {
+        if escape.contains(c) {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+fn write_values(f: &mut fmt::Formatter, values: &[PatternValue], escape: &str) -> fmt::Result {
+    for (idx, value) in values.iter().enumerate() {
+        if idx != 0 {
+            f.write_char(',')?;
+        }
+
+        match &value.value {
+            Value::Int(i) => write!(f, "{}", i)?,
+            Value::Double(d) => write!(f, "{}", d)?,
+            Value::String(s) => write_escaped(f, s, escape)?,
+            Value::LangSet(langs) => {
+                for (idx, lang) in langs.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_char('|')?;
+                    }
+
+                    write_escaped(f, lang, escape)?;
+                }
+            }
+            Value::Bool(b) => f.write_str(if *b { "True" } else { "False" })?,
+            Value::Range(start, end) => write!(f, "[{} {}]", start, end)?,
+            Value::Matrix([xx, xy, yx, yy]) => write!(f, "{} {} {} {}", xx, xy, yx, yy)?,
+            Value::CharSet(charset) => {
+                for (idx, &(start, end)) in charset.ranges().iter().enumerate() {
+                    if idx != 0 {
+                        f.write_char(' ')?;
+                    }
+
+                    if start == end {
+                        write!(f, "{:x}", start)?;
+                    } else {
+                        write!(f, "{:x}-{:x}", start, end)?;
+                    }
+                }
+            }
+            // patterns never hold unevaluated values
+            Value::Constant(_) | Value::Property(..) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Position in libfontconfig's object table which orders `FcNameUnparse`, unknown properties come last
+pub(crate) fn object_order(kind: &PropertyKind) -> usize {
+    crate::cache::OBJECTS
+        .iter()
+        .position(|name| *name == kind.name())
+        .unwrap_or(crate::cache::OBJECTS.len())
+}
+
+/// Values joined by `,` without escaping like `FcNameUnparseValueList`
+pub(crate) struct UnescapedValues<'a>(pub &'a [PatternValue]);
+
+impl fmt::Display for UnescapedValues<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_values(f, self.0, "")
+    }
+}
+
+/// Canonical font name: families, sizes then other properties in libfontconfig's object order
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(families) = self.get(&PropertyKind::Family) {
+            write_values(f, families, ESCAPE_FIXED)?;
+        }
+
+        if let Some(sizes) = self.get(&PropertyKind::Size) {
+            f.write_char('-')?;
+            write_values(f, sizes, ESCAPE_FIXED)?;
+        }
+
+        let mut props = self
+            .iter()
+            .filter(|(kind, _)| !matches!(kind, PropertyKind::Family | PropertyKind::Size))
+            .collect::<Vec<_>>();
+        props.sort_by_key(|&(kind, _)| (object_order(kind), kind));
+
+        for (kind, values) in props {
+            f.write_char(':')?;
+            write_escaped(f, kind.name(), ESCAPE_VARIABLE)?;
+            f.write_char('=')?;
+            write_values(f, values, ESCAPE_VARIABLE)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full() {
+        k9::assert_equal!(
+            parse_name("DejaVu Sans,Noto\\-Sans-12:weight=bold:lang=ja").unwrap(),
+            vec![
+                Property::Family("DejaVu Sans".into()),
+                Property::Family("Noto-Sans".into()),
+                Property::Size(12.0.into()),
+                Property::Weight(200.into()),
+                Property::Lang(Value::LangSet("ja".into()).into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_constant() {
+        k9::assert_equal!(
+            parse_name(":bold:italic:foo").unwrap(),
+            vec![Property::Weight(200.into()), Property::Slant(100.into())]
+        );
+        k9::assert_equal!(
+            parse_name("sans-serif").unwrap(),
+            vec![Property::Family("sans".into())]
+        );
+    }
+
+    #[test]
+    fn parse_typed() {
+        let pattern: Pattern =
+            "Mono:antialias=off:matrix=1 0.2 0 1:charset=20-7e a0:weight=[light bold]"
+                .parse()
+                .unwrap();
+
+        k9::assert_equal!(pattern.get_bool(&PropertyKind::Antialias, 0), Some(false));
+        k9::assert_equal!(
+            pattern.get_value(&PropertyKind::Matrix, 0),
+            Some(&Value::Matrix([1.0, 0.2, 0.0, 1.0]))
+        );
+        k9::assert_equal!(
+            pattern.get_value(&PropertyKind::Charset, 0),
+            Some(&Value::CharSet(CharSet::from_ranges(vec![
+                (0x20, 0x7e),
+                (0xa0, 0xa0)
+            ])))
+        );
+        k9::assert_equal!(
+            pattern.get_value(&PropertyKind::Weight, 0),
+            Some(&Value::Range(50, 200))
+        );
+
+        assert!("Mono:weight=heavyish".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn parse_langset() {
+        let pattern: Pattern = ":lang=ja|zh_TW".parse().unwrap();
+
+        k9::assert_equal!(
+            pattern.get_value(&PropertyKind::Lang, 0),
+            Some(&Value::LangSet(["ja", "zh-tw"].iter().collect()))
+        );
+        k9::assert_equal!(pattern.to_string(), ":lang=ja|zh-tw");
+    }
+
+    #[test]
+    fn format_round_trip() {
+        let name = "A\\-B,C-10.5:slant=100:weight=200:hinting=True:foo=b\\:ar";
+        let pattern: Pattern = name.parse().unwrap();
+
+        k9::assert_equal!(pattern.to_string(), name);
+        k9::assert_equal!(pattern.to_string().parse::<Pattern>().unwrap(), pattern);
+    }
+}
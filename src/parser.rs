@@ -2,27 +2,219 @@
 
 use crate::*;
 use roxmltree::Node;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Text and path of the document being parsed, used to locate nodes
+pub(crate) struct Source<'a> {
+    text: &'a str,
+    file: Option<Arc<Path>>,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str, file: Option<Arc<Path>>) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self {
+            text,
+            file,
+            line_starts,
+        }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.text[line_start..offset].chars().count() + 1;
 
+        Position {
+            line: line as u32,
+            column: column as u32,
+        }
+    }
+
+    pub fn span(&self, node: Node) -> Span {
+        let range = node.range();
+
+        Span {
+            file: self.file.clone(),
+            start: self.position(range.start),
+            end: self.position(range.end),
+        }
+    }
+
+    /// Attach location of `node` to `err` unless a nested element already did
+    fn locate(&self, err: Error, node: Node) -> Error {
+        match err {
+            Error::Located(mut span, err) => {
+                if span.file.is_none() {
+                    span.file = self.file.clone();
+                }
+                Error::Located(span, err)
+            }
+            err => Error::Located(self.span(node), Box::new(err)),
+        }
+    }
+}
+
+/// Parse parts of `<fontconfig>` lazily, problems which don't stop parsing are pushed to `diagnostics`
 pub fn parse_config<'a>(
     xml_doc: &'a roxmltree::Document,
-) -> Result<impl Iterator<Item = Result<ConfigPart>> + 'a> {
+    file: Option<Arc<Path>>,
+    options: ParseOptions,
+    diagnostics: &'a mut Vec<Diagnostic>,
+) -> Result<impl Iterator<Item = Result<Spanned<ConfigPart>>> + 'a> {
     let fontconfig = xml_doc.root_element();
 
     if fontconfig.tag_name().name() != "fontconfig" {
         return Err(Error::NoFontconfig);
     }
 
-    Ok(fontconfig
-        .children()
-        .filter_map(|c| parse_config_part(c).transpose()))
+    let source = Source::new(xml_doc.input_text(), file);
+
+    Ok(fontconfig.children().filter_map(move |c| {
+        if let Err(err) = check_element(&source, c, options, diagnostics) {
+            return Some(Err(err));
+        }
+
+        parse_config_part(&source, c, diagnostics).transpose()
+    }))
+}
+
+/// Attributes of each element in `fonts.dtd`, elements of expressions are checked separately
+const ELEMENT_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("description", &["domain"]),
+    ("dir", &["prefix", "salt"]),
+    ("cachedir", &["prefix"]),
+    ("include", &["prefix", "ignore_missing", "deprecated"]),
+    ("remap-dir", &["prefix", "as-path", "salt"]),
+    ("reset-dirs", &[]),
+    ("config", &[]),
+    ("rescan", &[]),
+    ("blank", &[]),
+    ("selectfont", &[]),
+    ("acceptfont", &[]),
+    ("rejectfont", &[]),
+    ("glob", &[]),
+    ("pattern", &[]),
+    ("patelt", &["name"]),
+    ("match", &["target"]),
+    (
+        "test",
+        &["qual", "name", "target", "compare", "ignore-blanks"],
+    ),
+    ("edit", &["name", "mode", "binding"]),
+    ("alias", &["binding"]),
+    ("family", &[]),
+    ("prefer", &[]),
+    ("accept", &[]),
+    ("default", &[]),
+    ("int", &[]),
+    ("double", &[]),
+    ("string", &[]),
+    ("bool", &[]),
+    ("const", &[]),
+    ("matrix", &[]),
+    ("charset", &[]),
+    ("langset", &[]),
+    ("range", &[]),
+    ("name", &["target"]),
+];
+
+fn element_attributes(name: &str) -> Option<&'static [&'static str]> {
+    ELEMENT_ATTRIBUTES
+        .iter()
+        .find(|(element, _)| *element == name)
+        .map(|(_, attrs)| *attrs)
+        .or_else(|| {
+            let is_operator = name.parse::<ListOp>().is_ok()
+                || name.parse::<UnaryOp>().is_ok()
+                || name.parse::<BinaryOp>().is_ok()
+                || name.parse::<TernaryOp>().is_ok();
+
+            if is_operator {
+                Some(&[])
+            } else {
+                None
+            }
+        })
+}
+
+/// Report unknown elements and attributes in `node` and its descendants like `FcStartElement`
+///
+/// Strict mode fails at the first one, otherwise each is reported as a warning
+fn check_element(
+    source: &Source,
+    node: Node,
+    options: ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+    for node in node.descendants().filter(|n| n.is_element()) {
+        let name = node.tag_name().name();
+
+        let err = match element_attributes(name) {
+            None => Some((
+                DiagnosticCode::UnknownElement,
+                Error::UnknownElement(name.into()),
+            )),
+            Some(attrs) => node
+                .attributes()
+                .find(|attr| !attrs.contains(&attr.name()))
+                .map(|attr| {
+                    (
+                        DiagnosticCode::UnknownAttribute,
+                        Error::UnknownAttribute(name.into(), attr.name().into()),
+                    )
+                }),
+        };
+
+        match err {
+            Some((_, err)) if options.strict => return Err(source.locate(err, node)),
+            Some((code, err)) => report(
+                diagnostics,
+                Diagnostic::from_error(Severity::Warning, code, &err, Some(source.span(node))),
+            ),
+            None => {}
+        }
+    }
+
+    Ok(())
 }
 
-fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
+fn parse_config_part(
+    source: &Source,
+    child: Node,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Option<Spanned<ConfigPart>>> {
+    match parse_config_part_value(source, child, diagnostics) {
+        Ok(part) => Ok(part.map(|value| Spanned {
+            span: source.span(child),
+            value,
+        })),
+        Err(err) => Err(source.locate(err, child)),
+    }
+}
+
+fn parse_config_part_value(
+    source: &Source,
+    child: Node,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Option<ConfigPart>> {
     let part = match child.tag_name().name() {
-        "description" => ConfigPart::Description(try_text!(child).into()),
+        "description" => ConfigPart::Description(Description {
+            domain: child.attribute("domain").map(Into::into),
+            text: try_text!(child).into(),
+        }),
         "alias" => {
             let mut alias = Alias::default();
 
+            parse_attrs!(child, {
+                "binding" => alias.binding,
+            });
+
             for child in child.children() {
                 let families =
                     child
@@ -93,14 +285,17 @@ fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
         "include" => {
             let mut dir = Include::default();
             let mut ignore_missing = "";
+            let mut deprecated = "";
 
             parse_attrs!(child, {
                 "prefix" => dir.prefix,
             }, {
                 "ignore_missing" => ignore_missing,
+                "deprecated" => deprecated,
             });
 
             dir.ignore_missing = matches!(ignore_missing, "yes");
+            dir.deprecated = matches!(deprecated, "yes");
             dir.path = try_text!(child).into();
 
             ConfigPart::Include(dir)
@@ -134,19 +329,24 @@ fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
             for child in child.children() {
                 let matches = child.children().filter_map(|c| match c.tag_name().name() {
                     "pattern" => {
-                        let patelts = c.children().filter_map(|patelt| {
-                            if patelt.tag_name().name() == "patelt" {
-                                let mut kind = PropertyKind::default();
-                                parse_attrs_opt!(patelt, {
-                                    "name" => kind,
-                                });
-                                parse_expr(patelt.first_element_child()?)
-                                    .ok()
-                                    .map(|expr| kind.make_property(expr))
-                            } else {
-                                None
-                            }
-                        });
+                        let patelts = c
+                            .children()
+                            .filter(|patelt| patelt.tag_name().name() == "patelt")
+                            .filter_map(|patelt| match parse_patelt(patelt) {
+                                Ok(prop) => Some(prop),
+                                Err(err) => {
+                                    report(
+                                        diagnostics,
+                                        Diagnostic::from_error(
+                                            Severity::Warning,
+                                            DiagnosticCode::InvalidPatternElement,
+                                            &err,
+                                            Some(source.span(patelt)),
+                                        ),
+                                    );
+                                    None
+                                }
+                            });
                         Some(FontMatch::Pattern(patelts.collect()))
                     }
                     "glob" => c.text().map(Into::into).map(FontMatch::Glob),
@@ -167,7 +367,10 @@ fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
             ConfigPart::SelectFont(s)
         }
         "match" => {
-            let mut m = Match::default();
+            let mut m = Match {
+                span: source.span(child),
+                ..Default::default()
+            };
 
             parse_attrs!(child, {
                 "target" => m.target,
@@ -175,44 +378,12 @@ fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
 
             for child in child.children() {
                 match child.tag_name().name() {
-                    "test" => {
-                        let mut t = Test::default();
-                        let mut kind = PropertyKind::default();
-
-                        parse_attrs!(child, {
-                            "name" => kind,
-                            "qual" => t.qual,
-                            "target" => t.target,
-                            "compare" => t.compare,
-                        });
-
-                        t.value = kind.make_property(parse_expr(
-                            child
-                                .first_element_child()
-                                .ok_or_else(|| Error::InvalidFormat(format!("Empty test value")))?,
-                        )?);
-
-                        m.tests.push(t);
-                    }
-
-                    "edit" => {
-                        let mut e = Edit::default();
-                        let mut kind = PropertyKind::default();
-
-                        parse_attrs!(child, {
-                            "name" => kind,
-                            "mode" => e.mode,
-                            "binding" => e.binding,
-                        });
-
-                        e.value = kind.make_property(parse_expr(
-                            child
-                                .first_element_child()
-                                .ok_or_else(|| Error::InvalidFormat(format!("Empty edit value")))?,
-                        )?);
-
-                        m.edits.push(e);
-                    }
+                    "test" => m
+                        .tests
+                        .push(parse_test(source, child).map_err(|err| source.locate(err, child))?),
+                    "edit" => m
+                        .edits
+                        .push(parse_edit(source, child).map_err(|err| source.locate(err, child))?),
                     _ => {}
                 }
             }
@@ -227,6 +398,85 @@ fn parse_config_part(child: Node) -> Result<Option<ConfigPart>> {
     Ok(Some(part))
 }
 
+/// `<patelt name="...">` with a single value
+fn parse_patelt(node: Node) -> Result<Property> {
+    let mut kind = PropertyKind::default();
+    parse_attrs!(node, {
+        "name" => kind,
+    });
+
+    let value = match node.first_element_child() {
+        Some(value) => value,
+        None => return Err(Error::InvalidFormat(format!("Expect patelt value"))),
+    };
+
+    Ok(kind.make_property(parse_expr(value)?))
+}
+
+fn parse_test(source: &Source, node: Node) -> Result<Test> {
+    let mut t = Test {
+        span: source.span(node),
+        ..Default::default()
+    };
+    let mut ignore_blanks = "";
+
+    parse_attrs!(node, {
+        "name" => t.kind,
+        "qual" => t.qual,
+        "target" => t.target,
+        "compare" => t.compare,
+    }, {
+        "ignore-blanks" => ignore_blanks,
+    });
+
+    if !ignore_blanks.is_empty() {
+        t.ignore_blanks = crate::name::parse_bool(ignore_blanks)?;
+    }
+
+    t.values = get_exprs(&node).collect::<Result<_>>()?;
+
+    if t.values.is_empty() {
+        return Err(Error::InvalidFormat(format!("Empty test value")));
+    }
+
+    Ok(t)
+}
+
+fn parse_edit(source: &Source, node: Node) -> Result<Edit> {
+    let mut e = Edit {
+        span: source.span(node),
+        ..Default::default()
+    };
+    parse_attrs!(node, {
+        "name" => e.kind,
+        "mode" => e.mode,
+        "binding" => e.binding,
+    });
+
+    // delete modes don't need any value
+    e.values = get_exprs(&node).collect::<Result<_>>()?;
+
+    Ok(e)
+}
+
+/// `<langset>` holds `<string>` elements, a plain text is read as a single language
+fn parse_langset(node: Node) -> Result<LangSet> {
+    if !node.children().any(|c| c.is_element()) {
+        return Ok(LangSet::from(node.text().unwrap_or_default()));
+    }
+
+    node.children()
+        .filter(|c| c.is_element())
+        .map(|c| match c.tag_name().name() {
+            "string" => Ok(c.text().unwrap_or_default()),
+            name => Err(locate(
+                Error::InvalidFormat(format!("Expect string in langset, got {}", name)),
+                c,
+            )),
+        })
+        .collect()
+}
+
 fn parse_int_or_range(node: Node) -> Result<IntOrRange> {
     let mut texts = get_texts(&node);
 
@@ -240,7 +490,21 @@ fn parse_int_or_range(node: Node) -> Result<IntOrRange> {
     }
 }
 
-fn parse_expr(node: Node) -> Result<Expression> {
+/// Parse an expression, errors are located at the innermost element which caused them
+pub(crate) fn parse_expr(node: Node) -> Result<Expression> {
+    parse_expr_value(node).map_err(|err| locate(err, node))
+}
+
+/// Locate `err` at `node` unless it's already located
+fn locate(err: Error, node: Node) -> Error {
+    match err {
+        err @ Error::Located(..) => err,
+        // the document is only scanned for lines when an error happens
+        err => Source::new(node.document().input_text(), None).locate(err, node),
+    }
+}
+
+fn parse_expr_value(node: Node) -> Result<Expression> {
     let mut exprs = get_exprs(&node);
     let mut texts = get_texts(&node);
 
@@ -252,22 +516,35 @@ fn parse_expr(node: Node) -> Result<Expression> {
 
     match node.tag_name().name() {
         "string" => Ok(Value::String(try_text!(node).into()).into()),
-        "langset" => Ok(Value::LangSet(try_text!(node).into()).into()),
+        "langset" => parse_langset(node).map(|langs| Value::LangSet(langs).into()),
         "double" => Ok(Value::Double(try_text!(node).parse()?).into()),
         "int" => Ok(Value::Int(try_text!(node).parse()?).into()),
         "bool" => Ok(Value::Bool(try_text!(node).parse()?).into()),
         "const" => Ok(Value::Constant(try_text!(node).parse()?).into()),
-        "matrix" => Ok(Expression::Matrix(Box::new([
-            next!(exprs)?,
-            next!(exprs)?,
-            next!(exprs)?,
-            next!(exprs)?,
-        ]))),
+        "matrix" => {
+            // the number of elements is checked by validation
+            let matrix = exprs.collect::<Result<Vec<_>>>()?;
+
+            // a matrix of literals is already evaluated
+            let doubles = matrix
+                .iter()
+                .filter_map(|expr| match expr {
+                    Expression::Simple(Value::Double(d)) => Some(*d),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            match doubles[..] {
+                [xx, xy, yx, yy] => Ok(Value::Matrix([xx, xy, yx, yy]).into()),
+                _ => Ok(Expression::Matrix(matrix)),
+            }
+        }
         "charset" => {
             let charset = node
                 .children()
-                .filter_map(|c| parse_int_or_range(c).ok())
-                .collect();
+                .filter(|c| c.is_element())
+                .map(|c| parse_int_or_range(c).map_err(|err| locate(err, c)))
+                .collect::<Result<_>>()?;
 
             Ok(Value::CharSet(charset).into())
         }
@@ -357,7 +634,7 @@ mod tests {
         test_parse_charset,
         parse_expr,
         "<charset><range><int>0</int><int>123</int></range></charset>",
-        Expression::from(vec![IntOrRange::Range(0, 123)]),
+        Expression::from(CharSet::from(vec![IntOrRange::Range(0, 123)])),
     );
 
     make_parse_test!(
@@ -382,10 +659,194 @@ mod tests {
         "<range>0<int>10</int></range>",
     );
 
+    make_parse_test!(
+        test_parse_invalid_matrix,
+        parse_expr,
+        "<matrix><int>1</int><int>0</int><int>0</int><int>1</int><int>0</int></matrix>",
+        Expression::Matrix(vec![
+            Value::Int(1).into(),
+            Value::Int(0).into(),
+            Value::Int(0).into(),
+            Value::Int(1).into(),
+            Value::Int(0).into(),
+        ]),
+    );
+
     make_parse_test!(
         test_langset,
         parse_expr,
         "<langset>ko-KR</langset>",
         Expression::from(Value::LangSet("ko-KR".into())),
     );
+
+    make_parse_test!(
+        test_langset_strings,
+        parse_expr,
+        "<langset><string>zh_TW</string> <string>ja</string></langset>",
+        Expression::from(Value::LangSet(["ja", "zh-tw"].iter().collect())),
+    );
+
+    make_parse_test!(
+        test_charset,
+        parse_expr,
+        "<charset>\n<int>65</int>\n<range><int>66</int><int>90</int></range>\n</charset>",
+        Expression::from(Value::CharSet(CharSet::from_ranges(vec![(65, 90)]))),
+    );
+
+    make_parse_failed_test!(
+        test_parse_invalid_charset,
+        parse_expr,
+        "<charset><int>65</int><string>B</string></charset>",
+    );
+
+    #[test]
+    fn test_spans() {
+        let parts = parse_config_parts_with_options(
+            "<fontconfig>\n  <dir>a</dir>\n  <match>\n    <test name=\"family\"><string>A</string></test>\n    <edit name=\"family\"><string>B</string></edit>\n  </match>\n</fontconfig>",
+            Some(Path::new("fonts.conf")),
+            &ParseOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        k9::assert_equal!(parts[0].span.start, Position { line: 2, column: 3 });
+        k9::assert_equal!(
+            parts[0].span.end,
+            Position {
+                line: 2,
+                column: 15
+            }
+        );
+        k9::assert_equal!(parts[1].span.to_string(), "fonts.conf:3:3");
+
+        let m = match &parts[1].value {
+            ConfigPart::Match(m) => m,
+            part => panic!("Unexpected part {:?}", part),
+        };
+        k9::assert_equal!(m.span.start, Position { line: 3, column: 3 });
+        k9::assert_equal!(m.tests[0].span.start, Position { line: 4, column: 5 });
+        k9::assert_equal!(m.edits[0].span.to_string(), "fonts.conf:5:5");
+    }
+
+    #[test]
+    fn test_error_location() {
+        let err = parse_config_parts_with_options(
+            "<fontconfig>\n  <match>\n    <edit name=\"weight\">\n      <const>heavyish</const>\n    </edit>\n  </match>\n</fontconfig>",
+            Some(Path::new("30-metric-aliases.conf")),
+            &ParseOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
+
+        k9::assert_equal!(err.span().unwrap().start, Position { line: 4, column: 7 });
+        assert!(matches!(err.inner(), Error::ParseEnumError(..)));
+        assert!(err
+            .to_string()
+            .starts_with("30-metric-aliases.conf:4:7: Unknown variant"));
+
+        // attribute errors are located at the element
+        let err =
+            parse_config_parts("<fontconfig>\n<match target=\"foo\"/></fontconfig>").unwrap_err();
+        k9::assert_equal!(err.span().unwrap().to_string(), "2:1");
+    }
+
+    #[test]
+    fn test_strict() {
+        let strict = ParseOptions { strict: true };
+        let typo = "<fontconfig>\n  <match>\n    <edit name=\"family\" mod=\"assign\"><string>A</string></edit>\n  </match>\n</fontconfig>";
+
+        let err =
+            parse_config_parts_with_options(typo, None, &strict, &mut Vec::new()).unwrap_err();
+        k9::assert_equal!(err.span().unwrap().to_string(), "3:5");
+        assert!(
+            matches!(err.inner(), Error::UnknownAttribute(name, attr) if name == "edit" && attr == "mod")
+        );
+
+        let err = parse_config_parts_with_options(
+            "<fontconfig>\n<alias>\n<family>serif</family>\n<prefered><family>A</family></prefered>\n</alias>\n</fontconfig>",
+            None,
+            &strict,
+            &mut Vec::new(),
+        )
+        .unwrap_err();
+        k9::assert_equal!(err.span().unwrap().to_string(), "4:1");
+        k9::assert_equal!(err.inner().to_string(), "Unknown element <prefered>");
+
+        // lenient mode skips them
+        let parts = parse_config_parts(typo).unwrap();
+        k9::assert_equal!(parts.len(), 1);
+
+        // operators are known elements
+        parse_config_parts_with_options(
+            "<fontconfig><match><edit name=\"size\"><times><name>size</name><floor><double>1.5</double></floor></times></edit></match></fontconfig>",
+            None,
+            &strict,
+            &mut Vec::new(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_strict_operators() {
+        let strict = ParseOptions { strict: true };
+        let mut edits = String::new();
+
+        for op in ["or", "and", "plus", "minus", "times", "divide"]
+            .iter()
+            .chain(&["eq", "not_eq", "less", "less_eq", "more", "more_eq"])
+            .chain(&["contains", "not_contains"])
+        {
+            edits += &format!(
+                "<edit name=\"size\"><{0}><int>1</int><int>2</int></{0}></edit>",
+                op
+            );
+        }
+        for op in ["not", "ceil", "cecil", "floor", "round", "trunc"] {
+            edits += &format!(
+                "<edit name=\"size\"><{0}><double>1.5</double></{0}></edit>",
+                op
+            );
+        }
+        edits += "<edit name=\"size\"><if><bool>true</bool><int>1</int><int>2</int></if></edit>";
+
+        let parts = parse_config_parts_with_options(
+            &format!("<fontconfig><match>{}</match></fontconfig>", edits),
+            None,
+            &strict,
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        match &parts[0].value {
+            ConfigPart::Match(m) => {
+                k9::assert_equal!(m.edits.len(), 21);
+                // `ceil` and the misspelled `cecil` are the same
+                k9::assert_equal!(m.edits[15].values, m.edits[16].values);
+                k9::assert_equal!(UnaryOp::Cecil.as_str(), "ceil");
+            }
+            part => panic!("Unexpected part {:?}", part),
+        }
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let mut diagnostics = Vec::new();
+        let parts = parse_config_parts_with_options(
+            "<fontconfig>\n<foo/>\n<selectfont>\n<rejectfont>\n<pattern>\n<patelt name=\"spacing\"><const>mono</const></patelt>\n<patelt name=\"spacing\"><const>monospace</const></patelt>\n</pattern>\n</rejectfont>\n</selectfont>\n</fontconfig>",
+            None,
+            &ParseOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+
+        k9::assert_equal!(parts.len(), 1);
+        k9::assert_equal!(diagnostics.len(), 2);
+        k9::assert_equal!(diagnostics[0].code, DiagnosticCode::UnknownElement);
+        k9::assert_equal!(
+            diagnostics[0].to_string(),
+            "2:1: warning[unknown-element]: Unknown element <foo>"
+        );
+        k9::assert_equal!(diagnostics[1].code, DiagnosticCode::InvalidPatternElement);
+        k9::assert_equal!(diagnostics[1].span.as_ref().unwrap().to_string(), "7:24");
+    }
 }
@@ -0,0 +1,752 @@
+//! Font file scanning like `FcFreeTypeQueryAll` and `FcDirScan`
+
+mod cache;
+mod lang;
+mod orth;
+mod woff;
+
+use crate::eval::number;
+use crate::*;
+
+use std::path::{Path, PathBuf};
+use ttf_parser::{Face, Tag};
+
+/// Fonts and subdirectories found in a font directory, see [`FontConfig::scan_dir`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScannedDir {
+    pub dir: PathBuf,
+    /// Patterns of every face in files of the directory
+    pub fonts: Vec<Pattern>,
+    /// Subdirectories in file name order, they aren't scanned
+    pub subdirs: Vec<PathBuf>,
+}
+
+impl FontConfig {
+    /// Scan a font file and run every `<match target="scan">` rule against each face like `fc-scan`
+    pub fn scan_file<P: AsRef<Path> + ?Sized>(&self, path: &P) -> Result<Vec<Pattern>> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let mut fonts = scan_font_data(&data, Some(path))?;
+
+        for font in fonts.iter_mut() {
+            self.substitute_with(MatchTarget::Scan, font, None);
+        }
+
+        Ok(fonts)
+    }
+
+    /// Scan files in `dir` like `FcDirScanConfig`
+    ///
+    /// Entries rejected by [`accept_filename`](Self::accept_filename) are skipped,
+    /// so are files which aren't fonts.
+    pub fn scan_dir<P: AsRef<Path> + ?Sized>(&self, dir: &P) -> Result<ScannedDir> {
+        let dir = dir.as_ref();
+        let mut entries = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        // same as `FcSortCmpStr`
+        entries.sort();
+
+        let mut scanned = ScannedDir {
+            dir: dir.to_path_buf(),
+            ..Default::default()
+        };
+
+        for path in entries {
+            if !self.accept_filename(&path) {
+                continue;
+            }
+
+            if path.is_dir() {
+                scanned.subdirs.push(path);
+            } else if let Ok(mut fonts) = self.scan_file(&path) {
+                scanned.fonts.append(&mut fonts);
+            }
+        }
+
+        Ok(scanned)
+    }
+
+    /// Scan every directory of [`dirs`](Self::dirs) and their subdirectories
+    ///
+    /// Directories which can't be read are skipped and fonts rejected by
+    /// [`accept_font`](Self::accept_font) are dropped, like fonts of `FcConfigBuildFonts`.
+    pub fn scan_fonts(&self) -> Vec<Pattern> {
+        self.collect_fonts(|dir| self.scan_dir(&dir.path))
+    }
+
+    /// Walk [`dirs`](Self::dirs) with `scan` which gives fonts and subdirectories of a directory
+    ///
+    /// Subdirectories are given with salt of the configured directory.
+    pub(crate) fn collect_fonts(
+        &self,
+        mut scan: impl FnMut(&DirData) -> Result<ScannedDir>,
+    ) -> Vec<Pattern> {
+        let mut fonts = Vec::new();
+        let mut pending = self.dirs.iter().rev().cloned().collect::<Vec<_>>();
+        let mut visited = Vec::new();
+
+        while let Some(dir) = pending.pop() {
+            // symlinked directories are scanned once
+            let canonical = std::fs::canonicalize(&dir.path).unwrap_or_else(|_| dir.path.clone());
+            if visited.contains(&canonical) {
+                continue;
+            }
+            visited.push(canonical);
+
+            let scanned = match scan(&dir) {
+                Ok(scanned) => scanned,
+                Err(_) => continue,
+            };
+
+            fonts.extend(
+                scanned
+                    .fonts
+                    .into_iter()
+                    .filter(|font| self.accept_font(font)),
+            );
+            pending.extend(scanned.subdirs.into_iter().rev().map(|path| DirData {
+                path,
+                salt: dir.salt.clone(),
+            }));
+        }
+
+        fonts
+    }
+}
+
+/// Patterns of every face in TrueType, OpenType, collection or WOFF data like `FcFreeTypeQueryAll`
+///
+/// Variable fonts give a pattern with [`PropertyKind::Variable`] for the whole font and one for each
+/// named instance whose index has the instance number in its upper 16 bits.
+/// `file` is only recorded in the patterns.
+pub fn scan_font_data(data: &[u8], file: Option<&Path>) -> Result<Vec<Pattern>> {
+    let decoded;
+    let data = if data.starts_with(b"wOFF") {
+        decoded = woff::decode(data)?;
+        &decoded[..]
+    } else if data.starts_with(b"wOF2") {
+        return Err(Error::InvalidFont("WOFF2 isn't supported".into()));
+    } else {
+        data
+    };
+
+    let count = ttf_parser::fonts_in_collection(data).unwrap_or(1);
+    let mut patterns = Vec::new();
+
+    for index in 0..count {
+        let face = Face::parse(data, index).map_err(|err| Error::InvalidFont(err.to_string()))?;
+        scan_face(&face, index, file, &mut patterns);
+    }
+
+    Ok(patterns)
+}
+
+const NAME_FAMILY: u16 = 1;
+const NAME_STYLE: u16 = 2;
+const NAME_FULL_NAME: u16 = 4;
+const NAME_POSTSCRIPT: u16 = 6;
+const NAME_TYPOGRAPHIC_FAMILY: u16 = 16;
+const NAME_TYPOGRAPHIC_STYLE: u16 = 17;
+const NAME_WWS_FAMILY: u16 = 21;
+const NAME_WWS_STYLE: u16 = 22;
+
+const SPACING_DUAL: Int = 90;
+const SPACING_MONO: Int = 100;
+
+const SLANT_ITALIC: Int = 100;
+const SLANT_OBLIQUE: Int = 110;
+
+fn scan_face(face: &Face, index: u32, file: Option<&Path>, patterns: &mut Vec<Pattern>) {
+    let mut pattern = Pattern::new();
+
+    let families = names(
+        face,
+        &[NAME_WWS_FAMILY, NAME_TYPOGRAPHIC_FAMILY, NAME_FAMILY],
+    );
+    let styles = names(face, &[NAME_WWS_STYLE, NAME_TYPOGRAPHIC_STYLE, NAME_STYLE]);
+    add_names(
+        &mut pattern,
+        PropertyKind::Family,
+        PropertyKind::FamilyLang,
+        &families,
+    );
+    add_names(
+        &mut pattern,
+        PropertyKind::Style,
+        PropertyKind::StyleLang,
+        &styles,
+    );
+    add_names(
+        &mut pattern,
+        PropertyKind::FullName,
+        PropertyKind::FullNameLang,
+        &names(face, &[NAME_FULL_NAME]),
+    );
+
+    if let Some((name, _)) = names(face, &[NAME_POSTSCRIPT]).into_iter().next() {
+        pattern.add(PropertyKind::Postscriptname, name);
+    }
+
+    let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"));
+    let style = styles.first().map_or("", |(name, _)| name.as_str());
+
+    // the style name wins over OS/2 flags like libfontconfig, `Oblique` faces often set the italic bit
+    let slant =
+        slant_from_style(style).unwrap_or_else(|| match face.tables().os2.map(|os2| os2.style()) {
+            Some(ttf_parser::Style::Italic) => SLANT_ITALIC,
+            Some(ttf_parser::Style::Oblique) => SLANT_OBLIQUE,
+            _ => 0,
+        });
+    pattern.add(PropertyKind::Slant, slant);
+
+    let weight = face
+        .tables()
+        .os2
+        .map(|os2| os2.weight().to_number())
+        .filter(|weight| *weight != 0)
+        .map_or(80.0, |weight| {
+            // some fonts use 1 to 9 like `usWidthClass`
+            let weight = if weight < 10 { weight * 100 } else { weight };
+            weight_from_opentype(weight as Double)
+        });
+    pattern.add(PropertyKind::Weight, number(weight));
+
+    let width = face
+        .tables()
+        .os2
+        .map_or(100, |os2| width_from_class(os2.width().to_number()));
+    pattern.add(PropertyKind::Width, width);
+
+    if let Some(foundry) = os2.and_then(|os2| os2.get(58..62)) {
+        let foundry = String::from_utf8_lossy(foundry)
+            .trim_end_matches([' ', '\0'])
+            .to_string();
+
+        if !foundry.is_empty() && foundry.chars().all(|c| c.is_ascii_graphic()) {
+            pattern.add(PropertyKind::Foundry, foundry);
+        }
+    }
+
+    let chars = chars(face);
+
+    if let Some(spacing) = spacing(face, &chars) {
+        pattern.add(PropertyKind::Spacing, spacing);
+    }
+
+    let charset = CharSet::from_ranges(char_ranges(&chars));
+    // `ulCodePageRange1` exists since version 1
+    let code_pages = os2
+        .filter(|os2| os2.len() >= 82 && u16::from_be_bytes([os2[0], os2[1]]) >= 1)
+        .map(|os2| u32::from_be_bytes([os2[78], os2[79], os2[80], os2[81]]));
+    pattern.add(
+        PropertyKind::Lang,
+        lang::charset_langs(&charset, code_pages),
+    );
+    pattern.add(PropertyKind::Charset, charset);
+
+    if let Some(file) = file {
+        pattern.add(PropertyKind::File, file.to_string_lossy().into_owned());
+    }
+
+    let has_table = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag)).is_some();
+    let outline = has_table(b"glyf") || has_table(b"CFF ") || has_table(b"CFF2");
+
+    if has_table(b"glyf") {
+        pattern.add(PropertyKind::Fontformat, "TrueType");
+    } else if has_table(b"CFF ") || has_table(b"CFF2") {
+        pattern.add(PropertyKind::Fontformat, "CFF");
+    }
+
+    pattern.add(PropertyKind::Outline, outline);
+    pattern.add(PropertyKind::Scalable, outline);
+    pattern.add(
+        PropertyKind::Color,
+        has_table(b"COLR") || has_table(b"CBDT") || has_table(b"sbix") || has_table(b"SVG "),
+    );
+    pattern.add(
+        PropertyKind::Decorative,
+        styles.iter().any(|(style, _)| is_decorative(style)),
+    );
+    pattern.add(PropertyKind::Fonthashint, has_table(b"prep"));
+
+    let symbol = face.tables().cmap.map_or(false, |cmap| {
+        cmap.subtables.into_iter().any(|subtable| {
+            subtable.platform_id == ttf_parser::PlatformId::Windows && subtable.encoding_id == 0
+        })
+    });
+    pattern.add(PropertyKind::Symbol, symbol);
+
+    if let Some(capability) = capability(face) {
+        pattern.add(PropertyKind::Capability, capability);
+    }
+
+    if let Some(head) = face.raw_face().table(Tag::from_bytes(b"head")) {
+        if let Some(revision) = head.get(4..8) {
+            let revision = u32::from_be_bytes([revision[0], revision[1], revision[2], revision[3]]);
+            pattern.add(PropertyKind::Fontversion, revision);
+        }
+    }
+
+    // only the pattern of the whole variable font is variable
+    pattern.add(PropertyKind::Variable, false);
+
+    let axes = face.variation_axes();
+
+    if axes.is_empty() {
+        pattern.add(PropertyKind::Index, index);
+        patterns.push(pattern);
+        return;
+    }
+
+    let mut variable = pattern.clone();
+    variable.add(PropertyKind::Index, index);
+    variable.delete(&PropertyKind::Variable);
+    variable.add(PropertyKind::Variable, true);
+
+    for axis in axes {
+        let range = |convert: fn(Double) -> Double| {
+            Value::Range(
+                convert(axis.min_value as Double).round() as Int,
+                convert(axis.max_value as Double).round() as Int,
+            )
+        };
+
+        match &axis.tag.to_bytes() {
+            b"wght" => {
+                variable.delete(&PropertyKind::Weight);
+                variable.add(PropertyKind::Weight, range(weight_from_opentype));
+            }
+            b"wdth" => {
+                variable.delete(&PropertyKind::Width);
+                variable.add(PropertyKind::Width, range(|width| width));
+            }
+            b"opsz" => {
+                variable.add(PropertyKind::Size, range(|size| size));
+            }
+            _ => {}
+        }
+    }
+
+    patterns.push(variable);
+
+    for (idx, instance) in named_instances(face).into_iter().enumerate() {
+        let mut font = pattern.clone();
+        font.add(PropertyKind::Index, index | ((idx as u32 + 1) << 16));
+
+        for (axis, value) in axes.into_iter().zip(instance.coords) {
+            let value = value as Double;
+
+            match &axis.tag.to_bytes() {
+                b"wght" => {
+                    font.delete(&PropertyKind::Weight);
+                    font.add(PropertyKind::Weight, number(weight_from_opentype(value)));
+                }
+                b"wdth" => {
+                    font.delete(&PropertyKind::Width);
+                    font.add(PropertyKind::Width, number(value));
+                }
+                b"ital" if value >= 1.0 => {
+                    font.delete(&PropertyKind::Slant);
+                    font.add(PropertyKind::Slant, SLANT_ITALIC);
+                }
+                b"slnt" if value != 0.0 => {
+                    font.delete(&PropertyKind::Slant);
+                    font.add(PropertyKind::Slant, SLANT_OBLIQUE);
+                }
+                _ => {}
+            }
+        }
+
+        let styles = names(face, &[instance.style_name_id]);
+
+        if !styles.is_empty() {
+            font.delete(&PropertyKind::Style);
+            font.delete(&PropertyKind::StyleLang);
+            add_names(
+                &mut font,
+                PropertyKind::Style,
+                PropertyKind::StyleLang,
+                &styles,
+            );
+
+            if let (Some((family, _)), Some((style, _))) = (families.first(), styles.first()) {
+                font.delete(&PropertyKind::FullName);
+                font.delete(&PropertyKind::FullNameLang);
+                font.add(PropertyKind::FullName, format!("{} {}", family, style));
+            }
+        }
+
+        if let Some(id) = instance.postscript_name_id {
+            if let Some((name, _)) = names(face, &[id]).into_iter().next() {
+                font.delete(&PropertyKind::Postscriptname);
+                font.add(PropertyKind::Postscriptname, name);
+            }
+        }
+
+        patterns.push(font);
+    }
+}
+
+/// Names with their language for `ids` in priority order, English names come first
+///
+/// Same names are only kept once.
+fn names(face: &Face, ids: &[u16]) -> Vec<(String, String)> {
+    let mut names = face
+        .names()
+        .into_iter()
+        .filter_map(|name| {
+            let priority = ids.iter().position(|id| *id == name.name_id)?;
+            let lang = name_lang(&name);
+            let value = match name.to_string() {
+                Some(value) => value,
+                // Mac Roman names are only used when they're ASCII
+                None if name.platform_id == ttf_parser::PlatformId::Macintosh
+                    && name.encoding_id == 0
+                    && name.name.is_ascii() =>
+                {
+                    String::from_utf8_lossy(name.name).into_owned()
+                }
+                None => return None,
+            };
+            let value = value.trim().to_string();
+
+            if value.is_empty() {
+                return None;
+            }
+
+            Some((lang != "en", priority, value, lang))
+        })
+        .collect::<Vec<_>>();
+
+    // `sort_by_key` is stable so names keep the order of the table
+    names.sort_by_key(|(not_en, priority, _, _)| (*not_en, *priority));
+
+    let mut ret: Vec<(String, String)> = Vec::new();
+
+    for (_, _, value, lang) in names {
+        if !ret
+            .iter()
+            .any(|(v, _)| crate::eval::str_eq_ignore_blanks_and_case(v, &value))
+        {
+            ret.push((value, lang));
+        }
+    }
+
+    ret
+}
+
+fn add_names(
+    pattern: &mut Pattern,
+    kind: PropertyKind,
+    lang_kind: PropertyKind,
+    names: &[(String, String)],
+) {
+    for (name, lang) in names {
+        pattern.add(kind.clone(), name.as_str());
+        pattern.add(lang_kind.clone(), lang.as_str());
+    }
+}
+
+/// Language of a name record like `FcSfntNameLanguage`, unknown languages are `und`
+fn name_lang(name: &ttf_parser::name::Name) -> String {
+    use ttf_parser::PlatformId;
+
+    let lang = match name.platform_id {
+        PlatformId::Windows => match name.language_id {
+            0x0404 => "zh-tw",
+            0x0804 => "zh-cn",
+            0x0c04 => "zh-hk",
+            0x1004 => "zh-sg",
+            0x1404 => "zh-mo",
+            0x0414 => "nb",
+            0x0814 => "nn",
+            id => match id & 0x3ff {
+                0x01 => "ar",
+                0x05 => "cs",
+                0x06 => "da",
+                0x07 => "de",
+                0x08 => "el",
+                0x09 => "en",
+                0x0a => "es",
+                0x0b => "fi",
+                0x0c => "fr",
+                0x0d => "he",
+                0x0e => "hu",
+                0x10 => "it",
+                0x11 => "ja",
+                0x12 => "ko",
+                0x13 => "nl",
+                0x15 => "pl",
+                0x16 => "pt",
+                0x19 => "ru",
+                0x1d => "sv",
+                0x1e => "th",
+                0x1f => "tr",
+                0x22 => "uk",
+                0x29 => "fa",
+                0x2a => "vi",
+                0x39 => "hi",
+                _ => "und",
+            },
+        },
+        PlatformId::Macintosh => match name.language_id {
+            0 => "en",
+            1 => "fr",
+            2 => "de",
+            3 => "it",
+            4 => "nl",
+            6 => "es",
+            11 => "ja",
+            19 => "zh-tw",
+            23 => "ko",
+            33 => "zh-cn",
+            _ => "und",
+        },
+        _ => "und",
+    };
+
+    lang.into()
+}
+
+fn slant_from_style(style: &str) -> Option<Int> {
+    let style = style.to_ascii_lowercase();
+
+    if style.contains("italic") {
+        Some(SLANT_ITALIC)
+    } else if style.contains("oblique") {
+        Some(SLANT_OBLIQUE)
+    } else {
+        None
+    }
+}
+
+/// Same as `FcContainsDecorative`, blanks and case are ignored
+fn is_decorative(style: &str) -> bool {
+    let style = style.replace(' ', "").to_ascii_lowercase();
+
+    ["shadow", "caps", "antiqua", "romansc", "embosed", "dunhill"]
+        .iter()
+        .any(|decorative| style.contains(decorative))
+}
+
+/// Same as `FcFontCapabilities`, sorted script tags of `GSUB` and `GPOS` after a Graphite table
+fn capability(face: &Face) -> Option<String> {
+    let mut scripts = Vec::new();
+
+    for tag in [b"GSUB", b"GPOS"] {
+        let table = match face.raw_face().table(Tag::from_bytes(tag)) {
+            Some(table) => table,
+            None => continue,
+        };
+        let u16_at = |offset: usize| {
+            table
+                .get(offset..offset + 2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+        };
+        let list = u16_at(4).unwrap_or(0);
+        let count = u16_at(list).unwrap_or(0);
+
+        scripts
+            .extend((0..count).map_while(|idx| table.get(list + 2 + idx * 6..list + 6 + idx * 6)));
+    }
+
+    scripts.sort_unstable();
+    scripts.dedup();
+
+    // the Graphite tag is followed by a space even without any script
+    let silf = face.raw_face().table(Tag::from_bytes(b"Silf")).is_some();
+    let otlayout = scripts
+        .iter()
+        .map(|tag| format!("otlayout:{}", String::from_utf8_lossy(tag)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match (silf, otlayout.is_empty()) {
+        (false, true) => None,
+        (false, false) => Some(otlayout),
+        (true, _) => Some(format!("ttable:Silf {}", otlayout)),
+    }
+}
+
+/// Same as `FcWeightFromOpenTypeDouble`, values between the table are interpolated
+pub(crate) fn weight_from_opentype(weight: Double) -> Double {
+    const MAP: &[(Double, Double)] = &[
+        (0.0, 0.0),
+        (100.0, 0.0),
+        (200.0, 40.0),
+        (300.0, 50.0),
+        (350.0, 55.0),
+        (380.0, 75.0),
+        (400.0, 80.0),
+        (500.0, 100.0),
+        (600.0, 180.0),
+        (700.0, 200.0),
+        (800.0, 205.0),
+        (900.0, 210.0),
+        (1000.0, 215.0),
+    ];
+
+    let weight = weight.clamp(0.0, 1000.0);
+    let idx = MAP
+        .iter()
+        .position(|(ot, _)| *ot >= weight)
+        .unwrap_or(MAP.len() - 1);
+
+    if idx == 0 || MAP[idx].0 == weight {
+        return MAP[idx].1;
+    }
+
+    let (ot0, fc0) = MAP[idx - 1];
+    let (ot1, fc1) = MAP[idx];
+    fc0 + (fc1 - fc0) * (weight - ot0) / (ot1 - ot0)
+}
+
+/// `usWidthClass` as fontconfig width
+fn width_from_class(class: u16) -> Int {
+    match class {
+        1 => 50,
+        2 => 63,
+        3 => 75,
+        4 => 87,
+        6 => 113,
+        7 => 125,
+        8 => 150,
+        9 => 200,
+        _ => 100,
+    }
+}
+
+/// Sorted characters mapped to a glyph by unicode `cmap` subtables
+fn chars(face: &Face) -> Vec<(u32, ttf_parser::GlyphId)> {
+    let mut chars = Vec::new();
+
+    if let Some(cmap) = face.tables().cmap {
+        for subtable in cmap.subtables.into_iter().filter(|s| s.is_unicode()) {
+            subtable.codepoints(|c| {
+                if let Some(glyph) = subtable.glyph_index(c).filter(|g| g.0 != 0) {
+                    chars.push((c, glyph));
+                }
+            });
+        }
+    }
+
+    chars.sort_by_key(|(c, _)| *c);
+    chars.dedup_by_key(|(c, _)| *c);
+    chars
+}
+
+fn char_ranges(chars: &[(u32, ttf_parser::GlyphId)]) -> Vec<(Int, Int)> {
+    let mut ranges: Vec<(Int, Int)> = Vec::new();
+
+    for (c, _) in chars {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == *c => *end = *c,
+            _ => ranges.push((*c, *c)),
+        }
+    }
+
+    ranges
+}
+
+/// Same as `FcFreeTypeSpacing`, advances of mapped glyphs decide mono or dual width
+fn spacing(face: &Face, chars: &[(u32, ttf_parser::GlyphId)]) -> Option<Int> {
+    let approximately_equal = |l: i32, r: i32| (l - r).abs() <= l.max(r) / 33;
+    // widened so doubling an advance can't overflow
+    let mut advances: Vec<i32> = Vec::new();
+
+    for (_, glyph) in chars {
+        let advance = match face.glyph_hor_advance(*glyph) {
+            Some(0) | None => continue,
+            Some(advance) => i32::from(advance),
+        };
+
+        if !advances.iter().any(|a| approximately_equal(*a, advance)) {
+            if advances.len() == 2 {
+                return None;
+            }
+            advances.push(advance);
+        }
+    }
+
+    match advances[..] {
+        [_] => Some(SPACING_MONO),
+        [l, r] if approximately_equal(l.min(r) * 2, l.max(r)) => Some(SPACING_DUAL),
+        _ => None,
+    }
+}
+
+struct NamedInstance {
+    style_name_id: u16,
+    coords: Vec<f32>,
+    postscript_name_id: Option<u16>,
+}
+
+/// Named instances of `fvar` which ttf-parser doesn't expose
+fn named_instances(face: &Face) -> Vec<NamedInstance> {
+    let data = match face.raw_face().table(Tag::from_bytes(b"fvar")) {
+        Some(data) => data,
+        None => return Vec::new(),
+    };
+
+    let u16_at = |offset: usize| {
+        data.get(offset..offset + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+    };
+
+    let header = (|| {
+        Some((
+            u16_at(4)? as usize,
+            u16_at(8)? as usize,
+            u16_at(10)? as usize,
+            u16_at(12)? as usize,
+            u16_at(14)? as usize,
+        ))
+    })();
+
+    let (axes_offset, axis_count, axis_size, instance_count, instance_size) = match header {
+        Some(header) => header,
+        None => return Vec::new(),
+    };
+
+    let start = axes_offset + axis_count * axis_size;
+
+    (0..instance_count)
+        .map_while(|idx| {
+            let offset = start + idx * instance_size;
+            let coords = (0..axis_count)
+                .map(|axis| {
+                    let pos = offset + 4 + axis * 4;
+                    data.get(pos..pos + 4)
+                        .map(|b| i32::from_be_bytes([b[0], b[1], b[2], b[3]]) as f32 / 65536.0)
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let postscript_name_id = if instance_size >= 4 + axis_count * 4 + 2 {
+                u16_at(offset + 4 + axis_count * 4).filter(|id| *id != 0xffff)
+            } else {
+                None
+            };
+
+            Some(NamedInstance {
+                style_name_id: u16_at(offset)?,
+                coords,
+                postscript_name_id,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weight() {
+        k9::assert_equal!(weight_from_opentype(400.0), 80.0);
+        k9::assert_equal!(weight_from_opentype(700.0), 200.0);
+        k9::assert_equal!(weight_from_opentype(450.0), 90.0);
+        k9::assert_equal!(weight_from_opentype(50.0), 0.0);
+        k9::assert_equal!(weight_from_opentype(1200.0), 215.0);
+    }
+}
@@ -0,0 +1,463 @@
+//! Persistent cache of scanned fonts for each font directory
+//!
+//! Each directory has a file in the first usable [`FontConfig::cache_dirs`] entry. Files are written
+//! to a temporary file and renamed over the old one, so processes updating the same cache at once
+//! never see a partial file.
+
+use super::ScannedDir;
+use crate::*;
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 8] = b"FCPRSCAN";
+
+/// Bumped whenever the layout or the scanner output changes
+const VERSION: u32 = 1;
+
+// value tags
+const TAG_INT: u8 = 0;
+const TAG_DOUBLE: u8 = 1;
+const TAG_STRING: u8 = 2;
+const TAG_BOOL: u8 = 3;
+const TAG_RANGE: u8 = 4;
+const TAG_LANGSET: u8 = 5;
+const TAG_CHARSET: u8 = 6;
+const TAG_MATRIX: u8 = 7;
+
+impl FontConfig {
+    /// Same as [`scan_dir`](Self::scan_dir) but reuses the cache of `dir` when it's up to date
+    ///
+    /// The cache is outdated when modification time of `dir` changed or it's older than the last
+    /// [`rescans`](Config::rescans) interval, unless the interval is zero. Otherwise `dir` is scanned
+    /// and the cache is written to the first of [`cache_dirs`](Self::cache_dirs) which can be written.
+    /// Like libfontconfig caches, changing `<match target="scan">` rules doesn't outdate caches.
+    pub fn scan_dir_cached(&self, dir: &DirData) -> Result<ScannedDir> {
+        let name = cache_file_name(dir);
+
+        for cache_dir in self.cache_dirs.iter() {
+            if let Some(scanned) = self.read_cache(&cache_dir.join(&name), &dir.path) {
+                return Ok(scanned);
+            }
+        }
+
+        // taken before scanning so changes made while scanning outdate the cache
+        let modified = modified(&dir.path)?;
+        let scanned_at = SystemTime::now();
+        let scanned = self.scan_dir(&dir.path)?;
+        let data = encode(&scanned, modified, scanned_at);
+
+        for cache_dir in self.cache_dirs.iter() {
+            if write_atomic(cache_dir, &name, &data).is_ok() {
+                break;
+            }
+        }
+
+        Ok(scanned)
+    }
+
+    /// Same as [`scan_fonts`](Self::scan_fonts) using [`scan_dir_cached`](Self::scan_dir_cached)
+    ///
+    /// Subdirectories use salt of the configured directory they're in.
+    pub fn scan_fonts_cached(&self) -> Vec<Pattern> {
+        self.collect_fonts(|dir| self.scan_dir_cached(dir))
+    }
+
+    fn read_cache(&self, file: &Path, dir: &Path) -> Option<ScannedDir> {
+        let data = std::fs::read(file).ok()?;
+        let cache = decode(&data)?;
+
+        if cache.scanned.dir != dir || cache.modified != modified(dir).ok()? {
+            return None;
+        }
+
+        let rescan = self.config.rescans.last().copied().unwrap_or(0);
+
+        if rescan != 0 {
+            let age = cache.scanned_at.elapsed().ok()?;
+
+            if age > Duration::from_secs(rescan.into()) {
+                return None;
+            }
+        }
+
+        Some(cache.scanned)
+    }
+}
+
+/// Same name for a directory and salt in every process, remapping isn't used since paths of
+/// cached fonts are of the scanned directory
+fn cache_file_name(dir: &DirData) -> String {
+    format!(
+        "{}-fontconfig-parser.cache-{}",
+        dir.cache_hash(&[]),
+        VERSION
+    )
+}
+
+fn modified(dir: &Path) -> Result<SystemTime> {
+    Ok(std::fs::metadata(dir)?.modified()?)
+}
+
+/// Write to a temporary file in `cache_dir` then rename it to `name`
+fn write_atomic(cache_dir: &Path, name: &str, data: &[u8]) -> std::io::Result<()> {
+    std::fs::create_dir_all(cache_dir)?;
+
+    // unique for each writer so they never write to the same file
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+    let temp = cache_dir.join(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = std::fs::File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(data)?;
+            file.sync_all()
+        })
+        .and_then(|_| std::fs::rename(&temp, cache_dir.join(name)));
+
+    if result.is_err() {
+        std::fs::remove_file(&temp).ok();
+    }
+
+    result
+}
+
+struct DirCache {
+    scanned: ScannedDir,
+    modified: SystemTime,
+    scanned_at: SystemTime,
+}
+
+fn encode(scanned: &ScannedDir, modified: SystemTime, scanned_at: SystemTime) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    out.extend_from_slice(MAGIC);
+    put_u32(&mut out, VERSION);
+    put_time(&mut out, modified);
+    put_time(&mut out, scanned_at);
+    put_path(&mut out, &scanned.dir);
+
+    put_u32(&mut out, scanned.subdirs.len() as u32);
+    for subdir in scanned.subdirs.iter() {
+        put_path(&mut out, subdir);
+    }
+
+    put_u32(&mut out, scanned.fonts.len() as u32);
+    for font in scanned.fonts.iter() {
+        put_pattern(&mut out, font);
+    }
+
+    out
+}
+
+/// `None` for files of other versions or which are broken
+fn decode(data: &[u8]) -> Option<DirCache> {
+    let mut r = Reader(data);
+
+    if r.take(MAGIC.len())? != MAGIC || r.u32()? != VERSION {
+        return None;
+    }
+
+    let modified = r.time()?;
+    let scanned_at = r.time()?;
+    let dir = r.path()?;
+    let subdirs = (0..r.u32()?)
+        .map(|_| r.path())
+        .collect::<Option<Vec<_>>>()?;
+    let fonts = (0..r.u32()?)
+        .map(|_| r.pattern())
+        .collect::<Option<Vec<_>>>()?;
+
+    if !r.0.is_empty() {
+        return None;
+    }
+
+    Some(DirCache {
+        scanned: ScannedDir {
+            dir,
+            subdirs,
+            fonts,
+        },
+        modified,
+        scanned_at,
+    })
+}
+
+fn put_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_f64(out: &mut Vec<u8>, v: f64) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_u32(out, bytes.len() as u32);
+    out.extend_from_slice(bytes);
+}
+
+fn put_time(out: &mut Vec<u8>, time: SystemTime) {
+    let since = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    out.extend_from_slice(&since.as_secs().to_le_bytes());
+    put_u32(out, since.subsec_nanos());
+}
+
+#[cfg(unix)]
+fn put_path(out: &mut Vec<u8>, path: &Path) {
+    use std::os::unix::ffi::OsStrExt;
+
+    put_bytes(out, path.as_os_str().as_bytes());
+}
+
+#[cfg(not(unix))]
+fn put_path(out: &mut Vec<u8>, path: &Path) {
+    put_bytes(out, path.to_string_lossy().as_bytes());
+}
+
+fn put_pattern(out: &mut Vec<u8>, pattern: &Pattern) {
+    put_u32(out, pattern.len() as u32);
+
+    for (kind, values) in pattern.iter() {
+        put_bytes(out, kind.name().as_bytes());
+
+        let values = values
+            .iter()
+            .filter(|v| !matches!(v.value, Value::Constant(_) | Value::Property(..)))
+            .collect::<Vec<_>>();
+
+        put_u32(out, values.len() as u32);
+
+        for value in values {
+            out.push(match value.binding {
+                EditBinding::Strong => 1,
+                _ => 0,
+            });
+            put_value(out, &value.value);
+        }
+    }
+}
+
+fn put_value(out: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Int(i) => {
+            out.push(TAG_INT);
+            put_u32(out, *i);
+        }
+        Value::Double(d) => {
+            out.push(TAG_DOUBLE);
+            put_f64(out, *d);
+        }
+        Value::String(s) => {
+            out.push(TAG_STRING);
+            put_bytes(out, s.as_bytes());
+        }
+        Value::Bool(b) => {
+            out.push(TAG_BOOL);
+            out.push(*b as u8);
+        }
+        Value::Range(start, end) => {
+            out.push(TAG_RANGE);
+            put_u32(out, *start);
+            put_u32(out, *end);
+        }
+        Value::LangSet(langs) => {
+            out.push(TAG_LANGSET);
+            put_u32(out, langs.len() as u32);
+            for lang in langs.iter() {
+                put_bytes(out, lang.as_bytes());
+            }
+        }
+        Value::CharSet(charset) => {
+            out.push(TAG_CHARSET);
+            put_u32(out, charset.ranges().len() as u32);
+            for (start, end) in charset.ranges() {
+                put_u32(out, *start);
+                put_u32(out, *end);
+            }
+        }
+        Value::Matrix(matrix) => {
+            out.push(TAG_MATRIX);
+            for v in matrix.iter() {
+                put_f64(out, *v);
+            }
+        }
+        // filtered by `put_pattern`
+        Value::Constant(_) | Value::Property(..) => unreachable!(),
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Some(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.u64().map(f64::from_bits)
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        std::str::from_utf8(self.bytes()?).ok().map(Into::into)
+    }
+
+    fn time(&mut self) -> Option<SystemTime> {
+        let secs = self.u64()?;
+        let nanos = self.u32()?;
+
+        // `Duration::new` panics when nanoseconds carry over the maximum seconds
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+
+        UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+    }
+
+    #[cfg(unix)]
+    fn path(&mut self) -> Option<PathBuf> {
+        use std::os::unix::ffi::OsStrExt;
+
+        Some(std::ffi::OsStr::from_bytes(self.bytes()?).into())
+    }
+
+    #[cfg(not(unix))]
+    fn path(&mut self) -> Option<PathBuf> {
+        self.string().map(Into::into)
+    }
+
+    fn pattern(&mut self) -> Option<Pattern> {
+        let mut pattern = Pattern::new();
+
+        for _ in 0..self.u32()? {
+            let kind = self.string()?.parse::<PropertyKind>().ok()?;
+
+            for _ in 0..self.u32()? {
+                let binding = match self.u8()? {
+                    1 => EditBinding::Strong,
+                    _ => EditBinding::Weak,
+                };
+                let value = self.value()?;
+
+                if !pattern.add_with_binding(kind.clone(), value, binding) {
+                    return None;
+                }
+            }
+        }
+
+        Some(pattern)
+    }
+
+    fn value(&mut self) -> Option<Value> {
+        let value = match self.u8()? {
+            TAG_INT => Value::Int(self.u32()?),
+            TAG_DOUBLE => Value::Double(self.f64()?),
+            TAG_STRING => Value::String(self.string()?),
+            TAG_BOOL => Value::Bool(self.u8()? != 0),
+            TAG_RANGE => Value::Range(self.u32()?, self.u32()?),
+            TAG_LANGSET => Value::LangSet(
+                (0..self.u32()?)
+                    .map(|_| self.string())
+                    .collect::<Option<Vec<_>>>()?
+                    .into(),
+            ),
+            TAG_CHARSET => Value::CharSet(CharSet::from_ranges(
+                (0..self.u32()?)
+                    .map(|_| Some((self.u32()?, self.u32()?)))
+                    .collect::<Option<Vec<_>>>()?,
+            )),
+            TAG_MATRIX => Value::Matrix([self.f64()?, self.f64()?, self.f64()?, self.f64()?]),
+            _ => return None,
+        };
+
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut font =
+            "Test Sans,テスト:style=Bold:weight=[50 210]:size=12.5:outline=true:lang=en|ja"
+                .parse::<Pattern>()
+                .unwrap();
+        font.add(
+            PropertyKind::Charset,
+            CharSet::from_ranges(vec![(0x20, 0x7e), (0x4e00, 0x9fff)]),
+        );
+        font.add(PropertyKind::Matrix, Value::Matrix([1.0, 0.2, 0.0, 1.0]));
+        font.add_weak(PropertyKind::Index, 3u32);
+
+        let scanned = ScannedDir {
+            dir: "/usr/share/fonts".into(),
+            fonts: vec![font],
+            subdirs: vec!["/usr/share/fonts/truetype".into()],
+        };
+        let modified = UNIX_EPOCH + Duration::new(1_700_000_000, 123);
+        let data = encode(&scanned, modified, modified);
+        let cache = decode(&data).unwrap();
+
+        k9::assert_equal!(cache.scanned, scanned);
+        k9::assert_equal!(cache.modified, modified);
+
+        assert!(decode(&data[..data.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn corrupt() {
+        let scanned = ScannedDir {
+            dir: "/usr/share/fonts".into(),
+            fonts: Vec::new(),
+            subdirs: Vec::new(),
+        };
+        let data = encode(&scanned, UNIX_EPOCH, UNIX_EPOCH);
+        let time = MAGIC.len() + 4;
+
+        // header ends within the modification time
+        assert!(decode(&data[..time + 10]).is_none());
+
+        let mut overflow = data.clone();
+        overflow[time..time + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        overflow[time + 8..time + 12].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+        assert!(decode(&overflow).is_none());
+
+        let mut nanos = data;
+        nanos[time + 8..time + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(decode(&nanos).is_none());
+    }
+}
@@ -0,0 +1,123 @@
+//! Languages supported by a charset like `FcFreeTypeLangSet`
+//!
+//! A language is supported when every code point of its orthography is in the charset, libfontconfig
+//! tolerates no missing ones.
+
+use super::orth::ORTHOGRAPHIES;
+use crate::{CharSet, Int, LangSet};
+
+/// CJK languages which share Han characters, a font is only made for one of them
+const EXCLUSIVE: &[&str] = &["ja", "ko", "zh-cn", "zh-tw"];
+
+/// Language of each CJK bit of OS/2 `ulCodePageRange1` like `FcCodePageRange`
+const CODE_PAGES: &[(u32, &str)] = &[
+    (17, "ja"),
+    (18, "zh-cn"),
+    (19, "ko"),
+    (20, "zh-tw"),
+    (21, "ko"),
+];
+
+/// Number of 256 code point pages which have any of `ranges`, like `num` of a `FcCharSet`
+fn page_count(ranges: &[(Int, Int)]) -> usize {
+    let mut pages = ranges
+        .iter()
+        .flat_map(|&(start, end)| (start >> 8)..=(end >> 8))
+        .collect::<Vec<_>>();
+    pages.dedup();
+    pages.len()
+}
+
+/// Languages whose orthography is covered by `charset`
+///
+/// When OS/2 code pages of the font claim a single CJK language, other CJK languages are left out.
+pub(crate) fn charset_langs(charset: &CharSet, code_pages: Option<u32>) -> LangSet {
+    let mut claimed = CODE_PAGES
+        .iter()
+        .filter(|(bit, _)| code_pages.map_or(false, |pages| pages & (1 << bit) != 0))
+        .map(|(_, lang)| *lang)
+        .collect::<Vec<_>>();
+    // both Korean bits claim the same language
+    claimed.sort_unstable();
+    claimed.dedup();
+
+    // a font claiming several of them may support any
+    let exclusive = match claimed[..] {
+        [lang] => ORTHOGRAPHIES.iter().find(|(l, _)| *l == lang),
+        _ => None,
+    };
+
+    ORTHOGRAPHIES
+        .iter()
+        .filter(|(lang, orth)| {
+            // libfontconfig only compares the number of pages with the claimed language
+            let excluded = exclusive.map_or(false, |(_, ex)| {
+                EXCLUSIVE.contains(lang) && page_count(orth) != page_count(ex)
+            });
+
+            !excluded && charset.is_superset(&CharSet::from_ranges(orth.iter().copied()))
+        })
+        .map(|(lang, _)| *lang)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LangResult;
+
+    fn orth(lang: &str) -> CharSet {
+        let (_, orth) = ORTHOGRAPHIES.iter().find(|(l, _)| *l == lang).unwrap();
+        CharSet::from_ranges(orth.iter().copied())
+    }
+
+    #[test]
+    fn table() {
+        let langs = ORTHOGRAPHIES
+            .iter()
+            .map(|(lang, _)| *lang)
+            .collect::<Vec<_>>();
+        k9::assert_equal!(langs, crate::cache::LANGS);
+    }
+
+    #[test]
+    fn langs() {
+        let latin1 = CharSet::from_ranges(vec![(0x20, 0x7e), (0xa0, 0xff)]);
+        let langs = charset_langs(&latin1, None);
+        k9::assert_equal!(langs.len(), 70);
+        k9::assert_equal!(langs.compare_lang("nl"), LangResult::Equal);
+        // `š` and `ž` are missing
+        k9::assert_equal!(langs.compare_lang("fi"), LangResult::DifferentLang);
+
+        // no missing letter is tolerated
+        let bg = orth("bg");
+        let hard_sign = CharSet::from_ranges(vec![(0x44a, 0x44a)]);
+        k9::assert_equal!(
+            charset_langs(&bg, None).compare_lang("bg"),
+            LangResult::Equal
+        );
+        k9::assert_equal!(
+            charset_langs(&bg.subtract(&hard_sign), None).compare_lang("bg"),
+            LangResult::DifferentLang
+        );
+
+        let cjk = orth("ja").union(&orth("zh-cn"));
+        let langs = |code_pages| {
+            let langs = charset_langs(&cjk, code_pages);
+            ["ja", "zh-cn"]
+                .iter()
+                .filter(|lang| langs.compare_lang(lang) == LangResult::Equal)
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        k9::assert_equal!(langs(None), ["ja", "zh-cn"]);
+        k9::assert_equal!(langs(Some(1 << 18 | 1 << 30)), ["zh-cn"]);
+        // several claimed languages don't exclude any
+        k9::assert_equal!(langs(Some(1 << 17 | 1 << 18)), ["ja", "zh-cn"]);
+
+        let cjk = orth("ko").union(&orth("ja"));
+        let langs = charset_langs(&cjk, Some(1 << 19 | 1 << 21));
+        k9::assert_equal!(langs.compare_lang("ko"), LangResult::Equal);
+        k9::assert_equal!(langs.compare_lang("ja"), LangResult::DifferentLang);
+    }
+}
@@ -0,0 +1,3790 @@
+//! Orthography of each language in order of `LANGS` of the cache module, generated by `gen-orth.py`
+//! from libfontconfig 2.14.1
+
+use crate::Int;
+
+/// Language and its code points, languages with the same orthography share a table
+pub(crate) const ORTHOGRAPHIES: &[(&str, &[(Int, Int)])] = &[
+    ("aa", AA),
+    ("ab", AB),
+    ("af", AF),
+    ("am", AM),
+    ("ar", AR),
+    ("as", AS),
+    ("ast", AST),
+    ("av", AV),
+    ("ay", AY),
+    ("az-az", AZ_AZ),
+    ("az-ir", AZ_IR),
+    ("ba", BA),
+    ("bm", BM),
+    ("be", BE),
+    ("bg", BG),
+    ("bh", BH),
+    ("bho", BH),
+    ("bi", BI),
+    ("bin", BIN),
+    ("bn", BN),
+    ("bo", BO),
+    ("br", BR),
+    ("bs", BS),
+    ("bua", BUA),
+    ("ca", CA),
+    ("ce", AV),
+    ("ch", CH),
+    ("chm", CHM),
+    ("chr", CHR),
+    ("co", CO),
+    ("cs", CS),
+    ("cu", CU),
+    ("cv", CV),
+    ("cy", CY),
+    ("da", DA),
+    ("de", DE),
+    ("dz", BO),
+    ("el", EL),
+    ("en", EN),
+    ("eo", EO),
+    ("es", ES),
+    ("et", ET),
+    ("eu", EU),
+    ("fa", AZ_IR),
+    ("fi", FI),
+    ("fj", FJ),
+    ("fo", FO),
+    ("fr", CO),
+    ("ff", FF),
+    ("fur", FUR),
+    ("fy", FY),
+    ("ga", GA),
+    ("gd", GD),
+    ("gez", GEZ),
+    ("gl", ES),
+    ("gn", GN),
+    ("gu", GU),
+    ("gv", GV),
+    ("ha", HA),
+    ("haw", HAW),
+    ("he", HE),
+    ("hi", BH),
+    ("ho", FJ),
+    ("hr", BS),
+    ("hu", HU),
+    ("hy", HY),
+    ("ia", FJ),
+    ("ig", IG),
+    ("id", ID),
+    ("ie", IE),
+    ("ik", IK),
+    ("io", FJ),
+    ("is", IS),
+    ("it", IT),
+    ("iu", IU),
+    ("ja", JA),
+    ("ka", KA),
+    ("kaa", KAA),
+    ("ki", KI),
+    ("kk", KK),
+    ("kl", KL),
+    ("km", KM),
+    ("kn", KN),
+    ("ko", KO),
+    ("kok", BH),
+    ("ks", KS),
+    ("ku-am", KU_AM),
+    ("ku-ir", KU_IR),
+    ("kum", KUM),
+    ("kv", KV),
+    ("kw", KW),
+    ("ky", KY),
+    ("la", LA),
+    ("lb", LB),
+    ("lez", AV),
+    ("ln", LN),
+    ("lo", LO),
+    ("lt", LT),
+    ("lv", LV),
+    ("mg", MG),
+    ("mh", MH),
+    ("mi", MI),
+    ("mk", MK),
+    ("ml", ML),
+    ("mn-cn", MN_CN),
+    ("mo", MO),
+    ("mr", BH),
+    ("mt", MT),
+    ("my", MY),
+    ("nb", NB),
+    ("nds", DE),
+    ("ne", NE),
+    ("nl", NL),
+    ("nn", NN),
+    ("no", NB),
+    ("nr", FJ),
+    ("nso", NSO),
+    ("ny", NY),
+    ("oc", OC),
+    ("om", FJ),
+    ("or", OR),
+    ("os", KUM),
+    ("pa", PA),
+    ("pl", PL),
+    ("ps-af", PS_AF),
+    ("ps-pk", PS_PK),
+    ("pt", PT),
+    ("rm", RM),
+    ("ro", RO),
+    ("ru", KUM),
+    ("sa", BH),
+    ("sah", SAH),
+    ("sco", SCO),
+    ("se", SE),
+    ("sel", KUM),
+    ("sh", SH),
+    ("shs", SHS),
+    ("si", SI),
+    ("sk", SK),
+    ("sl", BS),
+    ("sm", SM),
+    ("sma", SMA),
+    ("smj", SMJ),
+    ("smn", SMN),
+    ("sms", SMS),
+    ("so", FJ),
+    ("sq", SQ),
+    ("sr", SR),
+    ("ss", FJ),
+    ("st", FJ),
+    ("sv", SV),
+    ("sw", FJ),
+    ("syr", SYR),
+    ("ta", TA),
+    ("te", TE),
+    ("tg", TG),
+    ("th", TH),
+    ("ti-er", TI_ER),
+    ("ti-et", TI_ET),
+    ("tig", TIG),
+    ("tk", TK),
+    ("tl", TL),
+    ("tn", NSO),
+    ("to", SM),
+    ("tr", TR),
+    ("ts", FJ),
+    ("tt", TT),
+    ("tw", TW),
+    ("tyv", KY),
+    ("ug", UG),
+    ("uk", UK),
+    ("ur", UR),
+    ("uz", FJ),
+    ("ve", VE),
+    ("vi", VI),
+    ("vo", VO),
+    ("vot", VOT),
+    ("wa", WA),
+    ("wen", WEN),
+    ("wo", WO),
+    ("xh", FJ),
+    ("yap", YAP),
+    ("yi", HE),
+    ("yo", YO),
+    ("zh-cn", ZH_CN),
+    ("zh-hk", ZH_HK),
+    ("zh-mo", ZH_HK),
+    ("zh-sg", ZH_CN),
+    ("zh-tw", ZH_TW),
+    ("zu", FJ),
+    ("ak", TW),
+    ("an", ES),
+    ("ber-dz", BER_DZ),
+    ("ber-ma", BER_MA),
+    ("byn", TI_ER),
+    ("crh", CRH),
+    ("csb", CSB),
+    ("dv", DV),
+    ("ee", EE),
+    ("fat", TW),
+    ("fil", TL),
+    ("hne", BH),
+    ("hsb", HSB),
+    ("ht", HT),
+    ("hz", HZ),
+    ("ii", II),
+    ("jv", JV),
+    ("kab", BER_DZ),
+    ("kj", FJ),
+    ("kr", KR),
+    ("ku-iq", KU_IR),
+    ("ku-tr", KU_TR),
+    ("kwm", FJ),
+    ("lg", LG),
+    ("li", LI),
+    ("mai", BH),
+    ("mn-mn", MN_MN),
+    ("ms", FJ),
+    ("na", NA),
+    ("ng", FJ),
+    ("nv", NV),
+    ("ota", OTA),
+    ("pa-pk", UR),
+    ("pap-an", PAP_AN),
+    ("pap-aw", PAP_AW),
+    ("qu", QU),
+    ("quz", QU),
+    ("rn", FJ),
+    ("rw", FJ),
+    ("sc", SC),
+    ("sd", SD),
+    ("sg", SG),
+    ("sid", TI_ET),
+    ("sn", FJ),
+    ("su", ID),
+    ("ty", TY),
+    ("wal", TI_ET),
+    ("za", FJ),
+    ("lah", UR),
+    ("nqo", NQO),
+    ("brx", BRX),
+    ("sat", SAT),
+    ("doi", DOI),
+    ("mni", MNI),
+    ("und-zsye", UND_ZSYE),
+    ("und-zmth", UND_ZMTH),
+];
+
+#[rustfmt::skip]
+const AA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xca, 0xca), (0xce, 0xce), (0xd4, 0xd4),
+    (0xdb, 0xdb), (0xe2, 0xe2), (0xea, 0xea), (0xee, 0xee), (0xf4, 0xf4), (0xfb, 0xfb),
+];
+
+#[rustfmt::skip]
+const AB: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x40f, 0x44f), (0x451, 0x451), (0x45f, 0x45f), (0x49e, 0x49f), (0x4a6, 0x4a9),
+    (0x4ac, 0x4ad), (0x4b2, 0x4b7), (0x4bc, 0x4bf), (0x4d8, 0x4d9), (0x4e0, 0x4e1),
+];
+
+#[rustfmt::skip]
+const AF: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc8, 0xcb), (0xce, 0xcf), (0xd4, 0xd4), (0xdb, 0xdb),
+    (0xe8, 0xeb), (0xee, 0xef), (0xf4, 0xf4), (0xfb, 0xfb), (0x149, 0x149),
+];
+
+#[rustfmt::skip]
+const AM: &[(Int, Int)] = &[
+    (0x1200, 0x1206), (0x1208, 0x1216), (0x1218, 0x1226), (0x1228, 0x1230), (0x1238, 0x1246),
+    (0x1248, 0x1248), (0x124a, 0x124d), (0x1260, 0x126e), (0x1270, 0x1286), (0x1288, 0x1288),
+    (0x128a, 0x128d), (0x1290, 0x12ae), (0x12b0, 0x12b0), (0x12b2, 0x12b5), (0x12c8, 0x12ce),
+    (0x12d0, 0x12d6), (0x12d8, 0x12ee), (0x12f0, 0x12f7), (0x1300, 0x130e), (0x1310, 0x1310),
+    (0x1312, 0x1315), (0x1320, 0x1346), (0x1348, 0x1356),
+];
+
+#[rustfmt::skip]
+const AR: &[(Int, Int)] = &[
+    (0x621, 0x63a), (0x641, 0x64a),
+];
+
+#[rustfmt::skip]
+const AS: &[(Int, Int)] = &[
+    (0x981, 0x983), (0x985, 0x98c), (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9af), (0x9b2, 0x9b2),
+    (0x9b6, 0x9b9), (0x9bc, 0x9bc), (0x9be, 0x9c4), (0x9c7, 0x9c8), (0x9cb, 0x9cd), (0x9dc, 0x9dd),
+    (0x9df, 0x9df), (0x9f0, 0x9f1),
+];
+
+#[rustfmt::skip]
+const AST: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd1, 0xd1),
+    (0xd3, 0xd3), (0xda, 0xda), (0xdc, 0xdc), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed),
+    (0xf1, 0xf1), (0xf3, 0xf3), (0xfa, 0xfa), (0xfc, 0xfc), (0x1e24, 0x1e25), (0x1e36, 0x1e37),
+];
+
+#[rustfmt::skip]
+const AV: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x406, 0x406), (0x410, 0x44f), (0x451, 0x451),
+];
+
+#[rustfmt::skip]
+const AY: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xcf, 0xcf), (0xd1, 0xd1), (0xdc, 0xdc),
+    (0xe4, 0xe4), (0xef, 0xef), (0xf1, 0xf1), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const AZ_AZ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc7, 0xc7), (0xd6, 0xd6), (0xdc, 0xdc), (0xe7, 0xe7),
+    (0xf6, 0xf6), (0xfc, 0xfc), (0x11e, 0x11f), (0x130, 0x131), (0x15e, 0x15f), (0x18f, 0x18f),
+    (0x259, 0x259),
+];
+
+#[rustfmt::skip]
+const AZ_IR: &[(Int, Int)] = &[
+    (0x621, 0x624), (0x626, 0x63a), (0x641, 0x642), (0x644, 0x648), (0x64b, 0x64b), (0x654, 0x654),
+    (0x67e, 0x67e), (0x686, 0x686), (0x698, 0x698), (0x6a9, 0x6a9), (0x6af, 0x6af), (0x6cc, 0x6cc),
+];
+
+#[rustfmt::skip]
+const BA: &[(Int, Int)] = &[
+    (0x410, 0x44f), (0x492, 0x493), (0x498, 0x499), (0x4a0, 0x4a3), (0x4aa, 0x4ab), (0x4ae, 0x4af),
+    (0x4ba, 0x4bb), (0x4d8, 0x4d9), (0x4e8, 0x4e9),
+];
+
+#[rustfmt::skip]
+const BM: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x14a, 0x14b), (0x186, 0x186), (0x190, 0x190), (0x19d, 0x19d),
+    (0x254, 0x254), (0x25b, 0x25b), (0x272, 0x272),
+];
+
+#[rustfmt::skip]
+const BE: &[(Int, Int)] = &[
+    (0x406, 0x406), (0x40e, 0x40e), (0x410, 0x44f), (0x456, 0x456), (0x45e, 0x45e),
+];
+
+#[rustfmt::skip]
+const BG: &[(Int, Int)] = &[
+    (0x410, 0x42a), (0x42c, 0x42c), (0x42e, 0x44a), (0x44c, 0x44c), (0x44e, 0x44f),
+];
+
+#[rustfmt::skip]
+const BH: &[(Int, Int)] = &[
+    (0x905, 0x939), (0x93f, 0x94d),
+];
+
+#[rustfmt::skip]
+const BI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc9, 0xc9), (0xcf, 0xcf), (0xdc, 0xdc), (0xe9, 0xe9),
+    (0xef, 0xef), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const BIN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc1), (0xc8, 0xc9), (0xcc, 0xcd), (0xd2, 0xd3),
+    (0xd9, 0xda), (0xe0, 0xe1), (0xe8, 0xe9), (0xec, 0xed), (0xf2, 0xf3), (0xf9, 0xfa),
+    (0x300, 0x301), (0x1eb8, 0x1eb9), (0x1ecc, 0x1ecd),
+];
+
+#[rustfmt::skip]
+const BN: &[(Int, Int)] = &[
+    (0x981, 0x983), (0x985, 0x98c), (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9b0), (0x9b2, 0x9b2),
+    (0x9b6, 0x9b9), (0x9bc, 0x9bc), (0x9be, 0x9c4), (0x9c7, 0x9c8), (0x9cb, 0x9cd), (0x9dc, 0x9dd),
+    (0x9df, 0x9df),
+];
+
+#[rustfmt::skip]
+const BO: &[(Int, Int)] = &[
+    (0xf40, 0xf47), (0xf49, 0xf69), (0xf71, 0xf76), (0xf78, 0xf78), (0xf7a, 0xf7d), (0xf80, 0xf81),
+    (0xf90, 0xf97), (0xf99, 0xfb9),
+];
+
+#[rustfmt::skip]
+const BR: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xca, 0xca), (0xd1, 0xd1), (0xd4, 0xd4),
+    (0xd9, 0xd9), (0xdc, 0xdc), (0xe2, 0xe2), (0xea, 0xea), (0xf1, 0xf1), (0xf4, 0xf4),
+    (0xf9, 0xf9), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const BS: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x106, 0x107), (0x10c, 0x10d), (0x110, 0x111), (0x160, 0x161),
+    (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const BUA: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x4ae, 0x4af), (0x4ba, 0x4bb),
+];
+
+#[rustfmt::skip]
+const CA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc7, 0xc9), (0xcd, 0xcd), (0xcf, 0xcf),
+    (0xd2, 0xd3), (0xda, 0xda), (0xdc, 0xdc), (0xe0, 0xe0), (0xe7, 0xe9), (0xed, 0xed),
+    (0xef, 0xef), (0xf2, 0xf3), (0xfa, 0xfa), (0xfc, 0xfc), (0x13f, 0x140),
+];
+
+#[rustfmt::skip]
+const CH: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xd1, 0xd1), (0xdc, 0xdc), (0xe2, 0xe2),
+    (0xf1, 0xf1), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const CHM: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x4a4, 0x4a5), (0x4d2, 0x4d3), (0x4e6, 0x4e7),
+    (0x4f0, 0x4f1), (0x4f8, 0x4f9),
+];
+
+#[rustfmt::skip]
+const CHR: &[(Int, Int)] = &[
+    (0x13a0, 0x13f4),
+];
+
+#[rustfmt::skip]
+const CO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc2, 0xc2), (0xc6, 0xcb), (0xce, 0xcf),
+    (0xd4, 0xd4), (0xd9, 0xd9), (0xdb, 0xdc), (0xe0, 0xe0), (0xe2, 0xe2), (0xe6, 0xeb),
+    (0xee, 0xef), (0xf4, 0xf4), (0xf9, 0xf9), (0xfb, 0xfc), (0xff, 0xff), (0x152, 0x153),
+    (0x178, 0x178),
+];
+
+#[rustfmt::skip]
+const CS: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd3, 0xd3),
+    (0xda, 0xda), (0xdd, 0xdd), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed), (0xf3, 0xf3),
+    (0xfa, 0xfa), (0xfd, 0xfd), (0x10c, 0x10f), (0x11a, 0x11b), (0x147, 0x148), (0x158, 0x159),
+    (0x160, 0x161), (0x164, 0x165), (0x16e, 0x16f), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const CU: &[(Int, Int)] = &[
+    (0x401, 0x402), (0x405, 0x406), (0x408, 0x408), (0x40b, 0x40b), (0x40d, 0x40d), (0x40f, 0x418),
+    (0x41a, 0x42c), (0x42e, 0x44c), (0x44e, 0x450), (0x452, 0x452), (0x455, 0x456), (0x458, 0x458),
+    (0x45b, 0x45b), (0x45d, 0x45d), (0x45f, 0x479),
+];
+
+#[rustfmt::skip]
+const CV: &[(Int, Int)] = &[
+    (0x102, 0x103), (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x4aa, 0x4ab), (0x4d6, 0x4d7),
+    (0x4f2, 0x4f3),
+];
+
+#[rustfmt::skip]
+const CY: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc9, 0xca), (0xce, 0xcf), (0xd4, 0xd4),
+    (0xe2, 0xe2), (0xe9, 0xea), (0xee, 0xef), (0xf4, 0xf4), (0xff, 0xff), (0x174, 0x178),
+    (0x1e80, 0x1e85), (0x1ef2, 0x1ef3),
+];
+
+#[rustfmt::skip]
+const DA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc5, 0xc6), (0xc9, 0xc9), (0xcd, 0xcd),
+    (0xd3, 0xd3), (0xd8, 0xd8), (0xda, 0xda), (0xdd, 0xdd), (0xe1, 0xe1), (0xe5, 0xe6),
+    (0xe9, 0xe9), (0xed, 0xed), (0xf3, 0xf3), (0xf8, 0xf8), (0xfa, 0xfa), (0xfd, 0xfd),
+];
+
+#[rustfmt::skip]
+const DE: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xd6, 0xd6), (0xdc, 0xdc), (0xdf, 0xdf),
+    (0xe4, 0xe4), (0xf6, 0xf6), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const EL: &[(Int, Int)] = &[
+    (0x386, 0x386), (0x388, 0x38a), (0x38c, 0x38c), (0x38e, 0x3a1), (0x3a3, 0x3ce),
+];
+
+#[rustfmt::skip]
+const EN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc7, 0xcb), (0xcf, 0xcf), (0xd1, 0xd1),
+    (0xd4, 0xd4), (0xd6, 0xd6), (0xe0, 0xe0), (0xe7, 0xeb), (0xef, 0xef), (0xf1, 0xf1),
+    (0xf4, 0xf4), (0xf6, 0xf6),
+];
+
+#[rustfmt::skip]
+const EO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x108, 0x109), (0x11c, 0x11d), (0x124, 0x125), (0x134, 0x135),
+    (0x15c, 0x15d), (0x16c, 0x16d),
+];
+
+#[rustfmt::skip]
+const ES: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd1, 0xd1),
+    (0xd3, 0xd3), (0xda, 0xda), (0xdc, 0xdc), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed),
+    (0xf1, 0xf1), (0xf3, 0xf3), (0xfa, 0xfa), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const ET: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xd5, 0xd6), (0xdc, 0xdc), (0xe4, 0xe4),
+    (0xf5, 0xf6), (0xfc, 0xfc), (0x160, 0x161), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const EU: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd1, 0xd1), (0xdc, 0xdc), (0xf1, 0xf1), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const FI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc5), (0xd6, 0xd6), (0xe4, 0xe5), (0xf6, 0xf6),
+    (0x160, 0x161), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const FJ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a),
+];
+
+#[rustfmt::skip]
+const FO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc6, 0xc6), (0xcd, 0xcd), (0xd0, 0xd0),
+    (0xd3, 0xd3), (0xd8, 0xd8), (0xda, 0xda), (0xdd, 0xdd), (0xe1, 0xe1), (0xe6, 0xe6),
+    (0xed, 0xed), (0xf0, 0xf0), (0xf3, 0xf3), (0xf8, 0xf8), (0xfa, 0xfa), (0xfd, 0xfd),
+];
+
+#[rustfmt::skip]
+const FF: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x14a, 0x14b), (0x181, 0x181), (0x18a, 0x18a), (0x19d, 0x19d),
+    (0x1b3, 0x1b4), (0x253, 0x253), (0x257, 0x257), (0x272, 0x272),
+];
+
+#[rustfmt::skip]
+const FUR: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc2), (0xc8, 0xc8), (0xcc, 0xcc), (0xd2, 0xd2),
+    (0xd9, 0xd9), (0xe0, 0xe2), (0xe8, 0xe8), (0xec, 0xec), (0xf2, 0xf2), (0xf9, 0xf9),
+];
+
+#[rustfmt::skip]
+const FY: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc4, 0xc4), (0xc9, 0xcb), (0xcf, 0xcf),
+    (0xd4, 0xd4), (0xd6, 0xd6), (0xda, 0xdc), (0xdf, 0xdf), (0xe2, 0xe2), (0xe4, 0xe4),
+    (0xe9, 0xeb), (0xef, 0xef), (0xf4, 0xf4), (0xf6, 0xf6), (0xfa, 0xfc),
+];
+
+#[rustfmt::skip]
+const GA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd3, 0xd3),
+    (0xda, 0xda), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed), (0xf3, 0xf3), (0xfa, 0xfa),
+    (0x10a, 0x10b), (0x120, 0x121), (0x1e02, 0x1e03), (0x1e0a, 0x1e0b), (0x1e1e, 0x1e1f),
+    (0x1e40, 0x1e41), (0x1e56, 0x1e57), (0x1e60, 0x1e61), (0x1e6a, 0x1e6b),
+];
+
+#[rustfmt::skip]
+const GD: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc1), (0xc7, 0xc9), (0xcc, 0xcc), (0xd2, 0xd3),
+    (0xd9, 0xd9), (0xe0, 0xe1), (0xe7, 0xe9), (0xec, 0xec), (0xf2, 0xf3), (0xf9, 0xf9),
+];
+
+#[rustfmt::skip]
+const GEZ: &[(Int, Int)] = &[
+    (0x1200, 0x1206), (0x1208, 0x1216), (0x1218, 0x1226), (0x1228, 0x1230), (0x1238, 0x1246),
+    (0x1248, 0x1248), (0x124a, 0x124d), (0x1260, 0x1267), (0x1270, 0x1277), (0x1280, 0x1286),
+    (0x1288, 0x1288), (0x128a, 0x128d), (0x1290, 0x1297), (0x12a0, 0x12ae), (0x12b0, 0x12b0),
+    (0x12b2, 0x12b5), (0x12c8, 0x12ce), (0x12d0, 0x12d6), (0x12d8, 0x12df), (0x12e8, 0x12ee),
+    (0x12f0, 0x12f7), (0x1308, 0x130e), (0x1310, 0x1310), (0x1312, 0x1315), (0x1320, 0x1328),
+    (0x1330, 0x1346), (0x1348, 0x1356),
+];
+
+#[rustfmt::skip]
+const GN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd1, 0xd1),
+    (0xd3, 0xd3), (0xda, 0xda), (0xe1, 0xe1), (0xe3, 0xe3), (0xe9, 0xe9), (0xed, 0xed),
+    (0xf1, 0xf1), (0xf3, 0xf3), (0xf5, 0xf5), (0xfa, 0xfa), (0x129, 0x129), (0x169, 0x169),
+    (0x1ebd, 0x1ebd), (0x1ef9, 0x1ef9),
+];
+
+#[rustfmt::skip]
+const GU: &[(Int, Int)] = &[
+    (0xa81, 0xa83), (0xa85, 0xa8b), (0xa8d, 0xa8d), (0xa8f, 0xa91), (0xa93, 0xaa8), (0xaaa, 0xab0),
+    (0xab2, 0xab3), (0xab5, 0xab9), (0xabc, 0xac5), (0xac7, 0xac9), (0xacb, 0xacd), (0xad0, 0xad0),
+    (0xae0, 0xae0),
+];
+
+#[rustfmt::skip]
+const GV: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc7, 0xc7), (0xe7, 0xe7),
+];
+
+#[rustfmt::skip]
+const HA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x181, 0x181), (0x18a, 0x18a), (0x198, 0x199), (0x1b3, 0x1b4),
+    (0x253, 0x253), (0x257, 0x257),
+];
+
+#[rustfmt::skip]
+const HAW: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x112, 0x113), (0x12a, 0x12b), (0x14c, 0x14d),
+    (0x16a, 0x16b), (0x2bb, 0x2bb),
+];
+
+#[rustfmt::skip]
+const HE: &[(Int, Int)] = &[
+    (0x5d0, 0x5ea),
+];
+
+#[rustfmt::skip]
+const HU: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd3, 0xd3),
+    (0xd6, 0xd6), (0xda, 0xda), (0xdc, 0xdc), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed),
+    (0xf3, 0xf3), (0xf6, 0xf6), (0xfa, 0xfa), (0xfc, 0xfc), (0x150, 0x151), (0x170, 0x171),
+];
+
+#[rustfmt::skip]
+const HY: &[(Int, Int)] = &[
+    (0x531, 0x556), (0x561, 0x587),
+];
+
+#[rustfmt::skip]
+const IG: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x1eca, 0x1ecd), (0x1ee4, 0x1ee5),
+];
+
+#[rustfmt::skip]
+const ID: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc9, 0xc9), (0xe9, 0xe9),
+];
+
+#[rustfmt::skip]
+const IE: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd3, 0xd3),
+    (0xda, 0xda), (0xdd, 0xdd), (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed), (0xf3, 0xf3),
+    (0xfa, 0xfa), (0xfd, 0xfd),
+];
+
+#[rustfmt::skip]
+const IK: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x40e, 0x40e), (0x410, 0x44f), (0x451, 0x451), (0x45e, 0x45e),
+];
+
+#[rustfmt::skip]
+const IS: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc6, 0xc6), (0xc9, 0xc9), (0xcd, 0xcd),
+    (0xd0, 0xd0), (0xd3, 0xd3), (0xda, 0xda), (0xdd, 0xde), (0xe1, 0xe1), (0xe6, 0xe6),
+    (0xe9, 0xe9), (0xed, 0xed), (0xf0, 0xf0), (0xf3, 0xf3), (0xfa, 0xfa), (0xfd, 0xfe),
+];
+
+#[rustfmt::skip]
+const IT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc8, 0xc9), (0xcc, 0xcd), (0xcf, 0xcf),
+    (0xd2, 0xd3), (0xd9, 0xda), (0xe0, 0xe0), (0xe8, 0xe9), (0xec, 0xed), (0xef, 0xef),
+    (0xf2, 0xf3), (0xf9, 0xfa),
+];
+
+#[rustfmt::skip]
+const IU: &[(Int, Int)] = &[
+    (0x1401, 0x1406), (0x140a, 0x140b), (0x142f, 0x1434), (0x1438, 0x1439), (0x1449, 0x1449),
+    (0x144c, 0x1451), (0x1455, 0x1456), (0x1466, 0x1466), (0x146b, 0x1470), (0x1472, 0x1473),
+    (0x1483, 0x1483), (0x1489, 0x148e), (0x1490, 0x1491), (0x14a1, 0x14a1), (0x14a3, 0x14a8),
+    (0x14aa, 0x14ab), (0x14bb, 0x14bb), (0x14c0, 0x14c5), (0x14c7, 0x14c8), (0x14d0, 0x14d0),
+    (0x14d3, 0x14d8), (0x14da, 0x14db), (0x14ea, 0x14ea), (0x14ed, 0x14f2), (0x14f4, 0x14f5),
+    (0x14fa, 0x14fa), (0x14fc, 0x14fc), (0x14fe, 0x14fe), (0x1500, 0x1500), (0x1502, 0x1502),
+    (0x1505, 0x1505), (0x1526, 0x152b), (0x152d, 0x152e), (0x153e, 0x153e), (0x1542, 0x1542),
+    (0x1545, 0x1549), (0x154b, 0x154c), (0x1550, 0x1550), (0x1553, 0x155a), (0x155d, 0x155d),
+    (0x1575, 0x1577), (0x1579, 0x157c), (0x157e, 0x1585), (0x158b, 0x1596), (0x15a0, 0x15a6),
+    (0x15a8, 0x15ae), (0x166f, 0x1676),
+];
+
+#[rustfmt::skip]
+const JA: &[(Int, Int)] = &[
+    (0x3000, 0x3002), (0x3005, 0x3005), (0x3007, 0x3007), (0x3041, 0x3093), (0x309b, 0x309e),
+    (0x30a1, 0x30f6), (0x30fb, 0x30fe), (0x4e00, 0x4e01), (0x4e03, 0x4e03), (0x4e07, 0x4e0b),
+    (0x4e0d, 0x4e0e), (0x4e14, 0x4e14), (0x4e16, 0x4e16), (0x4e18, 0x4e19), (0x4e21, 0x4e21),
+    (0x4e26, 0x4e26), (0x4e2d, 0x4e2d), (0x4e32, 0x4e32), (0x4e38, 0x4e39), (0x4e3b, 0x4e3c),
+    (0x4e45, 0x4e45), (0x4e4f, 0x4e4f), (0x4e57, 0x4e57), (0x4e59, 0x4e59), (0x4e5d, 0x4e5e),
+    (0x4e71, 0x4e71), (0x4e73, 0x4e73), (0x4e7e, 0x4e7e), (0x4e80, 0x4e80), (0x4e86, 0x4e86),
+    (0x4e88, 0x4e89), (0x4e8b, 0x4e8c), (0x4e92, 0x4e92), (0x4e94, 0x4e95), (0x4e9c, 0x4e9c),
+    (0x4ea1, 0x4ea1), (0x4ea4, 0x4ea4), (0x4eab, 0x4ead), (0x4eba, 0x4eba), (0x4ec1, 0x4ec1),
+    (0x4eca, 0x4ecb), (0x4ecf, 0x4ecf), (0x4ed5, 0x4ed6), (0x4ed8, 0x4ed9), (0x4ee3, 0x4ee5),
+    (0x4eee, 0x4eee), (0x4ef0, 0x4ef0), (0x4ef2, 0x4ef2), (0x4ef6, 0x4ef6), (0x4efb, 0x4efb),
+    (0x4f01, 0x4f01), (0x4f0e, 0x4f11), (0x4f1a, 0x4f1a), (0x4f1d, 0x4f1d), (0x4f2f, 0x4f2f),
+    (0x4f34, 0x4f34), (0x4f38, 0x4f38), (0x4f3a, 0x4f3a), (0x4f3c, 0x4f3c), (0x4f46, 0x4f46),
+    (0x4f4d, 0x4f50), (0x4f53, 0x4f53), (0x4f55, 0x4f55), (0x4f59, 0x4f59), (0x4f5c, 0x4f5c),
+    (0x4f73, 0x4f73), (0x4f75, 0x4f75), (0x4f7f, 0x4f7f), (0x4f8b, 0x4f8b), (0x4f8d, 0x4f8d),
+    (0x4f9b, 0x4f9b), (0x4f9d, 0x4f9d), (0x4fa1, 0x4fa1), (0x4fae, 0x4faf), (0x4fb5, 0x4fb6),
+    (0x4fbf, 0x4fbf), (0x4fc2, 0x4fc3), (0x4fca, 0x4fca), (0x4fd7, 0x4fd7), (0x4fdd, 0x4fdd),
+    (0x4fe1, 0x4fe1), (0x4fee, 0x4fee), (0x4ff3, 0x4ff3), (0x4ff5, 0x4ff5), (0x4ff8, 0x4ff8),
+    (0x4ffa, 0x4ffa), (0x5009, 0x5009), (0x500b, 0x500b), (0x500d, 0x500d), (0x5012, 0x5012),
+    (0x5019, 0x5019), (0x501f, 0x501f), (0x5023, 0x5024), (0x502b, 0x502b), (0x5039, 0x5039),
+    (0x5049, 0x5049), (0x504f, 0x504f), (0x505c, 0x505c), (0x5065, 0x5065), (0x5074, 0x5076),
+    (0x507d, 0x507d), (0x508d, 0x508d), (0x5091, 0x5091), (0x5098, 0x5099), (0x50ac, 0x50ac),
+    (0x50b2, 0x50b2), (0x50b5, 0x50b5), (0x50b7, 0x50b7), (0x50be, 0x50be), (0x50c5, 0x50c5),
+    (0x50cd, 0x50cd), (0x50cf, 0x50cf), (0x50d5, 0x50d5), (0x50da, 0x50da), (0x50e7, 0x50e7),
+    (0x5100, 0x5100), (0x5104, 0x5104), (0x5112, 0x5112), (0x511f, 0x511f), (0x512a, 0x512a),
+    (0x5143, 0x5146), (0x5148, 0x5149), (0x514b, 0x514b), (0x514d, 0x514d), (0x5150, 0x5150),
+    (0x515a, 0x515a), (0x5165, 0x5165), (0x5168, 0x5168), (0x516b, 0x516d), (0x5171, 0x5171),
+    (0x5175, 0x5175), (0x5177, 0x5178), (0x517c, 0x517c), (0x5185, 0x5186), (0x518a, 0x518a),
+    (0x518d, 0x518d), (0x5192, 0x5192), (0x5197, 0x5197), (0x5199, 0x5199), (0x51a0, 0x51a0),
+    (0x51a5, 0x51a5), (0x51ac, 0x51ac), (0x51b6, 0x51b7), (0x51c4, 0x51c4), (0x51c6, 0x51c6),
+    (0x51cd, 0x51cd), (0x51dd, 0x51dd), (0x51e1, 0x51e1), (0x51e6, 0x51e6), (0x51f6, 0x51f6),
+    (0x51f8, 0x51fa), (0x5200, 0x5200), (0x5203, 0x5203), (0x5206, 0x5208), (0x520a, 0x520a),
+    (0x5211, 0x5211), (0x5217, 0x5217), (0x521d, 0x521d), (0x5224, 0x5225), (0x5229, 0x5229),
+    (0x5230, 0x5230), (0x5236, 0x523b), (0x5247, 0x5247), (0x524a, 0x524a), (0x524d, 0x524d),
+    (0x5256, 0x5256), (0x525b, 0x525b), (0x5263, 0x5264), (0x526f, 0x5270), (0x5272, 0x5272),
+    (0x5275, 0x5275), (0x5287, 0x5287), (0x529b, 0x529b), (0x529f, 0x52a0), (0x52a3, 0x52a3),
+    (0x52a9, 0x52aa), (0x52b1, 0x52b1), (0x52b4, 0x52b4), (0x52b9, 0x52b9), (0x52be, 0x52be),
+    (0x52c3, 0x52c3), (0x52c5, 0x52c5), (0x52c7, 0x52c7), (0x52c9, 0x52c9), (0x52d5, 0x52d5),
+    (0x52d8, 0x52d9), (0x52dd, 0x52dd), (0x52df, 0x52df), (0x52e2, 0x52e2), (0x52e4, 0x52e4),
+    (0x52e7, 0x52e7), (0x52f2, 0x52f2), (0x52fe, 0x52fe), (0x5302, 0x5302), (0x5305, 0x5305),
+    (0x5316, 0x5317), (0x5320, 0x5320), (0x5339, 0x533b), (0x533f, 0x533f), (0x5341, 0x5341),
+    (0x5343, 0x5343), (0x5347, 0x5348), (0x534a, 0x534a), (0x5351, 0x5354), (0x5357, 0x5358),
+    (0x535a, 0x535a), (0x5360, 0x5360), (0x5370, 0x5371), (0x5373, 0x5375), (0x5378, 0x5378),
+    (0x5384, 0x5384), (0x5398, 0x5398), (0x539a, 0x539a), (0x539f, 0x539f), (0x53b3, 0x53b3),
+    (0x53bb, 0x53bb), (0x53c2, 0x53c2), (0x53c8, 0x53c8), (0x53ca, 0x53ce), (0x53d4, 0x53d4),
+    (0x53d6, 0x53d7), (0x53d9, 0x53d9), (0x53e3, 0x53e5), (0x53eb, 0x53ec), (0x53ef, 0x53f0),
+    (0x53f2, 0x53f3), (0x53f7, 0x53f8), (0x5404, 0x5404), (0x5408, 0x5409), (0x540c, 0x5411),
+    (0x541b, 0x541b), (0x541f, 0x541f), (0x5426, 0x5426), (0x542b, 0x542b), (0x5438, 0x5439),
+    (0x5442, 0x5442), (0x5448, 0x544a), (0x5468, 0x5468), (0x546a, 0x546a), (0x5473, 0x5473),
+    (0x547c, 0x547d), (0x548c, 0x548c), (0x54b2, 0x54b2), (0x54bd, 0x54bd), (0x54c0, 0x54c1),
+    (0x54e1, 0x54e1), (0x54f2, 0x54f2), (0x54fa, 0x54fa), (0x5504, 0x5504), (0x5506, 0x5507),
+    (0x5510, 0x5510), (0x552f, 0x552f), (0x5531, 0x5531), (0x553e, 0x553e), (0x5546, 0x5546),
+    (0x554f, 0x554f), (0x5553, 0x5553), (0x5584, 0x5584), (0x5589, 0x5589), (0x559a, 0x559a),
+    (0x559c, 0x559d), (0x55a9, 0x55ab), (0x55b6, 0x55b6), (0x55c5, 0x55c5), (0x55e3, 0x55e3),
+    (0x5606, 0x5606), (0x5631, 0x5632), (0x5668, 0x5668), (0x5674, 0x5674), (0x5687, 0x5687),
+    (0x56da, 0x56db), (0x56de, 0x56de), (0x56e0, 0x56e0), (0x56e3, 0x56e3), (0x56f0, 0x56f0),
+    (0x56f2, 0x56f3), (0x56fa, 0x56fa), (0x56fd, 0x56fd), (0x570f, 0x570f), (0x5712, 0x5712),
+    (0x571f, 0x571f), (0x5727, 0x5728), (0x5730, 0x5730), (0x5742, 0x5742), (0x5747, 0x5747),
+    (0x574a, 0x574a), (0x5751, 0x5751), (0x576a, 0x576a), (0x5782, 0x5782), (0x578b, 0x578b),
+    (0x57a3, 0x57a3), (0x57cb, 0x57cb), (0x57ce, 0x57ce), (0x57df, 0x57df), (0x57f7, 0x57f7),
+    (0x57f9, 0x57fa), (0x57fc, 0x57fc), (0x5800, 0x5800), (0x5802, 0x5802), (0x5805, 0x5806),
+    (0x5815, 0x5815), (0x5824, 0x5824), (0x582a, 0x582a), (0x5831, 0x5831), (0x5834, 0x5834),
+    (0x5840, 0x5841), (0x584a, 0x584a), (0x5851, 0x5851), (0x5854, 0x5854), (0x5857, 0x5857),
+    (0x585a, 0x585a), (0x585e, 0x585e), (0x5869, 0x5869), (0x587e, 0x587e), (0x5883, 0x5883),
+    (0x5893, 0x5893), (0x5897, 0x5897), (0x589c, 0x589c), (0x58a8, 0x58a8), (0x58b3, 0x58b3),
+    (0x58be, 0x58be), (0x58c1, 0x58c1), (0x58c7, 0x58c7), (0x58ca, 0x58ca), (0x58cc, 0x58cc),
+    (0x58eb, 0x58eb), (0x58ee, 0x58ee), (0x58f0, 0x58f2), (0x5909, 0x5909), (0x590f, 0x590f),
+    (0x5915, 0x5916), (0x591a, 0x591a), (0x591c, 0x591c), (0x5922, 0x5922), (0x5927, 0x5927),
+    (0x5929, 0x592b), (0x592e, 0x592e), (0x5931, 0x5931), (0x5947, 0x5949), (0x594f, 0x594f),
+    (0x5951, 0x5951), (0x5954, 0x5954), (0x5965, 0x5965), (0x5968, 0x5968), (0x596a, 0x596a),
+    (0x596e, 0x596e), (0x5973, 0x5974), (0x597d, 0x597d), (0x5982, 0x5984), (0x598a, 0x598a),
+    (0x5996, 0x5996), (0x5999, 0x5999), (0x59a5, 0x59a5), (0x59a8, 0x59a8), (0x59ac, 0x59ac),
+    (0x59b9, 0x59b9), (0x59bb, 0x59bb), (0x59c9, 0x59c9), (0x59cb, 0x59cb), (0x59d3, 0x59d4),
+    (0x59eb, 0x59eb), (0x59fb, 0x59fb), (0x59ff, 0x59ff), (0x5a01, 0x5a01), (0x5a18, 0x5a18),
+    (0x5a20, 0x5a20), (0x5a2f, 0x5a2f), (0x5a46, 0x5a46), (0x5a5a, 0x5a5a), (0x5a66, 0x5a66),
+    (0x5a7f, 0x5a7f), (0x5a92, 0x5a92), (0x5a9b, 0x5a9b), (0x5ac1, 0x5ac1), (0x5ac9, 0x5ac9),
+    (0x5acc, 0x5acc), (0x5ae1, 0x5ae1), (0x5b22, 0x5b22), (0x5b50, 0x5b50), (0x5b54, 0x5b54),
+    (0x5b57, 0x5b58), (0x5b5d, 0x5b5d), (0x5b63, 0x5b64), (0x5b66, 0x5b66), (0x5b6b, 0x5b6b),
+    (0x5b85, 0x5b85), (0x5b87, 0x5b89), (0x5b8c, 0x5b8c), (0x5b97, 0x5b9d), (0x5b9f, 0x5b9f),
+    (0x5ba2, 0x5ba4), (0x5bae, 0x5bae), (0x5bb0, 0x5bb0), (0x5bb3, 0x5bb6), (0x5bb9, 0x5bb9),
+    (0x5bbf, 0x5bbf), (0x5bc2, 0x5bc2), (0x5bc4, 0x5bc4), (0x5bc6, 0x5bc6), (0x5bcc, 0x5bcc),
+    (0x5bd2, 0x5bd2), (0x5bdb, 0x5bdb), (0x5bdd, 0x5bdd), (0x5bdf, 0x5bdf), (0x5be1, 0x5be1),
+    (0x5be7, 0x5be7), (0x5be9, 0x5be9), (0x5bee, 0x5bee), (0x5bf8, 0x5bf8), (0x5bfa, 0x5bfa),
+    (0x5bfe, 0x5bff), (0x5c01, 0x5c02), (0x5c04, 0x5c04), (0x5c06, 0x5c06), (0x5c09, 0x5c0b),
+    (0x5c0e, 0x5c0f), (0x5c11, 0x5c11), (0x5c1a, 0x5c1a), (0x5c31, 0x5c31), (0x5c3a, 0x5c40),
+    (0x5c45, 0x5c45), (0x5c48, 0x5c48), (0x5c4a, 0x5c4b), (0x5c55, 0x5c55), (0x5c5e, 0x5c5e),
+    (0x5c64, 0x5c65), (0x5c6f, 0x5c6f), (0x5c71, 0x5c71), (0x5c90, 0x5c90), (0x5ca1, 0x5ca1),
+    (0x5ca9, 0x5ca9), (0x5cac, 0x5cac), (0x5cb3, 0x5cb3), (0x5cb8, 0x5cb8), (0x5ce0, 0x5ce1),
+    (0x5cf0, 0x5cf0), (0x5cf6, 0x5cf6), (0x5d07, 0x5d07), (0x5d0e, 0x5d0e), (0x5d16, 0x5d16),
+    (0x5d29, 0x5d29), (0x5d50, 0x5d50), (0x5ddd, 0x5dde), (0x5de1, 0x5de1), (0x5de3, 0x5de3),
+    (0x5de5, 0x5de8), (0x5dee, 0x5dee), (0x5df1, 0x5df1), (0x5dfb, 0x5dfb), (0x5dfe, 0x5dfe),
+    (0x5e02, 0x5e03), (0x5e06, 0x5e06), (0x5e0c, 0x5e0c), (0x5e1d, 0x5e1d), (0x5e25, 0x5e25),
+    (0x5e2b, 0x5e2b), (0x5e2d, 0x5e2d), (0x5e2f, 0x5e30), (0x5e33, 0x5e33), (0x5e38, 0x5e38),
+    (0x5e3d, 0x5e3d), (0x5e45, 0x5e45), (0x5e55, 0x5e55), (0x5e63, 0x5e63), (0x5e72, 0x5e74),
+    (0x5e78, 0x5e79), (0x5e7b, 0x5e7e), (0x5e81, 0x5e81), (0x5e83, 0x5e83), (0x5e8a, 0x5e8a),
+    (0x5e8f, 0x5e8f), (0x5e95, 0x5e95), (0x5e97, 0x5e97), (0x5e9c, 0x5e9c), (0x5ea6, 0x5ea7),
+    (0x5eab, 0x5eab), (0x5ead, 0x5ead), (0x5eb6, 0x5eb8), (0x5ec3, 0x5ec3), (0x5ec9, 0x5eca),
+    (0x5ef6, 0x5ef7), (0x5efa, 0x5efa), (0x5f01, 0x5f01), (0x5f04, 0x5f04), (0x5f0a, 0x5f0a),
+    (0x5f0f, 0x5f10), (0x5f13, 0x5f15), (0x5f1f, 0x5f1f), (0x5f25, 0x5f27), (0x5f31, 0x5f31),
+    (0x5f35, 0x5f35), (0x5f37, 0x5f37), (0x5f3e, 0x5f3e), (0x5f53, 0x5f53), (0x5f59, 0x5f59),
+    (0x5f62, 0x5f62), (0x5f69, 0x5f69), (0x5f6b, 0x5f6b), (0x5f70, 0x5f71), (0x5f79, 0x5f79),
+    (0x5f7c, 0x5f7c), (0x5f80, 0x5f81), (0x5f84, 0x5f85), (0x5f8b, 0x5f8c), (0x5f90, 0x5f90),
+    (0x5f92, 0x5f93), (0x5f97, 0x5f97), (0x5fa1, 0x5fa1), (0x5fa9, 0x5faa), (0x5fae, 0x5fae),
+    (0x5fb3, 0x5fb4), (0x5fb9, 0x5fb9), (0x5fc3, 0x5fc3), (0x5fc5, 0x5fc5), (0x5fcc, 0x5fcd),
+    (0x5fd7, 0x5fd9), (0x5fdc, 0x5fdc), (0x5fe0, 0x5fe0), (0x5feb, 0x5feb), (0x5ff5, 0x5ff5),
+    (0x6012, 0x6012), (0x6016, 0x6016), (0x601d, 0x601d), (0x6020, 0x6020), (0x6025, 0x6025),
+    (0x6027, 0x6028), (0x602a, 0x602a), (0x604b, 0x604b), (0x6050, 0x6050), (0x6052, 0x6052),
+    (0x6063, 0x6063), (0x6065, 0x6065), (0x6068, 0x6069), (0x606d, 0x606d), (0x606f, 0x606f),
+    (0x6075, 0x6075), (0x6094, 0x6094), (0x609f, 0x60a0), (0x60a3, 0x60a3), (0x60a6, 0x60a6),
+    (0x60a9, 0x60aa), (0x60b2, 0x60b2), (0x60bc, 0x60bc), (0x60c5, 0x60c5), (0x60d1, 0x60d1),
+    (0x60dc, 0x60dc), (0x60e7, 0x60e8), (0x60f0, 0x60f0), (0x60f3, 0x60f3), (0x6101, 0x6101),
+    (0x6109, 0x6109), (0x610f, 0x610f), (0x611a, 0x611b), (0x611f, 0x611f), (0x6144, 0x6144),
+    (0x6148, 0x6148), (0x614b, 0x614c), (0x614e, 0x614e), (0x6155, 0x6155), (0x6162, 0x6163),
+    (0x6168, 0x6168), (0x616e, 0x616e), (0x6170, 0x6170), (0x6176, 0x6176), (0x6182, 0x6182),
+    (0x618e, 0x618e), (0x61a4, 0x61a4), (0x61a7, 0x61a7), (0x61a9, 0x61a9), (0x61ac, 0x61ac),
+    (0x61b2, 0x61b2), (0x61b6, 0x61b6), (0x61be, 0x61be), (0x61c7, 0x61c7), (0x61d0, 0x61d0),
+    (0x61f2, 0x61f2), (0x61f8, 0x61f8), (0x6210, 0x6212), (0x621a, 0x621a), (0x6226, 0x6226),
+    (0x622f, 0x622f), (0x6234, 0x6234), (0x6238, 0x6238), (0x623b, 0x623b), (0x623f, 0x6240),
+    (0x6247, 0x6247), (0x6249, 0x6249), (0x624b, 0x624b), (0x624d, 0x624d), (0x6253, 0x6253),
+    (0x6255, 0x6255), (0x6271, 0x6271), (0x6276, 0x6276), (0x6279, 0x6279), (0x627f, 0x6280),
+    (0x6284, 0x6284), (0x628a, 0x628a), (0x6291, 0x6291), (0x6295, 0x6295), (0x6297, 0x6298),
+    (0x629c, 0x629c), (0x629e, 0x629e), (0x62ab, 0x62ab), (0x62b1, 0x62b1), (0x62b5, 0x62b5),
+    (0x62b9, 0x62b9), (0x62bc, 0x62bd), (0x62c5, 0x62c5), (0x62c9, 0x62c9), (0x62cd, 0x62cd),
+    (0x62d0, 0x62d0), (0x62d2, 0x62d3), (0x62d8, 0x62d9), (0x62db, 0x62db), (0x62dd, 0x62dd),
+    (0x62e0, 0x62e1), (0x62ec, 0x62ed), (0x62f3, 0x62f3), (0x62f6, 0x62f7), (0x62fe, 0x62fe),
+    (0x6301, 0x6301), (0x6307, 0x6307), (0x6311, 0x6311), (0x6319, 0x6319), (0x631f, 0x631f),
+    (0x6328, 0x6328), (0x632b, 0x632b), (0x632f, 0x632f), (0x633f, 0x633f), (0x6349, 0x6349),
+    (0x6355, 0x6355), (0x6357, 0x6357), (0x635c, 0x635c), (0x6368, 0x6368), (0x636e, 0x636e),
+    (0x637b, 0x637b), (0x6383, 0x6383), (0x6388, 0x6388), (0x638c, 0x638c), (0x6392, 0x6392),
+    (0x6398, 0x6398), (0x639b, 0x639b), (0x63a1, 0x63a2), (0x63a5, 0x63a5), (0x63a7, 0x63a8),
+    (0x63aa, 0x63aa), (0x63b2, 0x63b2), (0x63cf, 0x63d0), (0x63da, 0x63db), (0x63e1, 0x63e1),
+    (0x63ee, 0x63ee), (0x63f4, 0x63f4), (0x63fa, 0x63fa), (0x640d, 0x640d), (0x642c, 0x642d),
+    (0x643a, 0x643a), (0x643e, 0x643e), (0x6442, 0x6442), (0x6458, 0x6458), (0x6469, 0x6469),
+    (0x646f, 0x646f), (0x6483, 0x6483), (0x64a4, 0x64a4), (0x64ae, 0x64ae), (0x64b2, 0x64b2),
+    (0x64c1, 0x64c1), (0x64cd, 0x64cd), (0x64e6, 0x64e6), (0x64ec, 0x64ec), (0x652f, 0x652f),
+    (0x6539, 0x6539), (0x653b, 0x653b), (0x653e, 0x653f), (0x6545, 0x6545), (0x654f, 0x654f),
+    (0x6551, 0x6551), (0x6557, 0x6557), (0x6559, 0x6559), (0x6562, 0x6563), (0x656c, 0x656c),
+    (0x6570, 0x6570), (0x6574, 0x6575), (0x6577, 0x6577), (0x6587, 0x6587), (0x6589, 0x6589),
+    (0x658e, 0x658e), (0x6591, 0x6591), (0x6597, 0x6597), (0x6599, 0x6599), (0x659c, 0x659c),
+    (0x65a4, 0x65a5), (0x65ac, 0x65ad), (0x65b0, 0x65b0), (0x65b9, 0x65b9), (0x65bd, 0x65bd),
+    (0x65c5, 0x65c5), (0x65cb, 0x65cb), (0x65cf, 0x65cf), (0x65d7, 0x65d7), (0x65e2, 0x65e2),
+    (0x65e5, 0x65e9), (0x65ec, 0x65ec), (0x65fa, 0x65fa), (0x6606, 0x6607), (0x660e, 0x660e),
+    (0x6613, 0x6614), (0x661f, 0x6620), (0x6625, 0x6625), (0x6627, 0x6628), (0x662d, 0x662d),
+    (0x662f, 0x662f), (0x663c, 0x663c), (0x6642, 0x6642), (0x6669, 0x6669), (0x666e, 0x666f),
+    (0x6674, 0x6674), (0x6676, 0x6676), (0x6681, 0x6681), (0x6687, 0x6687), (0x6691, 0x6691),
+    (0x6696, 0x6697), (0x66a6, 0x66a6), (0x66ab, 0x66ab), (0x66ae, 0x66ae), (0x66b4, 0x66b4),
+    (0x66c7, 0x66c7), (0x66d6, 0x66d6), (0x66dc, 0x66dc), (0x66f2, 0x66f2), (0x66f4, 0x66f4),
+    (0x66f8, 0x66f9), (0x66fd, 0x66fd), (0x66ff, 0x6700), (0x6708, 0x6709), (0x670d, 0x670d),
+    (0x6715, 0x6715), (0x6717, 0x6717), (0x671b, 0x671b), (0x671d, 0x671d), (0x671f, 0x671f),
+    (0x6728, 0x6728), (0x672a, 0x672d), (0x6731, 0x6731), (0x6734, 0x6734), (0x673a, 0x673a),
+    (0x673d, 0x673d), (0x6749, 0x6749), (0x6750, 0x6751), (0x675f, 0x675f), (0x6761, 0x6761),
+    (0x6765, 0x6765), (0x676f, 0x676f), (0x6771, 0x6771), (0x677e, 0x677f), (0x6790, 0x6790),
+    (0x6795, 0x6795), (0x6797, 0x6797), (0x679a, 0x679a), (0x679c, 0x679d), (0x67a0, 0x67a0),
+    (0x67a2, 0x67a2), (0x67af, 0x67af), (0x67b6, 0x67b6), (0x67c4, 0x67c4), (0x67d0, 0x67d0),
+    (0x67d3, 0x67d4), (0x67f1, 0x67f1), (0x67f3, 0x67f3), (0x67f5, 0x67f5), (0x67fb, 0x67fb),
+    (0x67ff, 0x67ff), (0x6803, 0x6804), (0x6813, 0x6813), (0x6821, 0x6821), (0x682a, 0x682a),
+    (0x6838, 0x6839), (0x683c, 0x683d), (0x6841, 0x6841), (0x6843, 0x6843), (0x6848, 0x6848),
+    (0x6851, 0x6851), (0x685c, 0x685c), (0x685f, 0x685f), (0x6885, 0x6885), (0x6897, 0x6897),
+    (0x68a8, 0x68a8), (0x68b0, 0x68b0), (0x68c4, 0x68c4), (0x68cb, 0x68cb), (0x68d2, 0x68d2),
+    (0x68da, 0x68da), (0x68df, 0x68df), (0x68ee, 0x68ee), (0x68fa, 0x68fa), (0x6905, 0x6905),
+    (0x690d, 0x690e), (0x691c, 0x691c), (0x696d, 0x696d), (0x6975, 0x6975), (0x6977, 0x6977),
+    (0x697c, 0x697d), (0x6982, 0x6982), (0x69cb, 0x69cb), (0x69d8, 0x69d8), (0x69fd, 0x69fd),
+    (0x6a19, 0x6a19), (0x6a21, 0x6a21), (0x6a29, 0x6a2a), (0x6a39, 0x6a39), (0x6a4b, 0x6a4b),
+    (0x6a5f, 0x6a5f), (0x6b04, 0x6b04), (0x6b20, 0x6b21), (0x6b27, 0x6b27), (0x6b32, 0x6b32),
+    (0x6b3a, 0x6b3a), (0x6b3e, 0x6b3e), (0x6b4c, 0x6b4c), (0x6b53, 0x6b53), (0x6b62, 0x6b63),
+    (0x6b66, 0x6b66), (0x6b69, 0x6b69), (0x6b6f, 0x6b6f), (0x6b73, 0x6b74), (0x6b7b, 0x6b7b),
+    (0x6b89, 0x6b8b), (0x6b96, 0x6b96), (0x6bb4, 0x6bb5), (0x6bba, 0x6bbb), (0x6bbf, 0x6bc0),
+    (0x6bcd, 0x6bce), (0x6bd2, 0x6bd2), (0x6bd4, 0x6bd4), (0x6bdb, 0x6bdb), (0x6c0f, 0x6c0f),
+    (0x6c11, 0x6c11), (0x6c17, 0x6c17), (0x6c34, 0x6c34), (0x6c37, 0x6c38), (0x6c3e, 0x6c3e),
+    (0x6c41, 0x6c42), (0x6c4e, 0x6c4e), (0x6c57, 0x6c57), (0x6c5a, 0x6c5a), (0x6c5f, 0x6c60),
+    (0x6c70, 0x6c70), (0x6c7a, 0x6c7a), (0x6c7d, 0x6c7d), (0x6c83, 0x6c83), (0x6c88, 0x6c88),
+    (0x6c96, 0x6c96), (0x6c99, 0x6c99), (0x6ca1, 0x6ca2), (0x6cb3, 0x6cb3), (0x6cb8, 0x6cb9),
+    (0x6cbb, 0x6cbc), (0x6cbf, 0x6cbf), (0x6cc1, 0x6cc1), (0x6cc9, 0x6cca), (0x6ccc, 0x6ccc),
+    (0x6cd5, 0x6cd5), (0x6ce1, 0x6ce3), (0x6ce5, 0x6ce5), (0x6ce8, 0x6ce8), (0x6cf0, 0x6cf0),
+    (0x6cf3, 0x6cf3), (0x6d0b, 0x6d0b), (0x6d17, 0x6d17), (0x6d1e, 0x6d1e), (0x6d25, 0x6d25),
+    (0x6d2a, 0x6d2a), (0x6d3b, 0x6d3b), (0x6d3e, 0x6d3e), (0x6d41, 0x6d41), (0x6d44, 0x6d45),
+    (0x6d5c, 0x6d5c), (0x6d66, 0x6d66), (0x6d6a, 0x6d6a), (0x6d6e, 0x6d6e), (0x6d74, 0x6d74),
+    (0x6d77, 0x6d78), (0x6d88, 0x6d88), (0x6d99, 0x6d99), (0x6daf, 0x6daf), (0x6db2, 0x6db2),
+    (0x6dbc, 0x6dbc), (0x6dd1, 0x6dd1), (0x6de1, 0x6de1), (0x6deb, 0x6deb), (0x6df1, 0x6df1),
+    (0x6df7, 0x6df7), (0x6dfb, 0x6dfb), (0x6e05, 0x6e05), (0x6e07, 0x6e09), (0x6e0b, 0x6e0b),
+    (0x6e13, 0x6e13), (0x6e1b, 0x6e1b), (0x6e21, 0x6e21), (0x6e26, 0x6e26), (0x6e29, 0x6e29),
+    (0x6e2c, 0x6e2c), (0x6e2f, 0x6e2f), (0x6e56, 0x6e56), (0x6e67, 0x6e67), (0x6e6f, 0x6e6f),
+    (0x6e7e, 0x6e80), (0x6e90, 0x6e90), (0x6e96, 0x6e96), (0x6e9d, 0x6e9d), (0x6eb6, 0x6eb6),
+    (0x6eba, 0x6eba), (0x6ec5, 0x6ec5), (0x6ecb, 0x6ecb), (0x6ed1, 0x6ed1), (0x6edd, 0x6ede),
+    (0x6ef4, 0x6ef4), (0x6f01, 0x6f02), (0x6f06, 0x6f06), (0x6f0f, 0x6f0f), (0x6f14, 0x6f14),
+    (0x6f20, 0x6f20), (0x6f22, 0x6f22), (0x6f2b, 0x6f2c), (0x6f38, 0x6f38), (0x6f54, 0x6f54),
+    (0x6f5c, 0x6f5c), (0x6f5f, 0x6f5f), (0x6f64, 0x6f64), (0x6f6e, 0x6f6e), (0x6f70, 0x6f70),
+    (0x6f84, 0x6f84), (0x6fc0, 0x6fc1), (0x6fc3, 0x6fc3), (0x6feb, 0x6feb), (0x6fef, 0x6fef),
+    (0x702c, 0x702c), (0x706b, 0x706b), (0x706f, 0x7070), (0x707d, 0x707d), (0x7089, 0x708a),
+    (0x708e, 0x708e), (0x70ad, 0x70ad), (0x70b9, 0x70ba), (0x70c8, 0x70c8), (0x7121, 0x7121),
+    (0x7126, 0x7126), (0x7136, 0x7136), (0x713c, 0x713c), (0x714e, 0x714e), (0x7159, 0x7159),
+    (0x7167, 0x7167), (0x7169, 0x7169), (0x716e, 0x716e), (0x718a, 0x718a), (0x719f, 0x719f),
+    (0x71b1, 0x71b1), (0x71c3, 0x71c3), (0x71e5, 0x71e5), (0x7206, 0x7206), (0x722a, 0x722a),
+    (0x7235, 0x7236), (0x723d, 0x723d), (0x7247, 0x7248), (0x7259, 0x7259), (0x725b, 0x725b),
+    (0x7267, 0x7267), (0x7269, 0x7269), (0x7272, 0x7272), (0x7279, 0x7279), (0x72a0, 0x72a0),
+    (0x72ac, 0x72ac), (0x72af, 0x72af), (0x72b6, 0x72b6), (0x72c2, 0x72c2), (0x72d9, 0x72d9),
+    (0x72e9, 0x72e9), (0x72ec, 0x72ed), (0x731b, 0x731b), (0x731f, 0x731f), (0x732b, 0x732b),
+    (0x732e, 0x732e), (0x7336, 0x7336), (0x733f, 0x733f), (0x7344, 0x7344), (0x7363, 0x7363),
+    (0x7372, 0x7372), (0x7384, 0x7384), (0x7387, 0x7387), (0x7389, 0x7389), (0x738b, 0x738b),
+    (0x73a9, 0x73a9), (0x73cd, 0x73cd), (0x73e0, 0x73e0), (0x73ed, 0x73ed), (0x73fe, 0x73fe),
+    (0x7403, 0x7403), (0x7406, 0x7406), (0x7434, 0x7434), (0x7460, 0x7460), (0x7483, 0x7483),
+    (0x74a7, 0x74a7), (0x74b0, 0x74b0), (0x74bd, 0x74bd), (0x74e6, 0x74e6), (0x74f6, 0x74f6),
+    (0x7518, 0x7518), (0x751a, 0x751a), (0x751f, 0x751f), (0x7523, 0x7523), (0x7528, 0x7528),
+    (0x7530, 0x7533), (0x7537, 0x7537), (0x753a, 0x753b), (0x754c, 0x754c), (0x754f, 0x754f),
+    (0x7551, 0x7551), (0x7554, 0x7554), (0x7559, 0x7559), (0x755c, 0x755d), (0x7565, 0x7565),
+    (0x756a, 0x756a), (0x7570, 0x7570), (0x7573, 0x7573), (0x757f, 0x757f), (0x758e, 0x758e),
+    (0x7591, 0x7591), (0x75ab, 0x75ab), (0x75b2, 0x75b2), (0x75be, 0x75be), (0x75c5, 0x75c5),
+    (0x75c7, 0x75c7), (0x75d5, 0x75d5), (0x75d8, 0x75d8), (0x75db, 0x75db), (0x75e2, 0x75e2),
+    (0x75e9, 0x75e9), (0x75f4, 0x75f4), (0x760d, 0x760d), (0x7642, 0x7642), (0x7652, 0x7652),
+    (0x7656, 0x7656), (0x767a, 0x767b), (0x767d, 0x767e), (0x7684, 0x7684), (0x7686, 0x7687),
+    (0x76ae, 0x76ae), (0x76bf, 0x76bf), (0x76c6, 0x76c6), (0x76ca, 0x76ca), (0x76d7, 0x76d7),
+    (0x76db, 0x76db), (0x76df, 0x76df), (0x76e3, 0x76e4), (0x76ee, 0x76ee), (0x76f2, 0x76f2),
+    (0x76f4, 0x76f4), (0x76f8, 0x76f8), (0x76fe, 0x76fe), (0x7701, 0x7701), (0x7709, 0x7709),
+    (0x770b, 0x770c), (0x771f, 0x7720), (0x773a, 0x773a), (0x773c, 0x773c), (0x7740, 0x7740),
+    (0x7761, 0x7761), (0x7763, 0x7763), (0x7766, 0x7766), (0x77ac, 0x77ad), (0x77b3, 0x77b3),
+    (0x77db, 0x77db), (0x77e2, 0x77e2), (0x77e5, 0x77e5), (0x77ed, 0x77ed), (0x77ef, 0x77ef),
+    (0x77f3, 0x77f3), (0x7802, 0x7802), (0x7814, 0x7815), (0x7832, 0x7832), (0x7834, 0x7834),
+    (0x785d, 0x785d), (0x786b, 0x786c), (0x7881, 0x7881), (0x7891, 0x7891), (0x78ba, 0x78ba),
+    (0x78c1, 0x78c1), (0x78e8, 0x78e8), (0x7901, 0x7901), (0x790e, 0x790e), (0x793a, 0x793a),
+    (0x793c, 0x793c), (0x793e, 0x793e), (0x7948, 0x7949), (0x7956, 0x7956), (0x795d, 0x795e),
+    (0x7965, 0x7965), (0x7968, 0x7968), (0x796d, 0x796d), (0x7981, 0x7981), (0x7985, 0x7985),
+    (0x798d, 0x798d), (0x798f, 0x798f), (0x79c0, 0x79c1), (0x79cb, 0x79cb), (0x79d1, 0x79d2),
+    (0x79d8, 0x79d8), (0x79df, 0x79df), (0x79e9, 0x79e9), (0x79f0, 0x79f0), (0x79fb, 0x79fb),
+    (0x7a0b, 0x7a0b), (0x7a0e, 0x7a0e), (0x7a1a, 0x7a1a), (0x7a2e, 0x7a2e), (0x7a32, 0x7a32),
+    (0x7a3c, 0x7a3d), (0x7a3f, 0x7a40), (0x7a42, 0x7a42), (0x7a4d, 0x7a4d), (0x7a4f, 0x7a4f),
+    (0x7a6b, 0x7a6b), (0x7a74, 0x7a74), (0x7a76, 0x7a76), (0x7a7a, 0x7a7a), (0x7a81, 0x7a81),
+    (0x7a83, 0x7a83), (0x7a92, 0x7a93), (0x7a9f, 0x7a9f), (0x7aae, 0x7aaf), (0x7acb, 0x7acb),
+    (0x7adc, 0x7adc), (0x7ae0, 0x7ae0), (0x7ae5, 0x7ae5), (0x7aef, 0x7aef), (0x7af6, 0x7af6),
+    (0x7af9, 0x7af9), (0x7b11, 0x7b11), (0x7b1b, 0x7b1b), (0x7b26, 0x7b26), (0x7b2c, 0x7b2c),
+    (0x7b46, 0x7b46), (0x7b49, 0x7b49), (0x7b4b, 0x7b4b), (0x7b52, 0x7b52), (0x7b54, 0x7b54),
+    (0x7b56, 0x7b56), (0x7b87, 0x7b87), (0x7b8b, 0x7b8b), (0x7b97, 0x7b97), (0x7ba1, 0x7ba1),
+    (0x7bb1, 0x7bb1), (0x7bb8, 0x7bb8), (0x7bc0, 0x7bc0), (0x7bc4, 0x7bc4), (0x7bc9, 0x7bc9),
+    (0x7be4, 0x7be4), (0x7c21, 0x7c21), (0x7c3f, 0x7c3f), (0x7c4d, 0x7c4d), (0x7c60, 0x7c60),
+    (0x7c73, 0x7c73), (0x7c89, 0x7c89), (0x7c8b, 0x7c8b), (0x7c92, 0x7c92), (0x7c97, 0x7c98),
+    (0x7c9b, 0x7c9b), (0x7ca7, 0x7ca7), (0x7cbe, 0x7cbe), (0x7cd6, 0x7cd6), (0x7ce7, 0x7ce7),
+    (0x7cf8, 0x7cf8), (0x7cfb, 0x7cfb), (0x7cfe, 0x7cfe), (0x7d00, 0x7d00), (0x7d04, 0x7d05),
+    (0x7d0b, 0x7d0b), (0x7d0d, 0x7d0d), (0x7d14, 0x7d14), (0x7d19, 0x7d1b), (0x7d20, 0x7d22),
+    (0x7d2b, 0x7d2b), (0x7d2f, 0x7d30), (0x7d33, 0x7d33), (0x7d39, 0x7d3a), (0x7d42, 0x7d42),
+    (0x7d44, 0x7d44), (0x7d4c, 0x7d4c), (0x7d50, 0x7d50), (0x7d5e, 0x7d5e), (0x7d61, 0x7d61),
+    (0x7d66, 0x7d66), (0x7d71, 0x7d71), (0x7d75, 0x7d76), (0x7d79, 0x7d79), (0x7d99, 0x7d9a),
+    (0x7dad, 0x7dad), (0x7db1, 0x7db2), (0x7dbb, 0x7dbb), (0x7dbf, 0x7dbf), (0x7dca, 0x7dca),
+    (0x7dcf, 0x7dcf), (0x7dd1, 0x7dd2), (0x7dda, 0x7dda), (0x7de0, 0x7de0), (0x7de8, 0x7de9),
+    (0x7def, 0x7def), (0x7df4, 0x7df4), (0x7dfb, 0x7dfb), (0x7e01, 0x7e01), (0x7e04, 0x7e04),
+    (0x7e1b, 0x7e1b), (0x7e26, 0x7e26), (0x7e2b, 0x7e2b), (0x7e2e, 0x7e2e), (0x7e3e, 0x7e3e),
+    (0x7e41, 0x7e41), (0x7e4a, 0x7e4a), (0x7e54, 0x7e55), (0x7e6d, 0x7e6d), (0x7e70, 0x7e70),
+    (0x7f36, 0x7f36), (0x7f6a, 0x7f6a), (0x7f6e, 0x7f6e), (0x7f70, 0x7f70), (0x7f72, 0x7f72),
+    (0x7f75, 0x7f75), (0x7f77, 0x7f77), (0x7f85, 0x7f85), (0x7f8a, 0x7f8a), (0x7f8e, 0x7f8e),
+    (0x7f9e, 0x7f9e), (0x7fa4, 0x7fa4), (0x7fa8, 0x7fa9), (0x7fbd, 0x7fbd), (0x7fc1, 0x7fc1),
+    (0x7fcc, 0x7fcc), (0x7fd2, 0x7fd2), (0x7ffb, 0x7ffc), (0x8001, 0x8001), (0x8003, 0x8003),
+    (0x8005, 0x8005), (0x8010, 0x8010), (0x8015, 0x8015), (0x8017, 0x8017), (0x8033, 0x8033),
+    (0x8056, 0x8056), (0x805e, 0x805e), (0x8074, 0x8074), (0x8077, 0x8077), (0x8089, 0x8089),
+    (0x808c, 0x808c), (0x8096, 0x8096), (0x8098, 0x8098), (0x809d, 0x809d), (0x80a1, 0x80a2),
+    (0x80a5, 0x80a5), (0x80a9, 0x80aa), (0x80af, 0x80af), (0x80b2, 0x80b2), (0x80ba, 0x80ba),
+    (0x80c3, 0x80c3), (0x80c6, 0x80c6), (0x80cc, 0x80cc), (0x80ce, 0x80ce), (0x80de, 0x80de),
+    (0x80f4, 0x80f4), (0x80f8, 0x80f8), (0x80fd, 0x80fd), (0x8102, 0x8102), (0x8105, 0x8105),
+    (0x8107, 0x8108), (0x810a, 0x810a), (0x811a, 0x811a), (0x8131, 0x8131), (0x8133, 0x8133),
+    (0x814e, 0x814e), (0x8150, 0x8150), (0x8155, 0x8155), (0x816b, 0x816b), (0x8170, 0x8170),
+    (0x8178, 0x817a), (0x819a, 0x819a), (0x819c, 0x819d), (0x81a8, 0x81a8), (0x81b3, 0x81b3),
+    (0x81c6, 0x81c6), (0x81d3, 0x81d3), (0x81e3, 0x81e3), (0x81e8, 0x81e8), (0x81ea, 0x81ea),
+    (0x81ed, 0x81ed), (0x81f3, 0x81f4), (0x81fc, 0x81fc), (0x8208, 0x8208), (0x820c, 0x820c),
+    (0x820e, 0x820e), (0x8217, 0x8217), (0x821e, 0x821f), (0x822a, 0x822a), (0x822c, 0x822c),
+    (0x8236, 0x8237), (0x8239, 0x8239), (0x8247, 0x8247), (0x8266, 0x8266), (0x826f, 0x826f),
+    (0x8272, 0x8272), (0x8276, 0x8276), (0x828b, 0x828b), (0x829d, 0x829d), (0x82af, 0x82af),
+    (0x82b1, 0x82b1), (0x82b3, 0x82b3), (0x82b8, 0x82b8), (0x82bd, 0x82bd), (0x82d7, 0x82d7),
+    (0x82db, 0x82db), (0x82e5, 0x82e6), (0x82f1, 0x82f1), (0x8302, 0x8302), (0x830e, 0x830e),
+    (0x8328, 0x8328), (0x8336, 0x8336), (0x8349, 0x8349), (0x8352, 0x8352), (0x8358, 0x8358),
+    (0x8377, 0x8377), (0x83ca, 0x83ca), (0x83cc, 0x83cc), (0x83d3, 0x83d3), (0x83dc, 0x83dc),
+    (0x83ef, 0x83ef), (0x840e, 0x840e), (0x843d, 0x843d), (0x8449, 0x8449), (0x8457, 0x8457),
+    (0x845b, 0x845b), (0x846c, 0x846c), (0x84b8, 0x84b8), (0x84c4, 0x84c4), (0x84cb, 0x84cb),
+    (0x8511, 0x8511), (0x8535, 0x8535), (0x853d, 0x853d), (0x8584, 0x8584), (0x85a6, 0x85a6),
+    (0x85aa, 0x85ac), (0x85cd, 0x85cd), (0x85e4, 0x85e4), (0x85e9, 0x85e9), (0x85fb, 0x85fb),
+    (0x864e, 0x864e), (0x8650, 0x8650), (0x865a, 0x865a), (0x865c, 0x865c), (0x865e, 0x865e),
+    (0x866b, 0x866b), (0x8679, 0x8679), (0x868a, 0x868a), (0x8695, 0x8695), (0x86c7, 0x86c7),
+    (0x86cd, 0x86cd), (0x86ee, 0x86ee), (0x8702, 0x8702), (0x871c, 0x871c), (0x878d, 0x878d),
+    (0x8840, 0x8840), (0x8846, 0x8846), (0x884c, 0x884c), (0x8853, 0x8853), (0x8857, 0x8857),
+    (0x885b, 0x885b), (0x885d, 0x885d), (0x8861, 0x8861), (0x8863, 0x8863), (0x8868, 0x8868),
+    (0x8870, 0x8870), (0x8877, 0x8877), (0x888b, 0x888b), (0x8896, 0x8896), (0x88ab, 0x88ab),
+    (0x88c1, 0x88c2), (0x88c5, 0x88c5), (0x88cf, 0x88cf), (0x88d5, 0x88d5), (0x88dc, 0x88dc),
+    (0x88f8, 0x88f8), (0x88fd, 0x88fe), (0x8907, 0x8907), (0x8910, 0x8910), (0x8912, 0x8912),
+    (0x895f, 0x895f), (0x8972, 0x8972), (0x897f, 0x897f), (0x8981, 0x8981), (0x8986, 0x8987),
+    (0x898b, 0x898b), (0x898f, 0x898f), (0x8996, 0x8996), (0x899a, 0x899a), (0x89a7, 0x89a7),
+    (0x89aa, 0x89aa), (0x89b3, 0x89b3), (0x89d2, 0x89d2), (0x89e3, 0x89e3), (0x89e6, 0x89e6),
+    (0x8a00, 0x8a00), (0x8a02, 0x8a03), (0x8a08, 0x8a08), (0x8a0e, 0x8a0e), (0x8a13, 0x8a13),
+    (0x8a17, 0x8a18), (0x8a1f, 0x8a1f), (0x8a2a, 0x8a2a), (0x8a2d, 0x8a2d), (0x8a31, 0x8a31),
+    (0x8a33, 0x8a34), (0x8a3a, 0x8a3a), (0x8a3c, 0x8a3c), (0x8a50, 0x8a50), (0x8a54, 0x8a55),
+    (0x8a5e, 0x8a5e), (0x8a60, 0x8a60), (0x8a63, 0x8a63), (0x8a66, 0x8a66), (0x8a69, 0x8a69),
+    (0x8a6e, 0x8a6e), (0x8a70, 0x8a73), (0x8a87, 0x8a87), (0x8a89, 0x8a89), (0x8a8c, 0x8a8d),
+    (0x8a93, 0x8a93), (0x8a95, 0x8a95), (0x8a98, 0x8a98), (0x8a9e, 0x8a9e), (0x8aa0, 0x8aa0),
+    (0x8aa4, 0x8aa4), (0x8aac, 0x8aad), (0x8ab0, 0x8ab0), (0x8ab2, 0x8ab2), (0x8abf, 0x8abf),
+    (0x8ac7, 0x8ac7), (0x8acb, 0x8acb), (0x8ad6, 0x8ad6), (0x8ae6, 0x8ae7), (0x8aed, 0x8aee),
+    (0x8af8, 0x8af8), (0x8afe, 0x8afe), (0x8b00, 0x8b01), (0x8b04, 0x8b04), (0x8b0e, 0x8b0e),
+    (0x8b19, 0x8b19), (0x8b1b, 0x8b1b), (0x8b1d, 0x8b1d), (0x8b21, 0x8b21), (0x8b39, 0x8b39),
+    (0x8b58, 0x8b58), (0x8b5c, 0x8b5c), (0x8b66, 0x8b66), (0x8b70, 0x8b70), (0x8b72, 0x8b72),
+    (0x8b77, 0x8b77), (0x8c37, 0x8c37), (0x8c46, 0x8c46), (0x8c4a, 0x8c4a), (0x8c5a, 0x8c5a),
+    (0x8c61, 0x8c61), (0x8c6a, 0x8c6a), (0x8c8c, 0x8c8c), (0x8c9d, 0x8c9e), (0x8ca0, 0x8ca2),
+    (0x8ca7, 0x8cac), (0x8caf, 0x8caf), (0x8cb4, 0x8cb4), (0x8cb7, 0x8cb8), (0x8cbb, 0x8cbc),
+    (0x8cbf, 0x8cc0), (0x8cc2, 0x8cc4), (0x8cc7, 0x8cc7), (0x8cca, 0x8cca), (0x8cd3, 0x8cd3),
+    (0x8cdb, 0x8cdc), (0x8cde, 0x8cde), (0x8ce0, 0x8ce0), (0x8ce2, 0x8ce2), (0x8ce6, 0x8ce6),
+    (0x8cea, 0x8cea), (0x8ced, 0x8ced), (0x8cfc, 0x8cfc), (0x8d08, 0x8d08), (0x8d64, 0x8d64),
+    (0x8d66, 0x8d66), (0x8d70, 0x8d70), (0x8d74, 0x8d74), (0x8d77, 0x8d77), (0x8d85, 0x8d85),
+    (0x8d8a, 0x8d8a), (0x8da3, 0x8da3), (0x8db3, 0x8db3), (0x8ddd, 0x8ddd), (0x8de1, 0x8de1),
+    (0x8def, 0x8def), (0x8df3, 0x8df3), (0x8df5, 0x8df5), (0x8e0a, 0x8e0a), (0x8e0f, 0x8e0f),
+    (0x8e2a, 0x8e2a), (0x8e74, 0x8e74), (0x8e8d, 0x8e8d), (0x8eab, 0x8eab), (0x8eca, 0x8eca),
+    (0x8ecc, 0x8ecd), (0x8ed2, 0x8ed2), (0x8edf, 0x8edf), (0x8ee2, 0x8ee2), (0x8ef8, 0x8ef8),
+    (0x8efd, 0x8efd), (0x8f03, 0x8f03), (0x8f09, 0x8f09), (0x8f1d, 0x8f1d), (0x8f29, 0x8f2a),
+    (0x8f38, 0x8f38), (0x8f44, 0x8f44), (0x8f9b, 0x8f9b), (0x8f9e, 0x8f9e), (0x8fa3, 0x8fa3),
+    (0x8fb1, 0x8fb2), (0x8fba, 0x8fba), (0x8fbc, 0x8fbc), (0x8fc5, 0x8fc5), (0x8fce, 0x8fce),
+    (0x8fd1, 0x8fd1), (0x8fd4, 0x8fd4), (0x8feb, 0x8feb), (0x8fed, 0x8fed), (0x8ff0, 0x8ff0),
+    (0x8ff7, 0x8ff7), (0x8ffd, 0x8ffd), (0x9000, 0x9001), (0x9003, 0x9003), (0x9006, 0x9006),
+    (0x900f, 0x9010), (0x9013, 0x9014), (0x901a, 0x901a), (0x901d, 0x901d), (0x901f, 0x9020),
+    (0x9023, 0x9023), (0x902e, 0x902e), (0x9031, 0x9032), (0x9038, 0x9038), (0x9042, 0x9042),
+    (0x9045, 0x9045), (0x9047, 0x9047), (0x904a, 0x904b), (0x904d, 0x904e), (0x9053, 0x9055),
+    (0x905c, 0x905c), (0x9060, 0x9061), (0x9063, 0x9063), (0x9069, 0x9069), (0x906d, 0x906e),
+    (0x9075, 0x9075), (0x9077, 0x9078), (0x907a, 0x907a), (0x907f, 0x907f), (0x9084, 0x9084),
+    (0x90a3, 0x90a3), (0x90a6, 0x90a6), (0x90aa, 0x90aa), (0x90b8, 0x90b8), (0x90ca, 0x90ca),
+    (0x90ce, 0x90ce), (0x90e1, 0x90e1), (0x90e8, 0x90e8), (0x90ed, 0x90ed), (0x90f5, 0x90f5),
+    (0x90f7, 0x90f7), (0x90fd, 0x90fd), (0x914c, 0x914e), (0x9152, 0x9152), (0x9154, 0x9154),
+    (0x9162, 0x9162), (0x916a, 0x916a), (0x916c, 0x916c), (0x9175, 0x9175), (0x9177, 0x9178),
+    (0x9192, 0x9192), (0x919c, 0x919c), (0x91b8, 0x91b8), (0x91c7, 0x91c8), (0x91cc, 0x91cf),
+    (0x91d1, 0x91d1), (0x91dc, 0x91dd), (0x91e3, 0x91e3), (0x920d, 0x920d), (0x9234, 0x9234),
+    (0x9244, 0x9244), (0x925b, 0x925b), (0x9262, 0x9262), (0x9271, 0x9271), (0x9280, 0x9280),
+    (0x9283, 0x9283), (0x9285, 0x9285), (0x9298, 0x9298), (0x92ad, 0x92ad), (0x92ed, 0x92ed),
+    (0x92f3, 0x92f3), (0x92fc, 0x92fc), (0x9320, 0x9320), (0x9326, 0x9326), (0x932c, 0x932c),
+    (0x932e, 0x932f), (0x9332, 0x9332), (0x934b, 0x934b), (0x935b, 0x935b), (0x9375, 0x9375),
+    (0x938c, 0x938c), (0x9396, 0x9396), (0x93ae, 0x93ae), (0x93e1, 0x93e1), (0x9418, 0x9418),
+    (0x9451, 0x9451), (0x9577, 0x9577), (0x9580, 0x9580), (0x9589, 0x9589), (0x958b, 0x958b),
+    (0x9591, 0x9591), (0x9593, 0x9593), (0x95a2, 0x95a3), (0x95a5, 0x95a5), (0x95b2, 0x95b2),
+    (0x95c7, 0x95c7), (0x95d8, 0x95d8), (0x961c, 0x961c), (0x962a, 0x962a), (0x9632, 0x9632),
+    (0x963b, 0x963b), (0x9644, 0x9644), (0x964d, 0x964d), (0x9650, 0x9650), (0x965b, 0x965b),
+    (0x9662, 0x9665), (0x966a, 0x966a), (0x9670, 0x9670), (0x9673, 0x9673), (0x9675, 0x9676),
+    (0x9678, 0x9678), (0x967a, 0x967a), (0x967d, 0x967d), (0x9685, 0x9686), (0x968a, 0x968a),
+    (0x968e, 0x968f), (0x9694, 0x9694), (0x9699, 0x9699), (0x969b, 0x969c), (0x96a0, 0x96a0),
+    (0x96a3, 0x96a3), (0x96b7, 0x96b7), (0x96bb, 0x96bb), (0x96c4, 0x96c7), (0x96cc, 0x96cc),
+    (0x96d1, 0x96d1), (0x96e2, 0x96e3), (0x96e8, 0x96e8), (0x96ea, 0x96ea), (0x96f0, 0x96f0),
+    (0x96f2, 0x96f2), (0x96f6, 0x96f7), (0x96fb, 0x96fb), (0x9700, 0x9700), (0x9707, 0x9707),
+    (0x970a, 0x970a), (0x971c, 0x971c), (0x9727, 0x9727), (0x9732, 0x9732), (0x9752, 0x9752),
+    (0x9759, 0x9759), (0x975e, 0x975e), (0x9762, 0x9762), (0x9769, 0x9769), (0x9774, 0x9774),
+    (0x97d3, 0x97d3), (0x97f3, 0x97f3), (0x97fb, 0x97fb), (0x97ff, 0x97ff), (0x9802, 0x9803),
+    (0x9805, 0x9806), (0x9808, 0x9808), (0x9810, 0x9813), (0x9818, 0x9818), (0x982d, 0x982d),
+    (0x983b, 0x983c), (0x984c, 0x984e), (0x9854, 0x9855), (0x9858, 0x9858), (0x985e, 0x985e),
+    (0x9867, 0x9867), (0x98a8, 0x98a8), (0x98db, 0x98db), (0x98df, 0x98df), (0x98e2, 0x98e2),
+    (0x98ef, 0x98ef), (0x98f2, 0x98f2), (0x98fc, 0x98fe), (0x9905, 0x9905), (0x990a, 0x990a),
+    (0x990c, 0x990c), (0x9913, 0x9913), (0x9928, 0x9928), (0x9996, 0x9996), (0x9999, 0x9999),
+    (0x99ac, 0x99ac), (0x99c4, 0x99c6), (0x99d0, 0x99d0), (0x99d2, 0x99d2), (0x9a0e, 0x9a0e),
+    (0x9a12, 0x9a13), (0x9a30, 0x9a30), (0x9a5a, 0x9a5a), (0x9aa8, 0x9aa8), (0x9ab8, 0x9ab8),
+    (0x9ac4, 0x9ac4), (0x9ad8, 0x9ad8), (0x9aea, 0x9aea), (0x9b31, 0x9b31), (0x9b3c, 0x9b3c),
+    (0x9b42, 0x9b42), (0x9b45, 0x9b45), (0x9b54, 0x9b54), (0x9b5a, 0x9b5a), (0x9bae, 0x9bae),
+    (0x9be8, 0x9be8), (0x9ce5, 0x9ce5), (0x9cf4, 0x9cf4), (0x9d8f, 0x9d8f), (0x9db4, 0x9db4),
+    (0x9e7f, 0x9e7f), (0x9e93, 0x9e93), (0x9e97, 0x9e97), (0x9ea6, 0x9ea6), (0x9eba, 0x9ebb),
+    (0x9ec4, 0x9ec4), (0x9ed2, 0x9ed2), (0x9ed9, 0x9ed9), (0x9f13, 0x9f13), (0x9f3b, 0x9f3b),
+    (0x9f62, 0x9f62),
+];
+
+#[rustfmt::skip]
+const KA: &[(Int, Int)] = &[
+    (0x10d0, 0x10f0),
+];
+
+#[rustfmt::skip]
+const KAA: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x492, 0x493), (0x49a, 0x49b), (0x4a2, 0x4a3),
+    (0x4ae, 0x4af), (0x4b2, 0x4b3), (0x4d8, 0x4d9),
+];
+
+#[rustfmt::skip]
+const KI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x128, 0x129), (0x168, 0x169),
+];
+
+#[rustfmt::skip]
+const KK: &[(Int, Int)] = &[
+    (0x410, 0x44f), (0x456, 0x456), (0x492, 0x493), (0x49a, 0x49b), (0x4a2, 0x4a3), (0x4ba, 0x4bb),
+    (0x4d8, 0x4d9), (0x4e8, 0x4e9),
+];
+
+#[rustfmt::skip]
+const KL: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc3), (0xc5, 0xc6), (0xca, 0xca), (0xcd, 0xce),
+    (0xd4, 0xd4), (0xd8, 0xd8), (0xda, 0xdb), (0xe1, 0xe3), (0xe5, 0xe6), (0xea, 0xea),
+    (0xed, 0xee), (0xf4, 0xf4), (0xf8, 0xf8), (0xfa, 0xfb), (0x128, 0x129), (0x138, 0x138),
+    (0x168, 0x169),
+];
+
+#[rustfmt::skip]
+const KM: &[(Int, Int)] = &[
+    (0x1780, 0x179c), (0x179f, 0x17a2), (0x17a5, 0x17a7), (0x17a9, 0x17b3), (0x17b6, 0x17c5),
+];
+
+#[rustfmt::skip]
+const KN: &[(Int, Int)] = &[
+    (0xc82, 0xc83), (0xc85, 0xc8c), (0xc8e, 0xc90), (0xc92, 0xca8), (0xcaa, 0xcb3), (0xcb5, 0xcb9),
+    (0xcbe, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd), (0xcd5, 0xcd6), (0xcde, 0xcde), (0xce0, 0xce1),
+];
+
+#[rustfmt::skip]
+const KO: &[(Int, Int)] = &[
+    (0x3131, 0x3163), (0x3165, 0x318e), (0xac00, 0xac01), (0xac04, 0xac04), (0xac07, 0xac0a),
+    (0xac10, 0xac17), (0xac19, 0xac1d), (0xac20, 0xac20), (0xac24, 0xac24), (0xac2c, 0xac2d),
+    (0xac2f, 0xac31), (0xac38, 0xac39), (0xac3c, 0xac3c), (0xac40, 0xac40), (0xac4b, 0xac4b),
+    (0xac4d, 0xac4d), (0xac54, 0xac54), (0xac58, 0xac58), (0xac5c, 0xac5c), (0xac70, 0xac71),
+    (0xac74, 0xac74), (0xac77, 0xac78), (0xac7a, 0xac7a), (0xac80, 0xac81), (0xac83, 0xac86),
+    (0xac89, 0xac8c), (0xac90, 0xac90), (0xac94, 0xac94), (0xac9c, 0xac9d), (0xac9f, 0xaca1),
+    (0xaca8, 0xacaa), (0xacac, 0xacac), (0xacaf, 0xacb0), (0xacb8, 0xacb9), (0xacbb, 0xacbd),
+    (0xacc1, 0xacc1), (0xacc4, 0xacc4), (0xacc8, 0xacc8), (0xaccc, 0xaccc), (0xacd5, 0xacd5),
+    (0xacd7, 0xacd7), (0xace0, 0xace1), (0xace4, 0xace4), (0xace7, 0xace8), (0xacea, 0xacea),
+    (0xacec, 0xacec), (0xacef, 0xacf1), (0xacf3, 0xacf3), (0xacf5, 0xacf6), (0xacfc, 0xacfd),
+    (0xad00, 0xad00), (0xad04, 0xad04), (0xad06, 0xad06), (0xad0c, 0xad0d), (0xad0f, 0xad0f),
+    (0xad11, 0xad11), (0xad18, 0xad18), (0xad1c, 0xad1c), (0xad20, 0xad20), (0xad29, 0xad29),
+    (0xad2c, 0xad2d), (0xad34, 0xad35), (0xad38, 0xad38), (0xad3c, 0xad3c), (0xad44, 0xad45),
+    (0xad47, 0xad47), (0xad49, 0xad49), (0xad50, 0xad50), (0xad54, 0xad54), (0xad58, 0xad58),
+    (0xad61, 0xad61), (0xad63, 0xad63), (0xad6c, 0xad6d), (0xad70, 0xad70), (0xad73, 0xad76),
+    (0xad7b, 0xad7d), (0xad7f, 0xad7f), (0xad81, 0xad82), (0xad88, 0xad89), (0xad8c, 0xad8c),
+    (0xad90, 0xad90), (0xad9c, 0xad9d), (0xada4, 0xada4), (0xadb7, 0xadb7), (0xadc0, 0xadc1),
+    (0xadc4, 0xadc4), (0xadc8, 0xadc8), (0xadd0, 0xadd1), (0xadd3, 0xadd3), (0xaddc, 0xaddc),
+    (0xade0, 0xade0), (0xade4, 0xade4), (0xadf8, 0xadf9), (0xadfc, 0xadfc), (0xadff, 0xae01),
+    (0xae08, 0xae09), (0xae0b, 0xae0b), (0xae0d, 0xae0d), (0xae14, 0xae14), (0xae30, 0xae31),
+    (0xae34, 0xae34), (0xae37, 0xae38), (0xae3a, 0xae3a), (0xae40, 0xae41), (0xae43, 0xae43),
+    (0xae45, 0xae46), (0xae4a, 0xae4a), (0xae4c, 0xae4e), (0xae50, 0xae50), (0xae54, 0xae54),
+    (0xae56, 0xae56), (0xae5c, 0xae5d), (0xae5f, 0xae61), (0xae65, 0xae65), (0xae68, 0xae69),
+    (0xae6c, 0xae6c), (0xae70, 0xae70), (0xae78, 0xae79), (0xae7b, 0xae7d), (0xae84, 0xae85),
+    (0xae8c, 0xae8c), (0xaebc, 0xaebe), (0xaec0, 0xaec0), (0xaec4, 0xaec4), (0xaecc, 0xaecd),
+    (0xaecf, 0xaed1), (0xaed8, 0xaed9), (0xaedc, 0xaedc), (0xaee8, 0xaee8), (0xaeeb, 0xaeeb),
+    (0xaeed, 0xaeed), (0xaef4, 0xaef4), (0xaef8, 0xaef8), (0xaefc, 0xaefc), (0xaf07, 0xaf08),
+    (0xaf0d, 0xaf0d), (0xaf10, 0xaf10), (0xaf2c, 0xaf2d), (0xaf30, 0xaf30), (0xaf32, 0xaf32),
+    (0xaf34, 0xaf34), (0xaf3c, 0xaf3d), (0xaf3f, 0xaf3f), (0xaf41, 0xaf43), (0xaf48, 0xaf49),
+    (0xaf50, 0xaf50), (0xaf5c, 0xaf5d), (0xaf64, 0xaf65), (0xaf79, 0xaf79), (0xaf80, 0xaf80),
+    (0xaf84, 0xaf84), (0xaf88, 0xaf88), (0xaf90, 0xaf91), (0xaf95, 0xaf95), (0xaf9c, 0xaf9c),
+    (0xafb8, 0xafb9), (0xafbc, 0xafbc), (0xafc0, 0xafc0), (0xafc7, 0xafc9), (0xafcb, 0xafcb),
+    (0xafcd, 0xafce), (0xafd4, 0xafd4), (0xafdc, 0xafdc), (0xafe8, 0xafe9), (0xaff0, 0xaff1),
+    (0xaff4, 0xaff4), (0xaff8, 0xaff8), (0xb000, 0xb001), (0xb004, 0xb004), (0xb00c, 0xb00c),
+    (0xb010, 0xb010), (0xb014, 0xb014), (0xb01c, 0xb01d), (0xb028, 0xb028), (0xb044, 0xb045),
+    (0xb048, 0xb048), (0xb04a, 0xb04a), (0xb04c, 0xb04c), (0xb04e, 0xb04e), (0xb053, 0xb055),
+    (0xb057, 0xb057), (0xb059, 0xb059), (0xb05d, 0xb05d), (0xb07c, 0xb07d), (0xb080, 0xb080),
+    (0xb084, 0xb084), (0xb08c, 0xb08d), (0xb08f, 0xb08f), (0xb091, 0xb091), (0xb098, 0xb09a),
+    (0xb09c, 0xb09c), (0xb09f, 0xb0a2), (0xb0a8, 0xb0a9), (0xb0ab, 0xb0af), (0xb0b1, 0xb0b1),
+    (0xb0b3, 0xb0b5), (0xb0b8, 0xb0b8), (0xb0bc, 0xb0bc), (0xb0c4, 0xb0c5), (0xb0c7, 0xb0c9),
+    (0xb0d0, 0xb0d1), (0xb0d4, 0xb0d4), (0xb0d8, 0xb0d8), (0xb0e0, 0xb0e0), (0xb0e5, 0xb0e5),
+    (0xb108, 0xb109), (0xb10b, 0xb10c), (0xb110, 0xb110), (0xb112, 0xb113), (0xb118, 0xb119),
+    (0xb11b, 0xb11d), (0xb123, 0xb125), (0xb128, 0xb128), (0xb12c, 0xb12c), (0xb134, 0xb135),
+    (0xb137, 0xb139), (0xb140, 0xb141), (0xb144, 0xb144), (0xb148, 0xb148), (0xb150, 0xb151),
+    (0xb154, 0xb155), (0xb158, 0xb158), (0xb15c, 0xb15c), (0xb160, 0xb160), (0xb178, 0xb179),
+    (0xb17c, 0xb17c), (0xb180, 0xb180), (0xb182, 0xb182), (0xb188, 0xb189), (0xb18b, 0xb18b),
+    (0xb18d, 0xb18d), (0xb192, 0xb194), (0xb198, 0xb198), (0xb19c, 0xb19c), (0xb1a8, 0xb1a8),
+    (0xb1cc, 0xb1cc), (0xb1d0, 0xb1d0), (0xb1d4, 0xb1d4), (0xb1dc, 0xb1dd), (0xb1df, 0xb1df),
+    (0xb1e8, 0xb1e9), (0xb1ec, 0xb1ec), (0xb1f0, 0xb1f0), (0xb1f9, 0xb1f9), (0xb1fb, 0xb1fb),
+    (0xb1fd, 0xb1fd), (0xb204, 0xb205), (0xb208, 0xb208), (0xb20b, 0xb20c), (0xb214, 0xb215),
+    (0xb217, 0xb217), (0xb219, 0xb219), (0xb220, 0xb220), (0xb234, 0xb234), (0xb23c, 0xb23c),
+    (0xb258, 0xb258), (0xb25c, 0xb25c), (0xb260, 0xb260), (0xb268, 0xb269), (0xb274, 0xb275),
+    (0xb27c, 0xb27c), (0xb284, 0xb285), (0xb289, 0xb289), (0xb290, 0xb291), (0xb294, 0xb294),
+    (0xb298, 0xb29a), (0xb2a0, 0xb2a1), (0xb2a3, 0xb2a3), (0xb2a5, 0xb2a6), (0xb2aa, 0xb2aa),
+    (0xb2ac, 0xb2ac), (0xb2b0, 0xb2b0), (0xb2b4, 0xb2b4), (0xb2c8, 0xb2c9), (0xb2cc, 0xb2cc),
+    (0xb2d0, 0xb2d0), (0xb2d2, 0xb2d2), (0xb2d8, 0xb2d9), (0xb2db, 0xb2db), (0xb2dd, 0xb2dd),
+    (0xb2e2, 0xb2e2), (0xb2e4, 0xb2e6), (0xb2e8, 0xb2e8), (0xb2eb, 0xb2ef), (0xb2f3, 0xb2f5),
+    (0xb2f7, 0xb2fb), (0xb2ff, 0xb301), (0xb304, 0xb304), (0xb308, 0xb308), (0xb310, 0xb311),
+    (0xb313, 0xb315), (0xb31c, 0xb31c), (0xb354, 0xb356), (0xb358, 0xb358), (0xb35b, 0xb35c),
+    (0xb35e, 0xb35f), (0xb364, 0xb365), (0xb367, 0xb367), (0xb369, 0xb369), (0xb36b, 0xb36b),
+    (0xb36e, 0xb36e), (0xb370, 0xb371), (0xb374, 0xb374), (0xb378, 0xb378), (0xb380, 0xb381),
+    (0xb383, 0xb385), (0xb38c, 0xb38c), (0xb390, 0xb390), (0xb394, 0xb394), (0xb3a0, 0xb3a1),
+    (0xb3a8, 0xb3a8), (0xb3ac, 0xb3ac), (0xb3c4, 0xb3c5), (0xb3c8, 0xb3c8), (0xb3cb, 0xb3cc),
+    (0xb3ce, 0xb3ce), (0xb3d0, 0xb3d0), (0xb3d4, 0xb3d5), (0xb3d7, 0xb3d7), (0xb3d9, 0xb3d9),
+    (0xb3db, 0xb3db), (0xb3dd, 0xb3dd), (0xb3e0, 0xb3e0), (0xb3e4, 0xb3e4), (0xb3e8, 0xb3e8),
+    (0xb3fc, 0xb3fc), (0xb410, 0xb410), (0xb418, 0xb418), (0xb41c, 0xb41c), (0xb420, 0xb420),
+    (0xb428, 0xb429), (0xb42b, 0xb42b), (0xb434, 0xb434), (0xb450, 0xb451), (0xb454, 0xb454),
+    (0xb458, 0xb458), (0xb460, 0xb461), (0xb463, 0xb463), (0xb465, 0xb465), (0xb46c, 0xb46c),
+    (0xb480, 0xb480), (0xb488, 0xb488), (0xb49d, 0xb49d), (0xb4a4, 0xb4a4), (0xb4a8, 0xb4a8),
+    (0xb4ac, 0xb4ac), (0xb4b5, 0xb4b5), (0xb4b7, 0xb4b7), (0xb4b9, 0xb4b9), (0xb4c0, 0xb4c0),
+    (0xb4c4, 0xb4c4), (0xb4c8, 0xb4c8), (0xb4d0, 0xb4d0), (0xb4d5, 0xb4d5), (0xb4dc, 0xb4dd),
+    (0xb4e0, 0xb4e0), (0xb4e3, 0xb4e4), (0xb4e6, 0xb4e6), (0xb4ec, 0xb4ed), (0xb4ef, 0xb4ef),
+    (0xb4f1, 0xb4f1), (0xb4f8, 0xb4f8), (0xb514, 0xb515), (0xb518, 0xb518), (0xb51b, 0xb51c),
+    (0xb524, 0xb525), (0xb527, 0xb52a), (0xb530, 0xb531), (0xb534, 0xb534), (0xb538, 0xb538),
+    (0xb540, 0xb541), (0xb543, 0xb545), (0xb54b, 0xb54d), (0xb550, 0xb550), (0xb554, 0xb554),
+    (0xb55c, 0xb55d), (0xb55f, 0xb561), (0xb5a0, 0xb5a1), (0xb5a4, 0xb5a4), (0xb5a8, 0xb5a8),
+    (0xb5aa, 0xb5ab), (0xb5b0, 0xb5b1), (0xb5b3, 0xb5b5), (0xb5bb, 0xb5bd), (0xb5c0, 0xb5c0),
+    (0xb5c4, 0xb5c4), (0xb5cc, 0xb5cd), (0xb5cf, 0xb5d1), (0xb5d8, 0xb5d8), (0xb5ec, 0xb5ec),
+    (0xb610, 0xb611), (0xb614, 0xb614), (0xb618, 0xb618), (0xb625, 0xb625), (0xb62c, 0xb62c),
+    (0xb634, 0xb634), (0xb648, 0xb648), (0xb664, 0xb664), (0xb668, 0xb668), (0xb69c, 0xb69d),
+    (0xb6a0, 0xb6a0), (0xb6a4, 0xb6a4), (0xb6ab, 0xb6ac), (0xb6b1, 0xb6b1), (0xb6d4, 0xb6d4),
+    (0xb6f0, 0xb6f0), (0xb6f4, 0xb6f4), (0xb6f8, 0xb6f8), (0xb700, 0xb701), (0xb705, 0xb705),
+    (0xb728, 0xb729), (0xb72c, 0xb72c), (0xb72f, 0xb730), (0xb738, 0xb739), (0xb73b, 0xb73b),
+    (0xb744, 0xb744), (0xb748, 0xb748), (0xb74c, 0xb74c), (0xb754, 0xb755), (0xb760, 0xb760),
+    (0xb764, 0xb764), (0xb768, 0xb768), (0xb770, 0xb771), (0xb773, 0xb773), (0xb775, 0xb775),
+    (0xb77c, 0xb77d), (0xb780, 0xb780), (0xb784, 0xb784), (0xb78c, 0xb78d), (0xb78f, 0xb792),
+    (0xb796, 0xb799), (0xb79c, 0xb79c), (0xb7a0, 0xb7a0), (0xb7a8, 0xb7a9), (0xb7ab, 0xb7ad),
+    (0xb7b4, 0xb7b5), (0xb7b8, 0xb7b8), (0xb7c7, 0xb7c7), (0xb7c9, 0xb7c9), (0xb7ec, 0xb7ed),
+    (0xb7f0, 0xb7f0), (0xb7f4, 0xb7f4), (0xb7fc, 0xb7fd), (0xb7ff, 0xb801), (0xb807, 0xb809),
+    (0xb80c, 0xb80c), (0xb810, 0xb810), (0xb818, 0xb819), (0xb81b, 0xb81b), (0xb81d, 0xb81d),
+    (0xb824, 0xb825), (0xb828, 0xb828), (0xb82c, 0xb82c), (0xb834, 0xb835), (0xb837, 0xb839),
+    (0xb840, 0xb840), (0xb844, 0xb844), (0xb851, 0xb851), (0xb853, 0xb853), (0xb85c, 0xb85d),
+    (0xb860, 0xb860), (0xb864, 0xb864), (0xb86c, 0xb86d), (0xb86f, 0xb86f), (0xb871, 0xb871),
+    (0xb878, 0xb878), (0xb87c, 0xb87c), (0xb88d, 0xb88d), (0xb8a8, 0xb8a8), (0xb8b0, 0xb8b0),
+    (0xb8b4, 0xb8b4), (0xb8b8, 0xb8b8), (0xb8c0, 0xb8c1), (0xb8c3, 0xb8c3), (0xb8c5, 0xb8c5),
+    (0xb8cc, 0xb8cc), (0xb8d0, 0xb8d0), (0xb8d4, 0xb8d4), (0xb8dd, 0xb8dd), (0xb8df, 0xb8df),
+    (0xb8e1, 0xb8e1), (0xb8e8, 0xb8e9), (0xb8ec, 0xb8ec), (0xb8f0, 0xb8f0), (0xb8f8, 0xb8f9),
+    (0xb8fb, 0xb8fb), (0xb8fd, 0xb8fd), (0xb904, 0xb904), (0xb918, 0xb918), (0xb920, 0xb920),
+    (0xb93c, 0xb93d), (0xb940, 0xb940), (0xb944, 0xb944), (0xb94c, 0xb94c), (0xb94f, 0xb94f),
+    (0xb951, 0xb951), (0xb958, 0xb959), (0xb95c, 0xb95c), (0xb960, 0xb960), (0xb968, 0xb969),
+    (0xb96b, 0xb96b), (0xb96d, 0xb96d), (0xb974, 0xb975), (0xb978, 0xb978), (0xb97c, 0xb97c),
+    (0xb984, 0xb985), (0xb987, 0xb987), (0xb989, 0xb98a), (0xb98d, 0xb98e), (0xb9ac, 0xb9ad),
+    (0xb9b0, 0xb9b0), (0xb9b4, 0xb9b4), (0xb9bc, 0xb9bd), (0xb9bf, 0xb9bf), (0xb9c1, 0xb9c1),
+    (0xb9c8, 0xb9c9), (0xb9cc, 0xb9cc), (0xb9ce, 0xb9d2), (0xb9d8, 0xb9d9), (0xb9db, 0xb9db),
+    (0xb9dd, 0xb9de), (0xb9e1, 0xb9e1), (0xb9e3, 0xb9e5), (0xb9e8, 0xb9e8), (0xb9ec, 0xb9ec),
+    (0xb9f4, 0xb9f5), (0xb9f7, 0xb9fa), (0xba00, 0xba01), (0xba08, 0xba08), (0xba15, 0xba15),
+    (0xba38, 0xba39), (0xba3c, 0xba3c), (0xba40, 0xba40), (0xba42, 0xba42), (0xba48, 0xba49),
+    (0xba4b, 0xba4b), (0xba4d, 0xba4e), (0xba53, 0xba55), (0xba58, 0xba58), (0xba5c, 0xba5c),
+    (0xba64, 0xba65), (0xba67, 0xba69), (0xba70, 0xba71), (0xba74, 0xba74), (0xba78, 0xba78),
+    (0xba83, 0xba85), (0xba87, 0xba87), (0xba8c, 0xba8c), (0xbaa8, 0xbaa9), (0xbaab, 0xbaac),
+    (0xbab0, 0xbab0), (0xbab2, 0xbab2), (0xbab8, 0xbab9), (0xbabb, 0xbabb), (0xbabd, 0xbabd),
+    (0xbac4, 0xbac4), (0xbac8, 0xbac8), (0xbad8, 0xbad9), (0xbafc, 0xbafc), (0xbb00, 0xbb00),
+    (0xbb04, 0xbb04), (0xbb0d, 0xbb0d), (0xbb0f, 0xbb0f), (0xbb11, 0xbb11), (0xbb18, 0xbb18),
+    (0xbb1c, 0xbb1c), (0xbb20, 0xbb20), (0xbb29, 0xbb29), (0xbb2b, 0xbb2b), (0xbb34, 0xbb36),
+    (0xbb38, 0xbb38), (0xbb3b, 0xbb3e), (0xbb44, 0xbb45), (0xbb47, 0xbb47), (0xbb49, 0xbb49),
+    (0xbb4d, 0xbb4d), (0xbb4f, 0xbb50), (0xbb54, 0xbb54), (0xbb58, 0xbb58), (0xbb61, 0xbb61),
+    (0xbb63, 0xbb63), (0xbb6c, 0xbb6c), (0xbb88, 0xbb88), (0xbb8c, 0xbb8c), (0xbb90, 0xbb90),
+    (0xbba4, 0xbba4), (0xbba8, 0xbba8), (0xbbac, 0xbbac), (0xbbb4, 0xbbb4), (0xbbb7, 0xbbb7),
+    (0xbbc0, 0xbbc0), (0xbbc4, 0xbbc4), (0xbbc8, 0xbbc8), (0xbbd0, 0xbbd0), (0xbbd3, 0xbbd3),
+    (0xbbf8, 0xbbf9), (0xbbfc, 0xbbfc), (0xbbff, 0xbc00), (0xbc02, 0xbc02), (0xbc08, 0xbc09),
+    (0xbc0b, 0xbc0d), (0xbc0f, 0xbc0f), (0xbc11, 0xbc11), (0xbc14, 0xbc18), (0xbc1b, 0xbc1f),
+    (0xbc24, 0xbc25), (0xbc27, 0xbc27), (0xbc29, 0xbc29), (0xbc2d, 0xbc2d), (0xbc30, 0xbc31),
+    (0xbc34, 0xbc34), (0xbc38, 0xbc38), (0xbc40, 0xbc41), (0xbc43, 0xbc45), (0xbc49, 0xbc49),
+    (0xbc4c, 0xbc4d), (0xbc50, 0xbc50), (0xbc5d, 0xbc5d), (0xbc84, 0xbc85), (0xbc88, 0xbc88),
+    (0xbc8b, 0xbc8c), (0xbc8e, 0xbc8e), (0xbc94, 0xbc95), (0xbc97, 0xbc97), (0xbc99, 0xbc9a),
+    (0xbca0, 0xbca1), (0xbca4, 0xbca4), (0xbca7, 0xbca8), (0xbcb0, 0xbcb1), (0xbcb3, 0xbcb5),
+    (0xbcbc, 0xbcbd), (0xbcc0, 0xbcc0), (0xbcc4, 0xbcc4), (0xbccd, 0xbccd), (0xbccf, 0xbcd1),
+    (0xbcd5, 0xbcd5), (0xbcd8, 0xbcd8), (0xbcdc, 0xbcdc), (0xbcf4, 0xbcf6), (0xbcf8, 0xbcf8),
+    (0xbcfc, 0xbcfc), (0xbd04, 0xbd05), (0xbd07, 0xbd07), (0xbd09, 0xbd09), (0xbd10, 0xbd10),
+    (0xbd14, 0xbd14), (0xbd24, 0xbd24), (0xbd2c, 0xbd2c), (0xbd40, 0xbd40), (0xbd48, 0xbd49),
+    (0xbd4c, 0xbd4c), (0xbd50, 0xbd50), (0xbd58, 0xbd59), (0xbd64, 0xbd64), (0xbd68, 0xbd68),
+    (0xbd80, 0xbd81), (0xbd84, 0xbd84), (0xbd87, 0xbd8a), (0xbd90, 0xbd91), (0xbd93, 0xbd93),
+    (0xbd95, 0xbd95), (0xbd99, 0xbd9a), (0xbd9c, 0xbd9c), (0xbda4, 0xbda4), (0xbdb0, 0xbdb0),
+    (0xbdb8, 0xbdb8), (0xbdd4, 0xbdd5), (0xbdd8, 0xbdd8), (0xbddc, 0xbddc), (0xbde9, 0xbde9),
+    (0xbdf0, 0xbdf0), (0xbdf4, 0xbdf4), (0xbdf8, 0xbdf8), (0xbe00, 0xbe00), (0xbe03, 0xbe03),
+    (0xbe05, 0xbe05), (0xbe0c, 0xbe0d), (0xbe10, 0xbe10), (0xbe14, 0xbe14), (0xbe1c, 0xbe1d),
+    (0xbe1f, 0xbe1f), (0xbe44, 0xbe45), (0xbe48, 0xbe48), (0xbe4c, 0xbe4c), (0xbe4e, 0xbe4e),
+    (0xbe54, 0xbe55), (0xbe57, 0xbe57), (0xbe59, 0xbe5b), (0xbe60, 0xbe61), (0xbe64, 0xbe64),
+    (0xbe68, 0xbe68), (0xbe6a, 0xbe6a), (0xbe70, 0xbe71), (0xbe73, 0xbe75), (0xbe7b, 0xbe7d),
+    (0xbe80, 0xbe80), (0xbe84, 0xbe84), (0xbe8c, 0xbe8d), (0xbe8f, 0xbe91), (0xbe98, 0xbe99),
+    (0xbea8, 0xbea8), (0xbed0, 0xbed1), (0xbed4, 0xbed4), (0xbed7, 0xbed8), (0xbee0, 0xbee0),
+    (0xbee3, 0xbee5), (0xbeec, 0xbeec), (0xbf01, 0xbf01), (0xbf08, 0xbf09), (0xbf18, 0xbf19),
+    (0xbf1b, 0xbf1d), (0xbf40, 0xbf41), (0xbf44, 0xbf44), (0xbf48, 0xbf48), (0xbf50, 0xbf51),
+    (0xbf55, 0xbf55), (0xbf94, 0xbf94), (0xbfb0, 0xbfb0), (0xbfc5, 0xbfc5), (0xbfcc, 0xbfcd),
+    (0xbfd0, 0xbfd0), (0xbfd4, 0xbfd4), (0xbfdc, 0xbfdc), (0xbfdf, 0xbfdf), (0xbfe1, 0xbfe1),
+    (0xc03c, 0xc03c), (0xc051, 0xc051), (0xc058, 0xc058), (0xc05c, 0xc05c), (0xc060, 0xc060),
+    (0xc068, 0xc069), (0xc090, 0xc091), (0xc094, 0xc094), (0xc098, 0xc098), (0xc0a0, 0xc0a1),
+    (0xc0a3, 0xc0a3), (0xc0a5, 0xc0a5), (0xc0ac, 0xc0ad), (0xc0af, 0xc0b0), (0xc0b3, 0xc0b6),
+    (0xc0bc, 0xc0bd), (0xc0bf, 0xc0c1), (0xc0c5, 0xc0c5), (0xc0c8, 0xc0c9), (0xc0cc, 0xc0cc),
+    (0xc0d0, 0xc0d0), (0xc0d8, 0xc0d9), (0xc0db, 0xc0dd), (0xc0e4, 0xc0e5), (0xc0e8, 0xc0e8),
+    (0xc0ec, 0xc0ec), (0xc0f4, 0xc0f5), (0xc0f7, 0xc0f7), (0xc0f9, 0xc0f9), (0xc100, 0xc100),
+    (0xc104, 0xc104), (0xc108, 0xc108), (0xc110, 0xc110), (0xc115, 0xc115), (0xc11c, 0xc120),
+    (0xc123, 0xc124), (0xc126, 0xc127), (0xc12c, 0xc12d), (0xc12f, 0xc131), (0xc136, 0xc136),
+    (0xc138, 0xc139), (0xc13c, 0xc13c), (0xc140, 0xc140), (0xc148, 0xc149), (0xc14b, 0xc14d),
+    (0xc154, 0xc155), (0xc158, 0xc158), (0xc15c, 0xc15c), (0xc164, 0xc165), (0xc167, 0xc169),
+    (0xc170, 0xc170), (0xc174, 0xc174), (0xc178, 0xc178), (0xc185, 0xc185), (0xc18c, 0xc18e),
+    (0xc190, 0xc190), (0xc194, 0xc194), (0xc196, 0xc196), (0xc19c, 0xc19d), (0xc19f, 0xc19f),
+    (0xc1a1, 0xc1a1), (0xc1a5, 0xc1a5), (0xc1a8, 0xc1a9), (0xc1ac, 0xc1ac), (0xc1b0, 0xc1b0),
+    (0xc1bd, 0xc1bd), (0xc1c4, 0xc1c4), (0xc1c8, 0xc1c8), (0xc1cc, 0xc1cc), (0xc1d4, 0xc1d4),
+    (0xc1d7, 0xc1d8), (0xc1e0, 0xc1e0), (0xc1e4, 0xc1e4), (0xc1e8, 0xc1e8), (0xc1f0, 0xc1f1),
+    (0xc1f3, 0xc1f3), (0xc1fc, 0xc1fd), (0xc200, 0xc200), (0xc204, 0xc204), (0xc20c, 0xc20d),
+    (0xc20f, 0xc20f), (0xc211, 0xc211), (0xc218, 0xc219), (0xc21c, 0xc21c), (0xc21f, 0xc220),
+    (0xc228, 0xc229), (0xc22b, 0xc22b), (0xc22d, 0xc22d), (0xc22f, 0xc22f), (0xc231, 0xc232),
+    (0xc234, 0xc234), (0xc248, 0xc248), (0xc250, 0xc251), (0xc254, 0xc254), (0xc258, 0xc258),
+    (0xc260, 0xc260), (0xc265, 0xc265), (0xc26c, 0xc26d), (0xc270, 0xc270), (0xc274, 0xc274),
+    (0xc27c, 0xc27d), (0xc27f, 0xc27f), (0xc281, 0xc281), (0xc288, 0xc289), (0xc290, 0xc290),
+    (0xc298, 0xc298), (0xc29b, 0xc29b), (0xc29d, 0xc29d), (0xc2a4, 0xc2a5), (0xc2a8, 0xc2a8),
+    (0xc2ac, 0xc2ad), (0xc2b4, 0xc2b5), (0xc2b7, 0xc2b7), (0xc2b9, 0xc2b9), (0xc2dc, 0xc2dd),
+    (0xc2e0, 0xc2e0), (0xc2e3, 0xc2e4), (0xc2eb, 0xc2ed), (0xc2ef, 0xc2ef), (0xc2f1, 0xc2f1),
+    (0xc2f6, 0xc2f6), (0xc2f8, 0xc2f9), (0xc2fb, 0xc2fc), (0xc300, 0xc300), (0xc308, 0xc309),
+    (0xc30c, 0xc30d), (0xc313, 0xc315), (0xc318, 0xc318), (0xc31c, 0xc31c), (0xc324, 0xc325),
+    (0xc328, 0xc329), (0xc345, 0xc345), (0xc368, 0xc369), (0xc36c, 0xc36c), (0xc370, 0xc370),
+    (0xc372, 0xc372), (0xc378, 0xc379), (0xc37c, 0xc37d), (0xc384, 0xc384), (0xc388, 0xc388),
+    (0xc38c, 0xc38c), (0xc3d8, 0xc3d9), (0xc3dc, 0xc3dc), (0xc3df, 0xc3e0), (0xc3e2, 0xc3e2),
+    (0xc3e8, 0xc3e9), (0xc3ed, 0xc3ed), (0xc3f4, 0xc3f5), (0xc3f8, 0xc3f8), (0xc408, 0xc408),
+    (0xc410, 0xc410), (0xc424, 0xc424), (0xc42c, 0xc42c), (0xc430, 0xc430), (0xc434, 0xc434),
+    (0xc43c, 0xc43d), (0xc448, 0xc448), (0xc464, 0xc465), (0xc468, 0xc468), (0xc46c, 0xc46c),
+    (0xc474, 0xc475), (0xc479, 0xc479), (0xc480, 0xc480), (0xc494, 0xc494), (0xc49c, 0xc49c),
+    (0xc4b8, 0xc4b8), (0xc4bc, 0xc4bc), (0xc4e9, 0xc4e9), (0xc4f0, 0xc4f1), (0xc4f4, 0xc4f4),
+    (0xc4f8, 0xc4f8), (0xc4fa, 0xc4fa), (0xc4ff, 0xc501), (0xc50c, 0xc50c), (0xc510, 0xc510),
+    (0xc514, 0xc514), (0xc51c, 0xc51c), (0xc528, 0xc529), (0xc52c, 0xc52c), (0xc530, 0xc530),
+    (0xc538, 0xc539), (0xc53b, 0xc53b), (0xc53d, 0xc53d), (0xc544, 0xc545), (0xc548, 0xc54a),
+    (0xc54c, 0xc54e), (0xc553, 0xc555), (0xc557, 0xc559), (0xc55d, 0xc55e), (0xc560, 0xc561),
+    (0xc564, 0xc564), (0xc568, 0xc568), (0xc570, 0xc571), (0xc573, 0xc575), (0xc57c, 0xc57d),
+    (0xc580, 0xc580), (0xc584, 0xc584), (0xc587, 0xc587), (0xc58c, 0xc58d), (0xc58f, 0xc58f),
+    (0xc591, 0xc591), (0xc595, 0xc595), (0xc597, 0xc598), (0xc59c, 0xc59c), (0xc5a0, 0xc5a0),
+    (0xc5a9, 0xc5a9), (0xc5b4, 0xc5b5), (0xc5b8, 0xc5b9), (0xc5bb, 0xc5be), (0xc5c4, 0xc5ca),
+    (0xc5cc, 0xc5cc), (0xc5ce, 0xc5ce), (0xc5d0, 0xc5d1), (0xc5d4, 0xc5d4), (0xc5d8, 0xc5d8),
+    (0xc5e0, 0xc5e1), (0xc5e3, 0xc5e3), (0xc5e5, 0xc5e5), (0xc5ec, 0xc5ee), (0xc5f0, 0xc5f0),
+    (0xc5f4, 0xc5f4), (0xc5f6, 0xc5f7), (0xc5fc, 0xc601), (0xc605, 0xc608), (0xc60c, 0xc60c),
+    (0xc610, 0xc610), (0xc618, 0xc619), (0xc61b, 0xc61c), (0xc624, 0xc625), (0xc628, 0xc628),
+    (0xc62c, 0xc62e), (0xc630, 0xc630), (0xc633, 0xc635), (0xc637, 0xc637), (0xc639, 0xc639),
+    (0xc63b, 0xc63b), (0xc640, 0xc641), (0xc644, 0xc644), (0xc648, 0xc648), (0xc650, 0xc651),
+    (0xc653, 0xc655), (0xc65c, 0xc65d), (0xc660, 0xc660), (0xc66c, 0xc66c), (0xc66f, 0xc66f),
+    (0xc671, 0xc671), (0xc678, 0xc679), (0xc67c, 0xc67c), (0xc680, 0xc680), (0xc688, 0xc689),
+    (0xc68b, 0xc68b), (0xc68d, 0xc68d), (0xc694, 0xc695), (0xc698, 0xc698), (0xc69c, 0xc69c),
+    (0xc6a4, 0xc6a5), (0xc6a7, 0xc6a7), (0xc6a9, 0xc6a9), (0xc6b0, 0xc6b1), (0xc6b4, 0xc6b4),
+    (0xc6b8, 0xc6ba), (0xc6c0, 0xc6c1), (0xc6c3, 0xc6c3), (0xc6c5, 0xc6c5), (0xc6cc, 0xc6cd),
+    (0xc6d0, 0xc6d0), (0xc6d4, 0xc6d4), (0xc6dc, 0xc6dd), (0xc6e0, 0xc6e1), (0xc6e8, 0xc6e9),
+    (0xc6ec, 0xc6ec), (0xc6f0, 0xc6f0), (0xc6f8, 0xc6f9), (0xc6fd, 0xc6fd), (0xc704, 0xc705),
+    (0xc708, 0xc708), (0xc70c, 0xc70c), (0xc714, 0xc715), (0xc717, 0xc717), (0xc719, 0xc719),
+    (0xc720, 0xc721), (0xc724, 0xc724), (0xc728, 0xc728), (0xc730, 0xc731), (0xc733, 0xc733),
+    (0xc735, 0xc735), (0xc737, 0xc737), (0xc73c, 0xc73d), (0xc740, 0xc740), (0xc744, 0xc744),
+    (0xc74a, 0xc74a), (0xc74c, 0xc74d), (0xc74f, 0xc74f), (0xc751, 0xc758), (0xc75c, 0xc75c),
+    (0xc760, 0xc760), (0xc768, 0xc768), (0xc76b, 0xc76b), (0xc774, 0xc775), (0xc778, 0xc778),
+    (0xc77c, 0xc77e), (0xc783, 0xc785), (0xc787, 0xc78a), (0xc78e, 0xc78e), (0xc790, 0xc791),
+    (0xc794, 0xc794), (0xc796, 0xc798), (0xc79a, 0xc79a), (0xc7a0, 0xc7a1), (0xc7a3, 0xc7a6),
+    (0xc7ac, 0xc7ad), (0xc7b0, 0xc7b0), (0xc7b4, 0xc7b4), (0xc7bc, 0xc7bd), (0xc7bf, 0xc7c1),
+    (0xc7c8, 0xc7c9), (0xc7cc, 0xc7cc), (0xc7ce, 0xc7ce), (0xc7d0, 0xc7d0), (0xc7d8, 0xc7d8),
+    (0xc7dd, 0xc7dd), (0xc7e4, 0xc7e4), (0xc7e8, 0xc7e8), (0xc7ec, 0xc7ec), (0xc800, 0xc801),
+    (0xc804, 0xc804), (0xc808, 0xc808), (0xc80a, 0xc80a), (0xc810, 0xc811), (0xc813, 0xc813),
+    (0xc815, 0xc816), (0xc81c, 0xc81d), (0xc820, 0xc820), (0xc824, 0xc824), (0xc82c, 0xc82d),
+    (0xc82f, 0xc82f), (0xc831, 0xc831), (0xc838, 0xc838), (0xc83c, 0xc83c), (0xc840, 0xc840),
+    (0xc848, 0xc849), (0xc84c, 0xc84d), (0xc854, 0xc854), (0xc870, 0xc871), (0xc874, 0xc874),
+    (0xc878, 0xc878), (0xc87a, 0xc87a), (0xc880, 0xc881), (0xc883, 0xc883), (0xc885, 0xc887),
+    (0xc88b, 0xc88d), (0xc894, 0xc894), (0xc89d, 0xc89d), (0xc89f, 0xc89f), (0xc8a1, 0xc8a1),
+    (0xc8a8, 0xc8a8), (0xc8bc, 0xc8bd), (0xc8c4, 0xc8c4), (0xc8c8, 0xc8c8), (0xc8cc, 0xc8cc),
+    (0xc8d4, 0xc8d5), (0xc8d7, 0xc8d7), (0xc8d9, 0xc8d9), (0xc8e0, 0xc8e1), (0xc8e4, 0xc8e4),
+    (0xc8f5, 0xc8f5), (0xc8fc, 0xc8fd), (0xc900, 0xc900), (0xc904, 0xc906), (0xc90c, 0xc90d),
+    (0xc90f, 0xc90f), (0xc911, 0xc911), (0xc918, 0xc918), (0xc92c, 0xc92c), (0xc934, 0xc934),
+    (0xc950, 0xc951), (0xc954, 0xc954), (0xc958, 0xc958), (0xc960, 0xc961), (0xc963, 0xc963),
+    (0xc96c, 0xc96c), (0xc970, 0xc970), (0xc974, 0xc974), (0xc97c, 0xc97c), (0xc988, 0xc989),
+    (0xc98c, 0xc98c), (0xc990, 0xc990), (0xc998, 0xc999), (0xc99b, 0xc99b), (0xc99d, 0xc99d),
+    (0xc9c0, 0xc9c1), (0xc9c4, 0xc9c4), (0xc9c7, 0xc9c8), (0xc9ca, 0xc9ca), (0xc9d0, 0xc9d1),
+    (0xc9d3, 0xc9d3), (0xc9d5, 0xc9d6), (0xc9d9, 0xc9da), (0xc9dc, 0xc9dd), (0xc9e0, 0xc9e0),
+    (0xc9e2, 0xc9e2), (0xc9e4, 0xc9e4), (0xc9e7, 0xc9e7), (0xc9ec, 0xc9ed), (0xc9ef, 0xc9f1),
+    (0xc9f8, 0xc9f9), (0xc9fc, 0xc9fc), (0xca00, 0xca00), (0xca08, 0xca09), (0xca0b, 0xca0d),
+    (0xca14, 0xca14), (0xca18, 0xca18), (0xca29, 0xca29), (0xca4c, 0xca4d), (0xca50, 0xca50),
+    (0xca54, 0xca54), (0xca5c, 0xca5d), (0xca5f, 0xca61), (0xca68, 0xca68), (0xca7d, 0xca7d),
+    (0xca84, 0xca84), (0xca98, 0xca98), (0xcabc, 0xcabd), (0xcac0, 0xcac0), (0xcac4, 0xcac4),
+    (0xcacc, 0xcacd), (0xcacf, 0xcacf), (0xcad1, 0xcad1), (0xcad3, 0xcad3), (0xcad8, 0xcad9),
+    (0xcae0, 0xcae0), (0xcaec, 0xcaec), (0xcaf4, 0xcaf4), (0xcb08, 0xcb08), (0xcb10, 0xcb10),
+    (0xcb14, 0xcb14), (0xcb18, 0xcb18), (0xcb20, 0xcb21), (0xcb41, 0xcb41), (0xcb48, 0xcb49),
+    (0xcb4c, 0xcb4c), (0xcb50, 0xcb50), (0xcb58, 0xcb59), (0xcb5d, 0xcb5d), (0xcb64, 0xcb64),
+    (0xcb78, 0xcb79), (0xcb9c, 0xcb9c), (0xcbb8, 0xcbb8), (0xcbd4, 0xcbd4), (0xcbe4, 0xcbe4),
+    (0xcbe7, 0xcbe7), (0xcbe9, 0xcbe9), (0xcc0c, 0xcc0d), (0xcc10, 0xcc10), (0xcc14, 0xcc14),
+    (0xcc1c, 0xcc1d), (0xcc21, 0xcc22), (0xcc27, 0xcc29), (0xcc2c, 0xcc2c), (0xcc2e, 0xcc2e),
+    (0xcc30, 0xcc30), (0xcc38, 0xcc39), (0xcc3b, 0xcc3e), (0xcc44, 0xcc45), (0xcc48, 0xcc48),
+    (0xcc4c, 0xcc4c), (0xcc54, 0xcc55), (0xcc57, 0xcc59), (0xcc60, 0xcc60), (0xcc64, 0xcc64),
+    (0xcc66, 0xcc66), (0xcc68, 0xcc68), (0xcc70, 0xcc70), (0xcc75, 0xcc75), (0xcc98, 0xcc99),
+    (0xcc9c, 0xcc9c), (0xcca0, 0xcca0), (0xcca8, 0xcca9), (0xccab, 0xccad), (0xccb4, 0xccb5),
+    (0xccb8, 0xccb8), (0xccbc, 0xccbc), (0xccc4, 0xccc5), (0xccc7, 0xccc7), (0xccc9, 0xccc9),
+    (0xccd0, 0xccd0), (0xccd4, 0xccd4), (0xcce4, 0xcce4), (0xccec, 0xccec), (0xccf0, 0xccf0),
+    (0xcd01, 0xcd01), (0xcd08, 0xcd09), (0xcd0c, 0xcd0c), (0xcd10, 0xcd10), (0xcd18, 0xcd19),
+    (0xcd1b, 0xcd1b), (0xcd1d, 0xcd1d), (0xcd24, 0xcd24), (0xcd28, 0xcd28), (0xcd2c, 0xcd2c),
+    (0xcd39, 0xcd39), (0xcd5c, 0xcd5c), (0xcd60, 0xcd60), (0xcd64, 0xcd64), (0xcd6c, 0xcd6d),
+    (0xcd6f, 0xcd6f), (0xcd71, 0xcd71), (0xcd78, 0xcd78), (0xcd88, 0xcd88), (0xcd94, 0xcd95),
+    (0xcd98, 0xcd98), (0xcd9c, 0xcd9c), (0xcda4, 0xcda5), (0xcda7, 0xcda7), (0xcda9, 0xcda9),
+    (0xcdb0, 0xcdb0), (0xcdc4, 0xcdc4), (0xcdcc, 0xcdcc), (0xcdd0, 0xcdd0), (0xcde8, 0xcde8),
+    (0xcdec, 0xcdec), (0xcdf0, 0xcdf0), (0xcdf8, 0xcdf9), (0xcdfb, 0xcdfb), (0xcdfd, 0xcdfd),
+    (0xce04, 0xce04), (0xce08, 0xce08), (0xce0c, 0xce0c), (0xce14, 0xce14), (0xce19, 0xce19),
+    (0xce20, 0xce21), (0xce24, 0xce24), (0xce28, 0xce28), (0xce30, 0xce31), (0xce33, 0xce33),
+    (0xce35, 0xce35), (0xce58, 0xce59), (0xce5c, 0xce5c), (0xce5f, 0xce61), (0xce68, 0xce69),
+    (0xce6b, 0xce6b), (0xce6d, 0xce6d), (0xce74, 0xce75), (0xce78, 0xce78), (0xce7c, 0xce7c),
+    (0xce84, 0xce85), (0xce87, 0xce87), (0xce89, 0xce89), (0xce90, 0xce91), (0xce94, 0xce94),
+    (0xce98, 0xce98), (0xcea0, 0xcea1), (0xcea3, 0xcea5), (0xceac, 0xcead), (0xcec1, 0xcec1),
+    (0xcee4, 0xcee5), (0xcee8, 0xcee8), (0xceeb, 0xceec), (0xcef4, 0xcef5), (0xcef7, 0xcef9),
+    (0xcf00, 0xcf01), (0xcf04, 0xcf04), (0xcf08, 0xcf08), (0xcf10, 0xcf11), (0xcf13, 0xcf13),
+    (0xcf15, 0xcf15), (0xcf1c, 0xcf1c), (0xcf20, 0xcf20), (0xcf24, 0xcf24), (0xcf2c, 0xcf2d),
+    (0xcf2f, 0xcf31), (0xcf38, 0xcf38), (0xcf54, 0xcf55), (0xcf58, 0xcf58), (0xcf5c, 0xcf5c),
+    (0xcf64, 0xcf65), (0xcf67, 0xcf67), (0xcf69, 0xcf69), (0xcf70, 0xcf71), (0xcf74, 0xcf74),
+    (0xcf78, 0xcf78), (0xcf80, 0xcf80), (0xcf85, 0xcf85), (0xcf8c, 0xcf8c), (0xcfa1, 0xcfa1),
+    (0xcfa8, 0xcfa8), (0xcfb0, 0xcfb0), (0xcfc4, 0xcfc4), (0xcfe0, 0xcfe1), (0xcfe4, 0xcfe4),
+    (0xcfe8, 0xcfe8), (0xcff0, 0xcff1), (0xcff3, 0xcff3), (0xcff5, 0xcff5), (0xcffc, 0xcffc),
+    (0xd000, 0xd000), (0xd004, 0xd004), (0xd011, 0xd011), (0xd018, 0xd018), (0xd02d, 0xd02d),
+    (0xd034, 0xd035), (0xd038, 0xd038), (0xd03c, 0xd03c), (0xd044, 0xd045), (0xd047, 0xd047),
+    (0xd049, 0xd049), (0xd050, 0xd050), (0xd054, 0xd054), (0xd058, 0xd058), (0xd060, 0xd060),
+    (0xd06c, 0xd06d), (0xd070, 0xd070), (0xd074, 0xd074), (0xd07c, 0xd07d), (0xd081, 0xd081),
+    (0xd0a4, 0xd0a5), (0xd0a8, 0xd0a8), (0xd0ac, 0xd0ac), (0xd0b4, 0xd0b5), (0xd0b7, 0xd0b7),
+    (0xd0b9, 0xd0b9), (0xd0c0, 0xd0c1), (0xd0c4, 0xd0c4), (0xd0c8, 0xd0c9), (0xd0d0, 0xd0d1),
+    (0xd0d3, 0xd0d5), (0xd0dc, 0xd0dd), (0xd0e0, 0xd0e0), (0xd0e4, 0xd0e4), (0xd0ec, 0xd0ed),
+    (0xd0ef, 0xd0f1), (0xd0f8, 0xd0f8), (0xd10d, 0xd10d), (0xd130, 0xd131), (0xd134, 0xd134),
+    (0xd138, 0xd138), (0xd13a, 0xd13a), (0xd140, 0xd141), (0xd143, 0xd145), (0xd14c, 0xd14d),
+    (0xd150, 0xd150), (0xd154, 0xd154), (0xd15c, 0xd15d), (0xd15f, 0xd15f), (0xd161, 0xd161),
+    (0xd168, 0xd168), (0xd16c, 0xd16c), (0xd17c, 0xd17c), (0xd184, 0xd184), (0xd188, 0xd188),
+    (0xd1a0, 0xd1a1), (0xd1a4, 0xd1a4), (0xd1a8, 0xd1a8), (0xd1b0, 0xd1b1), (0xd1b3, 0xd1b3),
+    (0xd1b5, 0xd1b5), (0xd1ba, 0xd1ba), (0xd1bc, 0xd1bc), (0xd1c0, 0xd1c0), (0xd1d8, 0xd1d8),
+    (0xd1f4, 0xd1f4), (0xd1f8, 0xd1f8), (0xd207, 0xd207), (0xd209, 0xd209), (0xd210, 0xd210),
+    (0xd22c, 0xd22d), (0xd230, 0xd230), (0xd234, 0xd234), (0xd23c, 0xd23d), (0xd23f, 0xd23f),
+    (0xd241, 0xd241), (0xd248, 0xd248), (0xd25c, 0xd25c), (0xd264, 0xd264), (0xd280, 0xd281),
+    (0xd284, 0xd284), (0xd288, 0xd288), (0xd290, 0xd291), (0xd295, 0xd295), (0xd29c, 0xd29c),
+    (0xd2a0, 0xd2a0), (0xd2a4, 0xd2a4), (0xd2ac, 0xd2ac), (0xd2b1, 0xd2b1), (0xd2b8, 0xd2b9),
+    (0xd2bc, 0xd2bc), (0xd2bf, 0xd2c0), (0xd2c2, 0xd2c2), (0xd2c8, 0xd2c9), (0xd2cb, 0xd2cb),
+    (0xd2d4, 0xd2d4), (0xd2d8, 0xd2d8), (0xd2dc, 0xd2dc), (0xd2e4, 0xd2e5), (0xd2f0, 0xd2f1),
+    (0xd2f4, 0xd2f4), (0xd2f8, 0xd2f8), (0xd300, 0xd301), (0xd303, 0xd303), (0xd305, 0xd305),
+    (0xd30c, 0xd30e), (0xd310, 0xd310), (0xd314, 0xd314), (0xd316, 0xd316), (0xd31c, 0xd31d),
+    (0xd31f, 0xd321), (0xd325, 0xd325), (0xd328, 0xd329), (0xd32c, 0xd32c), (0xd330, 0xd330),
+    (0xd338, 0xd339), (0xd33b, 0xd33d), (0xd344, 0xd345), (0xd37c, 0xd37d), (0xd380, 0xd380),
+    (0xd384, 0xd384), (0xd38c, 0xd38d), (0xd38f, 0xd391), (0xd398, 0xd399), (0xd39c, 0xd39c),
+    (0xd3a0, 0xd3a0), (0xd3a8, 0xd3a9), (0xd3ab, 0xd3ab), (0xd3ad, 0xd3ad), (0xd3b4, 0xd3b4),
+    (0xd3b8, 0xd3b8), (0xd3bc, 0xd3bc), (0xd3c4, 0xd3c5), (0xd3c8, 0xd3c9), (0xd3d0, 0xd3d0),
+    (0xd3d8, 0xd3d8), (0xd3e1, 0xd3e1), (0xd3e3, 0xd3e3), (0xd3ec, 0xd3ed), (0xd3f0, 0xd3f0),
+    (0xd3f4, 0xd3f4), (0xd3fc, 0xd3fd), (0xd3ff, 0xd3ff), (0xd401, 0xd401), (0xd408, 0xd408),
+    (0xd41d, 0xd41d), (0xd440, 0xd440), (0xd444, 0xd444), (0xd45c, 0xd45c), (0xd460, 0xd460),
+    (0xd464, 0xd464), (0xd46d, 0xd46d), (0xd46f, 0xd46f), (0xd478, 0xd479), (0xd47c, 0xd47c),
+    (0xd47f, 0xd480), (0xd482, 0xd482), (0xd488, 0xd489), (0xd48b, 0xd48b), (0xd48d, 0xd48d),
+    (0xd494, 0xd494), (0xd4a9, 0xd4a9), (0xd4cc, 0xd4cc), (0xd4d0, 0xd4d0), (0xd4d4, 0xd4d4),
+    (0xd4dc, 0xd4dc), (0xd4df, 0xd4df), (0xd4e8, 0xd4e8), (0xd4ec, 0xd4ec), (0xd4f0, 0xd4f0),
+    (0xd4f8, 0xd4f8), (0xd4fb, 0xd4fb), (0xd4fd, 0xd4fd), (0xd504, 0xd504), (0xd508, 0xd508),
+    (0xd50c, 0xd50c), (0xd514, 0xd515), (0xd517, 0xd517), (0xd53c, 0xd53d), (0xd540, 0xd540),
+    (0xd544, 0xd544), (0xd54c, 0xd54d), (0xd54f, 0xd54f), (0xd551, 0xd551), (0xd558, 0xd559),
+    (0xd55c, 0xd55c), (0xd560, 0xd560), (0xd565, 0xd565), (0xd568, 0xd569), (0xd56b, 0xd56b),
+    (0xd56d, 0xd56d), (0xd574, 0xd575), (0xd578, 0xd578), (0xd57c, 0xd57c), (0xd584, 0xd585),
+    (0xd587, 0xd589), (0xd590, 0xd590), (0xd5a5, 0xd5a5), (0xd5c8, 0xd5c9), (0xd5cc, 0xd5cc),
+    (0xd5d0, 0xd5d0), (0xd5d2, 0xd5d2), (0xd5d8, 0xd5d9), (0xd5db, 0xd5db), (0xd5dd, 0xd5dd),
+    (0xd5e4, 0xd5e5), (0xd5e8, 0xd5e8), (0xd5ec, 0xd5ec), (0xd5f4, 0xd5f5), (0xd5f7, 0xd5f7),
+    (0xd5f9, 0xd5f9), (0xd600, 0xd601), (0xd604, 0xd604), (0xd608, 0xd608), (0xd610, 0xd611),
+    (0xd613, 0xd615), (0xd61c, 0xd61c), (0xd620, 0xd620), (0xd624, 0xd624), (0xd62d, 0xd62d),
+    (0xd638, 0xd639), (0xd63c, 0xd63c), (0xd640, 0xd640), (0xd645, 0xd645), (0xd648, 0xd649),
+    (0xd64b, 0xd64b), (0xd64d, 0xd64d), (0xd651, 0xd651), (0xd654, 0xd655), (0xd658, 0xd658),
+    (0xd65c, 0xd65c), (0xd667, 0xd667), (0xd669, 0xd669), (0xd670, 0xd671), (0xd674, 0xd674),
+    (0xd683, 0xd683), (0xd685, 0xd685), (0xd68c, 0xd68d), (0xd690, 0xd690), (0xd694, 0xd694),
+    (0xd69d, 0xd69d), (0xd69f, 0xd69f), (0xd6a1, 0xd6a1), (0xd6a8, 0xd6a8), (0xd6ac, 0xd6ac),
+    (0xd6b0, 0xd6b0), (0xd6b9, 0xd6b9), (0xd6bb, 0xd6bb), (0xd6c4, 0xd6c5), (0xd6c8, 0xd6c8),
+    (0xd6cc, 0xd6cc), (0xd6d1, 0xd6d1), (0xd6d4, 0xd6d4), (0xd6d7, 0xd6d7), (0xd6d9, 0xd6d9),
+    (0xd6e0, 0xd6e0), (0xd6e4, 0xd6e4), (0xd6e8, 0xd6e8), (0xd6f0, 0xd6f0), (0xd6f5, 0xd6f5),
+    (0xd6fc, 0xd6fd), (0xd700, 0xd700), (0xd704, 0xd704), (0xd711, 0xd711), (0xd718, 0xd719),
+    (0xd71c, 0xd71c), (0xd720, 0xd720), (0xd728, 0xd729), (0xd72b, 0xd72b), (0xd72d, 0xd72d),
+    (0xd734, 0xd735), (0xd738, 0xd738), (0xd73c, 0xd73c), (0xd744, 0xd744), (0xd747, 0xd747),
+    (0xd749, 0xd749), (0xd750, 0xd751), (0xd754, 0xd754), (0xd756, 0xd759), (0xd760, 0xd761),
+    (0xd763, 0xd763), (0xd765, 0xd765), (0xd769, 0xd769), (0xd76c, 0xd76c), (0xd770, 0xd770),
+    (0xd774, 0xd774), (0xd77c, 0xd77d), (0xd781, 0xd781), (0xd788, 0xd789), (0xd78c, 0xd78c),
+    (0xd790, 0xd790), (0xd798, 0xd799), (0xd79b, 0xd79b), (0xd79d, 0xd79d),
+];
+
+#[rustfmt::skip]
+const KS: &[(Int, Int)] = &[
+    (0x620, 0x624), (0x626, 0x628), (0x63a, 0x63a), (0x641, 0x642), (0x644, 0x646), (0x648, 0x648),
+    (0x657, 0x657), (0x65f, 0x65f), (0x672, 0x673), (0x679, 0x679), (0x67e, 0x67e), (0x686, 0x686),
+    (0x688, 0x688), (0x691, 0x691), (0x698, 0x698), (0x6a9, 0x6a9), (0x6af, 0x6af), (0x6ba, 0x6ba),
+    (0x6be, 0x6be), (0x6c3, 0x6c4), (0x6cc, 0x6cc), (0x6d2, 0x6d2),
+];
+
+#[rustfmt::skip]
+const KU_AM: &[(Int, Int)] = &[
+    (0x410, 0x425), (0x427, 0x42a), (0x42d, 0x42d), (0x430, 0x445), (0x447, 0x44a), (0x44d, 0x44d),
+    (0x4ba, 0x4bb), (0x4d8, 0x4d9), (0x4e6, 0x4e7), (0x51a, 0x51d),
+];
+
+#[rustfmt::skip]
+const KU_IR: &[(Int, Int)] = &[
+    (0x626, 0x628), (0x62a, 0x62a), (0x62c, 0x62f), (0x631, 0x634), (0x639, 0x63a), (0x641, 0x642),
+    (0x644, 0x648), (0x67e, 0x67e), (0x686, 0x686), (0x692, 0x692), (0x698, 0x698), (0x6a4, 0x6a4),
+    (0x6a9, 0x6a9), (0x6af, 0x6af), (0x6b5, 0x6b5), (0x6c6, 0x6c6), (0x6cc, 0x6cc), (0x6ce, 0x6ce),
+];
+
+#[rustfmt::skip]
+const KUM: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451),
+];
+
+#[rustfmt::skip]
+const KV: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x406, 0x406), (0x410, 0x44f), (0x451, 0x451), (0x456, 0x456), (0x4e6, 0x4e7),
+];
+
+#[rustfmt::skip]
+const KW: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x112, 0x113), (0x12a, 0x12b), (0x14c, 0x14d),
+    (0x16a, 0x16b), (0x232, 0x233),
+];
+
+#[rustfmt::skip]
+const KY: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x4a2, 0x4a3), (0x4ae, 0x4af),
+];
+
+#[rustfmt::skip]
+const LA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x112, 0x113), (0x12a, 0x12d), (0x14c, 0x14f),
+    (0x16a, 0x16d),
+];
+
+#[rustfmt::skip]
+const LB: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc4, 0xc4), (0xc8, 0xcb), (0xce, 0xce),
+    (0xd4, 0xd4), (0xd6, 0xd6), (0xdb, 0xdc), (0xdf, 0xdf), (0xe2, 0xe2), (0xe4, 0xe4),
+    (0xe8, 0xeb), (0xee, 0xee), (0xf4, 0xf4), (0xf6, 0xf6), (0xfb, 0xfc),
+];
+
+#[rustfmt::skip]
+const LN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc2), (0xc9, 0xca), (0xcd, 0xce), (0xd3, 0xd4),
+    (0xda, 0xdb), (0xe1, 0xe2), (0xe9, 0xea), (0xed, 0xee), (0xf3, 0xf4), (0xfa, 0xfb),
+    (0x11a, 0x11b), (0x186, 0x186), (0x190, 0x190), (0x254, 0x254), (0x25b, 0x25b), (0x301, 0x302),
+    (0x30c, 0x30c),
+];
+
+#[rustfmt::skip]
+const LO: &[(Int, Int)] = &[
+    (0xe81, 0xe82), (0xe84, 0xe84), (0xe87, 0xe88), (0xe8a, 0xe8a), (0xe8d, 0xe8d), (0xe94, 0xe97),
+    (0xe99, 0xe9f), (0xea1, 0xea3), (0xea5, 0xea5), (0xea7, 0xea7), (0xeaa, 0xeab), (0xead, 0xeb9),
+    (0xebb, 0xebd), (0xec0, 0xec4), (0xec6, 0xec6), (0xec8, 0xecd), (0xedc, 0xedd),
+];
+
+#[rustfmt::skip]
+const LT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x104, 0x105), (0x10c, 0x10d), (0x116, 0x119), (0x12e, 0x12f),
+    (0x160, 0x161), (0x16a, 0x16b), (0x172, 0x173), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const LV: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x10c, 0x10d), (0x112, 0x113), (0x122, 0x123),
+    (0x12a, 0x12b), (0x136, 0x137), (0x13b, 0x13c), (0x145, 0x146), (0x14c, 0x14d), (0x156, 0x157),
+    (0x160, 0x161), (0x16a, 0x16b), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const MG: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xd4, 0xd4), (0xe1, 0xe1), (0xf4, 0xf4),
+];
+
+#[rustfmt::skip]
+const MH: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x13b, 0x13c), (0x145, 0x146), (0x14c, 0x14d),
+    (0x16a, 0x16b),
+];
+
+#[rustfmt::skip]
+const MI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x100, 0x101), (0x112, 0x113), (0x12a, 0x12b), (0x14c, 0x14d),
+    (0x16a, 0x16b), (0x1e34, 0x1e35),
+];
+
+#[rustfmt::skip]
+const MK: &[(Int, Int)] = &[
+    (0x400, 0x400), (0x403, 0x403), (0x405, 0x405), (0x408, 0x40a), (0x40c, 0x40d), (0x40f, 0x418),
+    (0x41a, 0x428), (0x450, 0x450), (0x453, 0x453), (0x455, 0x455), (0x458, 0x45a), (0x45c, 0x45d),
+    (0x45f, 0x45f),
+];
+
+#[rustfmt::skip]
+const ML: &[(Int, Int)] = &[
+    (0xd02, 0xd03), (0xd05, 0xd0c), (0xd0e, 0xd10), (0xd12, 0xd28), (0xd2a, 0xd39), (0xd3e, 0xd43),
+    (0xd46, 0xd48), (0xd4a, 0xd4d), (0xd57, 0xd57), (0xd60, 0xd61),
+];
+
+#[rustfmt::skip]
+const MN_CN: &[(Int, Int)] = &[
+    (0x1820, 0x1877), (0x1880, 0x18a9),
+];
+
+#[rustfmt::skip]
+const MO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xce, 0xce), (0xe2, 0xe2), (0xee, 0xee),
+    (0x102, 0x103), (0x218, 0x21b), (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451),
+];
+
+#[rustfmt::skip]
+const MT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc8, 0xc8), (0xcc, 0xcc), (0xce, 0xce),
+    (0xd2, 0xd2), (0xd9, 0xd9), (0xe0, 0xe0), (0xe8, 0xe8), (0xec, 0xec), (0xee, 0xee),
+    (0xf2, 0xf2), (0xf9, 0xf9), (0x10a, 0x10b), (0x120, 0x121), (0x126, 0x127), (0x17b, 0x17c),
+];
+
+#[rustfmt::skip]
+const MY: &[(Int, Int)] = &[
+    (0x1000, 0x1021), (0x1023, 0x1027), (0x1029, 0x102a), (0x102c, 0x1032),
+];
+
+#[rustfmt::skip]
+const NB: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc5, 0xc6), (0xc9, 0xca), (0xd2, 0xd4),
+    (0xd8, 0xd8), (0xe0, 0xe0), (0xe5, 0xe6), (0xe9, 0xea), (0xf2, 0xf4), (0xf8, 0xf8),
+];
+
+#[rustfmt::skip]
+const NE: &[(Int, Int)] = &[
+    (0x901, 0x903), (0x905, 0x90b), (0x90f, 0x910), (0x913, 0x928), (0x92a, 0x930), (0x932, 0x932),
+    (0x935, 0x939), (0x93e, 0x943), (0x947, 0x948), (0x94b, 0x94d), (0x950, 0x950), (0x964, 0x970),
+];
+
+#[rustfmt::skip]
+const NL: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc2), (0xc4, 0xc4), (0xc8, 0xcb), (0xcd, 0xcd),
+    (0xcf, 0xcf), (0xd3, 0xd4), (0xd6, 0xd6), (0xda, 0xdc), (0xe1, 0xe2), (0xe4, 0xe4),
+    (0xe8, 0xeb), (0xed, 0xed), (0xef, 0xef), (0xf3, 0xf4), (0xf6, 0xf6), (0xfa, 0xfc),
+];
+
+#[rustfmt::skip]
+const NN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc4, 0xc6), (0xc9, 0xca), (0xd2, 0xd4),
+    (0xd6, 0xd6), (0xd8, 0xd8), (0xdc, 0xdc), (0xe0, 0xe0), (0xe4, 0xe6), (0xe9, 0xea),
+    (0xf2, 0xf4), (0xf6, 0xf6), (0xf8, 0xf8), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const NSO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xca, 0xca), (0xd4, 0xd4), (0xea, 0xea), (0xf4, 0xf4),
+    (0x160, 0x161),
+];
+
+#[rustfmt::skip]
+const NY: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x174, 0x175),
+];
+
+#[rustfmt::skip]
+const OC: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc1), (0xc7, 0xc9), (0xcd, 0xcd), (0xd2, 0xd3),
+    (0xda, 0xda), (0xe0, 0xe1), (0xe7, 0xe9), (0xed, 0xed), (0xf2, 0xf3), (0xfa, 0xfa),
+];
+
+#[rustfmt::skip]
+const OR: &[(Int, Int)] = &[
+    (0xb01, 0xb03), (0xb05, 0xb0c), (0xb0f, 0xb10), (0xb13, 0xb28), (0xb2a, 0xb30), (0xb32, 0xb33),
+    (0xb36, 0xb39), (0xb3c, 0xb43), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb56, 0xb57), (0xb5c, 0xb5d),
+    (0xb5f, 0xb61),
+];
+
+#[rustfmt::skip]
+const PA: &[(Int, Int)] = &[
+    (0xa05, 0xa0a), (0xa0f, 0xa10), (0xa13, 0xa28), (0xa2a, 0xa30), (0xa32, 0xa33), (0xa35, 0xa36),
+    (0xa38, 0xa39), (0xa3c, 0xa3c), (0xa3e, 0xa42), (0xa47, 0xa48), (0xa4b, 0xa4d), (0xa59, 0xa5c),
+    (0xa70, 0xa74),
+];
+
+#[rustfmt::skip]
+const PL: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd3, 0xd3), (0xf3, 0xf3), (0x104, 0x107), (0x118, 0x119),
+    (0x141, 0x144), (0x15a, 0x15b), (0x179, 0x17c),
+];
+
+#[rustfmt::skip]
+const PS_AF: &[(Int, Int)] = &[
+    (0x621, 0x624), (0x626, 0x63a), (0x641, 0x642), (0x644, 0x648), (0x64a, 0x64a), (0x67c, 0x67c),
+    (0x67e, 0x67e), (0x681, 0x681), (0x685, 0x686), (0x689, 0x689), (0x693, 0x693), (0x696, 0x696),
+    (0x698, 0x698), (0x69a, 0x69a), (0x6a9, 0x6a9), (0x6ab, 0x6ab), (0x6bc, 0x6bc), (0x6cc, 0x6cd),
+    (0x6d0, 0x6d0),
+];
+
+#[rustfmt::skip]
+const PS_PK: &[(Int, Int)] = &[
+    (0x621, 0x624), (0x626, 0x63a), (0x641, 0x642), (0x644, 0x648), (0x64a, 0x64a), (0x67c, 0x67c),
+    (0x67e, 0x67e), (0x681, 0x681), (0x685, 0x686), (0x689, 0x689), (0x693, 0x693), (0x696, 0x696),
+    (0x698, 0x698), (0x69a, 0x69a), (0x6a9, 0x6a9), (0x6ab, 0x6ab), (0x6bc, 0x6bc), (0x6cd, 0x6cd),
+    (0x6d0, 0x6d0), (0x6d2, 0x6d2),
+];
+
+#[rustfmt::skip]
+const PT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc3), (0xc7, 0xca), (0xcd, 0xcd), (0xd2, 0xd5),
+    (0xda, 0xda), (0xdc, 0xdc), (0xe0, 0xe3), (0xe7, 0xea), (0xed, 0xed), (0xf2, 0xf5),
+    (0xfa, 0xfa), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const RM: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc8, 0xc9), (0xcc, 0xcc), (0xce, 0xce),
+    (0xd2, 0xd2), (0xd9, 0xd9), (0xe0, 0xe0), (0xe8, 0xe9), (0xec, 0xec), (0xee, 0xee),
+    (0xf2, 0xf2), (0xf9, 0xf9),
+];
+
+#[rustfmt::skip]
+const RO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xce, 0xce), (0xe2, 0xe2), (0xee, 0xee),
+    (0x102, 0x103), (0x218, 0x21b),
+];
+
+#[rustfmt::skip]
+const SAH: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x494, 0x495), (0x4a4, 0x4a5), (0x4ae, 0x4af),
+    (0x4ba, 0x4bb), (0x4d8, 0x4d9),
+];
+
+#[rustfmt::skip]
+const SCO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x1b7, 0x1b7), (0x21c, 0x21d), (0x292, 0x292),
+];
+
+#[rustfmt::skip]
+const SE: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xe1, 0xe1), (0x10c, 0x10d), (0x110, 0x111),
+    (0x14a, 0x14b), (0x160, 0x161), (0x166, 0x167), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const SH: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x106, 0x107), (0x10c, 0x10d), (0x110, 0x111), (0x160, 0x161),
+    (0x17d, 0x17e), (0x402, 0x402), (0x408, 0x40b), (0x40f, 0x44f), (0x452, 0x452), (0x458, 0x45b),
+    (0x45f, 0x45f), (0x492, 0x493), (0x498, 0x499), (0x4a0, 0x4a3), (0x4aa, 0x4ab), (0x4ae, 0x4af),
+    (0x4ba, 0x4bb), (0x4d8, 0x4d9), (0x4e8, 0x4e9),
+];
+
+#[rustfmt::skip]
+const SHS: &[(Int, Int)] = &[
+    (0x37, 0x37), (0x41, 0x41), (0x43, 0x43), (0x45, 0x45), (0x47, 0x49), (0x4b, 0x55),
+    (0x57, 0x59), (0x61, 0x61), (0x63, 0x63), (0x65, 0x65), (0x67, 0x69), (0x6b, 0x75),
+    (0x77, 0x79), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xe1, 0xe1), (0xe9, 0xe9),
+    (0xed, 0xed), (0x313, 0x313),
+];
+
+#[rustfmt::skip]
+const SI: &[(Int, Int)] = &[
+    (0xd82, 0xd83), (0xd85, 0xd8d), (0xd91, 0xd96), (0xd9a, 0xda5), (0xda7, 0xdb1), (0xdb3, 0xdbb),
+    (0xdbd, 0xdbd), (0xdc0, 0xdc6), (0xdca, 0xdca), (0xdcf, 0xdd4), (0xdd6, 0xdd6), (0xdd8, 0xdde),
+    (0xdf2, 0xdf2),
+];
+
+#[rustfmt::skip]
+const SK: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc4, 0xc4), (0xc9, 0xc9), (0xcd, 0xcd),
+    (0xd3, 0xd4), (0xda, 0xda), (0xdd, 0xdd), (0xe1, 0xe1), (0xe4, 0xe4), (0xe9, 0xe9),
+    (0xed, 0xed), (0xf3, 0xf4), (0xfa, 0xfa), (0xfd, 0xfd), (0x10c, 0x10f), (0x139, 0x13a),
+    (0x13d, 0x13e), (0x147, 0x148), (0x154, 0x155), (0x160, 0x161), (0x164, 0x165), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const SM: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x2bb, 0x2bb),
+];
+
+#[rustfmt::skip]
+const SMA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc5), (0xcf, 0xcf), (0xd6, 0xd6), (0xe4, 0xe5),
+    (0xef, 0xef), (0xf6, 0xf6),
+];
+
+#[rustfmt::skip]
+const SMJ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc4, 0xc5), (0xd1, 0xd1), (0xe1, 0xe1),
+    (0xe4, 0xe5), (0xf1, 0xf1),
+];
+
+#[rustfmt::skip]
+const SMN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc2), (0xc4, 0xc4), (0xe1, 0xe2), (0xe4, 0xe4),
+    (0x10c, 0x10d), (0x110, 0x111), (0x14a, 0x14b), (0x160, 0x161), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const SMS: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc4, 0xc5), (0xd5, 0xd5), (0xe2, 0xe2),
+    (0xe4, 0xe5), (0xf5, 0xf5), (0x10c, 0x10d), (0x110, 0x111), (0x14a, 0x14b), (0x160, 0x161),
+    (0x17d, 0x17e), (0x1b7, 0x1b7), (0x1e4, 0x1e9), (0x1ee, 0x1ef), (0x292, 0x292),
+];
+
+#[rustfmt::skip]
+const SQ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc7, 0xc7), (0xcb, 0xcb), (0xe7, 0xe7), (0xeb, 0xeb),
+];
+
+#[rustfmt::skip]
+const SR: &[(Int, Int)] = &[
+    (0x402, 0x402), (0x408, 0x40b), (0x40f, 0x418), (0x41a, 0x428), (0x430, 0x438), (0x43a, 0x448),
+    (0x452, 0x452), (0x458, 0x45b), (0x45f, 0x45f),
+];
+
+#[rustfmt::skip]
+const SV: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc1), (0xc4, 0xc5), (0xc9, 0xc9), (0xcb, 0xcb),
+    (0xd6, 0xd6), (0xdc, 0xdc), (0xe0, 0xe1), (0xe4, 0xe5), (0xe9, 0xe9), (0xeb, 0xeb),
+    (0xf6, 0xf6), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const SYR: &[(Int, Int)] = &[
+    (0x710, 0x72c), (0x730, 0x73f),
+];
+
+#[rustfmt::skip]
+const TA: &[(Int, Int)] = &[
+    (0xb83, 0xb83), (0xb85, 0xb8a), (0xb8e, 0xb90), (0xb92, 0xb95), (0xb99, 0xb9a), (0xb9c, 0xb9c),
+    (0xb9e, 0xb9f), (0xba3, 0xba4), (0xba8, 0xbaa), (0xbae, 0xbb5), (0xbb7, 0xbb9), (0xbbe, 0xbc2),
+    (0xbc6, 0xbc8), (0xbca, 0xbcd), (0xbd7, 0xbd7),
+];
+
+#[rustfmt::skip]
+const TE: &[(Int, Int)] = &[
+    (0xc01, 0xc03), (0xc05, 0xc0c), (0xc0e, 0xc10), (0xc12, 0xc28), (0xc2a, 0xc33), (0xc35, 0xc39),
+    (0xc3e, 0xc44), (0xc46, 0xc48), (0xc4a, 0xc4d), (0xc55, 0xc56), (0xc60, 0xc61),
+];
+
+#[rustfmt::skip]
+const TG: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x492, 0x493), (0x49a, 0x49b), (0x4b2, 0x4b3),
+    (0x4b6, 0x4b7), (0x4e2, 0x4e3), (0x4ee, 0x4ef),
+];
+
+#[rustfmt::skip]
+const TH: &[(Int, Int)] = &[
+    (0xe01, 0xe3a), (0xe3f, 0xe4e),
+];
+
+#[rustfmt::skip]
+const TI_ER: &[(Int, Int)] = &[
+    (0x1200, 0x1206), (0x1208, 0x1216), (0x1218, 0x121f), (0x1228, 0x1230), (0x1238, 0x1246),
+    (0x1248, 0x1248), (0x124a, 0x124d), (0x1250, 0x1256), (0x1258, 0x1258), (0x125a, 0x125d),
+    (0x1260, 0x126e), (0x1270, 0x127f), (0x1290, 0x12ae), (0x12b0, 0x12b0), (0x12b2, 0x12b5),
+    (0x12c0, 0x12c0), (0x12c2, 0x12c5), (0x12c8, 0x12ce), (0x12d0, 0x12d6), (0x12d8, 0x12ee),
+    (0x12f0, 0x12f7), (0x1300, 0x130e), (0x1310, 0x1310), (0x1312, 0x1315), (0x1320, 0x133f),
+    (0x1348, 0x1356),
+];
+
+#[rustfmt::skip]
+const TI_ET: &[(Int, Int)] = &[
+    (0x1200, 0x1206), (0x1208, 0x1216), (0x1218, 0x1226), (0x1228, 0x1230), (0x1238, 0x1246),
+    (0x1248, 0x1248), (0x124a, 0x124d), (0x1250, 0x1256), (0x1258, 0x1258), (0x125a, 0x125d),
+    (0x1260, 0x126e), (0x1270, 0x1286), (0x1288, 0x1288), (0x128a, 0x128d), (0x1290, 0x12ae),
+    (0x12b0, 0x12b0), (0x12b2, 0x12b5), (0x12c0, 0x12c0), (0x12c2, 0x12c5), (0x12c8, 0x12ce),
+    (0x12d0, 0x12d6), (0x12d8, 0x12ee), (0x12f0, 0x12f7), (0x1300, 0x130e), (0x1310, 0x1310),
+    (0x1312, 0x1315), (0x1320, 0x1346), (0x1348, 0x1356),
+];
+
+#[rustfmt::skip]
+const TIG: &[(Int, Int)] = &[
+    (0x1200, 0x1206), (0x1208, 0x1216), (0x1218, 0x121f), (0x1228, 0x1230), (0x1238, 0x1246),
+    (0x1248, 0x1248), (0x124a, 0x124d), (0x1260, 0x126e), (0x1270, 0x127f), (0x1290, 0x1297),
+    (0x12a0, 0x12a6), (0x12a8, 0x12ae), (0x12b0, 0x12b0), (0x12b2, 0x12b5), (0x12c8, 0x12ce),
+    (0x12d0, 0x12d6), (0x12d8, 0x12df), (0x12e8, 0x12ee), (0x12f0, 0x12f7), (0x1300, 0x130e),
+    (0x1310, 0x1310), (0x1312, 0x1315), (0x1320, 0x133f), (0x1348, 0x1356),
+];
+
+#[rustfmt::skip]
+const TK: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xc7, 0xc7), (0xd6, 0xd6), (0xdc, 0xdd),
+    (0xe4, 0xe4), (0xe7, 0xe7), (0xf6, 0xf6), (0xfc, 0xfd), (0x147, 0x148), (0x15e, 0x15f),
+    (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const TL: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc2), (0xc8, 0xca), (0xcc, 0xce), (0xd1, 0xd4),
+    (0xd9, 0xdb), (0xe0, 0xe2), (0xe8, 0xea), (0xec, 0xee), (0xf1, 0xf4), (0xf9, 0xfb),
+];
+
+#[rustfmt::skip]
+const TR: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc7, 0xc7), (0xce, 0xce), (0xd6, 0xd6),
+    (0xdb, 0xdc), (0xe2, 0xe2), (0xe7, 0xe7), (0xee, 0xee), (0xf6, 0xf6), (0xfb, 0xfc),
+    (0x11e, 0x11f), (0x130, 0x131), (0x15e, 0x15f),
+];
+
+#[rustfmt::skip]
+const TT: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x496, 0x497), (0x4a2, 0x4a3), (0x4ae, 0x4af),
+    (0x4ba, 0x4bb), (0x4d8, 0x4d9),
+];
+
+#[rustfmt::skip]
+const TW: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc3, 0xc3), (0xd1, 0xd1), (0xd5, 0xd5), (0xe3, 0xe3),
+    (0xf1, 0xf1), (0xf5, 0xf5), (0x128, 0x129), (0x168, 0x169), (0x186, 0x186), (0x190, 0x190),
+    (0x254, 0x254), (0x25b, 0x25b), (0x303, 0x303), (0x306, 0x306), (0x329, 0x329),
+    (0x1ebc, 0x1ebd), (0x1ef8, 0x1ef9),
+];
+
+#[rustfmt::skip]
+const UG: &[(Int, Int)] = &[
+    (0x626, 0x628), (0x62a, 0x62a), (0x62c, 0x62c), (0x62e, 0x62f), (0x631, 0x634), (0x63a, 0x63a),
+    (0x641, 0x646), (0x648, 0x64a), (0x67e, 0x67e), (0x686, 0x686), (0x698, 0x698), (0x6ad, 0x6ad),
+    (0x6af, 0x6af), (0x6be, 0x6be), (0x6c6, 0x6c8), (0x6cb, 0x6cb), (0x6d0, 0x6d0), (0x6d5, 0x6d5),
+];
+
+#[rustfmt::skip]
+const UK: &[(Int, Int)] = &[
+    (0x404, 0x404), (0x406, 0x407), (0x410, 0x44f), (0x454, 0x454), (0x456, 0x457), (0x490, 0x491),
+];
+
+#[rustfmt::skip]
+const UR: &[(Int, Int)] = &[
+    (0x621, 0x624), (0x626, 0x628), (0x63a, 0x63a), (0x641, 0x642), (0x644, 0x646), (0x648, 0x648),
+    (0x679, 0x679), (0x67e, 0x67e), (0x686, 0x686), (0x688, 0x688), (0x691, 0x691), (0x698, 0x698),
+    (0x6a9, 0x6a9), (0x6af, 0x6af), (0x6ba, 0x6ba), (0x6be, 0x6be), (0x6c3, 0x6c3), (0x6cc, 0x6cc),
+    (0x6d2, 0x6d2),
+];
+
+#[rustfmt::skip]
+const VE: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x1e12, 0x1e13), (0x1e3c, 0x1e3d), (0x1e44, 0x1e45),
+    (0x1e4a, 0x1e4b), (0x1e70, 0x1e71),
+];
+
+#[rustfmt::skip]
+const VI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc3), (0xc8, 0xca), (0xcc, 0xcd), (0xd2, 0xd5),
+    (0xd9, 0xda), (0xdd, 0xdd), (0xe0, 0xe3), (0xe8, 0xea), (0xec, 0xed), (0xf2, 0xf5),
+    (0xf9, 0xfa), (0xfd, 0xfd), (0x102, 0x103), (0x110, 0x111), (0x128, 0x129), (0x168, 0x169),
+    (0x1a0, 0x1a1), (0x1af, 0x1b0), (0x300, 0x303), (0x306, 0x306), (0x309, 0x309), (0x31b, 0x31b),
+    (0x323, 0x323), (0x1ea0, 0x1ef9),
+];
+
+#[rustfmt::skip]
+const VO: &[(Int, Int)] = &[
+    (0x41, 0x50), (0x52, 0x56), (0x58, 0x5a), (0x61, 0x70), (0x72, 0x76), (0x78, 0x7a),
+    (0xc4, 0xc4), (0xd6, 0xd6), (0xdc, 0xdc), (0xe4, 0xe4), (0xf6, 0xf6), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const VOT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xd6, 0xd6), (0xdc, 0xdc), (0xe4, 0xe4),
+    (0xf6, 0xf6), (0xfc, 0xfc), (0x160, 0x161), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const WA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc5, 0xc5), (0xc7, 0xca), (0xce, 0xce),
+    (0xd4, 0xd4), (0xdb, 0xdb), (0xe2, 0xe2), (0xe5, 0xe5), (0xe7, 0xea), (0xee, 0xee),
+    (0xf4, 0xf4), (0xfb, 0xfb),
+];
+
+#[rustfmt::skip]
+const WEN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd3, 0xd3), (0xf3, 0xf3), (0x106, 0x107), (0x10c, 0x10d),
+    (0x11a, 0x11b), (0x141, 0x144), (0x154, 0x155), (0x158, 0x15b), (0x160, 0x161), (0x179, 0x17a),
+    (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const WO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc3, 0xc3), (0xc9, 0xc9), (0xcb, 0xcb),
+    (0xd1, 0xd1), (0xd3, 0xd3), (0xe0, 0xe0), (0xe3, 0xe3), (0xe9, 0xe9), (0xeb, 0xeb),
+    (0xf1, 0xf1), (0xf3, 0xf3), (0x14a, 0x14b),
+];
+
+#[rustfmt::skip]
+const YAP: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xcb, 0xcb), (0xd6, 0xd6), (0xe4, 0xe4),
+    (0xeb, 0xeb), (0xf6, 0xf6),
+];
+
+#[rustfmt::skip]
+const YO: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc3), (0xc8, 0xca), (0xcc, 0xce), (0xd2, 0xd5),
+    (0xd9, 0xdb), (0xe0, 0xe3), (0xe8, 0xea), (0xec, 0xee), (0xf2, 0xf5), (0xf9, 0xfb),
+    (0x11a, 0x11b), (0x128, 0x129), (0x143, 0x144), (0x168, 0x169), (0x1cd, 0x1d4), (0x1f8, 0x1f9),
+    (0x300, 0x303), (0x30c, 0x30c), (0x1e3e, 0x1e3f), (0x1e62, 0x1e63), (0x1eb8, 0x1eb9),
+    (0x1ebc, 0x1ebd), (0x1ecc, 0x1ecd),
+];
+
+#[rustfmt::skip]
+const ZH_CN: &[(Int, Int)] = &[
+    (0x2c7, 0x2c7), (0x2c9, 0x2c9), (0x4e00, 0x4e01), (0x4e03, 0x4e03), (0x4e07, 0x4e0e),
+    (0x4e10, 0x4e11), (0x4e13, 0x4e16), (0x4e18, 0x4e1e), (0x4e22, 0x4e22), (0x4e24, 0x4e25),
+    (0x4e27, 0x4e28), (0x4e2a, 0x4e2d), (0x4e30, 0x4e30), (0x4e32, 0x4e32), (0x4e34, 0x4e34),
+    (0x4e36, 0x4e36), (0x4e38, 0x4e3b), (0x4e3d, 0x4e3f), (0x4e43, 0x4e43), (0x4e45, 0x4e45),
+    (0x4e47, 0x4e49), (0x4e4b, 0x4e50), (0x4e52, 0x4e54), (0x4e56, 0x4e56), (0x4e58, 0x4e59),
+    (0x4e5c, 0x4e61), (0x4e66, 0x4e66), (0x4e69, 0x4e69), (0x4e70, 0x4e71), (0x4e73, 0x4e73),
+    (0x4e7e, 0x4e7e), (0x4e86, 0x4e86), (0x4e88, 0x4e89), (0x4e8b, 0x4e8f), (0x4e91, 0x4e95),
+    (0x4e98, 0x4e98), (0x4e9a, 0x4e9b), (0x4e9f, 0x4ea2), (0x4ea4, 0x4ea9), (0x4eab, 0x4eae),
+    (0x4eb2, 0x4eb3), (0x4eb5, 0x4eb5), (0x4eba, 0x4ebb), (0x4ebf, 0x4ec7), (0x4ec9, 0x4ecb),
+    (0x4ecd, 0x4ece), (0x4ed1, 0x4ed1), (0x4ed3, 0x4ed9), (0x4edd, 0x4edf), (0x4ee1, 0x4ee1),
+    (0x4ee3, 0x4ee5), (0x4ee8, 0x4ee8), (0x4eea, 0x4eec), (0x4ef0, 0x4ef0), (0x4ef2, 0x4ef3),
+    (0x4ef5, 0x4ef7), (0x4efb, 0x4efb), (0x4efd, 0x4efd), (0x4eff, 0x4eff), (0x4f01, 0x4f01),
+    (0x4f09, 0x4f0a), (0x4f0d, 0x4f11), (0x4f17, 0x4f1b), (0x4f1e, 0x4f20), (0x4f22, 0x4f22),
+    (0x4f24, 0x4f27), (0x4f2a, 0x4f2b), (0x4f2f, 0x4f30), (0x4f32, 0x4f32), (0x4f34, 0x4f34),
+    (0x4f36, 0x4f36), (0x4f38, 0x4f38), (0x4f3a, 0x4f3a), (0x4f3c, 0x4f3d), (0x4f43, 0x4f43),
+    (0x4f46, 0x4f46), (0x4f4d, 0x4f51), (0x4f53, 0x4f53), (0x4f55, 0x4f55), (0x4f57, 0x4f60),
+    (0x4f63, 0x4f65), (0x4f67, 0x4f67), (0x4f69, 0x4f69), (0x4f6c, 0x4f6c), (0x4f6f, 0x4f70),
+    (0x4f73, 0x4f74), (0x4f76, 0x4f76), (0x4f7b, 0x4f7c), (0x4f7e, 0x4f7f), (0x4f83, 0x4f84),
+    (0x4f88, 0x4f89), (0x4f8b, 0x4f8b), (0x4f8d, 0x4f8d), (0x4f8f, 0x4f8f), (0x4f91, 0x4f91),
+    (0x4f94, 0x4f94), (0x4f97, 0x4f97), (0x4f9b, 0x4f9b), (0x4f9d, 0x4f9d), (0x4fa0, 0x4fa0),
+    (0x4fa3, 0x4fa3), (0x4fa5, 0x4faa), (0x4fac, 0x4fac), (0x4fae, 0x4faf), (0x4fb5, 0x4fb5),
+    (0x4fbf, 0x4fbf), (0x4fc3, 0x4fc5), (0x4fca, 0x4fca), (0x4fce, 0x4fd1), (0x4fd7, 0x4fd8),
+    (0x4fda, 0x4fda), (0x4fdc, 0x4fdf), (0x4fe1, 0x4fe1), (0x4fe3, 0x4fe3), (0x4fe6, 0x4fe6),
+    (0x4fe8, 0x4fea), (0x4fed, 0x4fef), (0x4ff1, 0x4ff1), (0x4ff3, 0x4ff3), (0x4ff8, 0x4ff8),
+    (0x4ffa, 0x4ffa), (0x4ffe, 0x4ffe), (0x500c, 0x500d), (0x500f, 0x500f), (0x5012, 0x5012),
+    (0x5014, 0x5014), (0x5018, 0x501a), (0x501c, 0x501c), (0x501f, 0x501f), (0x5021, 0x5021),
+    (0x5025, 0x5026), (0x5028, 0x502a), (0x502c, 0x502e), (0x503a, 0x503a), (0x503c, 0x503c),
+    (0x503e, 0x503e), (0x5043, 0x5043), (0x5047, 0x5048), (0x504c, 0x504c), (0x504e, 0x504f),
+    (0x5055, 0x5055), (0x505a, 0x505a), (0x505c, 0x505c), (0x5065, 0x5065), (0x506c, 0x506c),
+    (0x5076, 0x5077), (0x507b, 0x507b), (0x507e, 0x5080), (0x5085, 0x5085), (0x5088, 0x5088),
+    (0x508d, 0x508d), (0x50a3, 0x50a3), (0x50a5, 0x50a5), (0x50a7, 0x50a9), (0x50ac, 0x50ac),
+    (0x50b2, 0x50b2), (0x50ba, 0x50bb), (0x50cf, 0x50cf), (0x50d6, 0x50d6), (0x50da, 0x50da),
+    (0x50e6, 0x50e7), (0x50ec, 0x50ee), (0x50f3, 0x50f3), (0x50f5, 0x50f5), (0x50fb, 0x50fb),
+    (0x5106, 0x5107), (0x510b, 0x510b), (0x5112, 0x5112), (0x5121, 0x5121), (0x513f, 0x5141),
+    (0x5143, 0x5146), (0x5148, 0x5149), (0x514b, 0x514b), (0x514d, 0x514d), (0x5151, 0x5151),
+    (0x5154, 0x5156), (0x515a, 0x515a), (0x515c, 0x515c), (0x5162, 0x5162), (0x5165, 0x5165),
+    (0x5168, 0x5168), (0x516b, 0x516e), (0x5170, 0x5171), (0x5173, 0x5179), (0x517b, 0x517d),
+    (0x5180, 0x5182), (0x5185, 0x5185), (0x5188, 0x5189), (0x518c, 0x518d), (0x5192, 0x5192),
+    (0x5195, 0x5197), (0x5199, 0x5199), (0x519b, 0x519c), (0x51a0, 0x51a0), (0x51a2, 0x51a2),
+    (0x51a4, 0x51a5), (0x51ab, 0x51ac), (0x51af, 0x51b3), (0x51b5, 0x51b7), (0x51bb, 0x51bd),
+    (0x51c0, 0x51c0), (0x51c4, 0x51c4), (0x51c6, 0x51c7), (0x51c9, 0x51c9), (0x51cb, 0x51cc),
+    (0x51cf, 0x51cf), (0x51d1, 0x51d1), (0x51db, 0x51db), (0x51dd, 0x51dd), (0x51e0, 0x51e1),
+    (0x51e4, 0x51e4), (0x51eb, 0x51eb), (0x51ed, 0x51ed), (0x51ef, 0x51f0), (0x51f3, 0x51f3),
+    (0x51f5, 0x51f6), (0x51f8, 0x51fd), (0x51ff, 0x5203), (0x5206, 0x5208), (0x520a, 0x520a),
+    (0x520d, 0x520e), (0x5211, 0x5212), (0x5216, 0x521b), (0x521d, 0x521d), (0x5220, 0x5220),
+    (0x5224, 0x5224), (0x5228, 0x5229), (0x522b, 0x522b), (0x522d, 0x522e), (0x5230, 0x5230),
+    (0x5233, 0x5233), (0x5236, 0x523b), (0x523d, 0x523d), (0x523f, 0x5243), (0x524a, 0x524a),
+    (0x524c, 0x524d), (0x5250, 0x5251), (0x5254, 0x5254), (0x5256, 0x5256), (0x525c, 0x525c),
+    (0x525e, 0x525e), (0x5261, 0x5261), (0x5265, 0x5265), (0x5267, 0x5267), (0x5269, 0x526a),
+    (0x526f, 0x526f), (0x5272, 0x5272), (0x527d, 0x527d), (0x527f, 0x527f), (0x5281, 0x5282),
+    (0x5288, 0x5288), (0x5290, 0x5290), (0x5293, 0x5293), (0x529b, 0x529b), (0x529d, 0x52a3),
+    (0x52a8, 0x52ad), (0x52b1, 0x52b3), (0x52be, 0x52bf), (0x52c3, 0x52c3), (0x52c7, 0x52c7),
+    (0x52c9, 0x52c9), (0x52cb, 0x52cb), (0x52d0, 0x52d0), (0x52d2, 0x52d2), (0x52d6, 0x52d6),
+    (0x52d8, 0x52d8), (0x52df, 0x52df), (0x52e4, 0x52e4), (0x52f0, 0x52f0), (0x52f9, 0x52fa),
+    (0x52fe, 0x5300), (0x5305, 0x5306), (0x5308, 0x5308), (0x530d, 0x530d), (0x530f, 0x5310),
+    (0x5315, 0x5317), (0x5319, 0x531a), (0x531d, 0x531d), (0x5320, 0x5321), (0x5323, 0x5323),
+    (0x5326, 0x5326), (0x532a, 0x532a), (0x532e, 0x532e), (0x5339, 0x533b), (0x533e, 0x533f),
+    (0x5341, 0x5341), (0x5343, 0x5343), (0x5345, 0x5345), (0x5347, 0x534a), (0x534e, 0x534f),
+    (0x5351, 0x5353), (0x5355, 0x5357), (0x535a, 0x535a), (0x535c, 0x535c), (0x535e, 0x5364),
+    (0x5366, 0x5367), (0x5369, 0x5369), (0x536b, 0x536b), (0x536e, 0x5371), (0x5373, 0x5375),
+    (0x5377, 0x5378), (0x537a, 0x537a), (0x537f, 0x537f), (0x5382, 0x5382), (0x5384, 0x5386),
+    (0x5389, 0x5389), (0x538b, 0x538d), (0x5395, 0x5395), (0x5398, 0x5398), (0x539a, 0x539a),
+    (0x539d, 0x539d), (0x539f, 0x539f), (0x53a2, 0x53a3), (0x53a5, 0x53a6), (0x53a8, 0x53a9),
+    (0x53ae, 0x53ae), (0x53b6, 0x53b6), (0x53bb, 0x53bb), (0x53bf, 0x53bf), (0x53c1, 0x53c2),
+    (0x53c8, 0x53cd), (0x53d1, 0x53d1), (0x53d4, 0x53d4), (0x53d6, 0x53d9), (0x53db, 0x53db),
+    (0x53df, 0x53e0), (0x53e3, 0x53e6), (0x53e8, 0x53f3), (0x53f5, 0x53f9), (0x53fb, 0x53fd),
+    (0x5401, 0x5401), (0x5403, 0x5404), (0x5406, 0x5406), (0x5408, 0x540a), (0x540c, 0x5413),
+    (0x5415, 0x5417), (0x541b, 0x541b), (0x541d, 0x5421), (0x5423, 0x5423), (0x5426, 0x5429),
+    (0x542b, 0x542f), (0x5431, 0x5432), (0x5434, 0x5435), (0x5438, 0x5439), (0x543b, 0x543c),
+    (0x543e, 0x543e), (0x5440, 0x5440), (0x5443, 0x5443), (0x5446, 0x5446), (0x5448, 0x5448),
+    (0x544a, 0x544b), (0x5450, 0x5450), (0x5452, 0x5459), (0x545b, 0x545c), (0x5462, 0x5462),
+    (0x5464, 0x5464), (0x5466, 0x5466), (0x5468, 0x5468), (0x5471, 0x5473), (0x5475, 0x5478),
+    (0x547b, 0x547d), (0x5480, 0x5480), (0x5482, 0x5482), (0x5484, 0x5484), (0x5486, 0x5486),
+    (0x548b, 0x548c), (0x548e, 0x5490), (0x5492, 0x5492), (0x5494, 0x5496), (0x5499, 0x549b),
+    (0x549d, 0x549d), (0x54a3, 0x54a4), (0x54a6, 0x54ad), (0x54af, 0x54af), (0x54b1, 0x54b1),
+    (0x54b3, 0x54b4), (0x54b8, 0x54b8), (0x54bb, 0x54bb), (0x54bd, 0x54bd), (0x54bf, 0x54c2),
+    (0x54c4, 0x54c4), (0x54c6, 0x54c9), (0x54cc, 0x54d5), (0x54d7, 0x54d7), (0x54d9, 0x54da),
+    (0x54dc, 0x54df), (0x54e5, 0x54ea), (0x54ed, 0x54ee), (0x54f2, 0x54f3), (0x54fa, 0x54fa),
+    (0x54fc, 0x54fd), (0x54ff, 0x54ff), (0x5501, 0x5501), (0x5506, 0x5507), (0x5509, 0x5509),
+    (0x550f, 0x5511), (0x5514, 0x5514), (0x551b, 0x551b), (0x5520, 0x5520), (0x5522, 0x5524),
+    (0x5527, 0x5527), (0x552a, 0x552a), (0x552c, 0x552c), (0x552e, 0x5531), (0x5533, 0x5533),
+    (0x5537, 0x5537), (0x553c, 0x553c), (0x553e, 0x553f), (0x5541, 0x5541), (0x5543, 0x5544),
+    (0x5546, 0x5546), (0x5549, 0x554a), (0x5550, 0x5550), (0x5555, 0x5556), (0x555c, 0x555c),
+    (0x5561, 0x5561), (0x5564, 0x5567), (0x556a, 0x556a), (0x556c, 0x556e), (0x5575, 0x5578),
+    (0x557b, 0x557c), (0x557e, 0x557e), (0x5580, 0x5584), (0x5587, 0x558b), (0x558f, 0x558f),
+    (0x5591, 0x5591), (0x5594, 0x5594), (0x5598, 0x5599), (0x559c, 0x559d), (0x559f, 0x559f),
+    (0x55a7, 0x55a7), (0x55b1, 0x55b1), (0x55b3, 0x55b3), (0x55b5, 0x55b5), (0x55b7, 0x55b7),
+    (0x55b9, 0x55b9), (0x55bb, 0x55bb), (0x55bd, 0x55be), (0x55c4, 0x55c5), (0x55c9, 0x55c9),
+    (0x55cc, 0x55cd), (0x55d1, 0x55d4), (0x55d6, 0x55d6), (0x55dc, 0x55dd), (0x55df, 0x55df),
+    (0x55e1, 0x55e1), (0x55e3, 0x55e6), (0x55e8, 0x55e8), (0x55ea, 0x55ec), (0x55ef, 0x55ef),
+    (0x55f2, 0x55f3), (0x55f5, 0x55f5), (0x55f7, 0x55f7), (0x55fd, 0x55fe), (0x5600, 0x5601),
+    (0x5608, 0x5609), (0x560c, 0x560c), (0x560e, 0x560f), (0x5618, 0x5618), (0x561b, 0x561b),
+    (0x561e, 0x561f), (0x5623, 0x5624), (0x5627, 0x5627), (0x562c, 0x562d), (0x5631, 0x5632),
+    (0x5634, 0x5634), (0x5636, 0x5636), (0x5639, 0x5639), (0x563b, 0x563b), (0x563f, 0x563f),
+    (0x564c, 0x564e), (0x5654, 0x5654), (0x5657, 0x5659), (0x565c, 0x565c), (0x5662, 0x5662),
+    (0x5664, 0x5664), (0x5668, 0x566c), (0x5671, 0x5671), (0x5676, 0x5676), (0x567b, 0x567c),
+    (0x5685, 0x5686), (0x568e, 0x568f), (0x5693, 0x5693), (0x56a3, 0x56a3), (0x56af, 0x56af),
+    (0x56b7, 0x56b7), (0x56bc, 0x56bc), (0x56ca, 0x56ca), (0x56d4, 0x56d4), (0x56d7, 0x56d7),
+    (0x56da, 0x56db), (0x56dd, 0x56e2), (0x56e4, 0x56e4), (0x56eb, 0x56eb), (0x56ed, 0x56ed),
+    (0x56f0, 0x56f1), (0x56f4, 0x56f5), (0x56f9, 0x56fa), (0x56fd, 0x56ff), (0x5703, 0x5704),
+    (0x5706, 0x5706), (0x5708, 0x570a), (0x571c, 0x571c), (0x571f, 0x571f), (0x5723, 0x5723),
+    (0x5728, 0x572a), (0x572c, 0x5730), (0x5733, 0x5733), (0x5739, 0x573b), (0x573e, 0x573e),
+    (0x5740, 0x5740), (0x5742, 0x5742), (0x5747, 0x5747), (0x574a, 0x574a), (0x574c, 0x5751),
+    (0x5757, 0x5757), (0x575a, 0x5761), (0x5764, 0x5764), (0x5766, 0x5766), (0x5768, 0x576b),
+    (0x576d, 0x576d), (0x576f, 0x576f), (0x5773, 0x5773), (0x5776, 0x5777), (0x577b, 0x577c),
+    (0x5782, 0x5786), (0x578b, 0x578c), (0x5792, 0x5793), (0x579b, 0x579b), (0x57a0, 0x57a4),
+    (0x57a6, 0x57a7), (0x57a9, 0x57a9), (0x57ab, 0x57ab), (0x57ad, 0x57ae), (0x57b2, 0x57b2),
+    (0x57b4, 0x57b4), (0x57b8, 0x57b8), (0x57c2, 0x57c3), (0x57cb, 0x57cb), (0x57ce, 0x57cf),
+    (0x57d2, 0x57d2), (0x57d4, 0x57d5), (0x57d8, 0x57da), (0x57dd, 0x57dd), (0x57df, 0x57e0),
+    (0x57e4, 0x57e4), (0x57ed, 0x57ed), (0x57ef, 0x57ef), (0x57f4, 0x57f4), (0x57f8, 0x57fa),
+    (0x57fd, 0x57fd), (0x5800, 0x5800), (0x5802, 0x5802), (0x5806, 0x5807), (0x580b, 0x580b),
+    (0x580d, 0x580d), (0x5811, 0x5811), (0x5815, 0x5815), (0x5819, 0x5819), (0x581e, 0x581e),
+    (0x5820, 0x5821), (0x5824, 0x5824), (0x582a, 0x582a), (0x5830, 0x5830), (0x5835, 0x5835),
+    (0x5844, 0x5844), (0x584c, 0x584d), (0x5851, 0x5851), (0x5854, 0x5854), (0x5858, 0x5858),
+    (0x585e, 0x585e), (0x5865, 0x5865), (0x586b, 0x586c), (0x587e, 0x587e), (0x5880, 0x5881),
+    (0x5883, 0x5883), (0x5885, 0x5885), (0x5889, 0x5889), (0x5892, 0x5893), (0x5899, 0x589a),
+    (0x589e, 0x589f), (0x58a8, 0x58a9), (0x58bc, 0x58bc), (0x58c1, 0x58c1), (0x58c5, 0x58c5),
+    (0x58d1, 0x58d1), (0x58d5, 0x58d5), (0x58e4, 0x58e4), (0x58eb, 0x58ec), (0x58ee, 0x58ee),
+    (0x58f0, 0x58f0), (0x58f3, 0x58f3), (0x58f6, 0x58f6), (0x58f9, 0x58f9), (0x5902, 0x5902),
+    (0x5904, 0x5904), (0x5907, 0x5907), (0x590d, 0x590d), (0x590f, 0x590f), (0x5914, 0x5916),
+    (0x5919, 0x591a), (0x591c, 0x591c), (0x591f, 0x591f), (0x5924, 0x5925), (0x5927, 0x5927),
+    (0x5929, 0x592b), (0x592d, 0x592f), (0x5931, 0x5931), (0x5934, 0x5934), (0x5937, 0x593a),
+    (0x593c, 0x593c), (0x5941, 0x5942), (0x5944, 0x5944), (0x5947, 0x5949), (0x594b, 0x594b),
+    (0x594e, 0x594f), (0x5951, 0x5951), (0x5954, 0x5958), (0x595a, 0x595a), (0x5960, 0x5960),
+    (0x5962, 0x5962), (0x5965, 0x5965), (0x5973, 0x5974), (0x5976, 0x5976), (0x5978, 0x5979),
+    (0x597d, 0x597d), (0x5981, 0x5984), (0x5986, 0x5988), (0x598a, 0x598a), (0x598d, 0x598d),
+    (0x5992, 0x5993), (0x5996, 0x5997), (0x5999, 0x5999), (0x599e, 0x599e), (0x59a3, 0x59a5),
+    (0x59a8, 0x59ab), (0x59ae, 0x59af), (0x59b2, 0x59b2), (0x59b9, 0x59b9), (0x59bb, 0x59bb),
+    (0x59be, 0x59be), (0x59c6, 0x59c6), (0x59ca, 0x59cb), (0x59d0, 0x59d4), (0x59d7, 0x59d8),
+    (0x59da, 0x59da), (0x59dc, 0x59dd), (0x59e3, 0x59e3), (0x59e5, 0x59e5), (0x59e8, 0x59e8),
+    (0x59ec, 0x59ec), (0x59f9, 0x59f9), (0x59fb, 0x59fb), (0x59ff, 0x59ff), (0x5a01, 0x5a01),
+    (0x5a03, 0x5a09), (0x5a0c, 0x5a0c), (0x5a11, 0x5a11), (0x5a13, 0x5a13), (0x5a18, 0x5a18),
+    (0x5a1c, 0x5a1c), (0x5a1f, 0x5a20), (0x5a23, 0x5a23), (0x5a25, 0x5a25), (0x5a29, 0x5a29),
+    (0x5a31, 0x5a32), (0x5a34, 0x5a34), (0x5a36, 0x5a36), (0x5a3c, 0x5a3c), (0x5a40, 0x5a40),
+    (0x5a46, 0x5a46), (0x5a49, 0x5a4a), (0x5a55, 0x5a55), (0x5a5a, 0x5a5a), (0x5a62, 0x5a62),
+    (0x5a67, 0x5a67), (0x5a6a, 0x5a6a), (0x5a74, 0x5a77), (0x5a7a, 0x5a7a), (0x5a7f, 0x5a7f),
+    (0x5a92, 0x5a92), (0x5a9a, 0x5a9b), (0x5aaa, 0x5aaa), (0x5ab2, 0x5ab3), (0x5ab5, 0x5ab5),
+    (0x5ab8, 0x5ab8), (0x5abe, 0x5abe), (0x5ac1, 0x5ac2), (0x5ac9, 0x5ac9), (0x5acc, 0x5acc),
+    (0x5ad2, 0x5ad2), (0x5ad4, 0x5ad4), (0x5ad6, 0x5ad6), (0x5ad8, 0x5ad8), (0x5adc, 0x5adc),
+    (0x5ae0, 0x5ae1), (0x5ae3, 0x5ae3), (0x5ae6, 0x5ae6), (0x5ae9, 0x5ae9), (0x5aeb, 0x5aeb),
+    (0x5af1, 0x5af1), (0x5b09, 0x5b09), (0x5b16, 0x5b17), (0x5b32, 0x5b32), (0x5b34, 0x5b34),
+    (0x5b37, 0x5b37), (0x5b40, 0x5b40), (0x5b50, 0x5b51), (0x5b53, 0x5b55), (0x5b57, 0x5b5d),
+    (0x5b5f, 0x5b5f), (0x5b62, 0x5b66), (0x5b69, 0x5b6a), (0x5b6c, 0x5b6c), (0x5b70, 0x5b71),
+    (0x5b73, 0x5b73), (0x5b75, 0x5b75), (0x5b7a, 0x5b7a), (0x5b7d, 0x5b7d), (0x5b80, 0x5b81),
+    (0x5b83, 0x5b85), (0x5b87, 0x5b89), (0x5b8b, 0x5b8c), (0x5b8f, 0x5b8f), (0x5b93, 0x5b93),
+    (0x5b95, 0x5b95), (0x5b97, 0x5b9e), (0x5ba0, 0x5ba6), (0x5baa, 0x5bab), (0x5bb0, 0x5bb0),
+    (0x5bb3, 0x5bb6), (0x5bb8, 0x5bb9), (0x5bbd, 0x5bbf), (0x5bc2, 0x5bc2), (0x5bc4, 0x5bc7),
+    (0x5bcc, 0x5bcc), (0x5bd0, 0x5bd0), (0x5bd2, 0x5bd3), (0x5bdd, 0x5bdf), (0x5be1, 0x5be1),
+    (0x5be4, 0x5be5), (0x5be8, 0x5be8), (0x5bee, 0x5bee), (0x5bf0, 0x5bf0), (0x5bf8, 0x5bfc),
+    (0x5bff, 0x5bff), (0x5c01, 0x5c01), (0x5c04, 0x5c04), (0x5c06, 0x5c06), (0x5c09, 0x5c0a),
+    (0x5c0f, 0x5c0f), (0x5c11, 0x5c11), (0x5c14, 0x5c16), (0x5c18, 0x5c18), (0x5c1a, 0x5c1a),
+    (0x5c1c, 0x5c1d), (0x5c22, 0x5c22), (0x5c24, 0x5c25), (0x5c27, 0x5c27), (0x5c2c, 0x5c2c),
+    (0x5c31, 0x5c31), (0x5c34, 0x5c34), (0x5c38, 0x5c42), (0x5c45, 0x5c45), (0x5c48, 0x5c4b),
+    (0x5c4e, 0x5c51), (0x5c55, 0x5c55), (0x5c59, 0x5c59), (0x5c5e, 0x5c5e), (0x5c60, 0x5c61),
+    (0x5c63, 0x5c63), (0x5c65, 0x5c66), (0x5c6e, 0x5c6f), (0x5c71, 0x5c71), (0x5c79, 0x5c7a),
+    (0x5c7f, 0x5c7f), (0x5c81, 0x5c82), (0x5c88, 0x5c88), (0x5c8c, 0x5c8d), (0x5c90, 0x5c91),
+    (0x5c94, 0x5c94), (0x5c96, 0x5c9c), (0x5ca2, 0x5ca3), (0x5ca9, 0x5ca9), (0x5cab, 0x5cad),
+    (0x5cb1, 0x5cb1), (0x5cb3, 0x5cb3), (0x5cb5, 0x5cb5), (0x5cb7, 0x5cb8), (0x5cbd, 0x5cbd),
+    (0x5cbf, 0x5cbf), (0x5cc1, 0x5cc1), (0x5cc4, 0x5cc4), (0x5ccb, 0x5ccb), (0x5cd2, 0x5cd2),
+    (0x5cd9, 0x5cd9), (0x5ce1, 0x5ce1), (0x5ce4, 0x5ce6), (0x5ce8, 0x5ce8), (0x5cea, 0x5cea),
+    (0x5ced, 0x5ced), (0x5cf0, 0x5cf0), (0x5cfb, 0x5cfb), (0x5d02, 0x5d03), (0x5d06, 0x5d07),
+    (0x5d0e, 0x5d0e), (0x5d14, 0x5d14), (0x5d16, 0x5d16), (0x5d1b, 0x5d1b), (0x5d1e, 0x5d1e),
+    (0x5d24, 0x5d24), (0x5d26, 0x5d27), (0x5d29, 0x5d29), (0x5d2d, 0x5d2e), (0x5d34, 0x5d34),
+    (0x5d3d, 0x5d3e), (0x5d47, 0x5d47), (0x5d4a, 0x5d4c), (0x5d58, 0x5d58), (0x5d5b, 0x5d5b),
+    (0x5d5d, 0x5d5d), (0x5d69, 0x5d69), (0x5d6b, 0x5d6c), (0x5d6f, 0x5d6f), (0x5d74, 0x5d74),
+    (0x5d82, 0x5d82), (0x5d99, 0x5d99), (0x5d9d, 0x5d9d), (0x5db7, 0x5db7), (0x5dc5, 0x5dc5),
+    (0x5dcd, 0x5dcd), (0x5ddb, 0x5ddb), (0x5ddd, 0x5dde), (0x5de1, 0x5de2), (0x5de5, 0x5de9),
+    (0x5deb, 0x5deb), (0x5dee, 0x5def), (0x5df1, 0x5df4), (0x5df7, 0x5df7), (0x5dfd, 0x5dfe),
+    (0x5e01, 0x5e03), (0x5e05, 0x5e06), (0x5e08, 0x5e08), (0x5e0c, 0x5e0c), (0x5e0f, 0x5e11),
+    (0x5e14, 0x5e16), (0x5e18, 0x5e1d), (0x5e26, 0x5e27), (0x5e2d, 0x5e2e), (0x5e31, 0x5e31),
+    (0x5e37, 0x5e38), (0x5e3b, 0x5e3d), (0x5e42, 0x5e42), (0x5e44, 0x5e45), (0x5e4c, 0x5e4c),
+    (0x5e54, 0x5e55), (0x5e5b, 0x5e5b), (0x5e5e, 0x5e5e), (0x5e61, 0x5e62), (0x5e72, 0x5e74),
+    (0x5e76, 0x5e76), (0x5e78, 0x5e78), (0x5e7a, 0x5e7d), (0x5e7f, 0x5e80), (0x5e84, 0x5e84),
+    (0x5e86, 0x5e87), (0x5e8a, 0x5e8b), (0x5e8f, 0x5e91), (0x5e93, 0x5e97), (0x5e99, 0x5e9a),
+    (0x5e9c, 0x5e9c), (0x5e9e, 0x5ea0), (0x5ea5, 0x5ea7), (0x5ead, 0x5ead), (0x5eb3, 0x5eb3),
+    (0x5eb5, 0x5eb9), (0x5ebe, 0x5ebe), (0x5ec9, 0x5eca), (0x5ed1, 0x5ed3), (0x5ed6, 0x5ed6),
+    (0x5edb, 0x5edb), (0x5ee8, 0x5ee8), (0x5eea, 0x5eea), (0x5ef4, 0x5ef4), (0x5ef6, 0x5ef7),
+    (0x5efa, 0x5efa), (0x5efe, 0x5f04), (0x5f08, 0x5f08), (0x5f0a, 0x5f0b), (0x5f0f, 0x5f0f),
+    (0x5f11, 0x5f11), (0x5f13, 0x5f13), (0x5f15, 0x5f15), (0x5f17, 0x5f18), (0x5f1b, 0x5f1b),
+    (0x5f1f, 0x5f20), (0x5f25, 0x5f27), (0x5f29, 0x5f2a), (0x5f2d, 0x5f2d), (0x5f2f, 0x5f2f),
+    (0x5f31, 0x5f31), (0x5f39, 0x5f3a), (0x5f3c, 0x5f3c), (0x5f40, 0x5f40), (0x5f50, 0x5f50),
+    (0x5f52, 0x5f53), (0x5f55, 0x5f58), (0x5f5d, 0x5f5d), (0x5f61, 0x5f62), (0x5f64, 0x5f64),
+    (0x5f66, 0x5f66), (0x5f69, 0x5f6a), (0x5f6c, 0x5f6d), (0x5f70, 0x5f71), (0x5f73, 0x5f73),
+    (0x5f77, 0x5f77), (0x5f79, 0x5f79), (0x5f7b, 0x5f7c), (0x5f80, 0x5f82), (0x5f84, 0x5f85),
+    (0x5f87, 0x5f8c), (0x5f90, 0x5f90), (0x5f92, 0x5f92), (0x5f95, 0x5f95), (0x5f97, 0x5f99),
+    (0x5f9c, 0x5f9c), (0x5fa1, 0x5fa1), (0x5fa8, 0x5fa8), (0x5faa, 0x5faa), (0x5fad, 0x5fae),
+    (0x5fb5, 0x5fb5), (0x5fb7, 0x5fb7), (0x5fbc, 0x5fbd), (0x5fc3, 0x5fc6), (0x5fc9, 0x5fc9),
+    (0x5fcc, 0x5fcd), (0x5fcf, 0x5fd2), (0x5fd6, 0x5fd9), (0x5fdd, 0x5fdd), (0x5fe0, 0x5fe1),
+    (0x5fe4, 0x5fe4), (0x5fe7, 0x5fe7), (0x5fea, 0x5feb), (0x5fed, 0x5fee), (0x5ff1, 0x5ff1),
+    (0x5ff5, 0x5ff5), (0x5ff8, 0x5ff8), (0x5ffb, 0x5ffb), (0x5ffd, 0x6006), (0x600a, 0x600a),
+    (0x600d, 0x600f), (0x6012, 0x6012), (0x6014, 0x6016), (0x6019, 0x6019), (0x601b, 0x601d),
+    (0x6020, 0x6021), (0x6025, 0x602b), (0x602f, 0x602f), (0x6035, 0x6035), (0x603b, 0x603c),
+    (0x603f, 0x603f), (0x6041, 0x6043), (0x604b, 0x604b), (0x604d, 0x604d), (0x6050, 0x6050),
+    (0x6052, 0x6052), (0x6055, 0x6055), (0x6059, 0x605a), (0x605d, 0x605d), (0x6062, 0x6064),
+    (0x6067, 0x606d), (0x606f, 0x6070), (0x6073, 0x6073), (0x6076, 0x6076), (0x6078, 0x607d),
+    (0x607f, 0x607f), (0x6083, 0x6084), (0x6089, 0x6089), (0x608c, 0x608d), (0x6092, 0x6092),
+    (0x6094, 0x6094), (0x6096, 0x6096), (0x609a, 0x609b), (0x609d, 0x609d), (0x609f, 0x60a0),
+    (0x60a3, 0x60a3), (0x60a6, 0x60a6), (0x60a8, 0x60a8), (0x60ab, 0x60ad), (0x60af, 0x60af),
+    (0x60b1, 0x60b2), (0x60b4, 0x60b4), (0x60b8, 0x60b8), (0x60bb, 0x60bc), (0x60c5, 0x60c6),
+    (0x60ca, 0x60cb), (0x60d1, 0x60d1), (0x60d5, 0x60d5), (0x60d8, 0x60d8), (0x60da, 0x60da),
+    (0x60dc, 0x60dd), (0x60df, 0x60e0), (0x60e6, 0x60e9), (0x60eb, 0x60f0), (0x60f3, 0x60f4),
+    (0x60f6, 0x60f6), (0x60f9, 0x60fa), (0x6100, 0x6101), (0x6106, 0x6106), (0x6108, 0x6109),
+    (0x610d, 0x610f), (0x6115, 0x6115), (0x611a, 0x611a), (0x611f, 0x6120), (0x6123, 0x6124),
+    (0x6126, 0x6127), (0x612b, 0x612b), (0x613f, 0x613f), (0x6148, 0x6148), (0x614a, 0x614a),
+    (0x614c, 0x614c), (0x614e, 0x614e), (0x6151, 0x6151), (0x6155, 0x6155), (0x615d, 0x615d),
+    (0x6162, 0x6162), (0x6167, 0x6168), (0x6170, 0x6170), (0x6175, 0x6175), (0x6177, 0x6177),
+    (0x618b, 0x618b), (0x618e, 0x618e), (0x6194, 0x6194), (0x619d, 0x619d), (0x61a7, 0x61a9),
+    (0x61ac, 0x61ac), (0x61b7, 0x61b7), (0x61be, 0x61be), (0x61c2, 0x61c2), (0x61c8, 0x61c8),
+    (0x61ca, 0x61cb), (0x61d1, 0x61d2), (0x61d4, 0x61d4), (0x61e6, 0x61e6), (0x61f5, 0x61f5),
+    (0x61ff, 0x61ff), (0x6206, 0x6206), (0x6208, 0x6208), (0x620a, 0x6212), (0x6215, 0x6218),
+    (0x621a, 0x621b), (0x621f, 0x621f), (0x6221, 0x6222), (0x6224, 0x6225), (0x622a, 0x622a),
+    (0x622c, 0x622c), (0x622e, 0x622e), (0x6233, 0x6234), (0x6237, 0x6237), (0x623d, 0x6241),
+    (0x6243, 0x6243), (0x6247, 0x6249), (0x624b, 0x624e), (0x6251, 0x6254), (0x6258, 0x6258),
+    (0x625b, 0x625b), (0x6263, 0x6263), (0x6266, 0x6267), (0x6269, 0x6270), (0x6273, 0x6273),
+    (0x6276, 0x6276), (0x6279, 0x6279), (0x627c, 0x627c), (0x627e, 0x6280), (0x6284, 0x6284),
+    (0x6289, 0x628a), (0x6291, 0x6293), (0x6295, 0x6298), (0x629a, 0x629b), (0x629f, 0x62a2),
+    (0x62a4, 0x62a5), (0x62a8, 0x62a8), (0x62ab, 0x62ac), (0x62b1, 0x62b1), (0x62b5, 0x62b5),
+    (0x62b9, 0x62b9), (0x62bb, 0x62bd), (0x62bf, 0x62bf), (0x62c2, 0x62c2), (0x62c4, 0x62ca),
+    (0x62cc, 0x62ce), (0x62d0, 0x62d0), (0x62d2, 0x62d4), (0x62d6, 0x62dc), (0x62df, 0x62df),
+    (0x62e2, 0x62e3), (0x62e5, 0x62e9), (0x62ec, 0x62ef), (0x62f1, 0x62f1), (0x62f3, 0x62f4),
+    (0x62f6, 0x62f7), (0x62fc, 0x62ff), (0x6301, 0x6302), (0x6307, 0x6309), (0x630e, 0x630e),
+    (0x6311, 0x6311), (0x6316, 0x6316), (0x631a, 0x631b), (0x631d, 0x6325), (0x6328, 0x6328),
+    (0x632a, 0x632b), (0x632f, 0x632f), (0x6332, 0x6332), (0x6339, 0x633a), (0x633d, 0x633d),
+    (0x6342, 0x6343), (0x6345, 0x6346), (0x6349, 0x6349), (0x634b, 0x6350), (0x6355, 0x6355),
+    (0x635e, 0x635f), (0x6361, 0x6363), (0x6367, 0x6367), (0x6369, 0x6369), (0x636d, 0x636e),
+    (0x6371, 0x6371), (0x6376, 0x6377), (0x637a, 0x637b), (0x6380, 0x6380), (0x6382, 0x6382),
+    (0x6387, 0x638a), (0x638c, 0x638c), (0x638e, 0x6390), (0x6392, 0x6392), (0x6396, 0x6396),
+    (0x6398, 0x6398), (0x63a0, 0x63a0), (0x63a2, 0x63a3), (0x63a5, 0x63a5), (0x63a7, 0x63aa),
+    (0x63ac, 0x63ae), (0x63b0, 0x63b0), (0x63b3, 0x63b4), (0x63b7, 0x63b8), (0x63ba, 0x63ba),
+    (0x63bc, 0x63bc), (0x63be, 0x63be), (0x63c4, 0x63c4), (0x63c6, 0x63c6), (0x63c9, 0x63c9),
+    (0x63cd, 0x63d0), (0x63d2, 0x63d2), (0x63d6, 0x63d6), (0x63de, 0x63de), (0x63e0, 0x63e1),
+    (0x63e3, 0x63e3), (0x63e9, 0x63ea), (0x63ed, 0x63ed), (0x63f2, 0x63f2), (0x63f4, 0x63f4),
+    (0x63f6, 0x63f6), (0x63f8, 0x63f8), (0x63fd, 0x63fd), (0x63ff, 0x6402), (0x6405, 0x6405),
+    (0x640b, 0x640c), (0x640f, 0x6410), (0x6413, 0x6414), (0x641b, 0x641c), (0x641e, 0x641e),
+    (0x6420, 0x6421), (0x6426, 0x6426), (0x642a, 0x642a), (0x642c, 0x642d), (0x6434, 0x6434),
+    (0x643a, 0x643a), (0x643d, 0x643d), (0x643f, 0x643f), (0x6441, 0x6441), (0x6444, 0x6448),
+    (0x644a, 0x644a), (0x6452, 0x6452), (0x6454, 0x6454), (0x6458, 0x6458), (0x645e, 0x645e),
+    (0x6467, 0x6467), (0x6469, 0x6469), (0x646d, 0x646d), (0x6478, 0x647a), (0x6482, 0x6482),
+    (0x6484, 0x6485), (0x6487, 0x6487), (0x6491, 0x6492), (0x6495, 0x6496), (0x6499, 0x6499),
+    (0x649e, 0x649e), (0x64a4, 0x64a4), (0x64a9, 0x64a9), (0x64ac, 0x64ae), (0x64b0, 0x64b0),
+    (0x64b5, 0x64b5), (0x64b7, 0x64b8), (0x64ba, 0x64ba), (0x64bc, 0x64bc), (0x64c0, 0x64c0),
+    (0x64c2, 0x64c2), (0x64c5, 0x64c5), (0x64cd, 0x64ce), (0x64d0, 0x64d0), (0x64d2, 0x64d2),
+    (0x64d7, 0x64d8), (0x64de, 0x64de), (0x64e2, 0x64e2), (0x64e4, 0x64e4), (0x64e6, 0x64e6),
+    (0x6500, 0x6500), (0x6509, 0x6509), (0x6512, 0x6512), (0x6518, 0x6518), (0x6525, 0x6525),
+    (0x652b, 0x652b), (0x652e, 0x652f), (0x6534, 0x6536), (0x6538, 0x6539), (0x653b, 0x653b),
+    (0x653e, 0x653f), (0x6545, 0x6545), (0x6548, 0x6549), (0x654c, 0x654c), (0x654f, 0x654f),
+    (0x6551, 0x6551), (0x6555, 0x6556), (0x6559, 0x6559), (0x655b, 0x655b), (0x655d, 0x655e),
+    (0x6562, 0x6563), (0x6566, 0x6566), (0x656b, 0x656c), (0x6570, 0x6570), (0x6572, 0x6572),
+    (0x6574, 0x6574), (0x6577, 0x6577), (0x6587, 0x6587), (0x658b, 0x658c), (0x6590, 0x6591),
+    (0x6593, 0x6593), (0x6597, 0x6597), (0x6599, 0x6599), (0x659b, 0x659c), (0x659f, 0x659f),
+    (0x65a1, 0x65a1), (0x65a4, 0x65a5), (0x65a7, 0x65a7), (0x65a9, 0x65a9), (0x65ab, 0x65ab),
+    (0x65ad, 0x65ad), (0x65af, 0x65b0), (0x65b9, 0x65b9), (0x65bc, 0x65bd), (0x65c1, 0x65c1),
+    (0x65c3, 0x65c6), (0x65cb, 0x65cc), (0x65ce, 0x65cf), (0x65d2, 0x65d2), (0x65d6, 0x65d7),
+    (0x65e0, 0x65e0), (0x65e2, 0x65e2), (0x65e5, 0x65e9), (0x65ec, 0x65f1), (0x65f6, 0x65f7),
+    (0x65fa, 0x65fa), (0x6600, 0x6600), (0x6602, 0x6603), (0x6606, 0x6606), (0x660a, 0x660a),
+    (0x660c, 0x660c), (0x660e, 0x660f), (0x6613, 0x6615), (0x6619, 0x6619), (0x661d, 0x661d),
+    (0x661f, 0x6620), (0x6625, 0x6625), (0x6627, 0x6628), (0x662d, 0x662d), (0x662f, 0x662f),
+    (0x6631, 0x6631), (0x6634, 0x6636), (0x663c, 0x663c), (0x663e, 0x663e), (0x6641, 0x6641),
+    (0x6643, 0x6643), (0x664b, 0x664c), (0x664f, 0x664f), (0x6652, 0x6657), (0x665a, 0x665a),
+    (0x665f, 0x665f), (0x6661, 0x6661), (0x6664, 0x6664), (0x6666, 0x6666), (0x6668, 0x6668),
+    (0x666e, 0x6670), (0x6674, 0x6674), (0x6676, 0x6677), (0x667a, 0x667a), (0x667e, 0x667e),
+    (0x6682, 0x6682), (0x6684, 0x6684), (0x6687, 0x6687), (0x668c, 0x668c), (0x6691, 0x6691),
+    (0x6696, 0x6697), (0x669d, 0x669d), (0x66a7, 0x66a8), (0x66ae, 0x66ae), (0x66b4, 0x66b4),
+    (0x66b9, 0x66b9), (0x66be, 0x66be), (0x66d9, 0x66d9), (0x66db, 0x66dd), (0x66e6, 0x66e6),
+    (0x66e9, 0x66e9), (0x66f0, 0x66f0), (0x66f2, 0x66f4), (0x66f7, 0x66f7), (0x66f9, 0x66f9),
+    (0x66fc, 0x66fc), (0x66fe, 0x6700), (0x6708, 0x670b), (0x670d, 0x670d), (0x6710, 0x6710),
+    (0x6714, 0x6715), (0x6717, 0x6717), (0x671b, 0x671b), (0x671d, 0x671d), (0x671f, 0x671f),
+    (0x6726, 0x6726), (0x6728, 0x6728), (0x672a, 0x672d), (0x672f, 0x672f), (0x6731, 0x6731),
+    (0x6734, 0x6735), (0x673a, 0x673a), (0x673d, 0x673d), (0x6740, 0x6740), (0x6742, 0x6743),
+    (0x6746, 0x6746), (0x6748, 0x6749), (0x674c, 0x674c), (0x674e, 0x6751), (0x6753, 0x6753),
+    (0x6756, 0x6756), (0x675c, 0x675c), (0x675e, 0x6761), (0x6765, 0x6765), (0x6768, 0x676a),
+    (0x676d, 0x676d), (0x676f, 0x6770), (0x6772, 0x6773), (0x6775, 0x6775), (0x6777, 0x6777),
+    (0x677c, 0x677c), (0x677e, 0x677f), (0x6781, 0x6781), (0x6784, 0x6784), (0x6787, 0x6787),
+    (0x6789, 0x6789), (0x678b, 0x678b), (0x6790, 0x6790), (0x6795, 0x6795), (0x6797, 0x6798),
+    (0x679a, 0x679a), (0x679c, 0x679e), (0x67a2, 0x67a3), (0x67a5, 0x67a5), (0x67a7, 0x67a8),
+    (0x67aa, 0x67ab), (0x67ad, 0x67ad), (0x67af, 0x67b0), (0x67b3, 0x67b3), (0x67b5, 0x67b8),
+    (0x67c1, 0x67c1), (0x67c3, 0x67c4), (0x67cf, 0x67d4), (0x67d8, 0x67da), (0x67dc, 0x67de),
+    (0x67e0, 0x67e0), (0x67e2, 0x67e2), (0x67e5, 0x67e5), (0x67e9, 0x67e9), (0x67ec, 0x67ec),
+    (0x67ef, 0x67f1), (0x67f3, 0x67f4), (0x67fd, 0x67fd), (0x67ff, 0x6800), (0x6805, 0x6805),
+    (0x6807, 0x680c), (0x680e, 0x680f), (0x6811, 0x6811), (0x6813, 0x6813), (0x6816, 0x6817),
+    (0x681d, 0x681d), (0x6821, 0x6821), (0x6829, 0x682a), (0x6832, 0x6833), (0x6837, 0x6839),
+    (0x683c, 0x683e), (0x6840, 0x6846), (0x6848, 0x684a), (0x684c, 0x684c), (0x684e, 0x684e),
+    (0x6850, 0x6851), (0x6853, 0x6855), (0x6860, 0x6869), (0x686b, 0x686b), (0x6874, 0x6874),
+    (0x6876, 0x6877), (0x6881, 0x6881), (0x6883, 0x6883), (0x6885, 0x6886), (0x688f, 0x688f),
+    (0x6893, 0x6893), (0x6897, 0x6897), (0x68a2, 0x68a2), (0x68a6, 0x68a8), (0x68ad, 0x68ad),
+    (0x68af, 0x68b0), (0x68b3, 0x68b3), (0x68b5, 0x68b5), (0x68c0, 0x68c0), (0x68c2, 0x68c2),
+    (0x68c9, 0x68c9), (0x68cb, 0x68cb), (0x68cd, 0x68cd), (0x68d2, 0x68d2), (0x68d5, 0x68d5),
+    (0x68d8, 0x68d8), (0x68da, 0x68da), (0x68e0, 0x68e0), (0x68e3, 0x68e3), (0x68ee, 0x68ee),
+    (0x68f0, 0x68f1), (0x68f5, 0x68f5), (0x68f9, 0x68fa), (0x68fc, 0x68fc), (0x6901, 0x6901),
+    (0x6905, 0x6905), (0x690b, 0x690b), (0x690d, 0x690e), (0x6910, 0x6910), (0x6912, 0x6912),
+    (0x691f, 0x6920), (0x6924, 0x6924), (0x692d, 0x692d), (0x6930, 0x6930), (0x6934, 0x6934),
+    (0x6939, 0x6939), (0x693d, 0x693d), (0x693f, 0x693f), (0x6942, 0x6942), (0x6954, 0x6954),
+    (0x6957, 0x6957), (0x695a, 0x695a), (0x695d, 0x695e), (0x6960, 0x6960), (0x6963, 0x6963),
+    (0x6966, 0x6966), (0x696b, 0x696b), (0x696e, 0x696e), (0x6971, 0x6971), (0x6977, 0x6979),
+    (0x697c, 0x697c), (0x6980, 0x6980), (0x6982, 0x6982), (0x6984, 0x6984), (0x6986, 0x6989),
+    (0x698d, 0x698d), (0x6994, 0x6995), (0x6998, 0x6998), (0x699b, 0x699c), (0x69a7, 0x69a8),
+    (0x69ab, 0x69ab), (0x69ad, 0x69ad), (0x69b1, 0x69b1), (0x69b4, 0x69b4), (0x69b7, 0x69b7),
+    (0x69bb, 0x69bb), (0x69c1, 0x69c1), (0x69ca, 0x69ca), (0x69cc, 0x69cc), (0x69ce, 0x69ce),
+    (0x69d0, 0x69d0), (0x69d4, 0x69d4), (0x69db, 0x69db), (0x69df, 0x69e0), (0x69ed, 0x69ed),
+    (0x69f2, 0x69f2), (0x69fd, 0x69fd), (0x69ff, 0x69ff), (0x6a0a, 0x6a0a), (0x6a17, 0x6a18),
+    (0x6a1f, 0x6a1f), (0x6a21, 0x6a21), (0x6a28, 0x6a28), (0x6a2a, 0x6a2a), (0x6a2f, 0x6a2f),
+    (0x6a31, 0x6a31), (0x6a35, 0x6a35), (0x6a3d, 0x6a3e), (0x6a44, 0x6a44), (0x6a47, 0x6a47),
+    (0x6a50, 0x6a50), (0x6a58, 0x6a59), (0x6a5b, 0x6a5b), (0x6a61, 0x6a61), (0x6a65, 0x6a65),
+    (0x6a71, 0x6a71), (0x6a79, 0x6a79), (0x6a7c, 0x6a7c), (0x6a80, 0x6a80), (0x6a84, 0x6a84),
+    (0x6a8e, 0x6a8e), (0x6a90, 0x6a91), (0x6a97, 0x6a97), (0x6aa0, 0x6aa0), (0x6aa9, 0x6aa9),
+    (0x6aab, 0x6aac), (0x6b20, 0x6b24), (0x6b27, 0x6b27), (0x6b32, 0x6b32), (0x6b37, 0x6b37),
+    (0x6b39, 0x6b3a), (0x6b3e, 0x6b3e), (0x6b43, 0x6b43), (0x6b46, 0x6b47), (0x6b49, 0x6b49),
+    (0x6b4c, 0x6b4c), (0x6b59, 0x6b59), (0x6b62, 0x6b67), (0x6b6a, 0x6b6a), (0x6b79, 0x6b79),
+    (0x6b7b, 0x6b7c), (0x6b81, 0x6b84), (0x6b86, 0x6b87), (0x6b89, 0x6b8b), (0x6b8d, 0x6b8d),
+    (0x6b92, 0x6b93), (0x6b96, 0x6b96), (0x6b9a, 0x6b9b), (0x6ba1, 0x6ba1), (0x6baa, 0x6baa),
+    (0x6bb3, 0x6bb5), (0x6bb7, 0x6bb7), (0x6bbf, 0x6bbf), (0x6bc1, 0x6bc2), (0x6bc5, 0x6bc5),
+    (0x6bcb, 0x6bcb), (0x6bcd, 0x6bcd), (0x6bcf, 0x6bcf), (0x6bd2, 0x6bd7), (0x6bd9, 0x6bd9),
+    (0x6bdb, 0x6bdb), (0x6be1, 0x6be1), (0x6bea, 0x6beb), (0x6bef, 0x6bef), (0x6bf3, 0x6bf3),
+    (0x6bf5, 0x6bf5), (0x6bf9, 0x6bf9), (0x6bfd, 0x6bfd), (0x6c05, 0x6c07), (0x6c0d, 0x6c0d),
+    (0x6c0f, 0x6c11), (0x6c13, 0x6c16), (0x6c18, 0x6c1b), (0x6c1f, 0x6c1f), (0x6c21, 0x6c22),
+    (0x6c24, 0x6c24), (0x6c26, 0x6c2a), (0x6c2e, 0x6c30), (0x6c32, 0x6c32), (0x6c34, 0x6c35),
+    (0x6c38, 0x6c38), (0x6c3d, 0x6c3d), (0x6c40, 0x6c42), (0x6c46, 0x6c47), (0x6c49, 0x6c4a),
+    (0x6c50, 0x6c50), (0x6c54, 0x6c55), (0x6c57, 0x6c57), (0x6c5b, 0x6c61), (0x6c64, 0x6c64),
+    (0x6c68, 0x6c6a), (0x6c70, 0x6c70), (0x6c72, 0x6c72), (0x6c74, 0x6c74), (0x6c76, 0x6c76),
+    (0x6c79, 0x6c79), (0x6c7d, 0x6c7e), (0x6c81, 0x6c83), (0x6c85, 0x6c86), (0x6c88, 0x6c89),
+    (0x6c8c, 0x6c8c), (0x6c8f, 0x6c90), (0x6c93, 0x6c94), (0x6c99, 0x6c99), (0x6c9b, 0x6c9b),
+    (0x6c9f, 0x6c9f), (0x6ca1, 0x6ca1), (0x6ca3, 0x6ca7), (0x6ca9, 0x6cab), (0x6cad, 0x6cae),
+    (0x6cb1, 0x6cb3), (0x6cb8, 0x6cb9), (0x6cbb, 0x6cbf), (0x6cc4, 0x6cc5), (0x6cc9, 0x6cca),
+    (0x6ccc, 0x6ccc), (0x6cd0, 0x6cd0), (0x6cd3, 0x6cd7), (0x6cdb, 0x6cdb), (0x6cde, 0x6cde),
+    (0x6ce0, 0x6ce3), (0x6ce5, 0x6ce5), (0x6ce8, 0x6ce8), (0x6cea, 0x6ceb), (0x6cee, 0x6cf1),
+    (0x6cf3, 0x6cf3), (0x6cf5, 0x6cf8), (0x6cfa, 0x6cfe), (0x6d01, 0x6d01), (0x6d04, 0x6d04),
+    (0x6d07, 0x6d07), (0x6d0b, 0x6d0c), (0x6d0e, 0x6d0e), (0x6d12, 0x6d12), (0x6d17, 0x6d17),
+    (0x6d19, 0x6d1b), (0x6d1e, 0x6d1e), (0x6d25, 0x6d25), (0x6d27, 0x6d27), (0x6d2a, 0x6d2b),
+    (0x6d2e, 0x6d2e), (0x6d31, 0x6d33), (0x6d35, 0x6d35), (0x6d39, 0x6d39), (0x6d3b, 0x6d3e),
+    (0x6d41, 0x6d41), (0x6d43, 0x6d43), (0x6d45, 0x6d48), (0x6d4a, 0x6d4b), (0x6d4d, 0x6d4f),
+    (0x6d51, 0x6d54), (0x6d59, 0x6d5a), (0x6d5c, 0x6d5c), (0x6d5e, 0x6d5e), (0x6d60, 0x6d60),
+    (0x6d63, 0x6d63), (0x6d66, 0x6d66), (0x6d69, 0x6d6a), (0x6d6e, 0x6d6f), (0x6d74, 0x6d74),
+    (0x6d77, 0x6d78), (0x6d7c, 0x6d7c), (0x6d82, 0x6d82), (0x6d85, 0x6d85), (0x6d88, 0x6d89),
+    (0x6d8c, 0x6d8c), (0x6d8e, 0x6d8e), (0x6d91, 0x6d91), (0x6d93, 0x6d95), (0x6d9b, 0x6d9b),
+    (0x6d9d, 0x6da1), (0x6da3, 0x6da4), (0x6da6, 0x6dab), (0x6dae, 0x6daf), (0x6db2, 0x6db2),
+    (0x6db5, 0x6db5), (0x6db8, 0x6db8), (0x6dbf, 0x6dc0), (0x6dc4, 0x6dc7), (0x6dcb, 0x6dcc),
+    (0x6dd1, 0x6dd1), (0x6dd6, 0x6dd6), (0x6dd8, 0x6dd9), (0x6ddd, 0x6dde), (0x6de0, 0x6de1),
+    (0x6de4, 0x6de4), (0x6de6, 0x6de6), (0x6deb, 0x6dec), (0x6dee, 0x6dee), (0x6df1, 0x6df1),
+    (0x6df3, 0x6df3), (0x6df7, 0x6df7), (0x6df9, 0x6df9), (0x6dfb, 0x6dfc), (0x6e05, 0x6e05),
+    (0x6e0a, 0x6e0a), (0x6e0c, 0x6e0e), (0x6e10, 0x6e11), (0x6e14, 0x6e14), (0x6e16, 0x6e17),
+    (0x6e1a, 0x6e1a), (0x6e1d, 0x6e1d), (0x6e20, 0x6e21), (0x6e23, 0x6e25), (0x6e29, 0x6e29),
+    (0x6e2b, 0x6e2b), (0x6e2d, 0x6e2d), (0x6e2f, 0x6e2f), (0x6e32, 0x6e32), (0x6e34, 0x6e34),
+    (0x6e38, 0x6e38), (0x6e3a, 0x6e3a), (0x6e43, 0x6e44), (0x6e4d, 0x6e4e), (0x6e53, 0x6e54),
+    (0x6e56, 0x6e56), (0x6e58, 0x6e58), (0x6e5b, 0x6e5b), (0x6e5f, 0x6e5f), (0x6e6b, 0x6e6b),
+    (0x6e6e, 0x6e6e), (0x6e7e, 0x6e7f), (0x6e83, 0x6e83), (0x6e85, 0x6e86), (0x6e89, 0x6e89),
+    (0x6e8f, 0x6e90), (0x6e98, 0x6e98), (0x6e9c, 0x6e9c), (0x6e9f, 0x6e9f), (0x6ea2, 0x6ea2),
+    (0x6ea5, 0x6ea5), (0x6ea7, 0x6ea7), (0x6eaa, 0x6eaa), (0x6eaf, 0x6eaf), (0x6eb1, 0x6eb2),
+    (0x6eb4, 0x6eb4), (0x6eb6, 0x6eb7), (0x6eba, 0x6ebb), (0x6ebd, 0x6ebd), (0x6ec1, 0x6ec2),
+    (0x6ec7, 0x6ec7), (0x6ecb, 0x6ecb), (0x6ecf, 0x6ecf), (0x6ed1, 0x6ed1), (0x6ed3, 0x6ed5),
+    (0x6ed7, 0x6ed7), (0x6eda, 0x6eda), (0x6ede, 0x6ee2), (0x6ee4, 0x6ee6), (0x6ee8, 0x6ee9),
+    (0x6ef4, 0x6ef4), (0x6ef9, 0x6ef9), (0x6f02, 0x6f02), (0x6f06, 0x6f06), (0x6f09, 0x6f09),
+    (0x6f0f, 0x6f0f), (0x6f13, 0x6f15), (0x6f20, 0x6f20), (0x6f24, 0x6f24), (0x6f29, 0x6f2b),
+    (0x6f2d, 0x6f2d), (0x6f2f, 0x6f2f), (0x6f31, 0x6f31), (0x6f33, 0x6f33), (0x6f36, 0x6f36),
+    (0x6f3e, 0x6f3e), (0x6f46, 0x6f47), (0x6f4b, 0x6f4b), (0x6f4d, 0x6f4d), (0x6f58, 0x6f58),
+    (0x6f5c, 0x6f5c), (0x6f5e, 0x6f5e), (0x6f62, 0x6f62), (0x6f66, 0x6f66), (0x6f6d, 0x6f6e),
+    (0x6f72, 0x6f72), (0x6f74, 0x6f74), (0x6f78, 0x6f78), (0x6f7a, 0x6f7a), (0x6f7c, 0x6f7c),
+    (0x6f84, 0x6f84), (0x6f88, 0x6f89), (0x6f8c, 0x6f8e), (0x6f9c, 0x6f9c), (0x6fa1, 0x6fa1),
+    (0x6fa7, 0x6fa7), (0x6fb3, 0x6fb3), (0x6fb6, 0x6fb6), (0x6fb9, 0x6fb9), (0x6fc0, 0x6fc0),
+    (0x6fc2, 0x6fc2), (0x6fc9, 0x6fc9), (0x6fd1, 0x6fd2), (0x6fde, 0x6fde), (0x6fe0, 0x6fe1),
+    (0x6fee, 0x6fef), (0x7011, 0x7011), (0x701a, 0x701b), (0x7023, 0x7023), (0x7035, 0x7035),
+    (0x7039, 0x7039), (0x704c, 0x704c), (0x704f, 0x704f), (0x705e, 0x705e), (0x706b, 0x706d),
+    (0x706f, 0x7070), (0x7075, 0x7076), (0x7078, 0x7078), (0x707c, 0x707c), (0x707e, 0x7080),
+    (0x7085, 0x7085), (0x7089, 0x708a), (0x708e, 0x708e), (0x7092, 0x7092), (0x7094, 0x7096),
+    (0x7099, 0x7099), (0x709c, 0x709d), (0x70ab, 0x70af), (0x70b1, 0x70b1), (0x70b3, 0x70b3),
+    (0x70b7, 0x70b9), (0x70bb, 0x70bd), (0x70c0, 0x70c3), (0x70c8, 0x70c8), (0x70ca, 0x70ca),
+    (0x70d8, 0x70d9), (0x70db, 0x70db), (0x70df, 0x70df), (0x70e4, 0x70e4), (0x70e6, 0x70e9),
+    (0x70eb, 0x70ed), (0x70ef, 0x70ef), (0x70f7, 0x70f7), (0x70f9, 0x70f9), (0x70fd, 0x70fd),
+    (0x7109, 0x710a), (0x7110, 0x7110), (0x7113, 0x7113), (0x7115, 0x7116), (0x7118, 0x711a),
+    (0x7126, 0x7126), (0x712f, 0x7131), (0x7136, 0x7136), (0x7145, 0x7145), (0x714a, 0x714a),
+    (0x714c, 0x714c), (0x714e, 0x714e), (0x715c, 0x715c), (0x715e, 0x715e), (0x7164, 0x7164),
+    (0x7166, 0x7168), (0x716e, 0x716e), (0x7172, 0x7173), (0x7178, 0x7178), (0x717a, 0x717a),
+    (0x717d, 0x717d), (0x7184, 0x7184), (0x718a, 0x718a), (0x718f, 0x718f), (0x7194, 0x7194),
+    (0x7198, 0x7199), (0x719f, 0x71a0), (0x71a8, 0x71a8), (0x71ac, 0x71ac), (0x71b3, 0x71b3),
+    (0x71b5, 0x71b5), (0x71b9, 0x71b9), (0x71c3, 0x71c3), (0x71ce, 0x71ce), (0x71d4, 0x71d5),
+    (0x71e0, 0x71e0), (0x71e5, 0x71e5), (0x71e7, 0x71e7), (0x71ee, 0x71ee), (0x71f9, 0x71f9),
+    (0x7206, 0x7206), (0x721d, 0x721d), (0x7228, 0x7228), (0x722a, 0x722a), (0x722c, 0x722c),
+    (0x7230, 0x7231), (0x7235, 0x7239), (0x723b, 0x723b), (0x723d, 0x723d), (0x723f, 0x723f),
+    (0x7247, 0x7248), (0x724c, 0x724d), (0x7252, 0x7252), (0x7256, 0x7256), (0x7259, 0x7259),
+    (0x725b, 0x725b), (0x725d, 0x725d), (0x725f, 0x725f), (0x7261, 0x7262), (0x7266, 0x7267),
+    (0x7269, 0x7269), (0x726e, 0x726f), (0x7272, 0x7272), (0x7275, 0x7275), (0x7279, 0x727a),
+    (0x727e, 0x7281), (0x7284, 0x7284), (0x728a, 0x728b), (0x728d, 0x728d), (0x728f, 0x728f),
+    (0x7292, 0x7292), (0x729f, 0x729f), (0x72ac, 0x72ad), (0x72af, 0x72b0), (0x72b4, 0x72b4),
+    (0x72b6, 0x72b9), (0x72c1, 0x72c4), (0x72c8, 0x72c8), (0x72cd, 0x72ce), (0x72d0, 0x72d0),
+    (0x72d2, 0x72d2), (0x72d7, 0x72d7), (0x72d9, 0x72d9), (0x72de, 0x72de), (0x72e0, 0x72e1),
+    (0x72e8, 0x72e9), (0x72ec, 0x72f4), (0x72f7, 0x72f8), (0x72fa, 0x72fc), (0x7301, 0x7301),
+    (0x7303, 0x7303), (0x730a, 0x730a), (0x730e, 0x730e), (0x7313, 0x7313), (0x7315, 0x7317),
+    (0x731b, 0x731e), (0x7321, 0x7322), (0x7325, 0x7325), (0x7329, 0x732c), (0x732e, 0x732e),
+    (0x7331, 0x7331), (0x7334, 0x7334), (0x7337, 0x7339), (0x733e, 0x733f), (0x734d, 0x734d),
+    (0x7350, 0x7350), (0x7352, 0x7352), (0x7357, 0x7357), (0x7360, 0x7360), (0x736c, 0x736d),
+    (0x736f, 0x736f), (0x737e, 0x737e), (0x7384, 0x7384), (0x7387, 0x7387), (0x7389, 0x7389),
+    (0x738b, 0x738b), (0x738e, 0x738e), (0x7391, 0x7391), (0x7396, 0x7396), (0x739b, 0x739b),
+    (0x739f, 0x739f), (0x73a2, 0x73a2), (0x73a9, 0x73a9), (0x73ab, 0x73ab), (0x73ae, 0x73b0),
+    (0x73b2, 0x73b3), (0x73b7, 0x73b7), (0x73ba, 0x73bb), (0x73c0, 0x73c0), (0x73c2, 0x73c2),
+    (0x73c8, 0x73ca), (0x73cd, 0x73cd), (0x73cf, 0x73d1), (0x73d9, 0x73d9), (0x73de, 0x73de),
+    (0x73e0, 0x73e0), (0x73e5, 0x73e5), (0x73e7, 0x73e7), (0x73e9, 0x73e9), (0x73ed, 0x73ed),
+    (0x73f2, 0x73f2), (0x7403, 0x7403), (0x7405, 0x7406), (0x7409, 0x740a), (0x740f, 0x7410),
+    (0x741a, 0x741b), (0x7422, 0x7422), (0x7425, 0x7426), (0x7428, 0x7428), (0x742a, 0x742a),
+    (0x742c, 0x742c), (0x742e, 0x742e), (0x7430, 0x7430), (0x7433, 0x7436), (0x743c, 0x743c),
+    (0x7441, 0x7441), (0x7455, 0x7455), (0x7457, 0x7457), (0x7459, 0x745c), (0x745e, 0x745f),
+    (0x746d, 0x746d), (0x7470, 0x7470), (0x7476, 0x7477), (0x747e, 0x747e), (0x7480, 0x7481),
+    (0x7483, 0x7483), (0x7487, 0x7487), (0x748b, 0x748b), (0x748e, 0x748e), (0x7490, 0x7490),
+    (0x749c, 0x749c), (0x749e, 0x749e), (0x74a7, 0x74a9), (0x74ba, 0x74ba), (0x74d2, 0x74d2),
+    (0x74dc, 0x74dc), (0x74de, 0x74de), (0x74e0, 0x74e0), (0x74e2, 0x74e4), (0x74e6, 0x74e6),
+    (0x74ee, 0x74ef), (0x74f4, 0x74f4), (0x74f6, 0x74f7), (0x74ff, 0x74ff), (0x7504, 0x7504),
+    (0x750d, 0x750d), (0x750f, 0x750f), (0x7511, 0x7511), (0x7513, 0x7513), (0x7518, 0x751a),
+    (0x751c, 0x751c), (0x751f, 0x751f), (0x7525, 0x7525), (0x7528, 0x7529), (0x752b, 0x752d),
+    (0x752f, 0x7533), (0x7535, 0x7535), (0x7537, 0x7538), (0x753a, 0x753b), (0x753e, 0x753e),
+    (0x7540, 0x7540), (0x7545, 0x7545), (0x7548, 0x7548), (0x754b, 0x754c), (0x754e, 0x754f),
+    (0x7554, 0x7554), (0x7559, 0x755c), (0x7565, 0x7566), (0x756a, 0x756a), (0x7572, 0x7572),
+    (0x7574, 0x7574), (0x7578, 0x7579), (0x757f, 0x757f), (0x7583, 0x7583), (0x7586, 0x7586),
+    (0x758b, 0x758b), (0x758f, 0x758f), (0x7591, 0x7592), (0x7594, 0x7594), (0x7596, 0x7597),
+    (0x7599, 0x759a), (0x759d, 0x759d), (0x759f, 0x75a1), (0x75a3, 0x75a5), (0x75ab, 0x75ac),
+    (0x75ae, 0x75b5), (0x75b8, 0x75b9), (0x75bc, 0x75be), (0x75c2, 0x75c5), (0x75c7, 0x75ca),
+    (0x75cd, 0x75cd), (0x75d2, 0x75d2), (0x75d4, 0x75d6), (0x75d8, 0x75d8), (0x75db, 0x75db),
+    (0x75de, 0x75de), (0x75e2, 0x75e4), (0x75e6, 0x75e8), (0x75ea, 0x75eb), (0x75f0, 0x75f1),
+    (0x75f4, 0x75f4), (0x75f9, 0x75f9), (0x75fc, 0x75fc), (0x75ff, 0x7601), (0x7603, 0x7603),
+    (0x7605, 0x7605), (0x760a, 0x760a), (0x760c, 0x760c), (0x7610, 0x7610), (0x7615, 0x7615),
+    (0x7617, 0x7619), (0x761b, 0x761b), (0x761f, 0x7620), (0x7622, 0x7622), (0x7624, 0x7626),
+    (0x7629, 0x762b), (0x762d, 0x762d), (0x7630, 0x7630), (0x7633, 0x7635), (0x7638, 0x7638),
+    (0x763c, 0x763c), (0x763e, 0x7640), (0x7643, 0x7643), (0x764c, 0x764d), (0x7654, 0x7654),
+    (0x7656, 0x7656), (0x765c, 0x765c), (0x765e, 0x765e), (0x7663, 0x7663), (0x766b, 0x766b),
+    (0x766f, 0x766f), (0x7678, 0x7678), (0x767b, 0x767b), (0x767d, 0x767e), (0x7682, 0x7682),
+    (0x7684, 0x7684), (0x7686, 0x7688), (0x768b, 0x768b), (0x768e, 0x768e), (0x7691, 0x7691),
+    (0x7693, 0x7693), (0x7696, 0x7696), (0x7699, 0x7699), (0x76a4, 0x76a4), (0x76ae, 0x76ae),
+    (0x76b1, 0x76b2), (0x76b4, 0x76b4), (0x76bf, 0x76bf), (0x76c2, 0x76c2), (0x76c5, 0x76c6),
+    (0x76c8, 0x76c8), (0x76ca, 0x76ca), (0x76cd, 0x76d2), (0x76d4, 0x76d4), (0x76d6, 0x76d8),
+    (0x76db, 0x76db), (0x76df, 0x76df), (0x76e5, 0x76e5), (0x76ee, 0x76ef), (0x76f1, 0x76f2),
+    (0x76f4, 0x76f4), (0x76f8, 0x76f9), (0x76fc, 0x76fc), (0x76fe, 0x76fe), (0x7701, 0x7701),
+    (0x7704, 0x7704), (0x7707, 0x7709), (0x770b, 0x770b), (0x770d, 0x770d), (0x7719, 0x771a),
+    (0x771f, 0x7720), (0x7722, 0x7722), (0x7726, 0x7726), (0x7728, 0x7729), (0x772d, 0x772d),
+    (0x772f, 0x772f), (0x7735, 0x7738), (0x773a, 0x773a), (0x773c, 0x773c), (0x7740, 0x7741),
+    (0x7743, 0x7743), (0x7747, 0x7747), (0x7750, 0x7751), (0x775a, 0x775b), (0x7761, 0x7763),
+    (0x7765, 0x7766), (0x7768, 0x7768), (0x776b, 0x776c), (0x7779, 0x7779), (0x777d, 0x7780),
+    (0x7784, 0x7785), (0x778c, 0x778e), (0x7791, 0x7792), (0x779f, 0x77a0), (0x77a2, 0x77a2),
+    (0x77a5, 0x77a5), (0x77a7, 0x77a7), (0x77a9, 0x77aa), (0x77ac, 0x77ac), (0x77b0, 0x77b0),
+    (0x77b3, 0x77b3), (0x77b5, 0x77b5), (0x77bb, 0x77bb), (0x77bd, 0x77bd), (0x77bf, 0x77bf),
+    (0x77cd, 0x77cd), (0x77d7, 0x77d7), (0x77db, 0x77dc), (0x77e2, 0x77e3), (0x77e5, 0x77e5),
+    (0x77e7, 0x77e7), (0x77e9, 0x77e9), (0x77eb, 0x77ee), (0x77f3, 0x77f3), (0x77f6, 0x77f6),
+    (0x77f8, 0x77f8), (0x77fd, 0x7802), (0x7809, 0x7809), (0x780c, 0x780d), (0x7811, 0x7812),
+    (0x7814, 0x7814), (0x7816, 0x7818), (0x781a, 0x781a), (0x781c, 0x781d), (0x781f, 0x781f),
+    (0x7823, 0x7823), (0x7825, 0x7827), (0x7829, 0x7829), (0x782c, 0x782d), (0x7830, 0x7830),
+    (0x7834, 0x7834), (0x7837, 0x783c), (0x783e, 0x783e), (0x7840, 0x7840), (0x7845, 0x7845),
+    (0x7847, 0x7847), (0x784c, 0x784c), (0x784e, 0x784e), (0x7850, 0x7850), (0x7852, 0x7852),
+    (0x7855, 0x7857), (0x785d, 0x785d), (0x786a, 0x786e), (0x7877, 0x7877), (0x787c, 0x787c),
+    (0x7887, 0x7887), (0x7889, 0x7889), (0x788c, 0x788e), (0x7891, 0x7891), (0x7893, 0x7893),
+    (0x7897, 0x7898), (0x789a, 0x789c), (0x789f, 0x789f), (0x78a1, 0x78a1), (0x78a3, 0x78a3),
+    (0x78a5, 0x78a5), (0x78a7, 0x78a7), (0x78b0, 0x78b4), (0x78b9, 0x78b9), (0x78be, 0x78be),
+    (0x78c1, 0x78c1), (0x78c5, 0x78c5), (0x78c9, 0x78cb), (0x78d0, 0x78d0), (0x78d4, 0x78d5),
+    (0x78d9, 0x78d9), (0x78e8, 0x78e8), (0x78ec, 0x78ec), (0x78f2, 0x78f2), (0x78f4, 0x78f4),
+    (0x78f7, 0x78f7), (0x78fa, 0x78fa), (0x7901, 0x7901), (0x7905, 0x7905), (0x7913, 0x7913),
+    (0x791e, 0x791e), (0x7924, 0x7924), (0x7934, 0x7934), (0x793a, 0x793c), (0x793e, 0x793e),
+    (0x7940, 0x7941), (0x7946, 0x7946), (0x7948, 0x7949), (0x7953, 0x7953), (0x7956, 0x7957),
+    (0x795a, 0x7960), (0x7962, 0x7962), (0x7965, 0x7965), (0x7967, 0x7968), (0x796d, 0x796d),
+    (0x796f, 0x796f), (0x7977, 0x7978), (0x797a, 0x797a), (0x7980, 0x7981), (0x7984, 0x7985),
+    (0x798a, 0x798a), (0x798f, 0x798f), (0x799a, 0x799a), (0x79a7, 0x79a7), (0x79b3, 0x79b3),
+    (0x79b9, 0x79bb), (0x79bd, 0x79be), (0x79c0, 0x79c1), (0x79c3, 0x79c3), (0x79c6, 0x79c6),
+    (0x79c9, 0x79c9), (0x79cb, 0x79cb), (0x79cd, 0x79cd), (0x79d1, 0x79d2), (0x79d5, 0x79d5),
+    (0x79d8, 0x79d8), (0x79df, 0x79df), (0x79e3, 0x79e4), (0x79e6, 0x79e7), (0x79e9, 0x79e9),
+    (0x79eb, 0x79eb), (0x79ed, 0x79ed), (0x79ef, 0x79f0), (0x79f8, 0x79f8), (0x79fb, 0x79fb),
+    (0x79fd, 0x79fd), (0x7a00, 0x7a00), (0x7a02, 0x7a03), (0x7a06, 0x7a06), (0x7a0b, 0x7a0b),
+    (0x7a0d, 0x7a0e), (0x7a14, 0x7a14), (0x7a17, 0x7a17), (0x7a1a, 0x7a1a), (0x7a1e, 0x7a1e),
+    (0x7a20, 0x7a20), (0x7a23, 0x7a23), (0x7a33, 0x7a33), (0x7a37, 0x7a37), (0x7a39, 0x7a39),
+    (0x7a3b, 0x7a3d), (0x7a3f, 0x7a3f), (0x7a46, 0x7a46), (0x7a51, 0x7a51), (0x7a57, 0x7a57),
+    (0x7a70, 0x7a70), (0x7a74, 0x7a74), (0x7a76, 0x7a7a), (0x7a7f, 0x7a81), (0x7a83, 0x7a84),
+    (0x7a86, 0x7a86), (0x7a88, 0x7a88), (0x7a8d, 0x7a8d), (0x7a91, 0x7a92), (0x7a95, 0x7a98),
+    (0x7a9c, 0x7a9d), (0x7a9f, 0x7aa0), (0x7aa5, 0x7aa6), (0x7aa8, 0x7aa8), (0x7aac, 0x7aad),
+    (0x7ab3, 0x7ab3), (0x7abf, 0x7abf), (0x7acb, 0x7acb), (0x7ad6, 0x7ad6), (0x7ad9, 0x7ad9),
+    (0x7ade, 0x7ae0), (0x7ae3, 0x7ae3), (0x7ae5, 0x7ae6), (0x7aed, 0x7aed), (0x7aef, 0x7aef),
+    (0x7af9, 0x7afa), (0x7afd, 0x7afd), (0x7aff, 0x7aff), (0x7b03, 0x7b04), (0x7b06, 0x7b06),
+    (0x7b08, 0x7b08), (0x7b0a, 0x7b0b), (0x7b0f, 0x7b0f), (0x7b11, 0x7b11), (0x7b14, 0x7b15),
+    (0x7b19, 0x7b19), (0x7b1b, 0x7b1b), (0x7b1e, 0x7b1e), (0x7b20, 0x7b20), (0x7b24, 0x7b26),
+    (0x7b28, 0x7b28), (0x7b2a, 0x7b2c), (0x7b2e, 0x7b2e), (0x7b31, 0x7b31), (0x7b33, 0x7b33),
+    (0x7b38, 0x7b38), (0x7b3a, 0x7b3a), (0x7b3c, 0x7b3c), (0x7b3e, 0x7b3e), (0x7b45, 0x7b45),
+    (0x7b47, 0x7b47), (0x7b49, 0x7b49), (0x7b4b, 0x7b4c), (0x7b4f, 0x7b52), (0x7b54, 0x7b54),
+    (0x7b56, 0x7b56), (0x7b58, 0x7b58), (0x7b5a, 0x7b5b), (0x7b5d, 0x7b5d), (0x7b60, 0x7b60),
+    (0x7b62, 0x7b62), (0x7b6e, 0x7b6e), (0x7b71, 0x7b72), (0x7b75, 0x7b75), (0x7b77, 0x7b77),
+    (0x7b79, 0x7b79), (0x7b7b, 0x7b7b), (0x7b7e, 0x7b7e), (0x7b80, 0x7b80), (0x7b85, 0x7b85),
+    (0x7b8d, 0x7b8d), (0x7b90, 0x7b90), (0x7b94, 0x7b95), (0x7b97, 0x7b97), (0x7b9c, 0x7b9d),
+    (0x7ba1, 0x7ba2), (0x7ba6, 0x7bad), (0x7bb1, 0x7bb1), (0x7bb4, 0x7bb4), (0x7bb8, 0x7bb8),
+    (0x7bc1, 0x7bc1), (0x7bc6, 0x7bc7), (0x7bcc, 0x7bcc), (0x7bd1, 0x7bd1), (0x7bd3, 0x7bd3),
+    (0x7bd9, 0x7bda), (0x7bdd, 0x7bdd), (0x7be1, 0x7be1), (0x7be5, 0x7be6), (0x7bea, 0x7bea),
+    (0x7bee, 0x7bee), (0x7bf1, 0x7bf1), (0x7bf7, 0x7bf7), (0x7bfc, 0x7bfc), (0x7bfe, 0x7bfe),
+    (0x7c07, 0x7c07), (0x7c0b, 0x7c0c), (0x7c0f, 0x7c0f), (0x7c16, 0x7c16), (0x7c1f, 0x7c1f),
+    (0x7c26, 0x7c27), (0x7c2a, 0x7c2a), (0x7c38, 0x7c38), (0x7c3f, 0x7c41), (0x7c4d, 0x7c4d),
+    (0x7c73, 0x7c74), (0x7c7b, 0x7c7d), (0x7c89, 0x7c89), (0x7c91, 0x7c92), (0x7c95, 0x7c95),
+    (0x7c97, 0x7c98), (0x7c9c, 0x7c9f), (0x7ca2, 0x7ca2), (0x7ca4, 0x7ca5), (0x7caa, 0x7caa),
+    (0x7cae, 0x7cae), (0x7cb1, 0x7cb3), (0x7cb9, 0x7cb9), (0x7cbc, 0x7cbe), (0x7cc1, 0x7cc1),
+    (0x7cc5, 0x7cc5), (0x7cc7, 0x7cc8), (0x7cca, 0x7cca), (0x7ccc, 0x7ccd), (0x7cd5, 0x7cd7),
+    (0x7cd9, 0x7cd9), (0x7cdc, 0x7cdc), (0x7cdf, 0x7ce0), (0x7ce8, 0x7ce8), (0x7cef, 0x7cef),
+    (0x7cf8, 0x7cf8), (0x7cfb, 0x7cfb), (0x7d0a, 0x7d0a), (0x7d20, 0x7d20), (0x7d22, 0x7d22),
+    (0x7d27, 0x7d27), (0x7d2b, 0x7d2b), (0x7d2f, 0x7d2f), (0x7d6e, 0x7d6e), (0x7d77, 0x7d77),
+    (0x7da6, 0x7da6), (0x7dae, 0x7dae), (0x7e3b, 0x7e3b), (0x7e41, 0x7e41), (0x7e47, 0x7e47),
+    (0x7e82, 0x7e82), (0x7e9b, 0x7e9b), (0x7e9f, 0x7ead), (0x7eaf, 0x7eb3), (0x7eb5, 0x7eba),
+    (0x7ebd, 0x7ed5), (0x7ed7, 0x7ee3), (0x7ee5, 0x7eeb), (0x7eed, 0x7ef8), (0x7efa, 0x7f09),
+    (0x7f0b, 0x7f0f), (0x7f11, 0x7f1d), (0x7f1f, 0x7f36), (0x7f38, 0x7f38), (0x7f3a, 0x7f3a),
+    (0x7f42, 0x7f42), (0x7f44, 0x7f45), (0x7f50, 0x7f51), (0x7f54, 0x7f55), (0x7f57, 0x7f58),
+    (0x7f5a, 0x7f5a), (0x7f5f, 0x7f5f), (0x7f61, 0x7f62), (0x7f68, 0x7f6a), (0x7f6e, 0x7f6e),
+    (0x7f71, 0x7f72), (0x7f74, 0x7f74), (0x7f79, 0x7f79), (0x7f7e, 0x7f7e), (0x7f81, 0x7f81),
+    (0x7f8a, 0x7f8a), (0x7f8c, 0x7f8c), (0x7f8e, 0x7f8e), (0x7f94, 0x7f94), (0x7f9a, 0x7f9a),
+    (0x7f9d, 0x7f9f), (0x7fa1, 0x7fa1), (0x7fa4, 0x7fa4), (0x7fa7, 0x7fa7), (0x7faf, 0x7fb0),
+    (0x7fb2, 0x7fb2), (0x7fb8, 0x7fb9), (0x7fbc, 0x7fbd), (0x7fbf, 0x7fbf), (0x7fc1, 0x7fc1),
+    (0x7fc5, 0x7fc5), (0x7fca, 0x7fca), (0x7fcc, 0x7fcc), (0x7fce, 0x7fce), (0x7fd4, 0x7fd5),
+    (0x7fd8, 0x7fd8), (0x7fdf, 0x7fe1), (0x7fe5, 0x7fe6), (0x7fe9, 0x7fe9), (0x7fee, 0x7fee),
+    (0x7ff0, 0x7ff1), (0x7ff3, 0x7ff3), (0x7ffb, 0x7ffc), (0x8000, 0x8001), (0x8003, 0x8006),
+    (0x800b, 0x800d), (0x8010, 0x8010), (0x8012, 0x8012), (0x8014, 0x8019), (0x801c, 0x801c),
+    (0x8020, 0x8020), (0x8022, 0x8022), (0x8025, 0x802a), (0x8031, 0x8031), (0x8033, 0x8033),
+    (0x8035, 0x8038), (0x803b, 0x803b), (0x803d, 0x803d), (0x803f, 0x803f), (0x8042, 0x8043),
+    (0x8046, 0x8046), (0x804a, 0x804d), (0x8052, 0x8052), (0x8054, 0x8054), (0x8058, 0x8058),
+    (0x805a, 0x805a), (0x8069, 0x806a), (0x8071, 0x8071), (0x807f, 0x8080), (0x8083, 0x8084),
+    (0x8086, 0x8087), (0x8089, 0x8089), (0x808b, 0x808c), (0x8093, 0x8093), (0x8096, 0x8096),
+    (0x8098, 0x8098), (0x809a, 0x809d), (0x809f, 0x80a2), (0x80a4, 0x80a5), (0x80a9, 0x80ab),
+    (0x80ad, 0x80af), (0x80b1, 0x80b2), (0x80b4, 0x80b4), (0x80b7, 0x80b7), (0x80ba, 0x80ba),
+    (0x80bc, 0x80c4), (0x80c6, 0x80c6), (0x80cc, 0x80ce), (0x80d6, 0x80d7), (0x80d9, 0x80de),
+    (0x80e1, 0x80e1), (0x80e4, 0x80e5), (0x80e7, 0x80ed), (0x80ef, 0x80f4), (0x80f6, 0x80f6),
+    (0x80f8, 0x80f8), (0x80fa, 0x80fa), (0x80fc, 0x80fd), (0x8102, 0x8102), (0x8106, 0x8106),
+    (0x8109, 0x810a), (0x810d, 0x8114), (0x8116, 0x8116), (0x8118, 0x8118), (0x811a, 0x811a),
+    (0x811e, 0x811e), (0x812c, 0x812c), (0x812f, 0x812f), (0x8131, 0x8132), (0x8136, 0x8136),
+    (0x8138, 0x8138), (0x813e, 0x813e), (0x8146, 0x8146), (0x8148, 0x8148), (0x814a, 0x814c),
+    (0x8150, 0x8151), (0x8153, 0x8155), (0x8159, 0x815a), (0x8160, 0x8160), (0x8165, 0x8165),
+    (0x8167, 0x8167), (0x8169, 0x8169), (0x816d, 0x816e), (0x8170, 0x8171), (0x8174, 0x8174),
+    (0x8179, 0x8180), (0x8182, 0x8182), (0x8188, 0x8188), (0x818a, 0x818a), (0x818f, 0x818f),
+    (0x8191, 0x8191), (0x8198, 0x8198), (0x819b, 0x819d), (0x81a3, 0x81a3), (0x81a6, 0x81a6),
+    (0x81a8, 0x81a8), (0x81aa, 0x81aa), (0x81b3, 0x81b3), (0x81ba, 0x81bb), (0x81c0, 0x81c3),
+    (0x81c6, 0x81c6), (0x81ca, 0x81ca), (0x81cc, 0x81cc), (0x81e3, 0x81e3), (0x81e7, 0x81e7),
+    (0x81ea, 0x81ea), (0x81ec, 0x81ed), (0x81f3, 0x81f4), (0x81fb, 0x81fc), (0x81fe, 0x81fe),
+    (0x8200, 0x8202), (0x8204, 0x8206), (0x820c, 0x820d), (0x8210, 0x8210), (0x8212, 0x8212),
+    (0x8214, 0x8214), (0x821b, 0x821c), (0x821e, 0x821f), (0x8221, 0x8223), (0x8228, 0x8228),
+    (0x822a, 0x822d), (0x822f, 0x8231), (0x8233, 0x8239), (0x823b, 0x823b), (0x823e, 0x823e),
+    (0x8244, 0x8244), (0x8247, 0x8247), (0x8249, 0x8249), (0x824b, 0x824b), (0x824f, 0x824f),
+    (0x8258, 0x8258), (0x825a, 0x825a), (0x825f, 0x825f), (0x8268, 0x8268), (0x826e, 0x8270),
+    (0x8272, 0x8274), (0x8279, 0x827a), (0x827d, 0x827f), (0x8282, 0x8282), (0x8284, 0x8284),
+    (0x8288, 0x8288), (0x828a, 0x828b), (0x828d, 0x828f), (0x8291, 0x8292), (0x8297, 0x8299),
+    (0x829c, 0x829d), (0x829f, 0x829f), (0x82a1, 0x82a1), (0x82a4, 0x82a6), (0x82a8, 0x82b1),
+    (0x82b3, 0x82b4), (0x82b7, 0x82b9), (0x82bd, 0x82be), (0x82c1, 0x82c1), (0x82c4, 0x82c4),
+    (0x82c7, 0x82c8), (0x82ca, 0x82cf), (0x82d1, 0x82d5), (0x82d7, 0x82d8), (0x82db, 0x82dc),
+    (0x82de, 0x82e1), (0x82e3, 0x82e6), (0x82eb, 0x82eb), (0x82ef, 0x82ef), (0x82f1, 0x82f1),
+    (0x82f4, 0x82f4), (0x82f7, 0x82f7), (0x82f9, 0x82f9), (0x82fb, 0x82fb), (0x8301, 0x8309),
+    (0x830c, 0x830c), (0x830e, 0x830f), (0x8311, 0x8311), (0x8314, 0x8315), (0x8317, 0x8317),
+    (0x831a, 0x831c), (0x8327, 0x8328), (0x832b, 0x832d), (0x832f, 0x832f), (0x8331, 0x8331),
+    (0x8333, 0x8336), (0x8338, 0x833a), (0x833c, 0x833c), (0x8340, 0x8340), (0x8343, 0x8343),
+    (0x8346, 0x8347), (0x8349, 0x8349), (0x834f, 0x8352), (0x8354, 0x8354), (0x835a, 0x835c),
+    (0x835e, 0x8361), (0x8363, 0x836f), (0x8377, 0x8378), (0x837b, 0x837d), (0x8385, 0x8386),
+    (0x8389, 0x8389), (0x838e, 0x838e), (0x8392, 0x8393), (0x8398, 0x8398), (0x839b, 0x839c),
+    (0x839e, 0x839e), (0x83a0, 0x83a0), (0x83a8, 0x83ab), (0x83b0, 0x83b4), (0x83b6, 0x83ba),
+    (0x83bc, 0x83bd), (0x83c0, 0x83c1), (0x83c5, 0x83c5), (0x83c7, 0x83c7), (0x83ca, 0x83ca),
+    (0x83cc, 0x83cc), (0x83cf, 0x83cf), (0x83d4, 0x83d4), (0x83d6, 0x83d6), (0x83d8, 0x83d8),
+    (0x83dc, 0x83dd), (0x83df, 0x83e1), (0x83e5, 0x83e5), (0x83e9, 0x83ea), (0x83f0, 0x83f2),
+    (0x83f8, 0x83f9), (0x83fd, 0x83fd), (0x8401, 0x8401), (0x8403, 0x8404), (0x8406, 0x8406),
+    (0x840b, 0x840f), (0x8411, 0x8411), (0x8418, 0x8418), (0x841c, 0x841d), (0x8424, 0x8428),
+    (0x8431, 0x8431), (0x8438, 0x8438), (0x843c, 0x843d), (0x8446, 0x8446), (0x8451, 0x8451),
+    (0x8457, 0x8457), (0x8459, 0x845c), (0x8461, 0x8461), (0x8463, 0x8463), (0x8469, 0x8469),
+    (0x846b, 0x846d), (0x8471, 0x8471), (0x8473, 0x8473), (0x8475, 0x8476), (0x8478, 0x8478),
+    (0x847a, 0x847a), (0x8482, 0x8482), (0x8487, 0x8489), (0x848b, 0x848c), (0x848e, 0x848e),
+    (0x8497, 0x8497), (0x8499, 0x8499), (0x849c, 0x849c), (0x84a1, 0x84a1), (0x84af, 0x84af),
+    (0x84b2, 0x84b2), (0x84b4, 0x84b4), (0x84b8, 0x84ba), (0x84bd, 0x84bd), (0x84bf, 0x84bf),
+    (0x84c1, 0x84c1), (0x84c4, 0x84c4), (0x84c9, 0x84ca), (0x84cd, 0x84cd), (0x84d0, 0x84d1),
+    (0x84d3, 0x84d3), (0x84d6, 0x84d6), (0x84dd, 0x84dd), (0x84df, 0x84e0), (0x84e3, 0x84e3),
+    (0x84e5, 0x84e6), (0x84ec, 0x84ec), (0x84f0, 0x84f0), (0x84fc, 0x84fc), (0x84ff, 0x84ff),
+    (0x850c, 0x850c), (0x8511, 0x8511), (0x8513, 0x8513), (0x8517, 0x8517), (0x851a, 0x851a),
+    (0x851f, 0x851f), (0x8521, 0x8521), (0x852b, 0x852c), (0x8537, 0x853d), (0x8543, 0x8543),
+    (0x8548, 0x854a), (0x8556, 0x8556), (0x8559, 0x8559), (0x855e, 0x855e), (0x8564, 0x8564),
+    (0x8568, 0x8568), (0x8572, 0x8572), (0x8574, 0x8574), (0x8579, 0x857b), (0x857e, 0x857e),
+    (0x8584, 0x8585), (0x8587, 0x8587), (0x858f, 0x858f), (0x859b, 0x859c), (0x85a4, 0x85a4),
+    (0x85a8, 0x85a8), (0x85aa, 0x85aa), (0x85ae, 0x85b0), (0x85b7, 0x85b7), (0x85b9, 0x85b9),
+    (0x85c1, 0x85c1), (0x85c9, 0x85c9), (0x85cf, 0x85d0), (0x85d3, 0x85d3), (0x85d5, 0x85d5),
+    (0x85dc, 0x85dc), (0x85e4, 0x85e4), (0x85e9, 0x85e9), (0x85fb, 0x85fb), (0x85ff, 0x85ff),
+    (0x8605, 0x8605), (0x8611, 0x8611), (0x8616, 0x8616), (0x8627, 0x8627), (0x8629, 0x8629),
+    (0x8638, 0x8638), (0x863c, 0x863c), (0x864d, 0x8651), (0x8654, 0x8654), (0x865a, 0x865a),
+    (0x865e, 0x865e), (0x8662, 0x8662), (0x866b, 0x866c), (0x866e, 0x866e), (0x8671, 0x8671),
+    (0x8679, 0x8682), (0x868a, 0x868d), (0x8693, 0x8693), (0x8695, 0x8695), (0x869c, 0x869d),
+    (0x86a3, 0x86a4), (0x86a7, 0x86aa), (0x86ac, 0x86ac), (0x86af, 0x86b1), (0x86b4, 0x86b6),
+    (0x86ba, 0x86ba), (0x86c0, 0x86c0), (0x86c4, 0x86c4), (0x86c6, 0x86c7), (0x86c9, 0x86cb),
+    (0x86ce, 0x86d1), (0x86d4, 0x86d4), (0x86d8, 0x86d9), (0x86db, 0x86db), (0x86de, 0x86df),
+    (0x86e4, 0x86e4), (0x86e9, 0x86e9), (0x86ed, 0x86ee), (0x86f0, 0x86f4), (0x86f8, 0x86f9),
+    (0x86fe, 0x86fe), (0x8700, 0x8700), (0x8702, 0x8703), (0x8707, 0x870a), (0x870d, 0x870d),
+    (0x8712, 0x8713), (0x8715, 0x8715), (0x8717, 0x8718), (0x871a, 0x871a), (0x871c, 0x871c),
+    (0x871e, 0x871e), (0x8721, 0x8723), (0x8725, 0x8725), (0x8729, 0x8729), (0x872e, 0x872e),
+    (0x8731, 0x8731), (0x8734, 0x8734), (0x8737, 0x8737), (0x873b, 0x873b), (0x873e, 0x873f),
+    (0x8747, 0x8749), (0x874c, 0x874c), (0x874e, 0x874e), (0x8753, 0x8753), (0x8757, 0x8757),
+    (0x8759, 0x8759), (0x8760, 0x8760), (0x8763, 0x8765), (0x876e, 0x876e), (0x8770, 0x8770),
+    (0x8774, 0x8774), (0x8776, 0x8776), (0x877b, 0x877e), (0x8782, 0x8783), (0x8785, 0x8785),
+    (0x8788, 0x8788), (0x878b, 0x878b), (0x878d, 0x878d), (0x8793, 0x8793), (0x8797, 0x8797),
+    (0x879f, 0x879f), (0x87a8, 0x87a8), (0x87ab, 0x87ad), (0x87af, 0x87af), (0x87b3, 0x87b3),
+    (0x87b5, 0x87b5), (0x87ba, 0x87ba), (0x87bd, 0x87bd), (0x87c0, 0x87c0), (0x87c6, 0x87c6),
+    (0x87ca, 0x87cb), (0x87d1, 0x87d3), (0x87db, 0x87db), (0x87e0, 0x87e0), (0x87e5, 0x87e5),
+    (0x87ea, 0x87ea), (0x87ee, 0x87ee), (0x87f9, 0x87f9), (0x87fe, 0x87fe), (0x8803, 0x8803),
+    (0x880a, 0x880a), (0x8813, 0x8813), (0x8815, 0x8816), (0x881b, 0x881b), (0x8821, 0x8822),
+    (0x8832, 0x8832), (0x8839, 0x8839), (0x883c, 0x883c), (0x8840, 0x8840), (0x8844, 0x8845),
+    (0x884c, 0x884d), (0x8854, 0x8854), (0x8857, 0x8857), (0x8859, 0x8859), (0x8861, 0x8865),
+    (0x8868, 0x8869), (0x886b, 0x886c), (0x886e, 0x886e), (0x8870, 0x8870), (0x8872, 0x8872),
+    (0x8877, 0x8877), (0x887d, 0x887f), (0x8881, 0x8882), (0x8884, 0x8885), (0x8888, 0x8888),
+    (0x888b, 0x888b), (0x888d, 0x888d), (0x8892, 0x8892), (0x8896, 0x8896), (0x889c, 0x889c),
+    (0x88a2, 0x88a2), (0x88a4, 0x88a4), (0x88ab, 0x88ab), (0x88ad, 0x88ad), (0x88b1, 0x88b1),
+    (0x88b7, 0x88b7), (0x88bc, 0x88bc), (0x88c1, 0x88c2), (0x88c5, 0x88c6), (0x88c9, 0x88c9),
+    (0x88ce, 0x88ce), (0x88d2, 0x88d2), (0x88d4, 0x88d5), (0x88d8, 0x88d9), (0x88df, 0x88df),
+    (0x88e2, 0x88e5), (0x88e8, 0x88e8), (0x88f0, 0x88f1), (0x88f3, 0x88f4), (0x88f8, 0x88f9),
+    (0x88fc, 0x88fc), (0x88fe, 0x88fe), (0x8902, 0x8902), (0x890a, 0x890a), (0x8910, 0x8910),
+    (0x8912, 0x8913), (0x8919, 0x891b), (0x8921, 0x8921), (0x8925, 0x8925), (0x892a, 0x892b),
+    (0x8930, 0x8930), (0x8934, 0x8934), (0x8936, 0x8936), (0x8941, 0x8941), (0x8944, 0x8944),
+    (0x895e, 0x895f), (0x8966, 0x8966), (0x897b, 0x897b), (0x897f, 0x897f), (0x8981, 0x8981),
+    (0x8983, 0x8983), (0x8986, 0x8986), (0x89c1, 0x89c2), (0x89c4, 0x89cc), (0x89ce, 0x89d2),
+    (0x89d6, 0x89d6), (0x89da, 0x89da), (0x89dc, 0x89dc), (0x89de, 0x89de), (0x89e3, 0x89e3),
+    (0x89e5, 0x89e6), (0x89eb, 0x89eb), (0x89ef, 0x89ef), (0x89f3, 0x89f3), (0x8a00, 0x8a00),
+    (0x8a07, 0x8a07), (0x8a3e, 0x8a3e), (0x8a48, 0x8a48), (0x8a79, 0x8a79), (0x8a89, 0x8a8a),
+    (0x8a93, 0x8a93), (0x8b07, 0x8b07), (0x8b26, 0x8b26), (0x8b66, 0x8b66), (0x8b6c, 0x8b6c),
+    (0x8ba0, 0x8bab), (0x8bad, 0x8bb0), (0x8bb2, 0x8bba), (0x8bbc, 0x8bc6), (0x8bc8, 0x8bcf),
+    (0x8bd1, 0x8be9), (0x8beb, 0x8c08), (0x8c0a, 0x8c1d), (0x8c1f, 0x8c37), (0x8c41, 0x8c41),
+    (0x8c46, 0x8c47), (0x8c49, 0x8c49), (0x8c4c, 0x8c4c), (0x8c55, 0x8c55), (0x8c5a, 0x8c5a),
+    (0x8c61, 0x8c62), (0x8c6a, 0x8c6b), (0x8c73, 0x8c73), (0x8c78, 0x8c7a), (0x8c82, 0x8c82),
+    (0x8c85, 0x8c85), (0x8c89, 0x8c8a), (0x8c8c, 0x8c8c), (0x8c94, 0x8c94), (0x8c98, 0x8c98),
+    (0x8d1d, 0x8d1f), (0x8d21, 0x8d50), (0x8d53, 0x8d56), (0x8d58, 0x8d5e), (0x8d60, 0x8d64),
+    (0x8d66, 0x8d67), (0x8d6b, 0x8d6b), (0x8d6d, 0x8d6d), (0x8d70, 0x8d70), (0x8d73, 0x8d77),
+    (0x8d81, 0x8d81), (0x8d84, 0x8d85), (0x8d8a, 0x8d8b), (0x8d91, 0x8d91), (0x8d94, 0x8d94),
+    (0x8d9f, 0x8d9f), (0x8da3, 0x8da3), (0x8db1, 0x8db1), (0x8db3, 0x8db5), (0x8db8, 0x8db8),
+    (0x8dba, 0x8dba), (0x8dbc, 0x8dbc), (0x8dbe, 0x8dbf), (0x8dc3, 0x8dc4), (0x8dc6, 0x8dc6),
+    (0x8dcb, 0x8dcc), (0x8dce, 0x8dcf), (0x8dd1, 0x8dd1), (0x8dd6, 0x8dd7), (0x8dda, 0x8ddb),
+    (0x8ddd, 0x8ddf), (0x8de3, 0x8de4), (0x8de8, 0x8de8), (0x8dea, 0x8dec), (0x8def, 0x8def),
+    (0x8df3, 0x8df3), (0x8df5, 0x8df5), (0x8df7, 0x8dfb), (0x8dfd, 0x8dfd), (0x8e05, 0x8e05),
+    (0x8e09, 0x8e0a), (0x8e0c, 0x8e0c), (0x8e0f, 0x8e0f), (0x8e14, 0x8e14), (0x8e1d, 0x8e1f),
+    (0x8e22, 0x8e23), (0x8e29, 0x8e2a), (0x8e2c, 0x8e2c), (0x8e2e, 0x8e2f), (0x8e31, 0x8e31),
+    (0x8e35, 0x8e35), (0x8e39, 0x8e3a), (0x8e3d, 0x8e3d), (0x8e40, 0x8e42), (0x8e44, 0x8e44),
+    (0x8e47, 0x8e4b), (0x8e51, 0x8e52), (0x8e59, 0x8e59), (0x8e66, 0x8e66), (0x8e69, 0x8e69),
+    (0x8e6c, 0x8e6d), (0x8e6f, 0x8e70), (0x8e72, 0x8e72), (0x8e74, 0x8e74), (0x8e76, 0x8e76),
+    (0x8e7c, 0x8e7c), (0x8e7f, 0x8e7f), (0x8e81, 0x8e81), (0x8e85, 0x8e85), (0x8e87, 0x8e87),
+    (0x8e8f, 0x8e90), (0x8e94, 0x8e94), (0x8e9c, 0x8e9c), (0x8e9e, 0x8e9e), (0x8eab, 0x8eac),
+    (0x8eaf, 0x8eaf), (0x8eb2, 0x8eb2), (0x8eba, 0x8eba), (0x8ece, 0x8ece), (0x8f66, 0x8f69),
+    (0x8f6b, 0x8f7f), (0x8f81, 0x8f8b), (0x8f8d, 0x8f91), (0x8f93, 0x8f9c), (0x8f9e, 0x8f9f),
+    (0x8fa3, 0x8fa3), (0x8fa8, 0x8fa9), (0x8fab, 0x8fab), (0x8fb0, 0x8fb1), (0x8fb6, 0x8fb6),
+    (0x8fb9, 0x8fb9), (0x8fbd, 0x8fbe), (0x8fc1, 0x8fc2), (0x8fc4, 0x8fc5), (0x8fc7, 0x8fc8),
+    (0x8fce, 0x8fce), (0x8fd0, 0x8fd1), (0x8fd3, 0x8fd5), (0x8fd8, 0x8fd9), (0x8fdb, 0x8fdf),
+    (0x8fe2, 0x8fe2), (0x8fe4, 0x8fe6), (0x8fe8, 0x8feb), (0x8fed, 0x8fee), (0x8ff0, 0x8ff0),
+    (0x8ff3, 0x8ff3), (0x8ff7, 0x8ff9), (0x8ffd, 0x8ffd), (0x9000, 0x9006), (0x9009, 0x900b),
+    (0x900d, 0x900d), (0x900f, 0x9012), (0x9014, 0x9014), (0x9016, 0x9017), (0x901a, 0x901b),
+    (0x901d, 0x9022), (0x9026, 0x9026), (0x902d, 0x902f), (0x9035, 0x9036), (0x9038, 0x9038),
+    (0x903b, 0x903c), (0x903e, 0x903e), (0x9041, 0x9042), (0x9044, 0x9044), (0x9047, 0x9047),
+    (0x904d, 0x904d), (0x904f, 0x9053), (0x9057, 0x9058), (0x905b, 0x905b), (0x9062, 0x9063),
+    (0x9065, 0x9065), (0x9068, 0x9068), (0x906d, 0x906e), (0x9074, 0x9075), (0x907d, 0x907d),
+    (0x907f, 0x9080), (0x9082, 0x9083), (0x9088, 0x9088), (0x908b, 0x908b), (0x9091, 0x9091),
+    (0x9093, 0x9093), (0x9095, 0x9095), (0x9097, 0x9097), (0x9099, 0x9099), (0x909b, 0x909b),
+    (0x909d, 0x909d), (0x90a1, 0x90a3), (0x90a6, 0x90a6), (0x90aa, 0x90aa), (0x90ac, 0x90ac),
+    (0x90ae, 0x90b1), (0x90b3, 0x90b6), (0x90b8, 0x90bb), (0x90be, 0x90be), (0x90c1, 0x90c1),
+    (0x90c4, 0x90c5), (0x90c7, 0x90c7), (0x90ca, 0x90ca), (0x90ce, 0x90d1), (0x90d3, 0x90d3),
+    (0x90d7, 0x90d7), (0x90db, 0x90dd), (0x90e1, 0x90e2), (0x90e6, 0x90e8), (0x90eb, 0x90eb),
+    (0x90ed, 0x90ed), (0x90ef, 0x90ef), (0x90f4, 0x90f4), (0x90f8, 0x90f8), (0x90fd, 0x90fe),
+    (0x9102, 0x9102), (0x9104, 0x9104), (0x9119, 0x9119), (0x911e, 0x911e), (0x9122, 0x9123),
+    (0x912f, 0x912f), (0x9131, 0x9131), (0x9139, 0x9139), (0x9143, 0x9143), (0x9146, 0x9146),
+    (0x9149, 0x9150), (0x9152, 0x9152), (0x9157, 0x9157), (0x915a, 0x915a), (0x915d, 0x915e),
+    (0x9161, 0x9165), (0x9169, 0x916a), (0x916c, 0x916c), (0x916e, 0x9172), (0x9174, 0x9179),
+    (0x917d, 0x917f), (0x9185, 0x9185), (0x9187, 0x9187), (0x9189, 0x9189), (0x918b, 0x918d),
+    (0x9190, 0x9192), (0x919a, 0x919b), (0x91a2, 0x91a3), (0x91aa, 0x91aa), (0x91ad, 0x91af),
+    (0x91b4, 0x91b5), (0x91ba, 0x91ba), (0x91c7, 0x91c7), (0x91c9, 0x91ca), (0x91cc, 0x91cf),
+    (0x91d1, 0x91d1), (0x91dc, 0x91dc), (0x9274, 0x9274), (0x928e, 0x928e), (0x92ae, 0x92ae),
+    (0x92c8, 0x92c8), (0x933e, 0x933e), (0x936a, 0x936a), (0x938f, 0x938f), (0x93ca, 0x93ca),
+    (0x93d6, 0x93d6), (0x943e, 0x943e), (0x946b, 0x946b), (0x9485, 0x9490), (0x9492, 0x9495),
+    (0x9497, 0x9497), (0x9499, 0x94c6), (0x94c8, 0x94ce), (0x94d0, 0x94d2), (0x94d5, 0x94d9),
+    (0x94db, 0x94e5), (0x94e7, 0x94fa), (0x94fc, 0x951b), (0x951d, 0x951f), (0x9521, 0x9526),
+    (0x9528, 0x9532), (0x9534, 0x953c), (0x953e, 0x9542), (0x9544, 0x9547), (0x9549, 0x954a),
+    (0x954c, 0x9554), (0x9556, 0x9559), (0x955b, 0x955f), (0x9561, 0x956d), (0x956f, 0x9573),
+    (0x9576, 0x9576), (0x957f, 0x957f), (0x95e8, 0x95eb), (0x95ed, 0x95fe), (0x9600, 0x9606),
+    (0x9608, 0x9612), (0x9614, 0x9617), (0x9619, 0x961a), (0x961c, 0x961d), (0x961f, 0x961f),
+    (0x9621, 0x9622), (0x962a, 0x962a), (0x962e, 0x962e), (0x9631, 0x9636), (0x963b, 0x963d),
+    (0x963f, 0x9640), (0x9642, 0x9642), (0x9644, 0x9649), (0x964b, 0x964d), (0x9650, 0x9650),
+    (0x9654, 0x9655), (0x965b, 0x965b), (0x965f, 0x965f), (0x9661, 0x9662), (0x9664, 0x9664),
+    (0x9667, 0x966a), (0x966c, 0x966c), (0x9672, 0x9672), (0x9674, 0x9677), (0x9685, 0x9686),
+    (0x9688, 0x9688), (0x968b, 0x968b), (0x968d, 0x968d), (0x968f, 0x9690), (0x9694, 0x9694),
+    (0x9697, 0x9699), (0x969c, 0x969c), (0x96a7, 0x96a7), (0x96b0, 0x96b0), (0x96b3, 0x96b3),
+    (0x96b6, 0x96b6), (0x96b9, 0x96b9), (0x96bc, 0x96be), (0x96c0, 0x96c1), (0x96c4, 0x96c7),
+    (0x96c9, 0x96c9), (0x96cc, 0x96cf), (0x96d2, 0x96d2), (0x96d5, 0x96d5), (0x96e0, 0x96e0),
+    (0x96e8, 0x96ea), (0x96ef, 0x96ef), (0x96f3, 0x96f3), (0x96f6, 0x96f7), (0x96f9, 0x96f9),
+    (0x96fe, 0x96fe), (0x9700, 0x9701), (0x9704, 0x9704), (0x9706, 0x9709), (0x970d, 0x970f),
+    (0x9713, 0x9713), (0x9716, 0x9716), (0x971c, 0x971c), (0x971e, 0x971e), (0x972a, 0x972a),
+    (0x972d, 0x972d), (0x9730, 0x9730), (0x9732, 0x9732), (0x9738, 0x9739), (0x973e, 0x973e),
+    (0x9752, 0x9753), (0x9756, 0x9756), (0x9759, 0x9759), (0x975b, 0x975b), (0x975e, 0x975e),
+    (0x9760, 0x9762), (0x9765, 0x9765), (0x9769, 0x9769), (0x9773, 0x9774), (0x9776, 0x9776),
+    (0x977c, 0x977c), (0x9785, 0x9785), (0x978b, 0x978b), (0x978d, 0x978d), (0x9791, 0x9792),
+    (0x9794, 0x9794), (0x9798, 0x9798), (0x97a0, 0x97a0), (0x97a3, 0x97a3), (0x97ab, 0x97ab),
+    (0x97ad, 0x97ad), (0x97af, 0x97af), (0x97b2, 0x97b2), (0x97b4, 0x97b4), (0x97e6, 0x97e7),
+    (0x97e9, 0x97ed), (0x97f3, 0x97f3), (0x97f5, 0x97f6), (0x9875, 0x988a), (0x988c, 0x988d),
+    (0x988f, 0x9891), (0x9893, 0x9894), (0x9896, 0x9898), (0x989a, 0x98a2), (0x98a4, 0x98a7),
+    (0x98ce, 0x98ce), (0x98d1, 0x98d3), (0x98d5, 0x98d5), (0x98d8, 0x98da), (0x98de, 0x98df),
+    (0x98e7, 0x98e8), (0x990d, 0x990d), (0x9910, 0x9910), (0x992e, 0x992e), (0x9954, 0x9955),
+    (0x9963, 0x9963), (0x9965, 0x9965), (0x9967, 0x9972), (0x9974, 0x9977), (0x997a, 0x997a),
+    (0x997c, 0x997d), (0x997f, 0x9981), (0x9984, 0x9988), (0x998a, 0x998b), (0x998d, 0x998d),
+    (0x998f, 0x9999), (0x99a5, 0x99a5), (0x99a8, 0x99a8), (0x9a6c, 0x9a71), (0x9a73, 0x9a82),
+    (0x9a84, 0x9a88), (0x9a8a, 0x9a8c), (0x9a8f, 0x9a93), (0x9a96, 0x9a98), (0x9a9a, 0x9aa5),
+    (0x9aa7, 0x9aa8), (0x9ab0, 0x9ab1), (0x9ab6, 0x9ab8), (0x9aba, 0x9aba), (0x9abc, 0x9abc),
+    (0x9ac0, 0x9ac2), (0x9ac5, 0x9ac5), (0x9acb, 0x9acc), (0x9ad1, 0x9ad1), (0x9ad3, 0x9ad3),
+    (0x9ad8, 0x9ad8), (0x9adf, 0x9adf), (0x9ae1, 0x9ae1), (0x9ae6, 0x9ae6), (0x9aeb, 0x9aeb),
+    (0x9aed, 0x9aed), (0x9aef, 0x9aef), (0x9af9, 0x9af9), (0x9afb, 0x9afb), (0x9b03, 0x9b03),
+    (0x9b08, 0x9b08), (0x9b0f, 0x9b0f), (0x9b13, 0x9b13), (0x9b1f, 0x9b1f), (0x9b23, 0x9b23),
+    (0x9b2f, 0x9b2f), (0x9b32, 0x9b32), (0x9b3b, 0x9b3c), (0x9b41, 0x9b45), (0x9b47, 0x9b49),
+    (0x9b4d, 0x9b4d), (0x9b4f, 0x9b4f), (0x9b51, 0x9b51), (0x9b54, 0x9b54), (0x9c7c, 0x9c7c),
+    (0x9c7f, 0x9c7f), (0x9c81, 0x9c82), (0x9c85, 0x9c88), (0x9c8b, 0x9c8b), (0x9c8d, 0x9c8e),
+    (0x9c90, 0x9c92), (0x9c94, 0x9c95), (0x9c9a, 0x9c9c), (0x9c9e, 0x9ca9), (0x9cab, 0x9cab),
+    (0x9cad, 0x9cae), (0x9cb0, 0x9cb8), (0x9cba, 0x9cbd), (0x9cc3, 0x9cc7), (0x9cca, 0x9cd0),
+    (0x9cd3, 0x9cd9), (0x9cdc, 0x9cdf), (0x9ce2, 0x9ce2), (0x9e1f, 0x9e23), (0x9e25, 0x9e26),
+    (0x9e28, 0x9e2d), (0x9e2f, 0x9e2f), (0x9e31, 0x9e33), (0x9e35, 0x9e3a), (0x9e3d, 0x9e3f),
+    (0x9e41, 0x9e4c), (0x9e4e, 0x9e4f), (0x9e51, 0x9e51), (0x9e55, 0x9e55), (0x9e57, 0x9e58),
+    (0x9e5a, 0x9e5c), (0x9e5e, 0x9e5e), (0x9e63, 0x9e64), (0x9e66, 0x9e6d), (0x9e70, 0x9e71),
+    (0x9e73, 0x9e73), (0x9e7e, 0x9e7f), (0x9e82, 0x9e82), (0x9e87, 0x9e88), (0x9e8b, 0x9e8b),
+    (0x9e92, 0x9e93), (0x9e9d, 0x9e9d), (0x9e9f, 0x9e9f), (0x9ea6, 0x9ea6), (0x9eb4, 0x9eb4),
+    (0x9eb8, 0x9eb8), (0x9ebb, 0x9ebb), (0x9ebd, 0x9ebe), (0x9ec4, 0x9ec4), (0x9ec9, 0x9ec9),
+    (0x9ecd, 0x9ecf), (0x9ed1, 0x9ed1), (0x9ed4, 0x9ed4), (0x9ed8, 0x9ed8), (0x9edb, 0x9edd),
+    (0x9edf, 0x9ee0), (0x9ee2, 0x9ee2), (0x9ee5, 0x9ee5), (0x9ee7, 0x9ee7), (0x9ee9, 0x9eea),
+    (0x9eef, 0x9eef), (0x9ef9, 0x9ef9), (0x9efb, 0x9efc), (0x9efe, 0x9efe), (0x9f0b, 0x9f0b),
+    (0x9f0d, 0x9f0e), (0x9f10, 0x9f10), (0x9f13, 0x9f13), (0x9f17, 0x9f17), (0x9f19, 0x9f19),
+    (0x9f20, 0x9f20), (0x9f22, 0x9f22), (0x9f2c, 0x9f2c), (0x9f2f, 0x9f2f), (0x9f37, 0x9f37),
+    (0x9f39, 0x9f39), (0x9f3b, 0x9f3b), (0x9f3d, 0x9f3e), (0x9f44, 0x9f44), (0x9f50, 0x9f51),
+    (0x9f7f, 0x9f80), (0x9f83, 0x9f8c), (0x9f99, 0x9f9b), (0x9f9f, 0x9fa0),
+];
+
+#[rustfmt::skip]
+const ZH_HK: &[(Int, Int)] = &[
+    (0x3007, 0x3007), (0x344c, 0x344c), (0x3464, 0x3464), (0x3473, 0x3473), (0x347a, 0x347a),
+    (0x347d, 0x347e), (0x3493, 0x3493), (0x3496, 0x3496), (0x34a5, 0x34a5), (0x34bc, 0x34bc),
+    (0x34c1, 0x34c1), (0x34c8, 0x34c8), (0x34df, 0x34df), (0x34e4, 0x34e4), (0x34fb, 0x34fb),
+    (0x3506, 0x3506), (0x353e, 0x353e), (0x3551, 0x3551), (0x3561, 0x3561), (0x356d, 0x356d),
+    (0x3570, 0x3570), (0x3572, 0x3572), (0x3577, 0x3578), (0x3584, 0x3584), (0x3597, 0x3597),
+    (0x35a1, 0x35a1), (0x35a5, 0x35a5), (0x35ad, 0x35ad), (0x35bf, 0x35bf), (0x35c1, 0x35c1),
+    (0x35c5, 0x35c5), (0x35c7, 0x35c7), (0x35ca, 0x35ca), (0x35ce, 0x35ce), (0x35d2, 0x35d2),
+    (0x35d6, 0x35d6), (0x35db, 0x35db), (0x35f1, 0x35f3), (0x35fb, 0x35fb), (0x35fe, 0x35fe),
+    (0x3609, 0x3609), (0x361a, 0x361a), (0x3623, 0x3623), (0x362d, 0x362d), (0x3635, 0x3635),
+    (0x3639, 0x3639), (0x3647, 0x3649), (0x364e, 0x364e), (0x365f, 0x365f), (0x367a, 0x367a),
+    (0x3681, 0x3681), (0x36a5, 0x36a5), (0x36aa, 0x36aa), (0x36ac, 0x36ac), (0x36b0, 0x36b1),
+    (0x36b5, 0x36b5), (0x36b9, 0x36b9), (0x36bc, 0x36bc), (0x36c1, 0x36c1), (0x36c3, 0x36c5),
+    (0x36d3, 0x36d4), (0x36d6, 0x36d6), (0x36dd, 0x36dd), (0x36e5, 0x36e6), (0x36f5, 0x36f5),
+    (0x3703, 0x3703), (0x3708, 0x3708), (0x370a, 0x370a), (0x370d, 0x370d), (0x371c, 0x371c),
+    (0x3723, 0x3723), (0x3725, 0x3725), (0x3730, 0x3730), (0x3732, 0x3733), (0x373a, 0x373a),
+    (0x3740, 0x3740), (0x3743, 0x3743), (0x3762, 0x3762), (0x376f, 0x376f), (0x3797, 0x3797),
+    (0x37a0, 0x37a0), (0x37b9, 0x37b9), (0x37be, 0x37be), (0x37f2, 0x37f2), (0x37f8, 0x37f8),
+    (0x37fb, 0x37fb), (0x380f, 0x380f), (0x3819, 0x3819), (0x3820, 0x3820), (0x382d, 0x382d),
+    (0x3836, 0x3836), (0x3838, 0x3838), (0x3863, 0x3863), (0x38a0, 0x38a0), (0x38c3, 0x38c3),
+    (0x38cc, 0x38cc), (0x38d1, 0x38d1), (0x38fa, 0x38fa), (0x3908, 0x3908), (0x3914, 0x3914),
+    (0x3927, 0x3927), (0x3932, 0x3932), (0x393f, 0x393f), (0x394d, 0x394d), (0x3963, 0x3963),
+    (0x3980, 0x3980), (0x3989, 0x398a), (0x3992, 0x3992), (0x399b, 0x399b), (0x39a1, 0x39a1),
+    (0x39a4, 0x39a4), (0x39b8, 0x39b8), (0x39dc, 0x39dc), (0x39e2, 0x39e2), (0x39e5, 0x39e5),
+    (0x39ec, 0x39ec), (0x39f8, 0x39f8), (0x39fb, 0x39fb), (0x39fe, 0x39fe), (0x3a01, 0x3a01),
+    (0x3a03, 0x3a03), (0x3a06, 0x3a06), (0x3a17, 0x3a18), (0x3a29, 0x3a2a), (0x3a34, 0x3a34),
+    (0x3a4b, 0x3a4b), (0x3a52, 0x3a52), (0x3a57, 0x3a57), (0x3a5c, 0x3a5c), (0x3a5e, 0x3a5e),
+    (0x3a66, 0x3a67), (0x3a97, 0x3a97), (0x3aab, 0x3aab), (0x3abd, 0x3abd), (0x3ade, 0x3ade),
+    (0x3af0, 0x3af0), (0x3af2, 0x3af2), (0x3afb, 0x3afb), (0x3b0e, 0x3b0e), (0x3b19, 0x3b19),
+    (0x3b22, 0x3b22), (0x3b2b, 0x3b2b), (0x3b39, 0x3b39), (0x3b42, 0x3b42), (0x3b58, 0x3b58),
+    (0x3b60, 0x3b60), (0x3b71, 0x3b72), (0x3b7b, 0x3b7c), (0x3b80, 0x3b80), (0x3b96, 0x3b96),
+    (0x3b99, 0x3b99), (0x3ba1, 0x3ba1), (0x3bbe, 0x3bbe), (0x3bc2, 0x3bc2), (0x3bc4, 0x3bc4),
+    (0x3bd7, 0x3bd7), (0x3bdd, 0x3bdd), (0x3bec, 0x3bec), (0x3bf2, 0x3bf3), (0x3c0d, 0x3c0d),
+    (0x3c11, 0x3c11), (0x3c15, 0x3c15), (0x3c54, 0x3c54), (0x3ccb, 0x3ccb), (0x3ccd, 0x3ccd),
+    (0x3cd1, 0x3cd1), (0x3cd6, 0x3cd6), (0x3cdc, 0x3cdc), (0x3ceb, 0x3ceb), (0x3d13, 0x3d13),
+    (0x3d1d, 0x3d1d), (0x3d32, 0x3d32), (0x3d46, 0x3d46), (0x3d4c, 0x3d4c), (0x3d4e, 0x3d4e),
+    (0x3d51, 0x3d51), (0x3d5f, 0x3d5f), (0x3d62, 0x3d62), (0x3d69, 0x3d6a), (0x3d6f, 0x3d6f),
+    (0x3d75, 0x3d75), (0x3d7d, 0x3d7d), (0x3d85, 0x3d85), (0x3d8f, 0x3d8f), (0x3d91, 0x3d91),
+    (0x3da5, 0x3da5), (0x3dad, 0x3dad), (0x3db4, 0x3db4), (0x3dbf, 0x3dbf), (0x3dc6, 0x3dc7),
+    (0x3dcd, 0x3dcd), (0x3dd3, 0x3dd3), (0x3ddb, 0x3ddb), (0x3deb, 0x3deb), (0x3df3, 0x3df3),
+    (0x3df7, 0x3df7), (0x3dfc, 0x3dfc), (0x3e40, 0x3e40), (0x3e43, 0x3e43), (0x3e48, 0x3e48),
+    (0x3e55, 0x3e55), (0x3e74, 0x3e74), (0x3ea8, 0x3eaa), (0x3ead, 0x3ead), (0x3eb1, 0x3eb1),
+    (0x3eb8, 0x3eb8), (0x3ebf, 0x3ebf), (0x3ec2, 0x3ec2), (0x3eca, 0x3eca), (0x3ecc, 0x3ecc),
+    (0x3ed1, 0x3ed1), (0x3ed6, 0x3ed7), (0x3ede, 0x3ede), (0x3ee1, 0x3ee1), (0x3ee7, 0x3ee7),
+    (0x3eeb, 0x3eeb), (0x3ef0, 0x3ef0), (0x3efa, 0x3efa), (0x3eff, 0x3eff), (0x3f04, 0x3f04),
+    (0x3f0e, 0x3f0e), (0x3f58, 0x3f59), (0x3f63, 0x3f63), (0x3f93, 0x3f93), (0x3fc0, 0x3fc0),
+    (0x3fd7, 0x3fd7), (0x3fdc, 0x3fdc), (0x3fe5, 0x3fe5), (0x3fed, 0x3fed), (0x3ff9, 0x3ffa),
+    (0x4004, 0x4004), (0x4039, 0x4039), (0x4045, 0x4045), (0x4053, 0x4053), (0x4057, 0x4057),
+    (0x4062, 0x4062), (0x4065, 0x4065), (0x406a, 0x406a), (0x406f, 0x406f), (0x40bb, 0x40bb),
+    (0x40bf, 0x40bf), (0x40c8, 0x40c8), (0x40d8, 0x40d8), (0x40df, 0x40df), (0x40fa, 0x40fa),
+    (0x4103, 0x4104), (0x4109, 0x4109), (0x410e, 0x410e), (0x4132, 0x4132), (0x4167, 0x4167),
+    (0x416c, 0x416c), (0x416e, 0x416e), (0x417f, 0x417f), (0x4190, 0x4190), (0x41b2, 0x41b2),
+    (0x41cf, 0x41cf), (0x41db, 0x41db), (0x41ef, 0x41ef), (0x41f9, 0x41f9), (0x4211, 0x4211),
+    (0x4240, 0x4240), (0x4260, 0x4260), (0x426a, 0x426a), (0x427a, 0x427a), (0x4294, 0x4294),
+    (0x42a2, 0x42a2), (0x42b5, 0x42b5), (0x42b9, 0x42b9), (0x42bc, 0x42bc), (0x42f4, 0x42f4),
+    (0x42fb, 0x42fc), (0x432b, 0x432b), (0x436e, 0x436e), (0x4397, 0x4397), (0x43ba, 0x43ba),
+    (0x43c1, 0x43c1), (0x43d9, 0x43d9), (0x43df, 0x43df), (0x43ed, 0x43ed), (0x43f2, 0x43f2),
+    (0x4401, 0x4402), (0x4413, 0x4413), (0x447a, 0x447a), (0x448f, 0x448f), (0x449f, 0x44a0),
+    (0x44b0, 0x44b0), (0x44b7, 0x44b7), (0x44dd, 0x44dd), (0x44df, 0x44df), (0x44e4, 0x44e4),
+    (0x44ea, 0x44ea), (0x44f4, 0x44f4), (0x4503, 0x4504), (0x4509, 0x4509), (0x4516, 0x4516),
+    (0x4527, 0x4527), (0x452e, 0x452e), (0x4533, 0x4533), (0x453b, 0x453b), (0x453f, 0x453f),
+    (0x4543, 0x4543), (0x4551, 0x4552), (0x4555, 0x4555), (0x4562, 0x4562), (0x456a, 0x456a),
+    (0x4577, 0x4577), (0x4585, 0x4585), (0x45e9, 0x45e9), (0x4603, 0x4603), (0x4606, 0x4606),
+    (0x460f, 0x460f), (0x4615, 0x4615), (0x4617, 0x4617), (0x465b, 0x465b), (0x467a, 0x467a),
+    (0x46cf, 0x46d0), (0x46f5, 0x46f5), (0x4718, 0x4718), (0x477c, 0x477c), (0x47d5, 0x47d5),
+    (0x47ed, 0x47ed), (0x47f4, 0x47f4), (0x4800, 0x4800), (0x480b, 0x480b), (0x4871, 0x4871),
+    (0x489b, 0x489b), (0x48ad, 0x48ad), (0x48d0, 0x48d0), (0x48dd, 0x48dd), (0x48ed, 0x48ed),
+    (0x48fa, 0x48fa), (0x4906, 0x4906), (0x491e, 0x491e), (0x492a, 0x492a), (0x492d, 0x492d),
+    (0x4935, 0x4935), (0x493c, 0x493c), (0x493e, 0x493e), (0x4945, 0x4945), (0x4951, 0x4951),
+    (0x4953, 0x4953), (0x4965, 0x4965), (0x496a, 0x496a), (0x4972, 0x4972), (0x4989, 0x4989),
+    (0x49a7, 0x49a7), (0x49df, 0x49df), (0x49e5, 0x49e5), (0x4a0f, 0x4a0f), (0x4a1d, 0x4a1d),
+    (0x4a24, 0x4a24), (0x4a35, 0x4a35), (0x4a96, 0x4a96), (0x4ab4, 0x4ab4), (0x4ab8, 0x4ab8),
+    (0x4ad1, 0x4ad1), (0x4ae4, 0x4ae4), (0x4aff, 0x4aff), (0x4b19, 0x4b19), (0x4b2c, 0x4b2c),
+    (0x4b37, 0x4b37), (0x4b6f, 0x4b70), (0x4b72, 0x4b72), (0x4b7b, 0x4b7b), (0x4b7e, 0x4b7e),
+    (0x4b8e, 0x4b8e), (0x4b90, 0x4b90), (0x4b93, 0x4b93), (0x4b96, 0x4b97), (0x4b9d, 0x4b9d),
+    (0x4bbd, 0x4bbe), (0x4bc0, 0x4bc0), (0x4c04, 0x4c04), (0x4c07, 0x4c07), (0x4c0e, 0x4c0e),
+    (0x4c3b, 0x4c3b), (0x4c3e, 0x4c3e), (0x4c5b, 0x4c5b), (0x4c6d, 0x4c6d), (0x4c77, 0x4c77),
+    (0x4c7b, 0x4c7b), (0x4c7d, 0x4c7d), (0x4c81, 0x4c81), (0x4cae, 0x4cae), (0x4cb0, 0x4cb0),
+    (0x4ccd, 0x4ccd), (0x4ce1, 0x4ce1), (0x4ced, 0x4ced), (0x4d09, 0x4d09), (0x4d10, 0x4d10),
+    (0x4d34, 0x4d34), (0x4d77, 0x4d77), (0x4d91, 0x4d91), (0x4d9c, 0x4d9c), (0x4e04, 0x4e04),
+    (0x4e21, 0x4e21), (0x4e2a, 0x4e2a), (0x4e5a, 0x4e5b), (0x4e6a, 0x4e6a), (0x4e78, 0x4e78),
+    (0x4e80, 0x4e80), (0x4e85, 0x4e85), (0x4e98, 0x4e98), (0x4ece, 0x4ece), (0x4eee, 0x4eee),
+    (0x4f37, 0x4f37), (0x4fe5, 0x4fe5), (0x4ff9, 0x4ff9), (0x5008, 0x5008), (0x503b, 0x503b),
+    (0x50cd, 0x50cd), (0x510d, 0x510e), (0x516a, 0x516a), (0x5186, 0x5186), (0x519a, 0x519a),
+    (0x51a7, 0x51a8), (0x51b2, 0x51b5), (0x51c9, 0x51c9), (0x51ed, 0x51ed), (0x51f4, 0x51f4),
+    (0x520b, 0x520b), (0x5226, 0x5227), (0x5234, 0x5234), (0x523c, 0x523c), (0x5257, 0x5257),
+    (0x528f, 0x528f), (0x52b5, 0x52b5), (0x52b9, 0x52b9), (0x52c5, 0x52c5), (0x52d1, 0x52d1),
+    (0x5338, 0x5338), (0x5374, 0x5374), (0x537d, 0x537d), (0x5393, 0x5393), (0x53a0, 0x53a0),
+    (0x53a6, 0x53a6), (0x53a8, 0x53a8), (0x53c1, 0x53c1), (0x53cc, 0x53cc), (0x53d9, 0x53d9),
+    (0x53e0, 0x53e0), (0x53f6, 0x53f6), (0x53fe, 0x53fe), (0x5413, 0x5414), (0x5416, 0x5416),
+    (0x5421, 0x5421), (0x544c, 0x544d), (0x546a, 0x546a), (0x546d, 0x546d), (0x548f, 0x548f),
+    (0x5493, 0x5494), (0x5497, 0x5497), (0x54a4, 0x54a4), (0x54b2, 0x54b2), (0x54cb, 0x54cb),
+    (0x54cd, 0x54cd), (0x54e3, 0x54e3), (0x5502, 0x5502), (0x5513, 0x5513), (0x551e, 0x551e),
+    (0x5525, 0x5525), (0x5553, 0x5553), (0x555d, 0x555d), (0x5569, 0x5569), (0x556b, 0x556b),
+    (0x5571, 0x5572), (0x5579, 0x5579), (0x5586, 0x5586), (0x5590, 0x5590), (0x55a9, 0x55a9),
+    (0x55b0, 0x55b0), (0x55ba, 0x55ba), (0x55bc, 0x55bc), (0x55d7, 0x55d7), (0x55de, 0x55de),
+    (0x55ec, 0x55ec), (0x55f0, 0x55f1), (0x55fb, 0x55fb), (0x5605, 0x5605), (0x5611, 0x5611),
+    (0x561e, 0x561e), (0x5622, 0x5623), (0x5625, 0x5625), (0x562d, 0x562d), (0x5643, 0x5643),
+    (0x564d, 0x564d), (0x564f, 0x564f), (0x5652, 0x5652), (0x5654, 0x5654), (0x565d, 0x565d),
+    (0x5689, 0x5689), (0x5692, 0x5692), (0x569f, 0x569f), (0x56a1, 0x56a1), (0x56a4, 0x56a4),
+    (0x56b1, 0x56b1), (0x56b9, 0x56b9), (0x56bf, 0x56bf), (0x56d6, 0x56d6), (0x56fd, 0x56fd),
+    (0x5742, 0x5742), (0x577a, 0x577a), (0x57c8, 0x57c8), (0x57d7, 0x57d7), (0x57de, 0x57de),
+    (0x5803, 0x5803), (0x5826, 0x5826), (0x583a, 0x583a), (0x5840, 0x5840), (0x5869, 0x5869),
+    (0x5872, 0x5873), (0x58aa, 0x58aa), (0x58bb, 0x58bb), (0x58e0, 0x58e0), (0x58f2, 0x58f3),
+    (0x58fb, 0x58fb), (0x590a, 0x590a), (0x5975, 0x5975), (0x599f, 0x599f), (0x59ac, 0x59ac),
+    (0x59c9, 0x59c9), (0x59eb, 0x59eb), (0x59f8, 0x59f8), (0x5a2b, 0x5a2b), (0x5a7e, 0x5a7e),
+    (0x5af2, 0x5af2), (0x5afa, 0x5afa), (0x5b46, 0x5b46), (0x5b6d, 0x5b6d), (0x5b9d, 0x5b9d),
+    (0x5b9f, 0x5b9f), (0x5bc3, 0x5bc3), (0x5bdb, 0x5bdb), (0x5bf3, 0x5bf3), (0x5c05, 0x5c05),
+    (0x5c4a, 0x5c4a), (0x5c5e, 0x5c5e), (0x5cef, 0x5cef), (0x5d8b, 0x5d8b), (0x5df5, 0x5df5),
+    (0x5e7a, 0x5e7a), (0x5e83, 0x5e83), (0x5ed0, 0x5ed0), (0x5ef8, 0x5ef9), (0x5efb, 0x5efc),
+    (0x5f0c, 0x5f0e), (0x5f5c, 0x5f5c), (0x5fa7, 0x5fa7), (0x5fdf, 0x5fdf), (0x6031, 0x6031),
+    (0x6075, 0x6075), (0x609e, 0x609e), (0x60a4, 0x60a4), (0x60d7, 0x60d7), (0x60e3, 0x60e3),
+    (0x6159, 0x6159), (0x6164, 0x6164), (0x617d, 0x617d), (0x6187, 0x6187), (0x61d0, 0x61d0),
+    (0x6239, 0x6239), (0x629d, 0x629d), (0x62a6, 0x62a6), (0x62c3, 0x62c3), (0x62c5, 0x62c5),
+    (0x62d5, 0x62d5), (0x6331, 0x6331), (0x6379, 0x6379), (0x63b9, 0x63b9), (0x63d1, 0x63d1),
+    (0x63de, 0x63de), (0x63e6, 0x63e6), (0x63f8, 0x63f8), (0x63fc, 0x63fc), (0x63fe, 0x63fe),
+    (0x6407, 0x6407), (0x6432, 0x6432), (0x643a, 0x643a), (0x647c, 0x647c), (0x648d, 0x648d),
+    (0x6491, 0x6491), (0x64b4, 0x64b4), (0x64dd, 0x64dd), (0x64e1, 0x64e1), (0x64e7, 0x64e7),
+    (0x651e, 0x651e), (0x6530, 0x6530), (0x654d, 0x654d), (0x6586, 0x6586), (0x6589, 0x6589),
+    (0x65e3, 0x65e3), (0x6630, 0x6630), (0x6644, 0x6644), (0x664b, 0x664b), (0x6667, 0x6667),
+    (0x666b, 0x666b), (0x6673, 0x6673), (0x668e, 0x668e), (0x66f1, 0x66f1), (0x6725, 0x6725),
+    (0x6736, 0x6736), (0x6761, 0x6761), (0x6767, 0x6767), (0x67a0, 0x67a0), (0x67b1, 0x67b1),
+    (0x6803, 0x6804), (0x681e, 0x681e), (0x6822, 0x6822), (0x6898, 0x6898), (0x68b6, 0x68b6),
+    (0x6900, 0x6900), (0x6936, 0x6936), (0x6961, 0x6961), (0x6973, 0x6973), (0x698a, 0x698a),
+    (0x69b2, 0x69b2), (0x6a0b, 0x6a0b), (0x6a2b, 0x6a2b), (0x6ac8, 0x6ac8), (0x6b35, 0x6b35),
+    (0x6b6f, 0x6b6f), (0x6b74, 0x6b74), (0x6b7a, 0x6b7a), (0x6be1, 0x6be1), (0x6c37, 0x6c37),
+    (0x6c39, 0x6c39), (0x6c5a, 0x6c5a), (0x6ca2, 0x6ca2), (0x6cea, 0x6cea), (0x6d5c, 0x6d5c),
+    (0x6d72, 0x6d72), (0x6d96, 0x6d96), (0x6e15, 0x6e15), (0x6e29, 0x6e29), (0x6e7c, 0x6e7c),
+    (0x6ed9, 0x6ed9), (0x6edb, 0x6edb), (0x6edd, 0x6edd), (0x6f16, 0x6f16), (0x6f56, 0x6f56),
+    (0x6f81, 0x6f81), (0x6fbe, 0x6fbe), (0x6ff6, 0x6ff6), (0x701e, 0x701e), (0x702c, 0x702c),
+    (0x7081, 0x7081), (0x7089, 0x7089), (0x70b9, 0x70b9), (0x70df, 0x70df), (0x70f1, 0x70f1),
+    (0x7105, 0x7105), (0x712b, 0x712b), (0x7140, 0x7140), (0x7145, 0x7145), (0x714a, 0x714a),
+    (0x7151, 0x7151), (0x7171, 0x7171), (0x71f6, 0x71f6), (0x7215, 0x7215), (0x7240, 0x7240),
+    (0x7282, 0x7282), (0x7287, 0x7287), (0x732a, 0x732a), (0x732e, 0x732e), (0x7341, 0x7341),
+    (0x7374, 0x7374), (0x73c9, 0x73c9), (0x73cf, 0x73cf), (0x7439, 0x7439), (0x743c, 0x743c),
+    (0x7448, 0x7448), (0x7460, 0x7460), (0x7505, 0x7505), (0x7534, 0x7534), (0x753b, 0x753b),
+    (0x754a, 0x754a), (0x7551, 0x7551), (0x7553, 0x7553), (0x7560, 0x7560), (0x7567, 0x7567),
+    (0x758d, 0x758e), (0x75b1, 0x75b1), (0x75b4, 0x75b4), (0x7602, 0x7602), (0x763b, 0x763b),
+    (0x764e, 0x764e), (0x7666, 0x7667), (0x7676, 0x7676), (0x767a, 0x767a), (0x770c, 0x770c),
+    (0x771e, 0x771e), (0x7740, 0x7740), (0x7758, 0x7758), (0x7778, 0x7778), (0x777a, 0x777a),
+    (0x7793, 0x7793), (0x77b9, 0x77b9), (0x77cb, 0x77cb), (0x7808, 0x7808), (0x7881, 0x7881),
+    (0x788d, 0x788d), (0x78b1, 0x78b1), (0x78b8, 0x78b8), (0x78d7, 0x78d7), (0x7906, 0x7906),
+    (0x792e, 0x792e), (0x7958, 0x7958), (0x7962, 0x7962), (0x7991, 0x7991), (0x79c4, 0x79c4),
+    (0x7a93, 0x7a93), (0x7ab0, 0x7ab0), (0x7ac8, 0x7ac9), (0x7adc, 0x7add), (0x7aea, 0x7aea),
+    (0x7b0b, 0x7b0b), (0x7b39, 0x7b39), (0x7b6f, 0x7b6f), (0x7c15, 0x7c15), (0x7ca6, 0x7ca7),
+    (0x7cae, 0x7cae), (0x7cc9, 0x7cc9), (0x7ccd, 0x7ccd), (0x7ced, 0x7ced), (0x7cf9, 0x7cf9),
+    (0x7cfc, 0x7cfc), (0x7d25, 0x7d25), (0x7d5d, 0x7d5d), (0x7d89, 0x7d89), (0x7dab, 0x7dab),
+    (0x7db3, 0x7db3), (0x7dcd, 0x7dcd), (0x7dcf, 0x7dcf), (0x7ddc, 0x7ddc), (0x7e6e, 0x7e6e),
+    (0x7f47, 0x7f47), (0x7f49, 0x7f49), (0x7f4e, 0x7f4e), (0x7f78, 0x7f78), (0x7f97, 0x7f97),
+    (0x7fa3, 0x7fa3), (0x8061, 0x8061), (0x80b6, 0x80b6), (0x80bd, 0x80bd), (0x80c6, 0x80c6),
+    (0x8107, 0x8107), (0x8117, 0x8117), (0x8137, 0x8137), (0x81a5, 0x81a5), (0x81b6, 0x81b6),
+    (0x81ef, 0x81ef), (0x8218, 0x8218), (0x8226, 0x8226), (0x8276, 0x8276), (0x82a6, 0x82a6),
+    (0x82aa, 0x82aa), (0x82f7, 0x82f7), (0x8318, 0x8318), (0x83d3, 0x83d3), (0x8418, 0x8418),
+    (0x8420, 0x8420), (0x8471, 0x8471), (0x84ad, 0x84ad), (0x84bd, 0x84bd), (0x84e2, 0x84e2),
+    (0x8503, 0x8503), (0x8534, 0x8534), (0x8570, 0x8570), (0x8602, 0x8602), (0x862f, 0x862f),
+    (0x86ef, 0x86ef), (0x8786, 0x8786), (0x87ce, 0x87ce), (0x8804, 0x8804), (0x882d, 0x882d),
+    (0x8846, 0x8846), (0x885e, 0x885e), (0x889c, 0x889c), (0x88c7, 0x88c7), (0x88cf, 0x88cf),
+    (0x8947, 0x8947), (0x8987, 0x8987), (0x8994, 0x8994), (0x89a5, 0x89a5), (0x89a7, 0x89a7),
+    (0x8a94, 0x8a94), (0x8b4c, 0x8b4c), (0x8b81, 0x8b81), (0x8b83, 0x8b83), (0x8b90, 0x8b90),
+    (0x8ccd, 0x8ccd), (0x8cdb, 0x8cdb), (0x8d03, 0x8d03), (0x8d0b, 0x8d0b), (0x8e0e, 0x8e0e),
+    (0x8e2a, 0x8e2a), (0x8e2d, 0x8e2d), (0x8e4f, 0x8e4f), (0x8e7e, 0x8e7e), (0x8e80, 0x8e80),
+    (0x8ead, 0x8ead), (0x8eda, 0x8eda), (0x8ee2, 0x8ee2), (0x8ef2, 0x8ef2), (0x8f2d, 0x8f2d),
+    (0x8fb5, 0x8fb5), (0x8fba, 0x8fbc), (0x8ff9, 0x8ff9), (0x9033, 0x9033), (0x9056, 0x9056),
+    (0x9061, 0x9061), (0x90a8, 0x90a8), (0x9176, 0x9176), (0x9208, 0x9208), (0x920e, 0x920e),
+    (0x922a, 0x922a), (0x9244, 0x9244), (0x9255, 0x9255), (0x925d, 0x925d), (0x9262, 0x9262),
+    (0x926e, 0x926e), (0x92b9, 0x92b9), (0x92be, 0x92be), (0x9307, 0x9307), (0x9340, 0x9340),
+    (0x9345, 0x9345), (0x9348, 0x9348), (0x9369, 0x9369), (0x9384, 0x9385), (0x9387, 0x9387),
+    (0x93ad, 0x93ad), (0x93bf, 0x93bf), (0x93f0, 0x93f0), (0x9404, 0x9404), (0x9426, 0x9427),
+    (0x9454, 0x9454), (0x945b, 0x945b), (0x9465, 0x9465), (0x9599, 0x9599), (0x95a2, 0x95a2),
+    (0x95aa, 0x95aa), (0x9696, 0x9696), (0x96a3, 0x96a3), (0x9721, 0x9721), (0x9751, 0x9751),
+    (0x976d, 0x976d), (0x97ee, 0x97ee), (0x97f5, 0x97f5), (0x9834, 0x9834), (0x98b7, 0x98b7),
+    (0x98c8, 0x98c8), (0x98e0, 0x98e0), (0x991c, 0x991c), (0x9938, 0x9938), (0x994a, 0x994a),
+    (0x994d, 0x994d), (0x9962, 0x9962), (0x99c5, 0x99c5), (0x99e1, 0x99e1), (0x9a10, 0x9a10),
+    (0x9b2a, 0x9b2a), (0x9b2d, 0x9b2d), (0x9b81, 0x9b81), (0x9b8b, 0x9b8b), (0x9b8e, 0x9b8e),
+    (0x9bed, 0x9bed), (0x9bf1, 0x9bf1), (0x9bff, 0x9bff), (0x9c02, 0x9c02), (0x9c0c, 0x9c0c),
+    (0x9c2f, 0x9c2f), (0x9c35, 0x9c35), (0x9c3a, 0x9c3a), (0x9c45, 0x9c45), (0x9c5d, 0x9c5d),
+    (0x9c72, 0x9c72), (0x9d34, 0x9d34), (0x9d50, 0x9d50), (0x9d5e, 0x9d5e), (0x9d93, 0x9d93),
+    (0x9dc0, 0x9dc0), (0x9dc4, 0x9dc4), (0x9dc9, 0x9dc9), (0x9dd4, 0x9dd4), (0x9e0a, 0x9e0a),
+    (0x9e0c, 0x9e0c), (0x9e90, 0x9e90), (0x9e95, 0x9e96), (0x9eaa, 0x9eab), (0x9eaf, 0x9eaf),
+    (0x9ebf, 0x9ebf), (0x9f08, 0x9f08), (0x9f26, 0x9f26), (0x9f62, 0x9f62), (0x9f8e, 0x9f8e),
+    (0x200ca, 0x200ca), (0x201a4, 0x201a4), (0x201a9, 0x201a9), (0x20325, 0x20325),
+    (0x20341, 0x20341), (0x2070e, 0x2070e), (0x20779, 0x20779), (0x20c41, 0x20c41),
+    (0x20c53, 0x20c53), (0x20c65, 0x20c65), (0x20c78, 0x20c78), (0x20c96, 0x20c96),
+    (0x20cb5, 0x20cb5), (0x20ccf, 0x20ccf), (0x20d31, 0x20d31), (0x20d71, 0x20d71),
+    (0x20d7e, 0x20d7f), (0x20d9c, 0x20d9c), (0x20da7, 0x20da7), (0x20e04, 0x20e04),
+    (0x20e09, 0x20e09), (0x20e4c, 0x20e4c), (0x20e73, 0x20e73), (0x20e76, 0x20e76),
+    (0x20e7a, 0x20e7a), (0x20e9d, 0x20e9d), (0x20ea2, 0x20ea2), (0x20ed7, 0x20ed7),
+    (0x20ef9, 0x20ef9), (0x20f2d, 0x20f2e), (0x20f3b, 0x20f3b), (0x20f4c, 0x20f4c),
+    (0x20fb4, 0x20fb4), (0x20fea, 0x20fea), (0x21014, 0x21014), (0x2105c, 0x2105c),
+    (0x2106f, 0x2106f), (0x21075, 0x21076), (0x2107b, 0x2107b), (0x210c1, 0x210c1),
+    (0x210d3, 0x210d3), (0x2113d, 0x2113d), (0x21145, 0x21145), (0x2114f, 0x2114f),
+    (0x2197c, 0x2197c), (0x21a34, 0x21a34), (0x21c2a, 0x21c2a), (0x21df9, 0x21df9),
+    (0x220c7, 0x220c7), (0x221a1, 0x221a1), (0x22acf, 0x22acf), (0x22b43, 0x22b43),
+    (0x22bca, 0x22bca), (0x22c51, 0x22c51), (0x22c55, 0x22c55), (0x22c62, 0x22c62),
+    (0x22cb2, 0x22cb2), (0x22cc2, 0x22cc2), (0x22d4c, 0x22d4c), (0x22d67, 0x22d67),
+    (0x22d8d, 0x22d8d), (0x22dee, 0x22dee), (0x22f74, 0x22f74), (0x23231, 0x23231),
+    (0x23595, 0x23595), (0x236ba, 0x236ba), (0x23cb7, 0x23cb7), (0x23e89, 0x23e89),
+    (0x23f80, 0x23f80), (0x244d3, 0x244d3), (0x24db8, 0x24db8), (0x24dea, 0x24dea),
+    (0x24ea7, 0x24ea7), (0x2512b, 0x2512b), (0x25148, 0x25148), (0x2517e, 0x2517e),
+    (0x25535, 0x25535), (0x25e49, 0x25e49), (0x26258, 0x26258), (0x266da, 0x266da),
+    (0x267cc, 0x267cc), (0x2688a, 0x2688a), (0x269f2, 0x269f2), (0x269fa, 0x269fa),
+    (0x27285, 0x27285), (0x27574, 0x27574), (0x27657, 0x27657), (0x27735, 0x27735),
+    (0x2775e, 0x2775e), (0x2789d, 0x2789d), (0x2797a, 0x2797a), (0x279a0, 0x279a0),
+    (0x27a3e, 0x27a3e), (0x27a59, 0x27a59), (0x27d73, 0x27d73), (0x28024, 0x28024),
+    (0x280bd, 0x280bd), (0x2815d, 0x2815d), (0x28207, 0x28207), (0x282e2, 0x282e2),
+    (0x2836d, 0x2836d), (0x289c0, 0x289c0), (0x289dc, 0x289dc), (0x28a0f, 0x28a0f),
+    (0x28b46, 0x28b46), (0x28b4e, 0x28b4e), (0x28cca, 0x28cca), (0x28ccd, 0x28ccd),
+    (0x28cd2, 0x28cd2), (0x28d99, 0x28d99), (0x28ee7, 0x28ee7), (0x294e5, 0x294e5),
+    (0x29720, 0x29720), (0x298d1, 0x298d1), (0x29a4d, 0x29a4d), (0x29d98, 0x29d98),
+    (0x2a632, 0x2a632), (0x2a65b, 0x2a65b),
+];
+
+#[rustfmt::skip]
+const ZH_TW: &[(Int, Int)] = &[
+    (0x4e00, 0x4e01), (0x4e03, 0x4e03), (0x4e07, 0x4e11), (0x4e14, 0x4e16), (0x4e18, 0x4e19),
+    (0x4e1e, 0x4e1f), (0x4e26, 0x4e26), (0x4e2b, 0x4e2b), (0x4e2d, 0x4e2e), (0x4e30, 0x4e33),
+    (0x4e38, 0x4e39), (0x4e3b, 0x4e3c), (0x4e42, 0x4e43), (0x4e45, 0x4e45), (0x4e47, 0x4e48),
+    (0x4e4b, 0x4e4b), (0x4e4d, 0x4e4f), (0x4e52, 0x4e53), (0x4e56, 0x4e56), (0x4e58, 0x4e59),
+    (0x4e5c, 0x4e5f), (0x4e69, 0x4e69), (0x4e73, 0x4e73), (0x4e7e, 0x4e7f), (0x4e82, 0x4e84),
+    (0x4e86, 0x4e86), (0x4e88, 0x4e88), (0x4e8b, 0x4e8e), (0x4e91, 0x4e95), (0x4e99, 0x4e99),
+    (0x4e9b, 0x4e9b), (0x4e9e, 0x4e9f), (0x4ea1, 0x4ea2), (0x4ea4, 0x4ea6), (0x4ea8, 0x4ea8),
+    (0x4eab, 0x4eae), (0x4eb3, 0x4eb3), (0x4eb6, 0x4eb6), (0x4eb9, 0x4eba), (0x4ec0, 0x4ec4),
+    (0x4ec6, 0x4ecb), (0x4ecd, 0x4ecd), (0x4ed4, 0x4eda), (0x4edc, 0x4edf), (0x4ee1, 0x4ee1),
+    (0x4ee3, 0x4ee5), (0x4ee8, 0x4ee9), (0x4ef0, 0x4ef7), (0x4efb, 0x4efb), (0x4efd, 0x4efd),
+    (0x4eff, 0x4f02), (0x4f04, 0x4f05), (0x4f08, 0x4f0b), (0x4f0d, 0x4f15), (0x4f18, 0x4f19),
+    (0x4f1d, 0x4f1d), (0x4f22, 0x4f22), (0x4f2c, 0x4f2d), (0x4f2f, 0x4f30), (0x4f33, 0x4f34),
+    (0x4f36, 0x4f36), (0x4f38, 0x4f38), (0x4f3a, 0x4f3f), (0x4f41, 0x4f41), (0x4f43, 0x4f43),
+    (0x4f46, 0x4f49), (0x4f4c, 0x4f64), (0x4f67, 0x4f67), (0x4f69, 0x4f6c), (0x4f6e, 0x4f70),
+    (0x4f73, 0x4f89), (0x4f8b, 0x4f8b), (0x4f8d, 0x4f8d), (0x4f8f, 0x4f92), (0x4f94, 0x4f98),
+    (0x4f9a, 0x4f9e), (0x4fae, 0x4faf), (0x4fb2, 0x4fb3), (0x4fb5, 0x4fb7), (0x4fb9, 0x4fbb),
+    (0x4fbf, 0x4fc5), (0x4fc7, 0x4fc7), (0x4fc9, 0x4fcb), (0x4fcd, 0x4fd1), (0x4fd3, 0x4fd4),
+    (0x4fd6, 0x4fe1), (0x4fec, 0x4fec), (0x4fee, 0x4fef), (0x4ff1, 0x4ff1), (0x4ff3, 0x4ff8),
+    (0x4ffa, 0x4ffa), (0x4ffe, 0x4ffe), (0x5000, 0x5000), (0x5005, 0x5007), (0x5009, 0x5009),
+    (0x500b, 0x500f), (0x5011, 0x501c), (0x501e, 0x5023), (0x5025, 0x502d), (0x502f, 0x5031),
+    (0x5033, 0x5033), (0x5035, 0x5035), (0x5037, 0x5037), (0x503c, 0x503c), (0x5040, 0x5041),
+    (0x5043, 0x5043), (0x5045, 0x504f), (0x5051, 0x5051), (0x5053, 0x5053), (0x5055, 0x5055),
+    (0x5057, 0x5057), (0x505a, 0x5065), (0x5068, 0x506b), (0x506d, 0x5070), (0x5072, 0x5077),
+    (0x507a, 0x507a), (0x507d, 0x507d), (0x5080, 0x5080), (0x5082, 0x5083), (0x5085, 0x5085),
+    (0x5087, 0x5087), (0x508b, 0x508e), (0x5091, 0x5092), (0x5094, 0x5096), (0x5098, 0x509e),
+    (0x50a2, 0x50a3), (0x50ac, 0x50b8), (0x50ba, 0x50bb), (0x50bd, 0x50bf), (0x50c1, 0x50c2),
+    (0x50c4, 0x50cb), (0x50ce, 0x50cf), (0x50d1, 0x50d1), (0x50d3, 0x50d7), (0x50da, 0x50db),
+    (0x50dd, 0x50dd), (0x50e0, 0x50e0), (0x50e3, 0x50ea), (0x50ec, 0x50f1), (0x50f3, 0x50f3),
+    (0x50f5, 0x50f6), (0x50f8, 0x50f9), (0x50fb, 0x50fb), (0x50fd, 0x5100), (0x5102, 0x510c),
+    (0x5110, 0x5115), (0x5117, 0x5118), (0x511a, 0x511a), (0x511c, 0x511c), (0x511f, 0x5122),
+    (0x5124, 0x5126), (0x5129, 0x512a), (0x512d, 0x512e), (0x5130, 0x5135), (0x5137, 0x513d),
+    (0x513f, 0x5141), (0x5143, 0x5149), (0x514b, 0x514d), (0x5152, 0x5152), (0x5154, 0x5155),
+    (0x5157, 0x5157), (0x5159, 0x515f), (0x5161, 0x5163), (0x5165, 0x5165), (0x5167, 0x5169),
+    (0x516b, 0x516e), (0x5171, 0x5171), (0x5175, 0x5178), (0x517c, 0x517c), (0x5180, 0x5180),
+    (0x5187, 0x5187), (0x5189, 0x518a), (0x518d, 0x518d), (0x518f, 0x518f), (0x5191, 0x5195),
+    (0x5197, 0x5198), (0x519e, 0x519e), (0x51a0, 0x51a0), (0x51a2, 0x51a2), (0x51a4, 0x51a5),
+    (0x51aa, 0x51aa), (0x51ac, 0x51ac), (0x51b0, 0x51b1), (0x51b6, 0x51b7), (0x51b9, 0x51b9),
+    (0x51bc, 0x51be), (0x51c4, 0x51c6), (0x51c8, 0x51c8), (0x51ca, 0x51ce), (0x51d0, 0x51d0),
+    (0x51d4, 0x51d4), (0x51d7, 0x51d8), (0x51dc, 0x51de), (0x51e0, 0x51e1), (0x51f0, 0x51f1),
+    (0x51f3, 0x51f3), (0x51f5, 0x51f6), (0x51f8, 0x51fa), (0x51fd, 0x51fd), (0x5200, 0x5201),
+    (0x5203, 0x5203), (0x5206, 0x520a), (0x520c, 0x520c), (0x520e, 0x520e), (0x5210, 0x5213),
+    (0x5216, 0x5217), (0x521c, 0x521e), (0x5221, 0x5221), (0x5224, 0x5225), (0x5228, 0x522a),
+    (0x522e, 0x522e), (0x5230, 0x5233), (0x5235, 0x5238), (0x523a, 0x523b), (0x5241, 0x5241),
+    (0x5243, 0x5244), (0x5246, 0x5247), (0x5249, 0x524e), (0x5252, 0x5252), (0x5254, 0x5256),
+    (0x525a, 0x525f), (0x5261, 0x5262), (0x5269, 0x526f), (0x5272, 0x5272), (0x5274, 0x5275),
+    (0x5277, 0x5278), (0x527a, 0x527d), (0x527f, 0x5284), (0x5287, 0x528d), (0x5291, 0x5291),
+    (0x5293, 0x5293), (0x5296, 0x5299), (0x529b, 0x529b), (0x529f, 0x52a0), (0x52a3, 0x52a3),
+    (0x52a6, 0x52a6), (0x52a9, 0x52ae), (0x52bb, 0x52bc), (0x52be, 0x52be), (0x52c0, 0x52c3),
+    (0x52c7, 0x52c7), (0x52c9, 0x52c9), (0x52cd, 0x52cd), (0x52d2, 0x52d3), (0x52d5, 0x52d9),
+    (0x52db, 0x52db), (0x52dd, 0x52df), (0x52e2, 0x52e4), (0x52e6, 0x52e6), (0x52e9, 0x52e9),
+    (0x52eb, 0x52eb), (0x52ef, 0x52f1), (0x52f3, 0x52f5), (0x52f7, 0x52f8), (0x52fa, 0x52fc),
+    (0x52fe, 0x52ff), (0x5305, 0x5306), (0x5308, 0x530b), (0x530d, 0x5312), (0x5315, 0x5317),
+    (0x5319, 0x531a), (0x531c, 0x531d), (0x531f, 0x5323), (0x532a, 0x532a), (0x532d, 0x532d),
+    (0x532f, 0x5331), (0x5334, 0x5334), (0x5337, 0x5337), (0x5339, 0x5339), (0x533c, 0x5341),
+    (0x5343, 0x5345), (0x5347, 0x534a), (0x534c, 0x534d), (0x5351, 0x5354), (0x5357, 0x5357),
+    (0x535a, 0x535a), (0x535c, 0x535c), (0x535e, 0x535e), (0x5360, 0x5361), (0x5363, 0x5363),
+    (0x5366, 0x5366), (0x536c, 0x536c), (0x536e, 0x5373), (0x5375, 0x5375), (0x5377, 0x5379),
+    (0x537b, 0x537c), (0x537f, 0x537f), (0x5382, 0x5382), (0x5384, 0x5384), (0x538a, 0x538a),
+    (0x538e, 0x538f), (0x5392, 0x5392), (0x5394, 0x5394), (0x5396, 0x539a), (0x539c, 0x539f),
+    (0x53a4, 0x53a5), (0x53a7, 0x53a7), (0x53ac, 0x53ad), (0x53b2, 0x53b2), (0x53b4, 0x53b4),
+    (0x53b9, 0x53b9), (0x53bb, 0x53bb), (0x53c3, 0x53c3), (0x53c8, 0x53cb), (0x53cd, 0x53cd),
+    (0x53d4, 0x53d4), (0x53d6, 0x53d7), (0x53db, 0x53db), (0x53df, 0x53df), (0x53e1, 0x53e6),
+    (0x53e8, 0x53f3), (0x53f5, 0x53f5), (0x53f8, 0x53f8), (0x53fb, 0x53fc), (0x5401, 0x5401),
+    (0x5403, 0x5404), (0x5406, 0x5412), (0x5418, 0x5419), (0x541b, 0x5420), (0x5424, 0x542e),
+    (0x5430, 0x5431), (0x5433, 0x5433), (0x5435, 0x5439), (0x543b, 0x543e), (0x5440, 0x5443),
+    (0x5445, 0x5448), (0x544a, 0x544a), (0x544e, 0x544f), (0x5454, 0x5454), (0x5460, 0x5468),
+    (0x546b, 0x546c), (0x546f, 0x5478), (0x547a, 0x5482), (0x5484, 0x5484), (0x5486, 0x5488),
+    (0x548b, 0x548e), (0x5490, 0x5492), (0x5495, 0x5496), (0x5498, 0x5498), (0x549a, 0x549a),
+    (0x54a0, 0x54a2), (0x54a5, 0x54b1), (0x54b3, 0x54b3), (0x54b6, 0x54b8), (0x54ba, 0x54c9),
+    (0x54ce, 0x54cf), (0x54d6, 0x54d6), (0x54de, 0x54de), (0x54e0, 0x54e2), (0x54e4, 0x54eb),
+    (0x54ed, 0x54ee), (0x54f1, 0x54f3), (0x54f7, 0x54f8), (0x54fa, 0x54fd), (0x54ff, 0x54ff),
+    (0x5501, 0x5501), (0x5503, 0x550c), (0x550e, 0x5512), (0x5514, 0x5514), (0x5517, 0x5517),
+    (0x551a, 0x551a), (0x5526, 0x5527), (0x552a, 0x552a), (0x552c, 0x5539), (0x553b, 0x553c),
+    (0x553e, 0x553e), (0x5540, 0x5541), (0x5543, 0x5546), (0x5548, 0x5548), (0x554a, 0x554b),
+    (0x554d, 0x5552), (0x5555, 0x5557), (0x555c, 0x555c), (0x555e, 0x555f), (0x5561, 0x5566),
+    (0x556a, 0x556a), (0x5575, 0x5577), (0x557b, 0x5584), (0x5587, 0x558f), (0x5591, 0x5595),
+    (0x5598, 0x559a), (0x559c, 0x559d), (0x559f, 0x559f), (0x55a1, 0x55a8), (0x55aa, 0x55ae),
+    (0x55b1, 0x55b3), (0x55b5, 0x55b5), (0x55bb, 0x55bb), (0x55bf, 0x55c0), (0x55c2, 0x55d6),
+    (0x55d9, 0x55dd), (0x55df, 0x55df), (0x55e1, 0x55e9), (0x55ef, 0x55ef), (0x55f2, 0x55f2),
+    (0x55f6, 0x55f7), (0x55f9, 0x55fa), (0x55fc, 0x5602), (0x5604, 0x5604), (0x5606, 0x5606),
+    (0x5608, 0x5609), (0x560c, 0x5610), (0x5612, 0x5617), (0x561b, 0x561d), (0x561f, 0x561f),
+    (0x5627, 0x5627), (0x5629, 0x562a), (0x562c, 0x562c), (0x562e, 0x5630), (0x5632, 0x5636),
+    (0x5638, 0x563b), (0x563d, 0x5642), (0x5645, 0x5646), (0x5648, 0x564a), (0x564c, 0x564c),
+    (0x564e, 0x564e), (0x5653, 0x5653), (0x5657, 0x565a), (0x565e, 0x565e), (0x5660, 0x5660),
+    (0x5662, 0x5666), (0x5668, 0x5674), (0x5676, 0x5679), (0x567e, 0x5687), (0x568c, 0x5690),
+    (0x5693, 0x5693), (0x5695, 0x5695), (0x5697, 0x569a), (0x569c, 0x569d), (0x56a5, 0x56a8),
+    (0x56aa, 0x56ae), (0x56b2, 0x56b7), (0x56bc, 0x56be), (0x56c0, 0x56c3), (0x56c5, 0x56c6),
+    (0x56c8, 0x56cd), (0x56d1, 0x56d1), (0x56d3, 0x56d4), (0x56d7, 0x56d7), (0x56da, 0x56db),
+    (0x56dd, 0x56e1), (0x56e4, 0x56e5), (0x56e7, 0x56e7), (0x56ea, 0x56eb), (0x56ee, 0x56ee),
+    (0x56f0, 0x56f0), (0x56f7, 0x56f7), (0x56f9, 0x56fa), (0x56ff, 0x56ff), (0x5701, 0x5704),
+    (0x5707, 0x570d), (0x5712, 0x5714), (0x5716, 0x5716), (0x5718, 0x5718), (0x571a, 0x571c),
+    (0x571e, 0x5720), (0x5722, 0x5723), (0x5728, 0x572a), (0x572c, 0x5730), (0x5733, 0x5734),
+    (0x573b, 0x573b), (0x573e, 0x573e), (0x5740, 0x5741), (0x5745, 0x5745), (0x5747, 0x5747),
+    (0x5749, 0x5752), (0x5761, 0x5762), (0x5764, 0x5764), (0x5766, 0x5766), (0x5768, 0x576b),
+    (0x576d, 0x576d), (0x576f, 0x5777), (0x577b, 0x577d), (0x5780, 0x5780), (0x5782, 0x5783),
+    (0x578b, 0x578c), (0x578f, 0x578f), (0x5793, 0x5795), (0x5797, 0x579b), (0x579d, 0x57a0),
+    (0x57a2, 0x57a5), (0x57ae, 0x57ae), (0x57b5, 0x57b6), (0x57b8, 0x57ba), (0x57bc, 0x57bd),
+    (0x57bf, 0x57bf), (0x57c1, 0x57c3), (0x57c6, 0x57c7), (0x57cb, 0x57cc), (0x57ce, 0x57d0),
+    (0x57d2, 0x57d2), (0x57d4, 0x57d5), (0x57dc, 0x57dc), (0x57df, 0x57e5), (0x57e7, 0x57e7),
+    (0x57e9, 0x57e9), (0x57ec, 0x57ee), (0x57f0, 0x57fd), (0x5800, 0x5802), (0x5804, 0x580e),
+    (0x5810, 0x5810), (0x5814, 0x5814), (0x5819, 0x5819), (0x581b, 0x581e), (0x5820, 0x5821),
+    (0x5823, 0x5825), (0x5827, 0x582a), (0x582c, 0x5839), (0x583b, 0x583b), (0x583d, 0x583d),
+    (0x583f, 0x583f), (0x5848, 0x584f), (0x5851, 0x5855), (0x5857, 0x585b), (0x585d, 0x585e),
+    (0x5862, 0x5865), (0x5868, 0x5868), (0x586b, 0x586b), (0x586d, 0x586d), (0x586f, 0x586f),
+    (0x5871, 0x5871), (0x5874, 0x5876), (0x5879, 0x5883), (0x5885, 0x588b), (0x588e, 0x5891),
+    (0x5893, 0x5894), (0x5898, 0x5898), (0x589c, 0x58a1), (0x58a3, 0x58a3), (0x58a5, 0x58a6),
+    (0x58a8, 0x58a9), (0x58ab, 0x58ac), (0x58ae, 0x58af), (0x58b1, 0x58b1), (0x58b3, 0x58b3),
+    (0x58ba, 0x58ba), (0x58bc, 0x58bf), (0x58c1, 0x58c2), (0x58c5, 0x58c9), (0x58ce, 0x58cf),
+    (0x58d1, 0x58d6), (0x58d8, 0x58db), (0x58dd, 0x58df), (0x58e2, 0x58e4), (0x58e7, 0x58e9),
+    (0x58eb, 0x58ec), (0x58ef, 0x58ef), (0x58f4, 0x58f4), (0x58f9, 0x58fa), (0x58fc, 0x58ff),
+    (0x5903, 0x5903), (0x5906, 0x5906), (0x590c, 0x590f), (0x5912, 0x5912), (0x5914, 0x5917),
+    (0x5919, 0x591a), (0x591c, 0x591c), (0x5920, 0x5920), (0x5922, 0x5922), (0x5924, 0x5925),
+    (0x5927, 0x5927), (0x5929, 0x592f), (0x5931, 0x5931), (0x5937, 0x5938), (0x593c, 0x593c),
+    (0x593e, 0x593e), (0x5940, 0x5940), (0x5944, 0x5945), (0x5947, 0x594a), (0x594e, 0x5951),
+    (0x5953, 0x5955), (0x5957, 0x5958), (0x595a, 0x595a), (0x595c, 0x595c), (0x5960, 0x5962),
+    (0x5967, 0x5967), (0x5969, 0x596b), (0x596d, 0x596e), (0x5970, 0x5974), (0x5976, 0x5979),
+    (0x597b, 0x5985), (0x598a, 0x598a), (0x598d, 0x5990), (0x5992, 0x5993), (0x5996, 0x5999),
+    (0x599d, 0x599e), (0x59a0, 0x59a8), (0x59ae, 0x59af), (0x59b1, 0x59b6), (0x59b9, 0x59be),
+    (0x59c0, 0x59c1), (0x59c3, 0x59c3), (0x59c5, 0x59c8), (0x59ca, 0x59d4), (0x59d6, 0x59d6),
+    (0x59d8, 0x59d8), (0x59da, 0x59de), (0x59e0, 0x59e1), (0x59e3, 0x59e6), (0x59e8, 0x59ea),
+    (0x59ec, 0x59ee), (0x59f1, 0x59f7), (0x59fa, 0x5a01), (0x5a03, 0x5a03), (0x5a09, 0x5a0a),
+    (0x5a0c, 0x5a0c), (0x5a0f, 0x5a0f), (0x5a11, 0x5a11), (0x5a13, 0x5a13), (0x5a15, 0x5a19),
+    (0x5a1b, 0x5a1c), (0x5a1e, 0x5a20), (0x5a23, 0x5a23), (0x5a25, 0x5a25), (0x5a29, 0x5a29),
+    (0x5a2d, 0x5a2e), (0x5a33, 0x5a33), (0x5a35, 0x5a39), (0x5a3c, 0x5a3c), (0x5a3e, 0x5a3e),
+    (0x5a40, 0x5a44), (0x5a46, 0x5a4a), (0x5a4c, 0x5a4d), (0x5a50, 0x5a53), (0x5a55, 0x5a58),
+    (0x5a5a, 0x5a60), (0x5a62, 0x5a62), (0x5a64, 0x5a67), (0x5a69, 0x5a6a), (0x5a6c, 0x5a6d),
+    (0x5a70, 0x5a70), (0x5a77, 0x5a78), (0x5a7a, 0x5a7d), (0x5a7f, 0x5a7f), (0x5a83, 0x5a84),
+    (0x5a8a, 0x5a8c), (0x5a8e, 0x5a90), (0x5a92, 0x5a95), (0x5a97, 0x5a97), (0x5a9a, 0x5a9f),
+    (0x5aa2, 0x5aa2), (0x5aa5, 0x5aa7), (0x5aa9, 0x5aa9), (0x5aac, 0x5aac), (0x5aae, 0x5ac2),
+    (0x5ac4, 0x5ac4), (0x5ac6, 0x5acd), (0x5ad5, 0x5ae3), (0x5ae5, 0x5ae6), (0x5ae8, 0x5aee),
+    (0x5af3, 0x5af9), (0x5afb, 0x5afb), (0x5afd, 0x5afd), (0x5aff, 0x5aff), (0x5b01, 0x5b03),
+    (0x5b05, 0x5b05), (0x5b07, 0x5b09), (0x5b0b, 0x5b0c), (0x5b0f, 0x5b10), (0x5b13, 0x5b14),
+    (0x5b16, 0x5b17), (0x5b19, 0x5b1b), (0x5b1d, 0x5b1e), (0x5b20, 0x5b21), (0x5b23, 0x5b28),
+    (0x5b2a, 0x5b2a), (0x5b2c, 0x5b30), (0x5b32, 0x5b32), (0x5b34, 0x5b34), (0x5b38, 0x5b38),
+    (0x5b3c, 0x5b40), (0x5b43, 0x5b43), (0x5b45, 0x5b45), (0x5b47, 0x5b48), (0x5b4b, 0x5b4e),
+    (0x5b50, 0x5b51), (0x5b53, 0x5b58), (0x5b5a, 0x5b5d), (0x5b5f, 0x5b5f), (0x5b62, 0x5b65),
+    (0x5b69, 0x5b69), (0x5b6b, 0x5b6c), (0x5b6e, 0x5b6e), (0x5b70, 0x5b73), (0x5b75, 0x5b75),
+    (0x5b77, 0x5b78), (0x5b7a, 0x5b7b), (0x5b7d, 0x5b7d), (0x5b7f, 0x5b7f), (0x5b81, 0x5b81),
+    (0x5b83, 0x5b85), (0x5b87, 0x5b89), (0x5b8b, 0x5b8c), (0x5b8e, 0x5b8f), (0x5b92, 0x5b93),
+    (0x5b95, 0x5b95), (0x5b97, 0x5b9c), (0x5ba2, 0x5ba8), (0x5bac, 0x5bae), (0x5bb0, 0x5bb0),
+    (0x5bb3, 0x5bb6), (0x5bb8, 0x5bb9), (0x5bbf, 0x5bc2), (0x5bc4, 0x5bc7), (0x5bca, 0x5bce),
+    (0x5bd0, 0x5bd4), (0x5bd6, 0x5bd6), (0x5bd8, 0x5bd9), (0x5bde, 0x5bec), (0x5bee, 0x5bf2),
+    (0x5bf5, 0x5bf6), (0x5bf8, 0x5bf8), (0x5bfa, 0x5bfa), (0x5c01, 0x5c01), (0x5c03, 0x5c04),
+    (0x5c07, 0x5c12), (0x5c15, 0x5c16), (0x5c1a, 0x5c1a), (0x5c1f, 0x5c1f), (0x5c22, 0x5c22),
+    (0x5c24, 0x5c25), (0x5c28, 0x5c28), (0x5c2a, 0x5c2a), (0x5c2c, 0x5c2c), (0x5c30, 0x5c31),
+    (0x5c33, 0x5c33), (0x5c37, 0x5c3c), (0x5c3e, 0x5c41), (0x5c44, 0x5c48), (0x5c4b, 0x5c51),
+    (0x5c54, 0x5c56), (0x5c58, 0x5c59), (0x5c5c, 0x5c5d), (0x5c60, 0x5c60), (0x5c62, 0x5c65),
+    (0x5c67, 0x5c6a), (0x5c6c, 0x5c6f), (0x5c71, 0x5c71), (0x5c73, 0x5c74), (0x5c79, 0x5c7c),
+    (0x5c7e, 0x5c7e), (0x5c86, 0x5c86), (0x5c88, 0x5c8d), (0x5c8f, 0x5c95), (0x5c9d, 0x5c9d),
+    (0x5c9f, 0x5cb1), (0x5cb3, 0x5cb3), (0x5cb5, 0x5cb8), (0x5cc6, 0x5ccc), (0x5cce, 0x5cd0),
+    (0x5cd2, 0x5cd4), (0x5cd6, 0x5cdb), (0x5cde, 0x5cdf), (0x5ce8, 0x5ce8), (0x5cea, 0x5cea),
+    (0x5cec, 0x5cee), (0x5cf0, 0x5cf1), (0x5cf4, 0x5cf4), (0x5cf6, 0x5cf9), (0x5cfb, 0x5cfb),
+    (0x5cfd, 0x5cfd), (0x5cff, 0x5d01), (0x5d06, 0x5d07), (0x5d0b, 0x5d0f), (0x5d11, 0x5d12),
+    (0x5d14, 0x5d14), (0x5d16, 0x5d17), (0x5d19, 0x5d1b), (0x5d1d, 0x5d20), (0x5d22, 0x5d29),
+    (0x5d2e, 0x5d2e), (0x5d30, 0x5d3a), (0x5d3c, 0x5d3d), (0x5d3f, 0x5d43), (0x5d45, 0x5d45),
+    (0x5d47, 0x5d47), (0x5d49, 0x5d4c), (0x5d4e, 0x5d4e), (0x5d50, 0x5d52), (0x5d55, 0x5d55),
+    (0x5d59, 0x5d59), (0x5d5e, 0x5d5e), (0x5d62, 0x5d63), (0x5d65, 0x5d65), (0x5d67, 0x5d69),
+    (0x5d6b, 0x5d6c), (0x5d6f, 0x5d6f), (0x5d71, 0x5d72), (0x5d77, 0x5d77), (0x5d79, 0x5d7a),
+    (0x5d7c, 0x5d82), (0x5d84, 0x5d84), (0x5d86, 0x5d8a), (0x5d8d, 0x5d8d), (0x5d92, 0x5d95),
+    (0x5d97, 0x5d97), (0x5d99, 0x5d9a), (0x5d9c, 0x5da2), (0x5da7, 0x5daa), (0x5dac, 0x5db2),
+    (0x5db4, 0x5db5), (0x5db7, 0x5db8), (0x5dba, 0x5dba), (0x5dbc, 0x5dbd), (0x5dc0, 0x5dc0),
+    (0x5dc2, 0x5dc3), (0x5dc6, 0x5dc7), (0x5dc9, 0x5dc9), (0x5dcb, 0x5dcb), (0x5dcd, 0x5dcd),
+    (0x5dcf, 0x5dcf), (0x5dd1, 0x5dd2), (0x5dd4, 0x5dd6), (0x5dd8, 0x5dd8), (0x5ddd, 0x5de2),
+    (0x5de5, 0x5de8), (0x5deb, 0x5deb), (0x5dee, 0x5dee), (0x5df0, 0x5df4), (0x5df7, 0x5df7),
+    (0x5df9, 0x5df9), (0x5dfd, 0x5dff), (0x5e02, 0x5e04), (0x5e06, 0x5e06), (0x5e0a, 0x5e0a),
+    (0x5e0c, 0x5e0c), (0x5e0e, 0x5e0e), (0x5e11, 0x5e11), (0x5e14, 0x5e1b), (0x5e1d, 0x5e1d),
+    (0x5e1f, 0x5e25), (0x5e28, 0x5e29), (0x5e2b, 0x5e2b), (0x5e2d, 0x5e2d), (0x5e33, 0x5e34),
+    (0x5e36, 0x5e38), (0x5e3d, 0x5e3e), (0x5e40, 0x5e41), (0x5e43, 0x5e45), (0x5e4a, 0x5e4f),
+    (0x5e53, 0x5e55), (0x5e57, 0x5e59), (0x5e5b, 0x5e5d), (0x5e5f, 0x5e63), (0x5e66, 0x5e70),
+    (0x5e72, 0x5e76), (0x5e78, 0x5e79), (0x5e7b, 0x5e7e), (0x5e80, 0x5e80), (0x5e82, 0x5e82),
+    (0x5e84, 0x5e84), (0x5e87, 0x5e8d), (0x5e8f, 0x5e8f), (0x5e95, 0x5e97), (0x5e9a, 0x5e9c),
+    (0x5ea0, 0x5ea0), (0x5ea2, 0x5ea8), (0x5eaa, 0x5eae), (0x5eb0, 0x5eb9), (0x5ebe, 0x5ebe),
+    (0x5ec1, 0x5ec2), (0x5ec4, 0x5ecc), (0x5ece, 0x5ece), (0x5ed1, 0x5ee3), (0x5ee5, 0x5ee9),
+    (0x5eec, 0x5eec), (0x5eee, 0x5eef), (0x5ef1, 0x5ef3), (0x5ef6, 0x5ef7), (0x5efa, 0x5efa),
+    (0x5efe, 0x5eff), (0x5f01, 0x5f02), (0x5f04, 0x5f05), (0x5f07, 0x5f08), (0x5f0a, 0x5f0b),
+    (0x5f0f, 0x5f0f), (0x5f12, 0x5f15), (0x5f17, 0x5f18), (0x5f1a, 0x5f1b), (0x5f1d, 0x5f1d),
+    (0x5f1f, 0x5f1f), (0x5f22, 0x5f24), (0x5f26, 0x5f29), (0x5f2d, 0x5f2e), (0x5f30, 0x5f31),
+    (0x5f33, 0x5f33), (0x5f35, 0x5f38), (0x5f3c, 0x5f3c), (0x5f40, 0x5f40), (0x5f43, 0x5f44),
+    (0x5f46, 0x5f46), (0x5f48, 0x5f4c), (0x5f4e, 0x5f4f), (0x5f54, 0x5f54), (0x5f56, 0x5f59),
+    (0x5f5d, 0x5f5d), (0x5f62, 0x5f62), (0x5f64, 0x5f65), (0x5f67, 0x5f67), (0x5f69, 0x5f6d),
+    (0x5f6f, 0x5f71), (0x5f73, 0x5f74), (0x5f76, 0x5f79), (0x5f7c, 0x5f82), (0x5f85, 0x5f8c),
+    (0x5f90, 0x5f92), (0x5f96, 0x5f99), (0x5f9b, 0x5f9c), (0x5f9e, 0x5fa1), (0x5fa5, 0x5fa6),
+    (0x5fa8, 0x5faf), (0x5fb2, 0x5fb2), (0x5fb5, 0x5fb7), (0x5fb9, 0x5fb9), (0x5fbb, 0x5fc1),
+    (0x5fc3, 0x5fc3), (0x5fc5, 0x5fc5), (0x5fc9, 0x5fc9), (0x5fcc, 0x5fcd), (0x5fcf, 0x5fd2),
+    (0x5fd4, 0x5fd9), (0x5fdd, 0x5fde), (0x5fe0, 0x5fe1), (0x5fe3, 0x5fe5), (0x5fe8, 0x5fe8),
+    (0x5fea, 0x5feb), (0x5fed, 0x5fef), (0x5ff1, 0x5ff1), (0x5ff3, 0x5ff5), (0x5ff7, 0x5ff8),
+    (0x5ffa, 0x5ffb), (0x5ffd, 0x5ffd), (0x5fff, 0x6000), (0x6009, 0x6017), (0x6019, 0x601e),
+    (0x6020, 0x6022), (0x6024, 0x602f), (0x6032, 0x6035), (0x6037, 0x6037), (0x6039, 0x6039),
+    (0x6040, 0x6047), (0x6049, 0x6049), (0x604c, 0x604d), (0x6050, 0x6050), (0x6053, 0x6055),
+    (0x6058, 0x605b), (0x605d, 0x605f), (0x6062, 0x6070), (0x6072, 0x6072), (0x607f, 0x6081),
+    (0x6083, 0x608a), (0x608c, 0x608e), (0x6090, 0x6090), (0x6092, 0x6092), (0x6094, 0x6097),
+    (0x609a, 0x609d), (0x609f, 0x60a0), (0x60a2, 0x60a3), (0x60a8, 0x60a8), (0x60b0, 0x60b2),
+    (0x60b4, 0x60c1), (0x60c3, 0x60cf), (0x60d1, 0x60d1), (0x60d3, 0x60d5), (0x60d8, 0x60dd),
+    (0x60df, 0x60e2), (0x60e4, 0x60e4), (0x60e6, 0x60e6), (0x60f0, 0x60fc), (0x60fe, 0x6101),
+    (0x6103, 0x6106), (0x6108, 0x610b), (0x610d, 0x6110), (0x6112, 0x6116), (0x6118, 0x6118),
+    (0x611a, 0x611d), (0x611f, 0x611f), (0x6123, 0x6123), (0x6127, 0x6129), (0x612b, 0x612c),
+    (0x612e, 0x612f), (0x6132, 0x6132), (0x6134, 0x6134), (0x6136, 0x6137), (0x613b, 0x613b),
+    (0x613e, 0x6141), (0x6144, 0x614f), (0x6152, 0x6156), (0x6158, 0x6158), (0x615a, 0x615b),
+    (0x615d, 0x615f), (0x6161, 0x6163), (0x6165, 0x6168), (0x616a, 0x616c), (0x616e, 0x616e),
+    (0x6170, 0x6177), (0x6179, 0x617a), (0x617c, 0x617c), (0x617e, 0x617e), (0x6180, 0x6180),
+    (0x6182, 0x6183), (0x6189, 0x618e), (0x6190, 0x6194), (0x6196, 0x6196), (0x619a, 0x619b),
+    (0x619d, 0x619d), (0x619f, 0x619f), (0x61a1, 0x61a2), (0x61a4, 0x61a4), (0x61a7, 0x61b6),
+    (0x61b8, 0x61b8), (0x61ba, 0x61ba), (0x61bc, 0x61bc), (0x61be, 0x61bf), (0x61c1, 0x61c3),
+    (0x61c5, 0x61cd), (0x61d6, 0x61d6), (0x61d8, 0x61d8), (0x61de, 0x61e0), (0x61e3, 0x61eb),
+    (0x61ed, 0x61ee), (0x61f0, 0x61f2), (0x61f5, 0x6201), (0x6203, 0x6204), (0x6207, 0x620a),
+    (0x620c, 0x620e), (0x6210, 0x6212), (0x6214, 0x6216), (0x6219, 0x621b), (0x621f, 0x6225),
+    (0x6227, 0x6227), (0x6229, 0x622b), (0x622d, 0x622e), (0x6230, 0x6230), (0x6232, 0x6234),
+    (0x6236, 0x6236), (0x623a, 0x623a), (0x623d, 0x6243), (0x6246, 0x624b), (0x624d, 0x624e),
+    (0x6250, 0x6254), (0x6258, 0x625c), (0x625e, 0x625e), (0x6260, 0x6266), (0x626d, 0x6274),
+    (0x6276, 0x6277), (0x6279, 0x6281), (0x6283, 0x6284), (0x6286, 0x628a), (0x628c, 0x628c),
+    (0x628e, 0x628f), (0x6291, 0x6298), (0x62a8, 0x62b1), (0x62b3, 0x62b6), (0x62b8, 0x62b9),
+    (0x62bb, 0x62bf), (0x62c2, 0x62c2), (0x62c4, 0x62c4), (0x62c6, 0x62d4), (0x62d6, 0x62dc),
+    (0x62eb, 0x6303), (0x6307, 0x6309), (0x630b, 0x6311), (0x6313, 0x6316), (0x6328, 0x632d),
+    (0x632f, 0x632f), (0x6332, 0x6334), (0x6336, 0x6336), (0x6338, 0x633e), (0x6340, 0x6351),
+    (0x6354, 0x635a), (0x6365, 0x6365), (0x6367, 0x6369), (0x636b, 0x636b), (0x636d, 0x6372),
+    (0x6375, 0x6378), (0x637a, 0x637d), (0x6380, 0x6385), (0x6387, 0x638a), (0x638c, 0x6392),
+    (0x6394, 0x6394), (0x6396, 0x6399), (0x639b, 0x63a5), (0x63a7, 0x63b1), (0x63bd, 0x63be),
+    (0x63c0, 0x63c0), (0x63c2, 0x63d0), (0x63d2, 0x63d3), (0x63d5, 0x63dd), (0x63df, 0x63e1),
+    (0x63e3, 0x63e5), (0x63e7, 0x63eb), (0x63ed, 0x63f6), (0x63f9, 0x63f9), (0x6406, 0x6406),
+    (0x6409, 0x6410), (0x6412, 0x6418), (0x641a, 0x641c), (0x641e, 0x6428), (0x642a, 0x6430),
+    (0x6433, 0x6437), (0x6439, 0x6439), (0x643d, 0x6441), (0x6443, 0x6443), (0x644b, 0x644b),
+    (0x644d, 0x644e), (0x6450, 0x6454), (0x6458, 0x6459), (0x645b, 0x6461), (0x6465, 0x6469),
+    (0x646b, 0x6470), (0x6472, 0x647b), (0x647d, 0x647d), (0x647f, 0x647f), (0x6482, 0x6482),
+    (0x6485, 0x6485), (0x6487, 0x648c), (0x648f, 0x6490), (0x6492, 0x6493), (0x6495, 0x649a),
+    (0x649c, 0x64a0), (0x64a2, 0x64a6), (0x64a9, 0x64a9), (0x64ab, 0x64ae), (0x64b0, 0x64b3),
+    (0x64bb, 0x64bf), (0x64c1, 0x64c5), (0x64c7, 0x64c7), (0x64c9, 0x64cb), (0x64cd, 0x64d0),
+    (0x64d2, 0x64d2), (0x64d4, 0x64d4), (0x64d6, 0x64db), (0x64e0, 0x64e0), (0x64e2, 0x64e4),
+    (0x64e6, 0x64e6), (0x64e8, 0x64e9), (0x64eb, 0x64ed), (0x64ef, 0x64f4), (0x64f7, 0x64f8),
+    (0x64fa, 0x6501), (0x6503, 0x6504), (0x6506, 0x6507), (0x6509, 0x6509), (0x650c, 0x6510),
+    (0x6513, 0x6519), (0x651b, 0x651d), (0x6520, 0x6526), (0x6529, 0x652f), (0x6532, 0x6533),
+    (0x6536, 0x6539), (0x653b, 0x653b), (0x653d, 0x653f), (0x6541, 0x6541), (0x6543, 0x6543),
+    (0x6545, 0x6546), (0x6548, 0x654a), (0x654f, 0x654f), (0x6551, 0x6551), (0x6553, 0x6559),
+    (0x655c, 0x655e), (0x6562, 0x6568), (0x656a, 0x656a), (0x656c, 0x656c), (0x656f, 0x656f),
+    (0x6572, 0x657c), (0x657f, 0x6584), (0x6587, 0x6587), (0x658c, 0x658c), (0x6590, 0x6592),
+    (0x6594, 0x6597), (0x6599, 0x6599), (0x659b, 0x65a2), (0x65a4, 0x65a5), (0x65a7, 0x65a8),
+    (0x65aa, 0x65ac), (0x65ae, 0x65b0), (0x65b2, 0x65b3), (0x65b6, 0x65b9), (0x65bb, 0x65bd),
+    (0x65bf, 0x65bf), (0x65c1, 0x65c6), (0x65cb, 0x65d0), (0x65d2, 0x65d3), (0x65d6, 0x65d7),
+    (0x65da, 0x65db), (0x65dd, 0x65df), (0x65e1, 0x65e2), (0x65e5, 0x65e6), (0x65e8, 0x65e9),
+    (0x65ec, 0x65f5), (0x65fa, 0x65fd), (0x6600, 0x6600), (0x6602, 0x6615), (0x661c, 0x661d),
+    (0x661f, 0x6622), (0x6624, 0x6628), (0x662b, 0x662b), (0x662d, 0x662f), (0x6631, 0x6636),
+    (0x6639, 0x663a), (0x6641, 0x6643), (0x6645, 0x6645), (0x6647, 0x6647), (0x6649, 0x664a),
+    (0x664c, 0x664c), (0x664f, 0x664f), (0x6651, 0x6652), (0x6659, 0x665f), (0x6661, 0x6662),
+    (0x6664, 0x6666), (0x6668, 0x6668), (0x666a, 0x666a), (0x666c, 0x666c), (0x666e, 0x6672),
+    (0x6674, 0x6674), (0x6676, 0x667c), (0x667e, 0x667e), (0x6680, 0x6680), (0x6684, 0x6684),
+    (0x6686, 0x668d), (0x6690, 0x6691), (0x6694, 0x6699), (0x669d, 0x669d), (0x669f, 0x66a2),
+    (0x66a8, 0x66ab), (0x66ae, 0x66b2), (0x66b4, 0x66b5), (0x66b7, 0x66bb), (0x66bd, 0x66be),
+    (0x66c0, 0x66c0), (0x66c4, 0x66c4), (0x66c6, 0x66cc), (0x66cf, 0x66cf), (0x66d2, 0x66d2),
+    (0x66d6, 0x66d6), (0x66d8, 0x66de), (0x66e0, 0x66e0), (0x66e3, 0x66e4), (0x66e6, 0x66e6),
+    (0x66e8, 0x66e9), (0x66eb, 0x66ee), (0x66f0, 0x66f0), (0x66f2, 0x66f4), (0x66f6, 0x66f9),
+    (0x66fc, 0x66fc), (0x66fe, 0x6701), (0x6703, 0x6705), (0x6708, 0x670b), (0x670d, 0x670d),
+    (0x670f, 0x6710), (0x6712, 0x6715), (0x6717, 0x6718), (0x671b, 0x671b), (0x671d, 0x671d),
+    (0x671f, 0x6723), (0x6726, 0x6728), (0x672a, 0x672e), (0x6731, 0x6731), (0x6733, 0x6735),
+    (0x6738, 0x673f), (0x6745, 0x6749), (0x674b, 0x6751), (0x6753, 0x6753), (0x6755, 0x6757),
+    (0x6759, 0x675a), (0x675c, 0x6760), (0x676a, 0x676a), (0x676c, 0x676d), (0x676f, 0x677f),
+    (0x6781, 0x6781), (0x6783, 0x6787), (0x6789, 0x6789), (0x678b, 0x678e), (0x6790, 0x6795),
+    (0x6797, 0x679a), (0x679c, 0x679d), (0x679f, 0x679f), (0x67ae, 0x67b0), (0x67b2, 0x67bb),
+    (0x67c0, 0x67c6), (0x67c8, 0x67d4), (0x67d8, 0x67df), (0x67e2, 0x67e7), (0x67e9, 0x67f8),
+    (0x67fa, 0x67fa), (0x67fc, 0x67fc), (0x67ff, 0x67ff), (0x6812, 0x6814), (0x6816, 0x6818),
+    (0x681a, 0x681a), (0x681c, 0x681d), (0x681f, 0x6821), (0x6825, 0x6826), (0x6828, 0x682b),
+    (0x682d, 0x682f), (0x6831, 0x6835), (0x6838, 0x683d), (0x6840, 0x6846), (0x6848, 0x6849),
+    (0x684b, 0x6851), (0x6853, 0x6854), (0x686b, 0x686b), (0x686d, 0x686f), (0x6871, 0x6872),
+    (0x6874, 0x6879), (0x687b, 0x6883), (0x6885, 0x6887), (0x6889, 0x688c), (0x688f, 0x6894),
+    (0x6896, 0x6897), (0x689b, 0x689d), (0x689f, 0x68a4), (0x68a7, 0x68b5), (0x68c4, 0x68c4),
+    (0x68c6, 0x68c9), (0x68cb, 0x68ce), (0x68d0, 0x68d8), (0x68da, 0x68da), (0x68dc, 0x68e1),
+    (0x68e3, 0x68e4), (0x68e6, 0x68ec), (0x68ee, 0x68fd), (0x6904, 0x6908), (0x690a, 0x6915),
+    (0x6917, 0x6917), (0x6925, 0x6925), (0x692a, 0x692a), (0x692f, 0x6930), (0x6932, 0x6935),
+    (0x6937, 0x6939), (0x693b, 0x693d), (0x693f, 0x6942), (0x6944, 0x6945), (0x6948, 0x694c),
+    (0x694e, 0x694f), (0x6951, 0x6954), (0x6956, 0x6960), (0x6962, 0x6963), (0x6965, 0x6966),
+    (0x6968, 0x6971), (0x6974, 0x697b), (0x6982, 0x6983), (0x6986, 0x6986), (0x698d, 0x698e),
+    (0x6990, 0x6991), (0x6993, 0x6997), (0x6999, 0x699c), (0x699e, 0x699e), (0x69a0, 0x69a1),
+    (0x69a3, 0x69b1), (0x69b3, 0x69b7), (0x69b9, 0x69b9), (0x69bb, 0x69bf), (0x69c1, 0x69c4),
+    (0x69c6, 0x69c6), (0x69c9, 0x69d0), (0x69d3, 0x69d4), (0x69d9, 0x69d9), (0x69e2, 0x69e2),
+    (0x69e4, 0x69e8), (0x69eb, 0x69ee), (0x69f1, 0x69f4), (0x69f6, 0x69f8), (0x69fb, 0x6a02),
+    (0x6a04, 0x6a0a), (0x6a0d, 0x6a0d), (0x6a0f, 0x6a0f), (0x6a11, 0x6a11), (0x6a13, 0x6a19),
+    (0x6a1b, 0x6a1b), (0x6a1d, 0x6a21), (0x6a23, 0x6a23), (0x6a25, 0x6a28), (0x6a32, 0x6a32),
+    (0x6a34, 0x6a35), (0x6a38, 0x6a41), (0x6a44, 0x6a44), (0x6a46, 0x6a49), (0x6a4b, 0x6a4b),
+    (0x6a4d, 0x6a51), (0x6a54, 0x6a56), (0x6a58, 0x6a5b), (0x6a5d, 0x6a62), (0x6a64, 0x6a64),
+    (0x6a66, 0x6a6b), (0x6a6d, 0x6a6d), (0x6a6f, 0x6a6f), (0x6a76, 0x6a76), (0x6a7e, 0x6a81),
+    (0x6a83, 0x6a85), (0x6a87, 0x6a87), (0x6a89, 0x6a89), (0x6a8c, 0x6a8e), (0x6a90, 0x6a97),
+    (0x6a9a, 0x6a9c), (0x6a9e, 0x6aa6), (0x6aa8, 0x6aa8), (0x6aac, 0x6aaf), (0x6ab3, 0x6ab4),
+    (0x6ab6, 0x6abb), (0x6abd, 0x6abd), (0x6ac2, 0x6ac3), (0x6ac5, 0x6ac7), (0x6acb, 0x6acd),
+    (0x6acf, 0x6ad1), (0x6ad3, 0x6ad3), (0x6ad9, 0x6ae1), (0x6ae5, 0x6ae5), (0x6ae7, 0x6ae8),
+    (0x6aea, 0x6aec), (0x6aee, 0x6af1), (0x6af3, 0x6af3), (0x6af8, 0x6afc), (0x6b00, 0x6b00),
+    (0x6b02, 0x6b04), (0x6b08, 0x6b0b), (0x6b0f, 0x6b13), (0x6b16, 0x6b1a), (0x6b1e, 0x6b1e),
+    (0x6b20, 0x6b21), (0x6b23, 0x6b23), (0x6b25, 0x6b25), (0x6b28, 0x6b28), (0x6b2c, 0x6b2d),
+    (0x6b2f, 0x6b2f), (0x6b31, 0x6b34), (0x6b36, 0x6b3f), (0x6b41, 0x6b43), (0x6b45, 0x6b4e),
+    (0x6b50, 0x6b51), (0x6b54, 0x6b56), (0x6b59, 0x6b59), (0x6b5b, 0x6b5c), (0x6b5e, 0x6b67),
+    (0x6b6a, 0x6b6a), (0x6b6d, 0x6b6d), (0x6b72, 0x6b72), (0x6b76, 0x6b79), (0x6b7b, 0x6b7b),
+    (0x6b7e, 0x6b80), (0x6b82, 0x6b84), (0x6b86, 0x6b86), (0x6b88, 0x6b8a), (0x6b8c, 0x6b8f),
+    (0x6b91, 0x6b91), (0x6b94, 0x6b99), (0x6b9b, 0x6b9b), (0x6b9e, 0x6ba0), (0x6ba2, 0x6ba7),
+    (0x6baa, 0x6bab), (0x6bad, 0x6bb0), (0x6bb2, 0x6bb3), (0x6bb5, 0x6bb7), (0x6bba, 0x6bba),
+    (0x6bbc, 0x6bbd), (0x6bbf, 0x6bc0), (0x6bc3, 0x6bcd), (0x6bcf, 0x6bd0), (0x6bd2, 0x6bd4),
+    (0x6bd6, 0x6bd8), (0x6bda, 0x6bdb), (0x6bde, 0x6bde), (0x6be0, 0x6be0), (0x6be2, 0x6be4),
+    (0x6be6, 0x6be8), (0x6beb, 0x6bec), (0x6bef, 0x6bf0), (0x6bf2, 0x6bf3), (0x6bf7, 0x6bf9),
+    (0x6bfb, 0x6c06), (0x6c08, 0x6c09), (0x6c0b, 0x6c0d), (0x6c0f, 0x6c11), (0x6c13, 0x6c16),
+    (0x6c18, 0x6c1b), (0x6c1d, 0x6c1d), (0x6c1f, 0x6c21), (0x6c23, 0x6c28), (0x6c2a, 0x6c2c),
+    (0x6c2e, 0x6c30), (0x6c33, 0x6c34), (0x6c36, 0x6c36), (0x6c38, 0x6c38), (0x6c3b, 0x6c3b),
+    (0x6c3e, 0x6c43), (0x6c46, 0x6c46), (0x6c4a, 0x6c50), (0x6c52, 0x6c52), (0x6c54, 0x6c55),
+    (0x6c57, 0x6c57), (0x6c59, 0x6c59), (0x6c5b, 0x6c61), (0x6c65, 0x6c6b), (0x6c6d, 0x6c6d),
+    (0x6c6f, 0x6c74), (0x6c76, 0x6c76), (0x6c78, 0x6c78), (0x6c7a, 0x6c7b), (0x6c7d, 0x6c7e),
+    (0x6c80, 0x6c90), (0x6c92, 0x6c96), (0x6c98, 0x6c9d), (0x6cab, 0x6cae), (0x6cb0, 0x6cb1),
+    (0x6cb3, 0x6cb4), (0x6cb6, 0x6cc7), (0x6cc9, 0x6cca), (0x6ccc, 0x6ccd), (0x6ccf, 0x6cd7),
+    (0x6cd9, 0x6cde), (0x6ce0, 0x6ce3), (0x6ce5, 0x6ce5), (0x6ce7, 0x6ce9), (0x6ceb, 0x6cf3),
+    (0x6cf5, 0x6cf5), (0x6cf9, 0x6cf9), (0x6d00, 0x6d01), (0x6d03, 0x6d04), (0x6d07, 0x6d12),
+    (0x6d16, 0x6d1b), (0x6d1d, 0x6d20), (0x6d22, 0x6d22), (0x6d25, 0x6d25), (0x6d27, 0x6d42),
+    (0x6d58, 0x6d5a), (0x6d5e, 0x6d6a), (0x6d6c, 0x6d70), (0x6d74, 0x6d80), (0x6d82, 0x6d8e),
+    (0x6d90, 0x6d95), (0x6d97, 0x6d98), (0x6daa, 0x6dac), (0x6dae, 0x6daf), (0x6db2, 0x6db5),
+    (0x6db7, 0x6db8), (0x6dba, 0x6dc0), (0x6dc2, 0x6dc2), (0x6dc4, 0x6dcd), (0x6dcf, 0x6de6),
+    (0x6de8, 0x6df7), (0x6df9, 0x6dfd), (0x6e00, 0x6e00), (0x6e03, 0x6e03), (0x6e05, 0x6e05),
+    (0x6e19, 0x6e1d), (0x6e1f, 0x6e28), (0x6e2b, 0x6e36), (0x6e38, 0x6e41), (0x6e43, 0x6e47),
+    (0x6e49, 0x6e4b), (0x6e4d, 0x6e4e), (0x6e51, 0x6e56), (0x6e58, 0x6e58), (0x6e5a, 0x6e69),
+    (0x6e6b, 0x6e6b), (0x6e6e, 0x6e6f), (0x6e71, 0x6e74), (0x6e77, 0x6e79), (0x6e88, 0x6e89),
+    (0x6e8d, 0x6e90), (0x6e92, 0x6e94), (0x6e96, 0x6e99), (0x6e9b, 0x6ea7), (0x6eaa, 0x6eab),
+    (0x6eae, 0x6eb4), (0x6eb6, 0x6eb7), (0x6eb9, 0x6eba), (0x6ebc, 0x6ed6), (0x6ed8, 0x6ed8),
+    (0x6edc, 0x6edc), (0x6eeb, 0x6eef), (0x6ef1, 0x6ef2), (0x6ef4, 0x6ef9), (0x6efb, 0x6f03),
+    (0x6f05, 0x6f0a), (0x6f0d, 0x6f0f), (0x6f12, 0x6f15), (0x6f18, 0x6f1a), (0x6f1c, 0x6f1c),
+    (0x6f1e, 0x6f23), (0x6f25, 0x6f27), (0x6f29, 0x6f33), (0x6f35, 0x6f3c), (0x6f3e, 0x6f41),
+    (0x6f43, 0x6f43), (0x6f4e, 0x6f55), (0x6f57, 0x6f58), (0x6f5a, 0x6f5b), (0x6f5d, 0x6f64),
+    (0x6f66, 0x6f67), (0x6f69, 0x6f70), (0x6f72, 0x6f73), (0x6f76, 0x6f78), (0x6f7a, 0x6f80),
+    (0x6f82, 0x6f82), (0x6f84, 0x6f89), (0x6f8b, 0x6f8e), (0x6f90, 0x6f90), (0x6f92, 0x6f97),
+    (0x6f9e, 0x6f9e), (0x6fa0, 0x6fb4), (0x6fb6, 0x6fb6), (0x6fb8, 0x6fba), (0x6fbc, 0x6fbd),
+    (0x6fbf, 0x6fc4), (0x6fc6, 0x6fcf), (0x6fd4, 0x6fd5), (0x6fd8, 0x6fd8), (0x6fdb, 0x6fe4),
+    (0x6fe6, 0x6fe9), (0x6feb, 0x6ff2), (0x6ff4, 0x6ff4), (0x6ff7, 0x6ff7), (0x6ffa, 0x6ffc),
+    (0x6ffe, 0x7001), (0x7004, 0x7007), (0x7009, 0x700f), (0x7011, 0x7011), (0x7014, 0x701d),
+    (0x701f, 0x7024), (0x7026, 0x702b), (0x702f, 0x7035), (0x7037, 0x703c), (0x703e, 0x7046),
+    (0x7048, 0x704a), (0x704c, 0x704c), (0x7051, 0x7052), (0x7055, 0x7058), (0x705a, 0x705b),
+    (0x705d, 0x7066), (0x7068, 0x706b), (0x7070, 0x7071), (0x7074, 0x7074), (0x7076, 0x7076),
+    (0x7078, 0x7078), (0x707a, 0x707a), (0x707c, 0x707d), (0x7082, 0x7086), (0x708a, 0x708a),
+    (0x708e, 0x708e), (0x7091, 0x7096), (0x7098, 0x709a), (0x709f, 0x709f), (0x70a1, 0x70a1),
+    (0x70a4, 0x70a4), (0x70a9, 0x70a9), (0x70ab, 0x70b1), (0x70b3, 0x70b5), (0x70b7, 0x70b8),
+    (0x70ba, 0x70ba), (0x70be, 0x70be), (0x70c5, 0x70c8), (0x70ca, 0x70cb), (0x70cd, 0x70cf),
+    (0x70d1, 0x70d4), (0x70d7, 0x70da), (0x70dc, 0x70de), (0x70e0, 0x70e2), (0x70e4, 0x70e4),
+    (0x70ef, 0x70f0), (0x70f3, 0x70f4), (0x70f6, 0x70fd), (0x70ff, 0x7100), (0x7102, 0x7102),
+    (0x7104, 0x7104), (0x7106, 0x7106), (0x7109, 0x710e), (0x7110, 0x7110), (0x7113, 0x7113),
+    (0x7117, 0x7117), (0x7119, 0x711c), (0x711e, 0x7123), (0x7125, 0x7126), (0x7128, 0x7128),
+    (0x712e, 0x7132), (0x7136, 0x7136), (0x713a, 0x713a), (0x7141, 0x7144), (0x7146, 0x7147),
+    (0x7149, 0x7149), (0x714b, 0x714e), (0x7150, 0x7150), (0x7152, 0x7154), (0x7156, 0x7156),
+    (0x7158, 0x715a), (0x715c, 0x716a), (0x716c, 0x716c), (0x716e, 0x716e), (0x7170, 0x7170),
+    (0x7172, 0x7172), (0x7178, 0x7178), (0x717b, 0x717b), (0x717d, 0x717d), (0x7180, 0x7182),
+    (0x7184, 0x7187), (0x7189, 0x718a), (0x718f, 0x7190), (0x7192, 0x7192), (0x7194, 0x7194),
+    (0x7197, 0x7197), (0x7199, 0x71a1), (0x71a4, 0x71a5), (0x71a7, 0x71aa), (0x71ac, 0x71ac),
+    (0x71af, 0x71b3), (0x71b5, 0x71b5), (0x71b8, 0x71b9), (0x71bc, 0x71cb), (0x71ce, 0x71d0),
+    (0x71d2, 0x71d2), (0x71d4, 0x71d6), (0x71d8, 0x71dc), (0x71df, 0x71e2), (0x71e4, 0x71e8),
+    (0x71ec, 0x71ee), (0x71f0, 0x71f2), (0x71f4, 0x71f4), (0x71f8, 0x71f9), (0x71fb, 0x71ff),
+    (0x7201, 0x7203), (0x7205, 0x7207), (0x720a, 0x720a), (0x720c, 0x720d), (0x7210, 0x7210),
+    (0x7213, 0x7214), (0x7219, 0x721b), (0x721d, 0x721f), (0x7222, 0x7223), (0x7226, 0x722a),
+    (0x722c, 0x722d), (0x7230, 0x7230), (0x7235, 0x7236), (0x7238, 0x723b), (0x723d, 0x723f),
+    (0x7241, 0x7242), (0x7244, 0x7244), (0x7246, 0x724c), (0x724f, 0x724f), (0x7252, 0x7253),
+    (0x7256, 0x7256), (0x7258, 0x725b), (0x725d, 0x7263), (0x7267, 0x7267), (0x7269, 0x726a),
+    (0x726c, 0x726c), (0x726e, 0x7270), (0x7272, 0x7274), (0x7276, 0x7279), (0x727b, 0x7281),
+    (0x7284, 0x7286), (0x7288, 0x7289), (0x728b, 0x728e), (0x7290, 0x7293), (0x7295, 0x7298),
+    (0x729a, 0x729b), (0x729d, 0x729e), (0x72a1, 0x72aa), (0x72ac, 0x72ac), (0x72ae, 0x72b0),
+    (0x72b4, 0x72b5), (0x72ba, 0x72ba), (0x72bd, 0x72bd), (0x72bf, 0x72c6), (0x72c9, 0x72cc),
+    (0x72ce, 0x72ce), (0x72d0, 0x72d2), (0x72d4, 0x72d4), (0x72d6, 0x72da), (0x72dc, 0x72dc),
+    (0x72df, 0x72e1), (0x72e3, 0x72e4), (0x72e6, 0x72e6), (0x72e8, 0x72eb), (0x72f3, 0x72f4),
+    (0x72f6, 0x7301), (0x7307, 0x7308), (0x730a, 0x730c), (0x730f, 0x730f), (0x7311, 0x7313),
+    (0x7316, 0x7319), (0x731b, 0x731e), (0x7322, 0x7323), (0x7325, 0x7327), (0x7329, 0x7329),
+    (0x732d, 0x732d), (0x7330, 0x7337), (0x733a, 0x733c), (0x733e, 0x7340), (0x7342, 0x7345),
+    (0x7349, 0x734a), (0x734c, 0x734e), (0x7350, 0x7352), (0x7357, 0x735b), (0x735d, 0x7362),
+    (0x7365, 0x736c), (0x736e, 0x7370), (0x7372, 0x7373), (0x7375, 0x7378), (0x737a, 0x738b),
+    (0x738e, 0x738e), (0x7392, 0x7397), (0x739d, 0x739d), (0x739f, 0x73a2), (0x73a4, 0x73a6),
+    (0x73a8, 0x73a9), (0x73ab, 0x73ad), (0x73b2, 0x73b9), (0x73bb, 0x73bc), (0x73be, 0x73c0),
+    (0x73c2, 0x73c3), (0x73c5, 0x73c8), (0x73ca, 0x73cd), (0x73d2, 0x73d4), (0x73d6, 0x73de),
+    (0x73e0, 0x73e0), (0x73e3, 0x73e3), (0x73e5, 0x73e5), (0x73e7, 0x73eb), (0x73ed, 0x73ee),
+    (0x73f4, 0x73f6), (0x73f8, 0x73f8), (0x73fa, 0x73fa), (0x73fc, 0x7401), (0x7403, 0x740d),
+    (0x7416, 0x7416), (0x741a, 0x741b), (0x741d, 0x741d), (0x7420, 0x7426), (0x7428, 0x7436),
+    (0x743a, 0x743a), (0x743f, 0x7442), (0x7444, 0x7444), (0x7446, 0x7446), (0x744a, 0x744b),
+    (0x744d, 0x7452), (0x7454, 0x7455), (0x7457, 0x7457), (0x7459, 0x745c), (0x745e, 0x745f),
+    (0x7462, 0x7464), (0x7467, 0x7467), (0x7469, 0x746a), (0x746d, 0x7473), (0x7475, 0x7475),
+    (0x7479, 0x7479), (0x747c, 0x7481), (0x7483, 0x7483), (0x7485, 0x748b), (0x7490, 0x7490),
+    (0x7492, 0x7492), (0x7494, 0x7495), (0x7497, 0x7498), (0x749a, 0x749a), (0x749c, 0x749c),
+    (0x749e, 0x74a1), (0x74a3, 0x74a3), (0x74a5, 0x74ab), (0x74ad, 0x74ad), (0x74af, 0x74b2),
+    (0x74b5, 0x74b8), (0x74ba, 0x74bb), (0x74bd, 0x74c3), (0x74c5, 0x74c5), (0x74ca, 0x74cb),
+    (0x74cf, 0x74cf), (0x74d4, 0x74e6), (0x74e8, 0x74e9), (0x74ec, 0x74ec), (0x74ee, 0x74ee),
+    (0x74f4, 0x74f7), (0x74fb, 0x74fb), (0x74fd, 0x7500), (0x7502, 0x7504), (0x7507, 0x7508),
+    (0x750b, 0x750d), (0x750f, 0x7518), (0x751a, 0x751a), (0x751c, 0x751d), (0x751f, 0x751f),
+    (0x7521, 0x7522), (0x7525, 0x7526), (0x7528, 0x7533), (0x7537, 0x753a), (0x753d, 0x7540),
+    (0x7547, 0x7548), (0x754b, 0x754c), (0x754e, 0x754f), (0x7554, 0x7554), (0x7559, 0x755d),
+    (0x755f, 0x755f), (0x7562, 0x7566), (0x756a, 0x756c), (0x756f, 0x7570), (0x7576, 0x7579),
+    (0x757d, 0x7580), (0x7584, 0x7584), (0x7586, 0x7587), (0x758a, 0x758c), (0x758f, 0x7591),
+    (0x7594, 0x7595), (0x7598, 0x759a), (0x759d, 0x759d), (0x75a2, 0x75a5), (0x75a7, 0x75a7),
+    (0x75aa, 0x75ab), (0x75b0, 0x75b0), (0x75b2, 0x75b3), (0x75b5, 0x75b6), (0x75b8, 0x75c2),
+    (0x75c4, 0x75c5), (0x75c7, 0x75c7), (0x75ca, 0x75d2), (0x75d4, 0x75d5), (0x75d7, 0x75db),
+    (0x75dd, 0x75e4), (0x75e6, 0x75e7), (0x75ed, 0x75ed), (0x75ef, 0x7601), (0x7603, 0x7603),
+    (0x7608, 0x760d), (0x760f, 0x7611), (0x7613, 0x7616), (0x7619, 0x7629), (0x762d, 0x762d),
+    (0x762f, 0x7635), (0x7638, 0x7638), (0x763a, 0x763a), (0x763c, 0x763d), (0x7642, 0x7643),
+    (0x7646, 0x7649), (0x764c, 0x764c), (0x7650, 0x7650), (0x7652, 0x7653), (0x7656, 0x765a),
+    (0x765c, 0x765c), (0x765f, 0x7662), (0x7664, 0x7665), (0x7669, 0x766a), (0x766c, 0x766e),
+    (0x7670, 0x7672), (0x7675, 0x7675), (0x7678, 0x7679), (0x767b, 0x767f), (0x7681, 0x7682),
+    (0x7684, 0x7684), (0x7686, 0x768b), (0x768e, 0x768f), (0x7692, 0x7693), (0x7695, 0x7696),
+    (0x7699, 0x769e), (0x76a4, 0x76a4), (0x76a6, 0x76a6), (0x76aa, 0x76ab), (0x76ad, 0x76b0),
+    (0x76b4, 0x76b5), (0x76b8, 0x76b8), (0x76ba, 0x76bb), (0x76bd, 0x76bf), (0x76c2, 0x76c6),
+    (0x76c8, 0x76ca), (0x76cd, 0x76ce), (0x76d2, 0x76d4), (0x76da, 0x76df), (0x76e1, 0x76e1),
+    (0x76e3, 0x76e7), (0x76e9, 0x76ea), (0x76ec, 0x76f5), (0x76f7, 0x76fc), (0x76fe, 0x76fe),
+    (0x7701, 0x7701), (0x7703, 0x7705), (0x7707, 0x770b), (0x7710, 0x7713), (0x7715, 0x7715),
+    (0x7719, 0x771b), (0x771d, 0x771d), (0x771f, 0x7720), (0x7722, 0x7723), (0x7725, 0x7725),
+    (0x7727, 0x7729), (0x772d, 0x772d), (0x772f, 0x772f), (0x7731, 0x773e), (0x7744, 0x7747),
+    (0x774a, 0x774f), (0x7752, 0x7752), (0x7754, 0x7756), (0x7759, 0x775c), (0x775e, 0x7763),
+    (0x7765, 0x776f), (0x7779, 0x7779), (0x777c, 0x7785), (0x7787, 0x7789), (0x778b, 0x778f),
+    (0x7791, 0x7791), (0x7795, 0x7795), (0x7797, 0x7797), (0x7799, 0x77a3), (0x77a5, 0x77a5),
+    (0x77a7, 0x77a8), (0x77aa, 0x77ad), (0x77b0, 0x77b7), (0x77ba, 0x77bd), (0x77bf, 0x77bf),
+    (0x77c2, 0x77c2), (0x77c4, 0x77c4), (0x77c7, 0x77c7), (0x77c9, 0x77ca), (0x77cc, 0x77d0),
+    (0x77d3, 0x77d5), (0x77d7, 0x77dc), (0x77de, 0x77de), (0x77e0, 0x77e0), (0x77e2, 0x77e3),
+    (0x77e5, 0x77e5), (0x77e7, 0x77e9), (0x77ec, 0x77f3), (0x77f7, 0x77fd), (0x7802, 0x7803),
+    (0x7805, 0x7806), (0x7809, 0x7809), (0x780c, 0x7814), (0x781d, 0x781d), (0x781f, 0x7823),
+    (0x7825, 0x7835), (0x7837, 0x7838), (0x7843, 0x7843), (0x7845, 0x7845), (0x7848, 0x784a),
+    (0x784c, 0x784e), (0x7850, 0x7850), (0x7852, 0x7852), (0x785c, 0x785e), (0x7860, 0x7860),
+    (0x7862, 0x7862), (0x7864, 0x7865), (0x7868, 0x7871), (0x7879, 0x7879), (0x787b, 0x787c),
+    (0x787e, 0x7880), (0x7883, 0x7887), (0x7889, 0x7889), (0x788c, 0x788c), (0x788e, 0x788f),
+    (0x7891, 0x7891), (0x7893, 0x789a), (0x789e, 0x78a5), (0x78a7, 0x78ad), (0x78b0, 0x78b0),
+    (0x78b2, 0x78b4), (0x78ba, 0x78bc), (0x78be, 0x78be), (0x78c1, 0x78c1), (0x78c3, 0x78c5),
+    (0x78c8, 0x78d1), (0x78d4, 0x78d5), (0x78da, 0x78db), (0x78dd, 0x78e3), (0x78e5, 0x78e5),
+    (0x78e7, 0x78ea), (0x78ec, 0x78ed), (0x78ef, 0x78ef), (0x78f2, 0x78f4), (0x78f7, 0x78f7),
+    (0x78f9, 0x78ff), (0x7901, 0x7902), (0x7904, 0x7905), (0x7909, 0x7909), (0x790c, 0x790c),
+    (0x790e, 0x790e), (0x7910, 0x7914), (0x7917, 0x7917), (0x7919, 0x7919), (0x791b, 0x791e),
+    (0x7921, 0x7921), (0x7923, 0x792d), (0x792f, 0x792f), (0x7931, 0x7931), (0x7935, 0x7935),
+    (0x7938, 0x793a), (0x793d, 0x7942), (0x7944, 0x794c), (0x794f, 0x7957), (0x795a, 0x7961),
+    (0x7963, 0x7965), (0x7967, 0x796b), (0x796d, 0x796d), (0x7970, 0x7970), (0x7972, 0x7974),
+    (0x7979, 0x797a), (0x797c, 0x797d), (0x797f, 0x797f), (0x7981, 0x7982), (0x7988, 0x7988),
+    (0x798a, 0x798b), (0x798d, 0x7990), (0x7992, 0x7998), (0x799a, 0x799c), (0x79a0, 0x79a2),
+    (0x79a4, 0x79a4), (0x79a6, 0x79a8), (0x79aa, 0x79ae), (0x79b0, 0x79b4), (0x79b6, 0x79bb),
+    (0x79bd, 0x79c1), (0x79c5, 0x79c5), (0x79c8, 0x79c9), (0x79cb, 0x79cb), (0x79cd, 0x79cf),
+    (0x79d1, 0x79d2), (0x79d5, 0x79d6), (0x79d8, 0x79d8), (0x79dc, 0x79e0), (0x79e3, 0x79e4),
+    (0x79e6, 0x79e7), (0x79e9, 0x79ee), (0x79f6, 0x79f8), (0x79fa, 0x79fb), (0x7a00, 0x7a00),
+    (0x7a02, 0x7a05), (0x7a08, 0x7a08), (0x7a0a, 0x7a0d), (0x7a10, 0x7a15), (0x7a17, 0x7a1c),
+    (0x7a1e, 0x7a20), (0x7a22, 0x7a22), (0x7a26, 0x7a26), (0x7a28, 0x7a28), (0x7a2b, 0x7a2b),
+    (0x7a2e, 0x7a31), (0x7a37, 0x7a37), (0x7a39, 0x7a39), (0x7a3b, 0x7a3d), (0x7a3f, 0x7a40),
+    (0x7a44, 0x7a44), (0x7a46, 0x7a48), (0x7a4a, 0x7a4e), (0x7a54, 0x7a54), (0x7a56, 0x7a58),
+    (0x7a5a, 0x7a5c), (0x7a5f, 0x7a62), (0x7a67, 0x7a69), (0x7a6b, 0x7a6e), (0x7a70, 0x7a71),
+    (0x7a74, 0x7a76), (0x7a78, 0x7a7b), (0x7a7e, 0x7a81), (0x7a84, 0x7a8c), (0x7a8f, 0x7a90),
+    (0x7a92, 0x7a92), (0x7a94, 0x7a99), (0x7a9e, 0x7aa0), (0x7aa2, 0x7aa3), (0x7aa8, 0x7aac),
+    (0x7aae, 0x7aaf), (0x7ab1, 0x7ab8), (0x7aba, 0x7aba), (0x7abe, 0x7ac1), (0x7ac4, 0x7ac5),
+    (0x7ac7, 0x7ac7), (0x7aca, 0x7acb), (0x7ad1, 0x7ad1), (0x7ad8, 0x7ad9), (0x7adf, 0x7ae0),
+    (0x7ae3, 0x7ae6), (0x7aeb, 0x7aeb), (0x7aed, 0x7aef), (0x7af6, 0x7af7), (0x7af9, 0x7afb),
+    (0x7afd, 0x7afd), (0x7aff, 0x7b01), (0x7b04, 0x7b06), (0x7b08, 0x7b0a), (0x7b0e, 0x7b13),
+    (0x7b18, 0x7b1b), (0x7b1d, 0x7b1e), (0x7b20, 0x7b20), (0x7b22, 0x7b26), (0x7b28, 0x7b28),
+    (0x7b2a, 0x7b35), (0x7b38, 0x7b38), (0x7b3b, 0x7b3b), (0x7b40, 0x7b40), (0x7b44, 0x7b52),
+    (0x7b54, 0x7b54), (0x7b56, 0x7b56), (0x7b58, 0x7b58), (0x7b60, 0x7b61), (0x7b63, 0x7b67),
+    (0x7b69, 0x7b69), (0x7b6d, 0x7b6e), (0x7b70, 0x7b78), (0x7b82, 0x7b82), (0x7b84, 0x7b85),
+    (0x7b87, 0x7b88), (0x7b8a, 0x7b91), (0x7b94, 0x7b99), (0x7b9b, 0x7b9d), (0x7ba0, 0x7ba1),
+    (0x7ba4, 0x7ba4), (0x7bac, 0x7bad), (0x7baf, 0x7baf), (0x7bb1, 0x7bb1), (0x7bb4, 0x7bb5),
+    (0x7bb7, 0x7bb9), (0x7bbe, 0x7bbe), (0x7bc0, 0x7bc1), (0x7bc4, 0x7bc4), (0x7bc6, 0x7bc7),
+    (0x7bc9, 0x7bcc), (0x7bce, 0x7bce), (0x7bd4, 0x7bd5), (0x7bd8, 0x7beb), (0x7bf0, 0x7bf4),
+    (0x7bf7, 0x7bf9), (0x7bfb, 0x7bfb), (0x7bfd, 0x7c03), (0x7c05, 0x7c07), (0x7c09, 0x7c11),
+    (0x7c19, 0x7c19), (0x7c1c, 0x7c23), (0x7c25, 0x7c2d), (0x7c30, 0x7c30), (0x7c33, 0x7c33),
+    (0x7c37, 0x7c39), (0x7c3b, 0x7c40), (0x7c43, 0x7c43), (0x7c45, 0x7c45), (0x7c47, 0x7c4a),
+    (0x7c4c, 0x7c4d), (0x7c50, 0x7c50), (0x7c53, 0x7c54), (0x7c57, 0x7c57), (0x7c59, 0x7c5c),
+    (0x7c5f, 0x7c60), (0x7c63, 0x7c67), (0x7c69, 0x7c6c), (0x7c6e, 0x7c6f), (0x7c72, 0x7c73),
+    (0x7c75, 0x7c75), (0x7c78, 0x7c7a), (0x7c7d, 0x7c7d), (0x7c7f, 0x7c81), (0x7c84, 0x7c85),
+    (0x7c88, 0x7c8a), (0x7c8c, 0x7c8d), (0x7c91, 0x7c92), (0x7c94, 0x7c98), (0x7c9e, 0x7c9f),
+    (0x7ca1, 0x7ca3), (0x7ca5, 0x7ca5), (0x7ca8, 0x7ca8), (0x7caf, 0x7caf), (0x7cb1, 0x7cb5),
+    (0x7cb9, 0x7cbf), (0x7cc5, 0x7cc5), (0x7cc8, 0x7cc8), (0x7cca, 0x7ccc), (0x7cce, 0x7cce),
+    (0x7cd0, 0x7cd2), (0x7cd4, 0x7cd7), (0x7cd9, 0x7cd9), (0x7cdc, 0x7ce0), (0x7ce2, 0x7ce2),
+    (0x7ce7, 0x7ce8), (0x7cea, 0x7cea), (0x7cec, 0x7cec), (0x7cee, 0x7cf2), (0x7cf4, 0x7cf4),
+    (0x7cf6, 0x7cf8), (0x7cfb, 0x7cfb), (0x7cfd, 0x7cfe), (0x7d00, 0x7d22), (0x7d28, 0x7d29),
+    (0x7d2b, 0x7d2c), (0x7d2e, 0x7d33), (0x7d35, 0x7d36), (0x7d38, 0x7d47), (0x7d4a, 0x7d4a),
+    (0x7d4e, 0x7d56), (0x7d58, 0x7d58), (0x7d5b, 0x7d5c), (0x7d5e, 0x7d5f), (0x7d61, 0x7d63),
+    (0x7d66, 0x7d6b), (0x7d6d, 0x7d73), (0x7d79, 0x7d7d), (0x7d7f, 0x7d81), (0x7d83, 0x7d86),
+    (0x7d88, 0x7d88), (0x7d8c, 0x7d8f), (0x7d91, 0x7d94), (0x7d96, 0x7d96), (0x7d9c, 0x7da3),
+    (0x7da6, 0x7da7), (0x7da9, 0x7daa), (0x7dac, 0x7db2), (0x7db4, 0x7db5), (0x7db7, 0x7dc2),
+    (0x7dc4, 0x7dc7), (0x7dc9, 0x7dcc), (0x7dce, 0x7dce), (0x7dd2, 0x7dd2), (0x7dd7, 0x7ddb),
+    (0x7ddd, 0x7de1), (0x7de3, 0x7de3), (0x7de6, 0x7dea), (0x7dec, 0x7dec), (0x7dee, 0x7df4),
+    (0x7df6, 0x7df7), (0x7df9, 0x7dfb), (0x7e03, 0x7e03), (0x7e08, 0x7e17), (0x7e1a, 0x7e25),
+    (0x7e29, 0x7e2b), (0x7e2d, 0x7e49), (0x7e4c, 0x7e4c), (0x7e50, 0x7e5a), (0x7e5c, 0x7e5c),
+    (0x7e5e, 0x7e63), (0x7e68, 0x7e6b), (0x7e6d, 0x7e6d), (0x7e6f, 0x7e70), (0x7e72, 0x7e7e),
+    (0x7e80, 0x7e82), (0x7e86, 0x7e88), (0x7e8a, 0x7e8d), (0x7e8f, 0x7e8f), (0x7e91, 0x7e91),
+    (0x7e93, 0x7e9c), (0x7f36, 0x7f36), (0x7f38, 0x7f3a), (0x7f3d, 0x7f3f), (0x7f43, 0x7f45),
+    (0x7f48, 0x7f48), (0x7f4a, 0x7f4d), (0x7f4f, 0x7f51), (0x7f54, 0x7f55), (0x7f58, 0x7f58),
+    (0x7f5b, 0x7f61), (0x7f63, 0x7f63), (0x7f65, 0x7f6e), (0x7f70, 0x7f70), (0x7f72, 0x7f73),
+    (0x7f75, 0x7f77), (0x7f79, 0x7f7f), (0x7f83, 0x7f83), (0x7f85, 0x7f8e), (0x7f91, 0x7f92),
+    (0x7f94, 0x7f96), (0x7f9a, 0x7f9e), (0x7fa0, 0x7fa2), (0x7fa4, 0x7fa9), (0x7fac, 0x7fad),
+    (0x7faf, 0x7fb3), (0x7fb5, 0x7fc3), (0x7fc5, 0x7fc5), (0x7fc7, 0x7fc7), (0x7fc9, 0x7fd2),
+    (0x7fd4, 0x7fd5), (0x7fd7, 0x7fd7), (0x7fdb, 0x7fdc), (0x7fde, 0x7fe3), (0x7fe5, 0x7fe6),
+    (0x7fe8, 0x7ff5), (0x7ff7, 0x7ff9), (0x7ffb, 0x8001), (0x8003, 0x8007), (0x800b, 0x8012),
+    (0x8014, 0x8019), (0x801b, 0x801c), (0x801e, 0x801f), (0x8021, 0x8021), (0x8024, 0x8024),
+    (0x8026, 0x8026), (0x8028, 0x802a), (0x802c, 0x802c), (0x8030, 0x8030), (0x8033, 0x8037),
+    (0x8039, 0x8039), (0x803d, 0x803f), (0x8043, 0x8043), (0x8046, 0x8048), (0x804a, 0x804a),
+    (0x804f, 0x8052), (0x8056, 0x8056), (0x8058, 0x8058), (0x805a, 0x805a), (0x805c, 0x805e),
+    (0x8064, 0x8064), (0x8067, 0x8067), (0x806c, 0x806c), (0x806f, 0x8073), (0x8075, 0x8079),
+    (0x807d, 0x807f), (0x8082, 0x8082), (0x8084, 0x8087), (0x8089, 0x808c), (0x808f, 0x8090),
+    (0x8092, 0x8093), (0x8095, 0x8096), (0x8098, 0x809d), (0x80a1, 0x80a3), (0x80a5, 0x80a5),
+    (0x80a9, 0x80ab), (0x80ad, 0x80af), (0x80b1, 0x80b2), (0x80b4, 0x80b5), (0x80b8, 0x80b8),
+    (0x80ba, 0x80ba), (0x80c2, 0x80c5), (0x80c7, 0x80ca), (0x80cc, 0x80d1), (0x80d4, 0x80de),
+    (0x80e0, 0x80e1), (0x80e3, 0x80e6), (0x80ed, 0x80ed), (0x80ef, 0x80f5), (0x80f8, 0x80fe),
+    (0x8100, 0x8102), (0x8105, 0x8106), (0x8108, 0x8108), (0x810a, 0x810a), (0x8115, 0x8116),
+    (0x8118, 0x8119), (0x811b, 0x811b), (0x811d, 0x811f), (0x8121, 0x8125), (0x8127, 0x8127),
+    (0x8129, 0x8129), (0x812b, 0x812d), (0x812f, 0x8130), (0x8139, 0x813a), (0x813d, 0x813e),
+    (0x8143, 0x8144), (0x8146, 0x8147), (0x814a, 0x8155), (0x815b, 0x815c), (0x815e, 0x815e),
+    (0x8160, 0x8162), (0x8164, 0x8167), (0x8169, 0x8169), (0x816b, 0x816b), (0x816e, 0x8174),
+    (0x8176, 0x817a), (0x817f, 0x8180), (0x8182, 0x8183), (0x8186, 0x818d), (0x818f, 0x818f),
+    (0x8195, 0x8195), (0x8197, 0x81a0), (0x81a2, 0x81a3), (0x81a6, 0x81a9), (0x81ab, 0x81ac),
+    (0x81ae, 0x81ae), (0x81b0, 0x81b5), (0x81b7, 0x81b7), (0x81b9, 0x81c0), (0x81c2, 0x81c7),
+    (0x81c9, 0x81ca), (0x81cc, 0x81cd), (0x81cf, 0x81d2), (0x81d5, 0x81d5), (0x81d7, 0x81db),
+    (0x81dd, 0x81e3), (0x81e5, 0x81ea), (0x81ec, 0x81ee), (0x81f2, 0x81f4), (0x81f7, 0x81fc),
+    (0x81fe, 0x8202), (0x8204, 0x8205), (0x8207, 0x820d), (0x8210, 0x8212), (0x8214, 0x8216),
+    (0x821b, 0x8222), (0x8225, 0x8225), (0x8228, 0x8228), (0x822a, 0x822c), (0x822f, 0x822f),
+    (0x8232, 0x823a), (0x823c, 0x823d), (0x823f, 0x8240), (0x8242, 0x8242), (0x8244, 0x8245),
+    (0x8247, 0x8247), (0x8249, 0x8249), (0x824b, 0x824b), (0x824e, 0x8253), (0x8255, 0x825c),
+    (0x825e, 0x825f), (0x8261, 0x8261), (0x8263, 0x8264), (0x8266, 0x8266), (0x8268, 0x8269),
+    (0x826b, 0x826f), (0x8271, 0x8272), (0x8274, 0x8275), (0x8277, 0x8278), (0x827c, 0x8280),
+    (0x8283, 0x8285), (0x828a, 0x828b), (0x828d, 0x8294), (0x8298, 0x829b), (0x829d, 0x82a5),
+    (0x82a7, 0x82a9), (0x82ab, 0x82b1), (0x82b3, 0x82be), (0x82c0, 0x82c0), (0x82c2, 0x82c3),
+    (0x82d1, 0x82d7), (0x82d9, 0x82d9), (0x82db, 0x82dc), (0x82de, 0x82e1), (0x82e3, 0x82e8),
+    (0x82ea, 0x82ed), (0x82ef, 0x82f6), (0x82f9, 0x82fb), (0x82fe, 0x82fe), (0x8300, 0x8309),
+    (0x830c, 0x830d), (0x8316, 0x8317), (0x8319, 0x8319), (0x831b, 0x831c), (0x831e, 0x831e),
+    (0x8320, 0x8320), (0x8322, 0x8322), (0x8324, 0x832d), (0x832f, 0x832f), (0x8331, 0x833c),
+    (0x833f, 0x8345), (0x8347, 0x8354), (0x8356, 0x8356), (0x8373, 0x8378), (0x837a, 0x837f),
+    (0x8381, 0x8381), (0x8383, 0x8383), (0x8386, 0x8390), (0x8392, 0x839b), (0x839d, 0x839e),
+    (0x83a0, 0x83a0), (0x83a2, 0x83ab), (0x83ae, 0x83b0), (0x83bd, 0x83bd), (0x83bf, 0x83cc),
+    (0x83ce, 0x83cf), (0x83d1, 0x83d1), (0x83d4, 0x83d9), (0x83db, 0x83e5), (0x83e7, 0x83ec),
+    (0x83ee, 0x83f6), (0x83f8, 0x83ff), (0x8401, 0x8401), (0x8403, 0x8404), (0x8406, 0x8407),
+    (0x8409, 0x8413), (0x841b, 0x841b), (0x8423, 0x8423), (0x8429, 0x8429), (0x842b, 0x842d),
+    (0x842f, 0x843d), (0x843f, 0x8440), (0x8442, 0x8447), (0x8449, 0x8449), (0x844b, 0x844e),
+    (0x8450, 0x8452), (0x8454, 0x8454), (0x8456, 0x8457), (0x8459, 0x845b), (0x845d, 0x8461),
+    (0x8463, 0x8463), (0x8465, 0x8469), (0x846b, 0x8470), (0x8473, 0x847a), (0x847d, 0x847e),
+    (0x8482, 0x8482), (0x8486, 0x8486), (0x848d, 0x8491), (0x8494, 0x8494), (0x8497, 0x84a2),
+    (0x84a4, 0x84a4), (0x84a7, 0x84ac), (0x84ae, 0x84b2), (0x84b4, 0x84b4), (0x84b6, 0x84b6),
+    (0x84b8, 0x84bc), (0x84bf, 0x84c2), (0x84c4, 0x84c7), (0x84c9, 0x84d4), (0x84d6, 0x84d7),
+    (0x84db, 0x84db), (0x84e7, 0x84ec), (0x84ee, 0x84f4), (0x84f6, 0x84f7), (0x84f9, 0x8500),
+    (0x8502, 0x8502), (0x8506, 0x850f), (0x8511, 0x851a), (0x851c, 0x8521), (0x8523, 0x8531),
+    (0x853b, 0x853b), (0x853d, 0x853e), (0x8540, 0x8541), (0x8543, 0x854a), (0x854d, 0x854e),
+    (0x8551, 0x8551), (0x8553, 0x8559), (0x855b, 0x855b), (0x855d, 0x855e), (0x8560, 0x856e),
+    (0x8571, 0x8571), (0x8575, 0x857c), (0x857e, 0x857e), (0x8580, 0x8591), (0x8594, 0x8596),
+    (0x8598, 0x85a4), (0x85a6, 0x85aa), (0x85af, 0x85b1), (0x85b3, 0x85ba), (0x85bd, 0x85c0),
+    (0x85c2, 0x85c9), (0x85cb, 0x85cb), (0x85cd, 0x85d2), (0x85d5, 0x85d5), (0x85d7, 0x85da),
+    (0x85dc, 0x85df), (0x85e1, 0x85e6), (0x85e8, 0x85ed), (0x85ef, 0x85f2), (0x85f6, 0x85fb),
+    (0x85fd, 0x8601), (0x8604, 0x8607), (0x8609, 0x860c), (0x8611, 0x8611), (0x8617, 0x861c),
+    (0x861e, 0x8627), (0x8629, 0x862a), (0x862c, 0x862e), (0x8631, 0x8636), (0x8638, 0x863c),
+    (0x863e, 0x8640), (0x8643, 0x8643), (0x8646, 0x8648), (0x864b, 0x864e), (0x8650, 0x8650),
+    (0x8652, 0x8656), (0x8659, 0x8659), (0x865b, 0x865c), (0x865e, 0x865f), (0x8661, 0x8665),
+    (0x8667, 0x866b), (0x866d, 0x8671), (0x8673, 0x8674), (0x8677, 0x8677), (0x8679, 0x867c),
+    (0x8685, 0x8687), (0x868a, 0x868e), (0x8690, 0x8691), (0x8693, 0x869a), (0x869c, 0x869e),
+    (0x86a1, 0x86a5), (0x86a7, 0x86aa), (0x86af, 0x86b1), (0x86b3, 0x86c9), (0x86cb, 0x86cc),
+    (0x86d0, 0x86d1), (0x86d3, 0x86d4), (0x86d6, 0x86df), (0x86e2, 0x86e4), (0x86e6, 0x86e6),
+    (0x86e8, 0x86ed), (0x86f5, 0x86fb), (0x86fe, 0x86fe), (0x8700, 0x870e), (0x8711, 0x8713),
+    (0x8718, 0x871c), (0x871e, 0x871e), (0x8720, 0x872a), (0x872c, 0x872e), (0x8730, 0x8735),
+    (0x8737, 0x8738), (0x873a, 0x873c), (0x873e, 0x8743), (0x8746, 0x8746), (0x874c, 0x876f),
+    (0x8773, 0x877b), (0x8781, 0x8785), (0x8787, 0x8789), (0x878d, 0x878d), (0x878f, 0x8794),
+    (0x8796, 0x8798), (0x879a, 0x879f), (0x87a2, 0x87a4), (0x87aa, 0x87b0), (0x87b2, 0x87c0),
+    (0x87c2, 0x87c6), (0x87c8, 0x87cc), (0x87d1, 0x87d4), (0x87d7, 0x87d9), (0x87db, 0x87e8),
+    (0x87ea, 0x87ed), (0x87ef, 0x87ef), (0x87f2, 0x87f4), (0x87f6, 0x87f7), (0x87f9, 0x87fc),
+    (0x87fe, 0x8803), (0x8805, 0x8806), (0x8808, 0x880d), (0x8810, 0x8811), (0x8813, 0x8817),
+    (0x8819, 0x8819), (0x881b, 0x881d), (0x881f, 0x8826), (0x8828, 0x882c), (0x882e, 0x8833),
+    (0x8835, 0x8839), (0x883b, 0x8841), (0x8843, 0x8844), (0x8848, 0x8848), (0x884a, 0x884e),
+    (0x8852, 0x8853), (0x8855, 0x8857), (0x8859, 0x885b), (0x885d, 0x885d), (0x8861, 0x8863),
+    (0x8867, 0x886b), (0x886d, 0x886d), (0x886f, 0x8872), (0x8874, 0x8877), (0x8879, 0x8879),
+    (0x887c, 0x8883), (0x8888, 0x8889), (0x888b, 0x888e), (0x8891, 0x8893), (0x8895, 0x889b),
+    (0x889e, 0x889f), (0x88a1, 0x88a2), (0x88a4, 0x88a4), (0x88a7, 0x88a8), (0x88aa, 0x88ac),
+    (0x88b1, 0x88b2), (0x88b6, 0x88ba), (0x88bc, 0x88be), (0x88c0, 0x88c2), (0x88c9, 0x88ce),
+    (0x88d0, 0x88d0), (0x88d2, 0x88d2), (0x88d4, 0x88df), (0x88e1, 0x88e1), (0x88e7, 0x88e8),
+    (0x88eb, 0x88ec), (0x88ee, 0x88f4), (0x88f6, 0x88fe), (0x8901, 0x8902), (0x8905, 0x8907),
+    (0x8909, 0x890c), (0x890e, 0x890e), (0x8910, 0x891a), (0x891e, 0x891f), (0x8921, 0x8923),
+    (0x8925, 0x8927), (0x8929, 0x8933), (0x8935, 0x8938), (0x893b, 0x893e), (0x8941, 0x8942),
+    (0x8944, 0x8944), (0x8946, 0x8946), (0x8949, 0x8949), (0x894b, 0x894c), (0x894f, 0x8953),
+    (0x8956, 0x8964), (0x8966, 0x8966), (0x8969, 0x896f), (0x8971, 0x8974), (0x8976, 0x8976),
+    (0x8979, 0x897c), (0x897e, 0x897f), (0x8981, 0x8983), (0x8985, 0x8986), (0x8988, 0x8988),
+    (0x898b, 0x898b), (0x898f, 0x898f), (0x8993, 0x8993), (0x8995, 0x8998), (0x899b, 0x899f),
+    (0x89a1, 0x89a4), (0x89a6, 0x89a6), (0x89aa, 0x89aa), (0x89ac, 0x89af), (0x89b2, 0x89b2),
+    (0x89b6, 0x89b7), (0x89b9, 0x89ba), (0x89bd, 0x89c0), (0x89d2, 0x89d6), (0x89d9, 0x89dd),
+    (0x89df, 0x89e6), (0x89e8, 0x89e9), (0x89eb, 0x89ed), (0x89f0, 0x89f4), (0x89f6, 0x89f8),
+    (0x89fa, 0x89fc), (0x89fe, 0x8a00), (0x8a02, 0x8a04), (0x8a07, 0x8a08), (0x8a0a, 0x8a0a),
+    (0x8a0c, 0x8a0c), (0x8a0e, 0x8a13), (0x8a15, 0x8a18), (0x8a1b, 0x8a1b), (0x8a1d, 0x8a1f),
+    (0x8a22, 0x8a23), (0x8a25, 0x8a25), (0x8a27, 0x8a27), (0x8a2a, 0x8a2a), (0x8a2c, 0x8a2d),
+    (0x8a30, 0x8a31), (0x8a34, 0x8a34), (0x8a36, 0x8a36), (0x8a39, 0x8a3c), (0x8a3e, 0x8a41),
+    (0x8a44, 0x8a46), (0x8a48, 0x8a48), (0x8a4a, 0x8a4a), (0x8a4c, 0x8a52), (0x8a54, 0x8a59),
+    (0x8a5b, 0x8a5b), (0x8a5e, 0x8a5e), (0x8a60, 0x8a63), (0x8a66, 0x8a66), (0x8a68, 0x8a69),
+    (0x8a6b, 0x8a6e), (0x8a70, 0x8a77), (0x8a79, 0x8a7c), (0x8a7f, 0x8a7f), (0x8a81, 0x8a87),
+    (0x8a8b, 0x8a8d), (0x8a8f, 0x8a8f), (0x8a91, 0x8a93), (0x8a95, 0x8a96), (0x8a98, 0x8a9a),
+    (0x8a9e, 0x8a9e), (0x8aa0, 0x8aa1), (0x8aa3, 0x8aa8), (0x8aaa, 0x8aab), (0x8ab0, 0x8ab0),
+    (0x8ab2, 0x8ab2), (0x8ab6, 0x8ab6), (0x8ab8, 0x8ac0), (0x8ac2, 0x8ac9), (0x8acb, 0x8acb),
+    (0x8acd, 0x8acd), (0x8acf, 0x8acf), (0x8ad1, 0x8ad9), (0x8adb, 0x8ae2), (0x8ae4, 0x8ae4),
+    (0x8ae6, 0x8ae8), (0x8aeb, 0x8aeb), (0x8aed, 0x8af8), (0x8afa, 0x8afc), (0x8afe, 0x8b02),
+    (0x8b04, 0x8b08), (0x8b0a, 0x8b0b), (0x8b0d, 0x8b1e), (0x8b20, 0x8b20), (0x8b22, 0x8b28),
+    (0x8b2a, 0x8b2c), (0x8b2e, 0x8b31), (0x8b33, 0x8b33), (0x8b35, 0x8b37), (0x8b39, 0x8b3e),
+    (0x8b40, 0x8b42), (0x8b45, 0x8b4b), (0x8b4e, 0x8b5a), (0x8b5c, 0x8b5d), (0x8b5f, 0x8b60),
+    (0x8b63, 0x8b63), (0x8b65, 0x8b68), (0x8b6a, 0x8b6d), (0x8b6f, 0x8b70), (0x8b74, 0x8b74),
+    (0x8b77, 0x8b7b), (0x8b7d, 0x8b80), (0x8b82, 0x8b82), (0x8b84, 0x8b86), (0x8b88, 0x8b88),
+    (0x8b8a, 0x8b8c), (0x8b8e, 0x8b8e), (0x8b92, 0x8b96), (0x8b98, 0x8b9a), (0x8b9c, 0x8b9c),
+    (0x8b9e, 0x8b9f), (0x8c37, 0x8c37), (0x8c39, 0x8c39), (0x8c3b, 0x8c3f), (0x8c41, 0x8c43),
+    (0x8c45, 0x8c50), (0x8c54, 0x8c57), (0x8c5a, 0x8c5a), (0x8c5c, 0x8c5d), (0x8c5f, 0x8c5f),
+    (0x8c61, 0x8c62), (0x8c64, 0x8c66), (0x8c68, 0x8c6d), (0x8c6f, 0x8c73), (0x8c75, 0x8c7b),
+    (0x8c7d, 0x8c7d), (0x8c80, 0x8c82), (0x8c84, 0x8c86), (0x8c89, 0x8c8a), (0x8c8c, 0x8c8d),
+    (0x8c8f, 0x8c95), (0x8c97, 0x8c9a), (0x8c9c, 0x8c9e), (0x8ca0, 0x8ca5), (0x8ca7, 0x8cac),
+    (0x8caf, 0x8cb0), (0x8cb2, 0x8cc5), (0x8cc7, 0x8cc8), (0x8cca, 0x8cca), (0x8ccc, 0x8ccc),
+    (0x8ccf, 0x8ccf), (0x8cd1, 0x8cd3), (0x8cd5, 0x8cd5), (0x8cd7, 0x8cd7), (0x8cd9, 0x8cda),
+    (0x8cdc, 0x8ce8), (0x8cea, 0x8cea), (0x8cec, 0x8cee), (0x8cf0, 0x8cf1), (0x8cf3, 0x8cf5),
+    (0x8cf8, 0x8cfe), (0x8d00, 0x8d00), (0x8d02, 0x8d02), (0x8d04, 0x8d0a), (0x8d0d, 0x8d0d),
+    (0x8d0f, 0x8d10), (0x8d13, 0x8d17), (0x8d19, 0x8d19), (0x8d1b, 0x8d1b), (0x8d64, 0x8d64),
+    (0x8d66, 0x8d69), (0x8d6b, 0x8d70), (0x8d72, 0x8d74), (0x8d76, 0x8d79), (0x8d7b, 0x8d7b),
+    (0x8d7d, 0x8d7d), (0x8d80, 0x8d81), (0x8d84, 0x8d85), (0x8d89, 0x8d8a), (0x8d8c, 0x8d96),
+    (0x8d99, 0x8d99), (0x8d9b, 0x8d9c), (0x8d9f, 0x8da1), (0x8da3, 0x8da3), (0x8da5, 0x8da5),
+    (0x8da7, 0x8da8), (0x8daa, 0x8daf), (0x8db2, 0x8db7), (0x8db9, 0x8dba), (0x8dbc, 0x8dbc),
+    (0x8dbe, 0x8dbf), (0x8dc1, 0x8dc2), (0x8dc5, 0x8dc8), (0x8dcb, 0x8dd1), (0x8dd3, 0x8dd3),
+    (0x8dd5, 0x8ddd), (0x8ddf, 0x8de4), (0x8de6, 0x8dec), (0x8dee, 0x8df4), (0x8dfa, 0x8dfa),
+    (0x8dfc, 0x8e00), (0x8e02, 0x8e07), (0x8e09, 0x8e0a), (0x8e0d, 0x8e0d), (0x8e0f, 0x8e27),
+    (0x8e29, 0x8e29), (0x8e2b, 0x8e2b), (0x8e2e, 0x8e2e), (0x8e30, 0x8e31), (0x8e33, 0x8e36),
+    (0x8e38, 0x8e39), (0x8e3c, 0x8e42), (0x8e44, 0x8e45), (0x8e47, 0x8e4e), (0x8e50, 0x8e50),
+    (0x8e53, 0x8e57), (0x8e59, 0x8e67), (0x8e69, 0x8e6a), (0x8e6c, 0x8e6d), (0x8e6f, 0x8e6f),
+    (0x8e72, 0x8e74), (0x8e76, 0x8e76), (0x8e78, 0x8e78), (0x8e7a, 0x8e7c), (0x8e81, 0x8e82),
+    (0x8e84, 0x8e8e), (0x8e90, 0x8e98), (0x8e9a, 0x8e9a), (0x8e9d, 0x8ea1), (0x8ea3, 0x8ea6),
+    (0x8ea8, 0x8eac), (0x8eb2, 0x8eb2), (0x8eba, 0x8eba), (0x8ebd, 0x8ebd), (0x8ec0, 0x8ec0),
+    (0x8ec2, 0x8ec2), (0x8ec9, 0x8ecd), (0x8ecf, 0x8ecf), (0x8ed1, 0x8ed4), (0x8ed7, 0x8ed8),
+    (0x8edb, 0x8ee1), (0x8ee5, 0x8ee9), (0x8eeb, 0x8eec), (0x8eee, 0x8eef), (0x8ef1, 0x8ef1),
+    (0x8ef4, 0x8efc), (0x8efe, 0x8f03), (0x8f05, 0x8f0b), (0x8f0d, 0x8f0e), (0x8f10, 0x8f18),
+    (0x8f1a, 0x8f20), (0x8f23, 0x8f26), (0x8f29, 0x8f2a), (0x8f2c, 0x8f2c), (0x8f2e, 0x8f2f),
+    (0x8f32, 0x8f39), (0x8f3b, 0x8f3b), (0x8f3e, 0x8f40), (0x8f42, 0x8f49), (0x8f4b, 0x8f4b),
+    (0x8f4d, 0x8f5b), (0x8f5d, 0x8f64), (0x8f9b, 0x8f9c), (0x8f9f, 0x8f9f), (0x8fa3, 0x8fa3),
+    (0x8fa6, 0x8fa6), (0x8fa8, 0x8fa8), (0x8fad, 0x8fb2), (0x8fb4, 0x8fb4), (0x8fbf, 0x8fbf),
+    (0x8fc2, 0x8fc2), (0x8fc4, 0x8fc6), (0x8fc9, 0x8fc9), (0x8fcb, 0x8fcb), (0x8fcd, 0x8fce),
+    (0x8fd1, 0x8fd7), (0x8fe0, 0x8fe6), (0x8fe8, 0x8fe8), (0x8fea, 0x8feb), (0x8fed, 0x8fee),
+    (0x8ff0, 0x8ff0), (0x8ff4, 0x8ff8), (0x8ffa, 0x9006), (0x900b, 0x900d), (0x900f, 0x9011),
+    (0x9014, 0x9017), (0x9019, 0x9024), (0x902d, 0x902f), (0x9031, 0x9032), (0x9034, 0x9036),
+    (0x9038, 0x9038), (0x903c, 0x903f), (0x9041, 0x9042), (0x9044, 0x9044), (0x9047, 0x9047),
+    (0x9049, 0x904b), (0x904d, 0x9055), (0x9058, 0x9059), (0x905b, 0x905e), (0x9060, 0x9060),
+    (0x9062, 0x9063), (0x9067, 0x9069), (0x906b, 0x906b), (0x906d, 0x9070), (0x9072, 0x9088),
+    (0x908a, 0x908b), (0x908d, 0x908d), (0x908f, 0x9091), (0x9094, 0x9095), (0x9097, 0x9099),
+    (0x909b, 0x909b), (0x909e, 0x90a3), (0x90a5, 0x90a7), (0x90aa, 0x90aa), (0x90af, 0x90b6),
+    (0x90b8, 0x90b8), (0x90bd, 0x90bf), (0x90c1, 0x90c1), (0x90c3, 0x90c3), (0x90c5, 0x90c5),
+    (0x90c7, 0x90c8), (0x90ca, 0x90cb), (0x90ce, 0x90ce), (0x90d4, 0x90dd), (0x90df, 0x90e5),
+    (0x90e8, 0x90ed), (0x90ef, 0x90f5), (0x90f9, 0x9109), (0x910b, 0x910b), (0x910d, 0x9112),
+    (0x9114, 0x9114), (0x9116, 0x9124), (0x9126, 0x9136), (0x9138, 0x913b), (0x913e, 0x9141),
+    (0x9143, 0x9150), (0x9152, 0x9153), (0x9155, 0x9158), (0x915a, 0x915a), (0x915f, 0x9165),
+    (0x9168, 0x916a), (0x916c, 0x916c), (0x916e, 0x916f), (0x9172, 0x9175), (0x9177, 0x917a),
+    (0x9180, 0x9187), (0x9189, 0x918b), (0x918d, 0x918d), (0x918f, 0x9193), (0x9199, 0x91a3),
+    (0x91a5, 0x91a5), (0x91a7, 0x91a8), (0x91aa, 0x91b5), (0x91b7, 0x91b7), (0x91b9, 0x91ba),
+    (0x91bc, 0x91be), (0x91c0, 0x91c3), (0x91c5, 0x91c7), (0x91c9, 0x91c9), (0x91cb, 0x91d1),
+    (0x91d3, 0x91d5), (0x91d7, 0x91da), (0x91dc, 0x91dd), (0x91e2, 0x91e4), (0x91e6, 0x91ee),
+    (0x91f1, 0x91f1), (0x91f3, 0x91f5), (0x91f7, 0x91f9), (0x91fd, 0x91fd), (0x91ff, 0x9207),
+    (0x9209, 0x920a), (0x920c, 0x920d), (0x920f, 0x9212), (0x9214, 0x9217), (0x9219, 0x921a),
+    (0x921c, 0x921c), (0x921e, 0x921e), (0x9223, 0x9227), (0x922d, 0x922e), (0x9230, 0x9234),
+    (0x9236, 0x923a), (0x923d, 0x9240), (0x9245, 0x9246), (0x9248, 0x9254), (0x9256, 0x9257),
+    (0x925a, 0x925b), (0x925e, 0x925e), (0x9260, 0x9261), (0x9263, 0x9267), (0x926c, 0x926d),
+    (0x926f, 0x9270), (0x9272, 0x9272), (0x9276, 0x9276), (0x9278, 0x9280), (0x9282, 0x9283),
+    (0x9285, 0x9288), (0x928a, 0x928e), (0x9291, 0x9291), (0x9293, 0x929d), (0x92a0, 0x92ac),
+    (0x92b2, 0x92b7), (0x92bb, 0x92bc), (0x92c0, 0x92d3), (0x92d5, 0x92d5), (0x92d7, 0x92d9),
+    (0x92dd, 0x92e1), (0x92e4, 0x92e4), (0x92e6, 0x92ea), (0x92ee, 0x92f1), (0x92f7, 0x92fc),
+    (0x92fe, 0x9302), (0x9304, 0x9304), (0x9306, 0x9306), (0x9308, 0x9309), (0x930b, 0x9310),
+    (0x9312, 0x9316), (0x9318, 0x931b), (0x931d, 0x932b), (0x932d, 0x932f), (0x9333, 0x9336),
+    (0x9338, 0x9339), (0x933c, 0x933c), (0x9346, 0x9347), (0x9349, 0x9352), (0x9354, 0x935c),
+    (0x935e, 0x935e), (0x9360, 0x9361), (0x9363, 0x9365), (0x9367, 0x9367), (0x936a, 0x936a),
+    (0x936c, 0x936d), (0x9370, 0x9371), (0x9375, 0x9377), (0x9379, 0x937c), (0x937e, 0x937e),
+    (0x9380, 0x9380), (0x9382, 0x9383), (0x9388, 0x938a), (0x938c, 0x938f), (0x9391, 0x9392),
+    (0x9394, 0x939b), (0x939d, 0x939f), (0x93a1, 0x93aa), (0x93ac, 0x93ac), (0x93ae, 0x93b5),
+    (0x93b7, 0x93b7), (0x93c0, 0x93c0), (0x93c2, 0x93c4), (0x93c7, 0x93c8), (0x93ca, 0x93ca),
+    (0x93cc, 0x93d2), (0x93d4, 0x93da), (0x93dc, 0x93df), (0x93e1, 0x93e4), (0x93e6, 0x93e8),
+    (0x93ec, 0x93ec), (0x93ee, 0x93ee), (0x93f5, 0x9400), (0x9403, 0x9403), (0x9406, 0x9407),
+    (0x9409, 0x9416), (0x9418, 0x9419), (0x9420, 0x9420), (0x9428, 0x942c), (0x942e, 0x942e),
+    (0x9430, 0x9433), (0x9435, 0x943d), (0x943f, 0x9440), (0x9444, 0x944c), (0x944f, 0x9452),
+    (0x9455, 0x9455), (0x9457, 0x9457), (0x945d, 0x945e), (0x9460, 0x9460), (0x9462, 0x9464),
+    (0x9468, 0x946b), (0x946d, 0x9478), (0x947c, 0x9483), (0x9577, 0x9577), (0x957a, 0x957d),
+    (0x9580, 0x9580), (0x9582, 0x9583), (0x9586, 0x9586), (0x9588, 0x9589), (0x958b, 0x9594),
+    (0x9598, 0x9598), (0x959b, 0x959c), (0x959e, 0x959f), (0x95a1, 0x95a1), (0x95a3, 0x95a5),
+    (0x95a8, 0x95a9), (0x95ab, 0x95ae), (0x95b0, 0x95b1), (0x95b5, 0x95b7), (0x95b9, 0x95c0),
+    (0x95c3, 0x95c3), (0x95c5, 0x95cd), (0x95d0, 0x95d6), (0x95da, 0x95dc), (0x95de, 0x95e5),
+    (0x961c, 0x961c), (0x961e, 0x961e), (0x9620, 0x9624), (0x9628, 0x9628), (0x962a, 0x962a),
+    (0x962c, 0x9632), (0x9639, 0x963d), (0x963f, 0x9640), (0x9642, 0x9644), (0x964a, 0x9651),
+    (0x9653, 0x9654), (0x9658, 0x9658), (0x965b, 0x965f), (0x9661, 0x9664), (0x966a, 0x966d),
+    (0x966f, 0x9678), (0x967c, 0x967e), (0x9680, 0x9680), (0x9683, 0x968b), (0x968d, 0x968e),
+    (0x9691, 0x9695), (0x9697, 0x9699), (0x969b, 0x969c), (0x969e, 0x969e), (0x96a1, 0x96a2),
+    (0x96a4, 0x96a4), (0x96a7, 0x96aa), (0x96ac, 0x96ac), (0x96ae, 0x96ae), (0x96b0, 0x96b1),
+    (0x96b3, 0x96b4), (0x96b8, 0x96b9), (0x96bb, 0x96bc), (0x96bf, 0x96ce), (0x96d2, 0x96df),
+    (0x96e1, 0x96e3), (0x96e5, 0x96e5), (0x96e8, 0x96ea), (0x96ef, 0x96f2), (0x96f5, 0x96fb),
+    (0x96fd, 0x96fd), (0x96ff, 0x9700), (0x9702, 0x9702), (0x9704, 0x9709), (0x970b, 0x970b),
+    (0x970d, 0x9713), (0x9716, 0x9716), (0x9718, 0x9719), (0x971c, 0x9720), (0x9722, 0x972c),
+    (0x972e, 0x9730), (0x9732, 0x9732), (0x9735, 0x9735), (0x9738, 0x973a), (0x973d, 0x973f),
+    (0x9742, 0x9744), (0x9746, 0x9749), (0x974b, 0x974b), (0x9752, 0x9752), (0x9756, 0x9756),
+    (0x9758, 0x9758), (0x975a, 0x975c), (0x975e, 0x975e), (0x9760, 0x9762), (0x9766, 0x9766),
+    (0x9768, 0x976a), (0x976c, 0x976c), (0x976e, 0x976e), (0x9770, 0x9770), (0x9772, 0x9774),
+    (0x9776, 0x9778), (0x977a, 0x9785), (0x9788, 0x9788), (0x978a, 0x978b), (0x978d, 0x978f),
+    (0x9794, 0x9794), (0x9797, 0x979a), (0x979c, 0x979e), (0x97a0, 0x97a6), (0x97a8, 0x97a8),
+    (0x97aa, 0x97ae), (0x97b3, 0x97b3), (0x97b6, 0x97b7), (0x97b9, 0x97b9), (0x97bb, 0x97bb),
+    (0x97bf, 0x97bf), (0x97c1, 0x97c1), (0x97c3, 0x97c7), (0x97c9, 0x97c9), (0x97cb, 0x97d0),
+    (0x97d3, 0x97d9), (0x97dc, 0x97df), (0x97e1, 0x97e1), (0x97e3, 0x97e3), (0x97e5, 0x97e5),
+    (0x97ed, 0x97ed), (0x97f0, 0x97f1), (0x97f3, 0x97f3), (0x97f6, 0x97f6), (0x97f8, 0x97fb),
+    (0x97fd, 0x9808), (0x980a, 0x980a), (0x980c, 0x9813), (0x9816, 0x9818), (0x981b, 0x981e),
+    (0x9820, 0x9821), (0x9824, 0x9824), (0x9826, 0x9829), (0x982b, 0x982b), (0x982d, 0x982d),
+    (0x982f, 0x9830), (0x9832, 0x9832), (0x9835, 0x9835), (0x9837, 0x9839), (0x983b, 0x983b),
+    (0x9841, 0x9841), (0x9843, 0x9846), (0x9848, 0x984a), (0x984c, 0x9853), (0x9857, 0x9859),
+    (0x985b, 0x9860), (0x9862, 0x9865), (0x9867, 0x9867), (0x9869, 0x986b), (0x986f, 0x9874),
+    (0x98a8, 0x98a9), (0x98ac, 0x98af), (0x98b1, 0x98b3), (0x98b6, 0x98b6), (0x98b8, 0x98b8),
+    (0x98ba, 0x98c2), (0x98c4, 0x98c4), (0x98c6, 0x98c6), (0x98c9, 0x98c9), (0x98cb, 0x98cc),
+    (0x98db, 0x98db), (0x98df, 0x98df), (0x98e2, 0x98e3), (0x98e5, 0x98e5), (0x98e7, 0x98e7),
+    (0x98e9, 0x98eb), (0x98ed, 0x98ed), (0x98ef, 0x98ef), (0x98f2, 0x98f2), (0x98f4, 0x98f4),
+    (0x98f6, 0x98f6), (0x98f9, 0x98fa), (0x98fc, 0x98fe), (0x9900, 0x9900), (0x9902, 0x9903),
+    (0x9905, 0x9905), (0x9907, 0x990a), (0x990c, 0x990c), (0x9910, 0x9918), (0x991a, 0x991b),
+    (0x991e, 0x991f), (0x9921, 0x9921), (0x9924, 0x9925), (0x9927, 0x9933), (0x9935, 0x9935),
+    (0x993a, 0x993a), (0x993c, 0x993f), (0x9941, 0x9941), (0x9943, 0x9943), (0x9945, 0x9945),
+    (0x9947, 0x9949), (0x994b, 0x994c), (0x994e, 0x994e), (0x9950, 0x9959), (0x995b, 0x995c),
+    (0x995e, 0x995f), (0x9961, 0x9961), (0x9996, 0x9999), (0x999c, 0x999e), (0x99a1, 0x99a1),
+    (0x99a3, 0x99a3), (0x99a5, 0x99a8), (0x99ab, 0x99b5), (0x99b9, 0x99bb), (0x99bd, 0x99bd),
+    (0x99c1, 0x99c3), (0x99c7, 0x99c7), (0x99c9, 0x99c9), (0x99cb, 0x99d9), (0x99db, 0x99dd),
+    (0x99df, 0x99df), (0x99e2, 0x99e5), (0x99e7, 0x99e7), (0x99e9, 0x99ea), (0x99ec, 0x99ee),
+    (0x99f0, 0x99f1), (0x99f4, 0x99f4), (0x99f6, 0x99ff), (0x9a01, 0x9a07), (0x9a09, 0x9a0b),
+    (0x9a0d, 0x9a0f), (0x9a11, 0x9a11), (0x9a14, 0x9a16), (0x9a19, 0x9a1e), (0x9a20, 0x9a20),
+    (0x9a22, 0x9a25), (0x9a27, 0x9a27), (0x9a29, 0x9a2e), (0x9a30, 0x9a32), (0x9a34, 0x9a3a),
+    (0x9a3d, 0x9a46), (0x9a48, 0x9a4a), (0x9a4c, 0x9a50), (0x9a52, 0x9a57), (0x9a59, 0x9a5b),
+    (0x9a5e, 0x9a60), (0x9a62, 0x9a62), (0x9a64, 0x9a6b), (0x9aa8, 0x9aa8), (0x9aab, 0x9aab),
+    (0x9aad, 0x9aad), (0x9aaf, 0x9ab1), (0x9ab3, 0x9ab4), (0x9ab7, 0x9ab9), (0x9abb, 0x9abc),
+    (0x9abe, 0x9ac2), (0x9ac6, 0x9ac7), (0x9aca, 0x9aca), (0x9acd, 0x9acd), (0x9acf, 0x9ad6),
+    (0x9ad8, 0x9ad8), (0x9adc, 0x9adc), (0x9adf, 0x9adf), (0x9ae1, 0x9ae1), (0x9ae3, 0x9ae3),
+    (0x9ae6, 0x9ae7), (0x9aeb, 0x9aef), (0x9af1, 0x9af3), (0x9af6, 0x9af7), (0x9af9, 0x9afe),
+    (0x9b01, 0x9b01), (0x9b03, 0x9b06), (0x9b08, 0x9b08), (0x9b0a, 0x9b0e), (0x9b10, 0x9b12),
+    (0x9b15, 0x9b1a), (0x9b1e, 0x9b20), (0x9b22, 0x9b25), (0x9b27, 0x9b29), (0x9b2b, 0x9b2b),
+    (0x9b2e, 0x9b2f), (0x9b31, 0x9b33), (0x9b35, 0x9b35), (0x9b37, 0x9b37), (0x9b3a, 0x9b3c),
+    (0x9b3e, 0x9b3f), (0x9b41, 0x9b46), (0x9b48, 0x9b48), (0x9b4a, 0x9b4f), (0x9b51, 0x9b52),
+    (0x9b54, 0x9b56), (0x9b58, 0x9b5b), (0x9b5f, 0x9b61), (0x9b64, 0x9b64), (0x9b66, 0x9b68),
+    (0x9b6c, 0x9b6c), (0x9b6f, 0x9b71), (0x9b74, 0x9b77), (0x9b7a, 0x9b7e), (0x9b80, 0x9b80),
+    (0x9b82, 0x9b82), (0x9b85, 0x9b88), (0x9b90, 0x9b93), (0x9b95, 0x9b95), (0x9b9a, 0x9b9b),
+    (0x9b9e, 0x9b9e), (0x9ba0, 0x9ba2), (0x9ba4, 0x9ba6), (0x9ba8, 0x9ba8), (0x9baa, 0x9bab),
+    (0x9bad, 0x9baf), (0x9bb5, 0x9bb6), (0x9bb8, 0x9bb9), (0x9bbd, 0x9bbd), (0x9bbf, 0x9bc1),
+    (0x9bc3, 0x9bc4), (0x9bc6, 0x9bca), (0x9bd3, 0x9bd7), (0x9bd9, 0x9bdc), (0x9bde, 0x9bde),
+    (0x9be0, 0x9be2), (0x9be4, 0x9be8), (0x9bea, 0x9bec), (0x9bf0, 0x9bf0), (0x9bf7, 0x9bf8),
+    (0x9bfd, 0x9bfd), (0x9c05, 0x9c09), (0x9c0b, 0x9c0b), (0x9c0d, 0x9c0e), (0x9c12, 0x9c14),
+    (0x9c17, 0x9c17), (0x9c1c, 0x9c1d), (0x9c21, 0x9c21), (0x9c23, 0x9c25), (0x9c28, 0x9c29),
+    (0x9c2b, 0x9c2d), (0x9c31, 0x9c34), (0x9c36, 0x9c37), (0x9c39, 0x9c39), (0x9c3b, 0x9c41),
+    (0x9c44, 0x9c44), (0x9c46, 0x9c46), (0x9c48, 0x9c4e), (0x9c50, 0x9c50), (0x9c52, 0x9c52),
+    (0x9c54, 0x9c59), (0x9c5e, 0x9c60), (0x9c62, 0x9c63), (0x9c66, 0x9c68), (0x9c6d, 0x9c6e),
+    (0x9c71, 0x9c71), (0x9c73, 0x9c75), (0x9c77, 0x9c7a), (0x9ce5, 0x9ce7), (0x9ce9, 0x9cea),
+    (0x9ced, 0x9ced), (0x9cf1, 0x9cf7), (0x9cf9, 0x9cfd), (0x9cff, 0x9d00), (0x9d03, 0x9d09),
+    (0x9d10, 0x9d10), (0x9d12, 0x9d12), (0x9d14, 0x9d15), (0x9d17, 0x9d19), (0x9d1b, 0x9d1b),
+    (0x9d1d, 0x9d20), (0x9d22, 0x9d23), (0x9d25, 0x9d26), (0x9d28, 0x9d29), (0x9d2d, 0x9d31),
+    (0x9d33, 0x9d33), (0x9d36, 0x9d38), (0x9d3b, 0x9d3b), (0x9d3d, 0x9d43), (0x9d45, 0x9d45),
+    (0x9d4a, 0x9d4c), (0x9d4f, 0x9d4f), (0x9d51, 0x9d54), (0x9d56, 0x9d5d), (0x9d5f, 0x9d61),
+    (0x9d67, 0x9d6c), (0x9d6f, 0x9d75), (0x9d77, 0x9d79), (0x9d7b, 0x9d7b), (0x9d7d, 0x9d7d),
+    (0x9d7f, 0x9d82), (0x9d84, 0x9d8c), (0x9d90, 0x9d90), (0x9d92, 0x9d92), (0x9d94, 0x9d94),
+    (0x9d96, 0x9da4), (0x9da6, 0x9daa), (0x9dac, 0x9dad), (0x9daf, 0x9daf), (0x9db1, 0x9dbc),
+    (0x9dbe, 0x9dbf), (0x9dc1, 0x9dc3), (0x9dc5, 0x9dc5), (0x9dc7, 0x9dc8), (0x9dca, 0x9dd3),
+    (0x9dd5, 0x9ddf), (0x9de1, 0x9de6), (0x9de8, 0x9de9), (0x9deb, 0x9df0), (0x9df2, 0x9dfb),
+    (0x9dfd, 0x9e07), (0x9e09, 0x9e09), (0x9e0b, 0x9e0b), (0x9e0d, 0x9e0d), (0x9e0f, 0x9e15),
+    (0x9e17, 0x9e17), (0x9e19, 0x9e1b), (0x9e1d, 0x9e1e), (0x9e75, 0x9e75), (0x9e79, 0x9e7a),
+    (0x9e7c, 0x9e7d), (0x9e7f, 0x9e80), (0x9e82, 0x9e83), (0x9e86, 0x9e8e), (0x9e91, 0x9e94),
+    (0x9e97, 0x9e97), (0x9e99, 0x9e9d), (0x9e9f, 0x9ea1), (0x9ea4, 0x9ea5), (0x9ea7, 0x9ea7),
+    (0x9ea9, 0x9ea9), (0x9ead, 0x9eae), (0x9eb0, 0x9eb0), (0x9eb4, 0x9eb7), (0x9ebb, 0x9ebc),
+    (0x9ebe, 0x9ebe), (0x9ec0, 0x9ec0), (0x9ec2, 0x9ec3), (0x9ec8, 0x9ec8), (0x9ecc, 0x9ed1),
+    (0x9ed3, 0x9ed6), (0x9ed8, 0x9ed8), (0x9eda, 0x9ee0), (0x9ee4, 0x9ee8), (0x9eeb, 0x9eeb),
+    (0x9eed, 0x9ef0), (0x9ef2, 0x9ef7), (0x9ef9, 0x9efd), (0x9eff, 0x9f01), (0x9f06, 0x9f07),
+    (0x9f09, 0x9f0a), (0x9f0e, 0x9f10), (0x9f12, 0x9f13), (0x9f15, 0x9f16), (0x9f18, 0x9f1c),
+    (0x9f1e, 0x9f1e), (0x9f20, 0x9f20), (0x9f22, 0x9f25), (0x9f28, 0x9f38), (0x9f3b, 0x9f3b),
+    (0x9f3d, 0x9f3e), (0x9f40, 0x9f43), (0x9f46, 0x9f4f), (0x9f52, 0x9f52), (0x9f54, 0x9f59),
+    (0x9f5b, 0x9f61), (0x9f63, 0x9f67), (0x9f6a, 0x9f6c), (0x9f6e, 0x9f72), (0x9f74, 0x9f7b),
+    (0x9f7e, 0x9f7e), (0x9f8d, 0x9f8d), (0x9f90, 0x9f92), (0x9f94, 0x9f95), (0x9f98, 0x9f98),
+    (0x9f9c, 0x9f9c), (0x9fa0, 0x9fa0), (0x9fa2, 0x9fa2), (0x9fa4, 0x9fa4), (0xfa0c, 0xfa0d),
+];
+
+#[rustfmt::skip]
+const BER_DZ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x10c, 0x10d), (0x190, 0x190), (0x194, 0x194), (0x1e6, 0x1e7),
+    (0x25b, 0x25b), (0x263, 0x263), (0x1e0c, 0x1e0d), (0x1e24, 0x1e25), (0x1e62, 0x1e63),
+    (0x1e6c, 0x1e6d), (0x1e92, 0x1e93),
+];
+
+#[rustfmt::skip]
+const BER_MA: &[(Int, Int)] = &[
+    (0x2d30, 0x2d31), (0x2d33, 0x2d33), (0x2d37, 0x2d37), (0x2d39, 0x2d39), (0x2d3b, 0x2d3d),
+    (0x2d40, 0x2d40), (0x2d43, 0x2d45), (0x2d47, 0x2d47), (0x2d49, 0x2d4a), (0x2d4d, 0x2d4f),
+    (0x2d53, 0x2d56), (0x2d59, 0x2d5c), (0x2d5f, 0x2d5f), (0x2d61, 0x2d63), (0x2d65, 0x2d65),
+    (0x2d6f, 0x2d6f),
+];
+
+#[rustfmt::skip]
+const CRH: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc7, 0xc7), (0xd1, 0xd1), (0xd6, 0xd6),
+    (0xdc, 0xdc), (0xe2, 0xe2), (0xe7, 0xe7), (0xf1, 0xf1), (0xf6, 0xf6), (0xfc, 0xfc),
+    (0x11e, 0x11f), (0x130, 0x131), (0x15e, 0x15f),
+];
+
+#[rustfmt::skip]
+const CSB: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc3, 0xc3), (0xc9, 0xc9), (0xcb, 0xcb), (0xd2, 0xd4),
+    (0xd9, 0xd9), (0xe3, 0xe3), (0xe9, 0xe9), (0xeb, 0xeb), (0xf2, 0xf4), (0xf9, 0xf9),
+    (0x104, 0x105), (0x141, 0x144), (0x17b, 0x17c),
+];
+
+#[rustfmt::skip]
+const DV: &[(Int, Int)] = &[
+    (0x780, 0x7b0),
+];
+
+#[rustfmt::skip]
+const EE: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc1), (0xc8, 0xc9), (0xcc, 0xcd), (0xd2, 0xd3),
+    (0xd9, 0xda), (0xe0, 0xe1), (0xe8, 0xe9), (0xec, 0xed), (0xf2, 0xf3), (0xf9, 0xfa),
+    (0x11a, 0x11b), (0x14a, 0x14b), (0x186, 0x186), (0x189, 0x189), (0x190, 0x192), (0x194, 0x194),
+    (0x1b2, 0x1b2), (0x1cd, 0x1d4), (0x254, 0x254), (0x256, 0x256), (0x25b, 0x25b), (0x263, 0x263),
+    (0x28b, 0x28b), (0x300, 0x301), (0x30c, 0x30c),
+];
+
+#[rustfmt::skip]
+const HSB: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd3, 0xd3), (0xf3, 0xf3), (0x106, 0x107), (0x10c, 0x10d),
+    (0x11a, 0x11b), (0x141, 0x144), (0x158, 0x159), (0x160, 0x161), (0x179, 0x17a), (0x17d, 0x17e),
+];
+
+#[rustfmt::skip]
+const HT: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc8, 0xc8), (0xd2, 0xd2), (0xe8, 0xe8), (0xf2, 0xf2),
+];
+
+#[rustfmt::skip]
+const HZ: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x32f, 0x32f), (0x1e12, 0x1e13), (0x1e4a, 0x1e4b),
+];
+
+#[rustfmt::skip]
+const II: &[(Int, Int)] = &[
+    (0xa000, 0xa48c),
+];
+
+#[rustfmt::skip]
+const JV: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc8, 0xc9), (0xe8, 0xe9),
+];
+
+#[rustfmt::skip]
+const KR: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x18e, 0x18e), (0x1dd, 0x1dd), (0x24c, 0x24d),
+];
+
+#[rustfmt::skip]
+const KU_TR: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc7, 0xc7), (0xca, 0xca), (0xce, 0xce), (0xdb, 0xdb),
+    (0xe7, 0xe7), (0xea, 0xea), (0xee, 0xee), (0xfb, 0xfb), (0x15e, 0x15f),
+];
+
+#[rustfmt::skip]
+const LG: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0x14a, 0x14b),
+];
+
+#[rustfmt::skip]
+const LI: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc4, 0xc4), (0xc8, 0xc8), (0xcb, 0xcb), (0xd3, 0xd3),
+    (0xd6, 0xd6), (0xe4, 0xe4), (0xe8, 0xe8), (0xeb, 0xeb), (0xf3, 0xf3), (0xf6, 0xf6),
+];
+
+#[rustfmt::skip]
+const MN_MN: &[(Int, Int)] = &[
+    (0x401, 0x401), (0x410, 0x44f), (0x451, 0x451), (0x4ae, 0x4af), (0x4e8, 0x4e9),
+];
+
+#[rustfmt::skip]
+const NA: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc3, 0xc3), (0xd1, 0xd1), (0xd5, 0xd5), (0xe3, 0xe3),
+    (0xf1, 0xf1), (0xf5, 0xf5), (0x168, 0x169),
+];
+
+#[rustfmt::skip]
+const NV: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc9, 0xc9), (0xcd, 0xcd), (0xd3, 0xd3),
+    (0xe1, 0xe1), (0xe9, 0xe9), (0xed, 0xed), (0xf3, 0xf3), (0x104, 0x105), (0x118, 0x119),
+    (0x12e, 0x12f), (0x141, 0x142), (0x1ea, 0x1eb), (0x2bc, 0x2bc), (0x301, 0x301),
+];
+
+#[rustfmt::skip]
+const OTA: &[(Int, Int)] = &[
+    (0x621, 0x622), (0x626, 0x63a), (0x641, 0x648), (0x67e, 0x67e), (0x686, 0x686), (0x698, 0x698),
+    (0x6ad, 0x6ad), (0x6af, 0x6af), (0x6cc, 0x6cc),
+];
+
+#[rustfmt::skip]
+const PAP_AN: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc1, 0xc1), (0xc8, 0xc9), (0xcd, 0xcd), (0xd1, 0xd3),
+    (0xd9, 0xda), (0xdc, 0xdc), (0xe1, 0xe1), (0xe8, 0xe9), (0xed, 0xed), (0xf1, 0xf3),
+    (0xf9, 0xfa), (0xfc, 0xfc),
+];
+
+#[rustfmt::skip]
+const PAP_AW: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd1, 0xd1), (0xf1, 0xf1),
+];
+
+#[rustfmt::skip]
+const QU: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xd1, 0xd1), (0xf1, 0xf1), (0x2c8, 0x2c8),
+];
+
+#[rustfmt::skip]
+const SC: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc0, 0xc0), (0xc8, 0xc8), (0xcc, 0xcc), (0xd2, 0xd2),
+    (0xd9, 0xd9), (0xe0, 0xe0), (0xe8, 0xe8), (0xec, 0xec), (0xf2, 0xf2), (0xf9, 0xf9),
+];
+
+#[rustfmt::skip]
+const SD: &[(Int, Int)] = &[
+    (0x621, 0x622), (0x624, 0x624), (0x626, 0x628), (0x62a, 0x63a), (0x641, 0x642), (0x644, 0x648),
+    (0x64a, 0x64a), (0x67a, 0x67b), (0x67d, 0x680), (0x683, 0x684), (0x686, 0x687), (0x68a, 0x68a),
+    (0x68c, 0x68d), (0x68f, 0x68f), (0x699, 0x699), (0x6a6, 0x6a6), (0x6a9, 0x6aa), (0x6af, 0x6af),
+    (0x6b1, 0x6b1), (0x6b3, 0x6b3), (0x6bb, 0x6bb), (0x6be, 0x6be),
+];
+
+#[rustfmt::skip]
+const SG: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xc2, 0xc2), (0xc4, 0xc4), (0xca, 0xcb), (0xce, 0xcf),
+    (0xd4, 0xd4), (0xd6, 0xd6), (0xdb, 0xdc), (0xe2, 0xe2), (0xe4, 0xe4), (0xea, 0xeb),
+    (0xee, 0xef), (0xf4, 0xf4), (0xf6, 0xf6), (0xfb, 0xfc),
+];
+
+#[rustfmt::skip]
+const TY: &[(Int, Int)] = &[
+    (0x41, 0x5a), (0x61, 0x7a), (0xcf, 0xcf), (0xef, 0xef), (0x100, 0x101), (0x112, 0x113),
+    (0x12a, 0x12b), (0x14c, 0x14d), (0x16a, 0x16b), (0x2bc, 0x2bc),
+];
+
+#[rustfmt::skip]
+const NQO: &[(Int, Int)] = &[
+    (0x7c0, 0x7fa),
+];
+
+#[rustfmt::skip]
+const BRX: &[(Int, Int)] = &[
+    (0x901, 0x903), (0x905, 0x90c), (0x90f, 0x910), (0x913, 0x928), (0x92a, 0x930), (0x932, 0x932),
+    (0x935, 0x939), (0x93c, 0x944), (0x947, 0x948), (0x94b, 0x94d), (0x950, 0x952), (0x960, 0x970),
+];
+
+#[rustfmt::skip]
+const SAT: &[(Int, Int)] = &[
+    (0x901, 0x903), (0x905, 0x90a), (0x90f, 0x910), (0x913, 0x928), (0x92a, 0x930), (0x932, 0x932),
+    (0x935, 0x935), (0x938, 0x939), (0x93c, 0x942), (0x947, 0x948), (0x94b, 0x94d), (0x950, 0x950),
+    (0x964, 0x970),
+];
+
+#[rustfmt::skip]
+const DOI: &[(Int, Int)] = &[
+    (0x902, 0x903), (0x905, 0x90c), (0x90f, 0x910), (0x913, 0x928), (0x92a, 0x930), (0x932, 0x932),
+    (0x935, 0x939), (0x93c, 0x944), (0x947, 0x948), (0x94b, 0x94d), (0x950, 0x952), (0x95b, 0x96f),
+];
+
+#[rustfmt::skip]
+const MNI: &[(Int, Int)] = &[
+    (0x964, 0x964), (0x981, 0x983), (0x985, 0x98c), (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9b0),
+    (0x9b2, 0x9b2), (0x9b6, 0x9b9), (0x9bc, 0x9c3), (0x9c7, 0x9c8), (0x9cb, 0x9ce), (0x9dc, 0x9dd),
+    (0x9df, 0x9df), (0x9e6, 0x9ef), (0x9f1, 0x9f1),
+];
+
+#[rustfmt::skip]
+const UND_ZSYE: &[(Int, Int)] = &[
+    (0x231a, 0x231b), (0x23e9, 0x23ec), (0x23f0, 0x23f0), (0x23f3, 0x23f3), (0x25fd, 0x25fe),
+    (0x2614, 0x2615), (0x2648, 0x2653), (0x267f, 0x267f), (0x2693, 0x2693), (0x26a1, 0x26a1),
+    (0x26aa, 0x26ab), (0x26bd, 0x26be), (0x26c4, 0x26c5), (0x26ce, 0x26ce), (0x26d4, 0x26d4),
+    (0x26ea, 0x26ea), (0x26f2, 0x26f3), (0x26f5, 0x26f5), (0x26fa, 0x26fa), (0x26fd, 0x26fd),
+    (0x2705, 0x2705), (0x270a, 0x270b), (0x2728, 0x2728), (0x274c, 0x274c), (0x274e, 0x274e),
+    (0x2753, 0x2755), (0x2757, 0x2757), (0x2795, 0x2797), (0x27b0, 0x27b0), (0x27bf, 0x27bf),
+    (0x2b1b, 0x2b1c), (0x2b50, 0x2b50), (0x2b55, 0x2b55), (0x1f004, 0x1f004), (0x1f0cf, 0x1f0cf),
+    (0x1f18e, 0x1f18e), (0x1f191, 0x1f19a), (0x1f1e6, 0x1f1ff), (0x1f201, 0x1f201),
+    (0x1f21a, 0x1f21a), (0x1f22f, 0x1f22f), (0x1f232, 0x1f236), (0x1f238, 0x1f23a),
+    (0x1f250, 0x1f251), (0x1f300, 0x1f320), (0x1f330, 0x1f335), (0x1f337, 0x1f37c),
+    (0x1f380, 0x1f393), (0x1f3a0, 0x1f3c4), (0x1f3c6, 0x1f3ca), (0x1f3e0, 0x1f3f0),
+    (0x1f440, 0x1f440), (0x1f442, 0x1f4f7), (0x1f4f9, 0x1f4fc), (0x1f500, 0x1f53d),
+    (0x1f550, 0x1f567), (0x1f5fb, 0x1f5ff), (0x1f601, 0x1f610), (0x1f612, 0x1f614),
+    (0x1f616, 0x1f616), (0x1f618, 0x1f618), (0x1f61a, 0x1f61a), (0x1f61c, 0x1f61e),
+    (0x1f620, 0x1f625), (0x1f628, 0x1f62b), (0x1f62d, 0x1f62d), (0x1f630, 0x1f633),
+    (0x1f635, 0x1f640), (0x1f645, 0x1f64f),
+];
+
+#[rustfmt::skip]
+const UND_ZMTH: &[(Int, Int)] = &[
+    (0x20, 0x21), (0x23, 0x26), (0x28, 0x5e), (0x61, 0x7e), (0xa1, 0xa7), (0xac, 0xac),
+    (0xb0, 0xb1), (0xb5, 0xb7), (0xbf, 0xbf), (0xd7, 0xd7), (0xf7, 0xf7), (0x131, 0x131),
+    (0x308, 0x308), (0x30a, 0x30a), (0x30c, 0x30c), (0x338, 0x338), (0x391, 0x3a1), (0x3a3, 0x3a4),
+    (0x3a6, 0x3a9), (0x3b1, 0x3c1), (0x3c3, 0x3c9), (0x3d5, 0x3d6), (0x3f0, 0x3f1),
+    (0x2016, 0x2016), (0x2020, 0x2022), (0x2026, 0x2026), (0x2044, 0x2044), (0x2057, 0x2057),
+    (0x20e1, 0x20e1), (0x2102, 0x2102), (0x210e, 0x2113), (0x2115, 0x2115), (0x2118, 0x211d),
+    (0x2124, 0x2124), (0x2200, 0x220d), (0x220f, 0x2219), (0x221d, 0x222a), (0x2234, 0x2240),
+    (0x228c, 0x22a5), (0x22c0, 0x22c3), (0x22c8, 0x22c8), (0x22cd, 0x22cf), (0x2308, 0x230b),
+    (0x2322, 0x2323), (0x25a0, 0x25a1), (0x27e6, 0x27e9), (0x1d400, 0x1d454), (0x1d456, 0x1d49c),
+    (0x1d49e, 0x1d49f), (0x1d4a2, 0x1d4a2), (0x1d4a5, 0x1d4a6), (0x1d4a9, 0x1d4ac),
+    (0x1d53b, 0x1d53e), (0x1d540, 0x1d544), (0x1d546, 0x1d546), (0x1d54a, 0x1d550),
+    (0x1d6a4, 0x1d6a5),
+];
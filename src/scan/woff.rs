@@ -0,0 +1,80 @@
+//! WOFF 1.0 decoding into a plain sfnt
+
+use crate::{Error, Result};
+
+const HEADER_LEN: usize = 44;
+const ENTRY_LEN: usize = 20;
+
+fn invalid(msg: &str) -> Error {
+    Error::InvalidFont(format!("WOFF {}", msg))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| invalid("header is truncated"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| invalid("header is truncated"))
+}
+
+/// Decompress every table of a WOFF file and lay them out as sfnt with the original flavor
+pub(crate) fn decode(data: &[u8]) -> Result<Vec<u8>> {
+    let flavor = read_u32(data, 4)?;
+    let num_tables = read_u16(data, 12)? as usize;
+
+    let mut tables = Vec::with_capacity(num_tables);
+
+    for idx in 0..num_tables {
+        let entry = HEADER_LEN + idx * ENTRY_LEN;
+        let tag = read_u32(data, entry)?;
+        let offset = read_u32(data, entry + 4)? as usize;
+        let comp_len = read_u32(data, entry + 8)? as usize;
+        let orig_len = read_u32(data, entry + 12)? as usize;
+        let checksum = read_u32(data, entry + 16)?;
+
+        let compressed = data
+            .get(offset..offset + comp_len)
+            .ok_or_else(|| invalid("table is out of bounds"))?;
+
+        let table = if comp_len < orig_len {
+            // a crafted table can't inflate beyond its declared length
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(compressed, orig_len)
+                .map_err(|err| invalid(&format!("table can't be decompressed: {}", err)))?
+        } else {
+            compressed.to_vec()
+        };
+
+        if table.len() != orig_len {
+            return Err(invalid("table has wrong length"));
+        }
+
+        tables.push((tag, checksum, table));
+    }
+
+    // offset table fields are only hints for binary search
+    let mut sfnt = Vec::new();
+    sfnt.extend_from_slice(&flavor.to_be_bytes());
+    sfnt.extend_from_slice(&(num_tables as u16).to_be_bytes());
+    sfnt.extend_from_slice(&[0; 6]);
+
+    let mut offset = 12 + num_tables * 16;
+
+    for (tag, checksum, table) in tables.iter() {
+        sfnt.extend_from_slice(&tag.to_be_bytes());
+        sfnt.extend_from_slice(&checksum.to_be_bytes());
+        sfnt.extend_from_slice(&(offset as u32).to_be_bytes());
+        sfnt.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += (table.len() + 3) & !3;
+    }
+
+    for (_, _, table) in tables.iter() {
+        sfnt.extend_from_slice(table);
+        sfnt.resize((sfnt.len() + 3) & !3, 0);
+    }
+
+    Ok(sfnt)
+}
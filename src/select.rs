@@ -0,0 +1,147 @@
+//! Font filtering by `<selectfont>` like `FcConfigAcceptFilename` and `FcConfigAcceptFont`
+
+use crate::eval::compare_value_with;
+use crate::matching::glob_match;
+use crate::*;
+
+use std::path::Path;
+
+impl FontConfig {
+    /// Whether a font file is allowed by `<glob>` of every `<selectfont>`
+    ///
+    /// A file matching any `<acceptfont>` glob is accepted even if a `<rejectfont>` glob matches it.
+    pub fn accept_filename<P: AsRef<Path> + ?Sized>(&self, path: &P) -> bool {
+        let path = path.as_ref().to_string_lossy();
+        let matches = |m: &FontMatch| matches!(m, FontMatch::Glob(glob) if glob_match(glob, &path));
+
+        self.accept_with(matches)
+    }
+
+    /// Whether a font is allowed by `<pattern>` of every `<selectfont>`
+    ///
+    /// A font matching any `<acceptfont>` pattern is accepted even if a `<rejectfont>` pattern matches it.
+    pub fn accept_font(&self, font: &Pattern) -> bool {
+        let matches =
+            |m: &FontMatch| matches!(m, FontMatch::Pattern(props) if pattern_matches(props, font));
+
+        self.accept_with(matches)
+    }
+
+    /// Fonts of `fonts` which pass both [`accept_filename`](Self::accept_filename) for their `file`
+    /// and [`accept_font`](Self::accept_font), like fonts loaded by `FcConfigAddCache`
+    pub fn filter_fonts<'a>(&self, fonts: &'a [Pattern]) -> Vec<&'a Pattern> {
+        fonts
+            .iter()
+            .filter(|font| {
+                font.get_string(&PropertyKind::File, 0)
+                    .map_or(true, |file| self.accept_filename(file))
+                    && self.accept_font(font)
+            })
+            .collect()
+    }
+
+    fn accept_with(&self, matches: impl Fn(&FontMatch) -> bool) -> bool {
+        let accepted = self
+            .select_fonts
+            .iter()
+            .any(|s| s.accepts.iter().any(&matches));
+
+        if accepted {
+            return true;
+        }
+
+        !self
+            .select_fonts
+            .iter()
+            .any(|s| s.rejects.iter().any(&matches))
+    }
+}
+
+/// Every property of `props` has to match a value of `font` like `FcListPatternMatchAny`
+///
+/// Strings are compared ignoring blanks and case, other values are contained in font values,
+/// e.g. a language in [`LangSet`] or a weight in a variable font range.
+fn pattern_matches(props: &[Property], font: &Pattern) -> bool {
+    props.iter().all(|prop| {
+        let kind = prop.kind();
+        let ctx = EvalContext {
+            target: MatchTarget::Font,
+            pattern: font,
+            original: None,
+            kind: &kind,
+        };
+
+        let value = match prop.expression().evaluate(&ctx) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        font.get(&kind)
+            .unwrap_or_default()
+            .iter()
+            .any(|font_value| {
+                let compare = match (&font_value.value, &value) {
+                    (Value::String(_), Value::String(_)) => TestCompare::Eq,
+                    _ => TestCompare::Contains,
+                };
+
+                compare_value_with(Some(&font_value.value), compare, Some(&value), true)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(xml: &str) -> FontConfig {
+        let mut c = FontConfig::default();
+
+        for part in parse_config_parts(xml).unwrap() {
+            if let ConfigPart::SelectFont(s) = part {
+                c.select_fonts.push(s);
+            }
+        }
+
+        c
+    }
+
+    #[test]
+    fn globs() {
+        let c = config(
+            r#"<fontconfig><selectfont>
+                <rejectfont><glob>/usr/share/fonts/X11/*</glob><glob>*.pcf.gz</glob></rejectfont>
+                <acceptfont><glob>/usr/share/fonts/X11/misc/cursor.pcf.gz</glob></acceptfont>
+            </selectfont></fontconfig>"#,
+        );
+
+        assert!(c.accept_filename("/usr/share/fonts/truetype/DejaVuSans.ttf"));
+        assert!(!c.accept_filename("/usr/share/fonts/X11/Type1/c0419bt_.pfb"));
+        assert!(!c.accept_filename("/home/user/.fonts/6x13.pcf.gz"));
+        assert!(c.accept_filename("/usr/share/fonts/X11/misc/cursor.pcf.gz"));
+    }
+
+    #[test]
+    fn patterns() {
+        let c = config(
+            r#"<fontconfig><selectfont>
+                <rejectfont>
+                    <pattern><patelt name="scalable"><bool>false</bool></patelt></pattern>
+                    <pattern><patelt name="lang"><string>ja</string></patelt><patelt name="weight"><const>bold</const></patelt></pattern>
+                </rejectfont>
+                <acceptfont>
+                    <pattern><patelt name="family"><string>Terminus</string></patelt></pattern>
+                </acceptfont>
+            </selectfont></fontconfig>"#,
+        );
+
+        let font = |name: &str| name.parse::<Pattern>().unwrap();
+
+        assert!(c.accept_font(&font("DejaVu Sans:scalable=true")));
+        assert!(!c.accept_font(&font("Fixed:scalable=false")));
+        assert!(c.accept_font(&font("terminus:scalable=false")));
+        // every element of a pattern has to match
+        assert!(c.accept_font(&font("Noto Sans CJK:lang=ja|ko:weight=80")));
+        assert!(!c.accept_font(&font("Noto Sans CJK:lang=ja|ko:weight=[80 210]")));
+    }
+}
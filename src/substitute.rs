@@ -0,0 +1,488 @@
+use crate::eval::{compare_value_with, EvalContext};
+use crate::types::is_valid;
+use crate::*;
+
+impl FontConfig {
+    /// Run every `<match target="pattern">` rule and `<alias>` against `pattern` in order
+    ///
+    /// This is the equivalent of `FcConfigSubstitute` with `FcMatchPattern`.
+    /// Matches and aliases are interleaved by [`Origin::order`] like fontconfig does.
+    /// Items without an origin in [`provenance`](FontConfig::provenance), e.g. pushed by hand,
+    /// come after every merged item in their list order, matches before aliases.
+    pub fn substitute(&self, pattern: &mut Pattern) {
+        let order =
+            |origins: &[Origin], idx: usize| origins.get(idx).map_or(usize::MAX, |o| o.order);
+
+        let mut aliases = self
+            .aliases
+            .iter()
+            .enumerate()
+            .map(|(idx, alias)| (alias, order(&self.provenance.aliases, idx)))
+            .peekable();
+
+        for (idx, m) in self.matches.iter().enumerate() {
+            let match_order = order(&self.provenance.matches, idx);
+
+            while let Some((alias, _)) = aliases.next_if(|(_, order)| *order < match_order) {
+                alias.apply(pattern);
+            }
+
+            if m.target == MatchTarget::Pattern {
+                m.apply(pattern, None);
+            }
+        }
+
+        for (alias, _) in aliases {
+            alias.apply(pattern);
+        }
+    }
+
+    /// Same as [`substitute`](Self::substitute) but add what `FcConfigSubstitute` adds before the rules run
+    ///
+    /// Each of [`default_langs`] is appended as a weak `lang` unless `pattern` has it already,
+    /// and `prgname` is the file name of the running program when it's missing.
+    pub fn config_substitute(&self, pattern: &mut Pattern) {
+        for lang in default_langs() {
+            let exists = pattern
+                .get(&PropertyKind::Lang)
+                .unwrap_or_default()
+                .iter()
+                .any(|v| match &v.value {
+                    Value::String(s) => s.eq_ignore_ascii_case(&lang),
+                    Value::LangSet(langs) => langs.compare_lang(&lang) == LangResult::Equal,
+                    _ => false,
+                });
+
+            if !exists {
+                pattern.add_weak(PropertyKind::Lang, lang);
+            }
+        }
+
+        if pattern.get(&PropertyKind::Prgname).is_none() {
+            if let Some(name) = prgname() {
+                pattern.add(PropertyKind::Prgname, name);
+            }
+        }
+
+        self.substitute(pattern);
+    }
+
+    /// Apply every `<alias>` to family list of `pattern` in order
+    pub fn apply_aliases(&self, pattern: &mut Pattern) {
+        for alias in self.aliases.iter() {
+            alias.apply(pattern);
+        }
+    }
+
+    /// Expand `families` through `<alias>` entries, e.g. `sans-serif` into concrete families
+    ///
+    /// Given families are strongly bound like families of a query pattern
+    pub fn expand_families<I, S>(&self, families: I) -> Vec<PatternValue>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut pattern = Pattern::new();
+
+        for family in families {
+            pattern.add(PropertyKind::Family, family.into());
+        }
+
+        self.apply_aliases(&mut pattern);
+
+        pattern
+            .get(&PropertyKind::Family)
+            .unwrap_or_default()
+            .to_vec()
+    }
+
+    /// Run every rule which has `target` against `pattern`
+    ///
+    /// `original` is the query pattern used by `<test target="pattern">` when `target` is [`MatchTarget::Font`]
+    pub(crate) fn substitute_with(
+        &self,
+        target: MatchTarget,
+        pattern: &mut Pattern,
+        original: Option<&Pattern>,
+    ) {
+        for m in self.matches.iter().filter(|m| m.target == target) {
+            m.apply(pattern, original);
+        }
+    }
+}
+
+/// File name of the running program like `FcGetPrgname`
+pub(crate) fn prgname() -> Option<String> {
+    let arg = std::env::args_os().next()?;
+    let name = std::path::Path::new(&arg).file_name()?.to_str()?;
+
+    Some(name.into())
+}
+
+impl Match {
+    /// Run tests against `pattern` and apply edits when all of them passed
+    ///
+    /// `original` is the query pattern of `<match target="font">`, it's ignored for other targets.
+    ///
+    /// Returns `true` when edits are applied
+    pub fn apply(&self, pattern: &mut Pattern, original: Option<&Pattern>) -> bool {
+        let original = match self.target {
+            MatchTarget::Font => original,
+            _ => None,
+        };
+
+        // matched value position of each property in `pattern`, edits are applied around it
+        let mut positions: Vec<(PropertyKind, Option<usize>)> = Vec::new();
+
+        for test in self.tests.iter() {
+            let on_original =
+                self.target == MatchTarget::Font && test.target == TestTarget::Pattern;
+            let subject = if on_original {
+                original
+            } else {
+                Some(&*pattern)
+            };
+
+            let position = match test.matches(self.target, subject, &*pattern, original) {
+                Some(position) => position,
+                None => return false,
+            };
+
+            if !on_original {
+                match positions.iter_mut().find(|(k, _)| *k == test.kind) {
+                    Some((_, p)) => *p = position,
+                    None => positions.push((test.kind.clone(), position)),
+                }
+            }
+        }
+
+        for edit in self.edits.iter() {
+            let kind = edit.kind.clone();
+            let position = match positions.iter_mut().find(|(k, _)| *k == kind) {
+                Some((_, p)) => p,
+                None => {
+                    positions.push((kind.clone(), None));
+                    &mut positions.last_mut().unwrap().1
+                }
+            };
+
+            let ctx = EvalContext {
+                target: self.target,
+                pattern,
+                original,
+                kind: &kind,
+            };
+
+            // values of wrong type are dropped like `FcConfigAdd`
+            let new_values = edit
+                .values
+                .iter()
+                .filter_map(|expr| expr.evaluate(&ctx))
+                .filter(|value| is_valid(&kind, value))
+                .collect();
+            edit.apply(pattern.values_mut(kind), position, new_values);
+            pattern.remove_empty();
+        }
+
+        true
+    }
+}
+
+impl Test {
+    /// Run test like `FcConfigMatchValueList`, each test value is compared in order
+    ///
+    /// Returns `None` when test failed, otherwise position of first matched value in `subject`.
+    /// Position can be `None` when `subject` doesn't have the property and qual is [`TestQual::All`]
+    fn matches(
+        &self,
+        target: MatchTarget,
+        subject: Option<&Pattern>,
+        pattern: &Pattern,
+        original: Option<&Pattern>,
+    ) -> Option<Option<usize>> {
+        let kind = &self.kind;
+
+        let values = match subject.and_then(|s| s.get(kind)) {
+            Some(values) => values,
+            None if self.qual == TestQual::All => return Some(None),
+            None => return None,
+        };
+
+        let ctx = EvalContext {
+            target,
+            pattern,
+            original,
+            kind,
+        };
+
+        let mut ret = None;
+
+        // result is kept between test values like fontconfig does
+        for expr in self.values.iter() {
+            let test_value = expr.evaluate(&ctx);
+
+            for (idx, value) in values.iter().enumerate() {
+                if compare_value_with(
+                    Some(&value.value),
+                    self.compare,
+                    test_value.as_ref(),
+                    self.ignore_blanks,
+                ) {
+                    if ret.is_none() {
+                        ret = Some(idx);
+                    }
+
+                    if self.qual != TestQual::All {
+                        break;
+                    }
+                } else if self.qual == TestQual::All {
+                    ret = None;
+                    break;
+                }
+            }
+        }
+
+        // `first` and `not_first` look for a match like `any` and then check where it is
+        match (self.qual, ret) {
+            (TestQual::First, Some(idx)) if idx != 0 => None,
+            (TestQual::NotFirst, Some(0)) => None,
+            _ => ret.map(Some),
+        }
+    }
+}
+
+impl Edit {
+    /// Apply edit to `values` like `FcConfigSubstituteWithPat`
+    ///
+    /// `position` is index of matched value from test which is updated to keep pointing same value
+    fn apply(
+        &self,
+        values: &mut Vec<PatternValue>,
+        position: &mut Option<usize>,
+        evaluated: Vec<Value>,
+    ) {
+        let new_values = |same: EditBinding| {
+            let binding = match self.binding {
+                EditBinding::Same => same,
+                binding => binding,
+            };
+
+            evaluated
+                .iter()
+                .cloned()
+                .map(|value| PatternValue::new(value, binding))
+                .collect::<Vec<_>>()
+        };
+
+        let matched = position.map(|p| (p, values[p].binding));
+
+        match (self.mode, matched) {
+            (EditMode::Assign, Some((pos, binding))) => {
+                let new_values = new_values(binding);
+                let len = new_values.len();
+                values.splice(pos..=pos, new_values);
+                *position = if len == 0 { None } else { Some(pos) };
+            }
+            (EditMode::Assign, None) | (EditMode::AssignReplace, _) => {
+                values.clear();
+                values.extend(new_values(EditBinding::Weak));
+                *position = None;
+            }
+            (EditMode::Prepend, Some((pos, binding))) => {
+                let new_values = new_values(binding);
+                *position = Some(pos + new_values.len());
+                values.splice(pos..pos, new_values);
+            }
+            (EditMode::Prepend, None) | (EditMode::PrependFirst, _) => {
+                let new_values = new_values(EditBinding::Weak);
+                if let Some(pos) = position {
+                    *pos += new_values.len();
+                }
+                values.splice(0..0, new_values);
+            }
+            (EditMode::Append, Some((pos, binding))) => {
+                values.splice(pos + 1..pos + 1, new_values(binding));
+            }
+            (EditMode::Append, None) | (EditMode::AppendLast, _) => {
+                values.extend(new_values(EditBinding::Weak));
+            }
+            (EditMode::Delete, Some((pos, _))) => {
+                values.remove(pos);
+                *position = None;
+            }
+            (EditMode::Delete, None) | (EditMode::DeleteAll, _) => {
+                values.clear();
+                *position = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn families(pattern: &Pattern) -> Vec<(String, EditBinding)> {
+        pattern
+            .get(&PropertyKind::Family)
+            .unwrap_or_default()
+            .iter()
+            .map(|v| match &v.value {
+                Value::String(s) => (s.clone(), v.binding),
+                v => panic!("Unexpected value {:?}", v),
+            })
+            .collect()
+    }
+
+    fn family_match(test: &str, mode: EditMode, binding: EditBinding, edit: &str) -> Match {
+        Match {
+            target: MatchTarget::Pattern,
+            tests: vec![Test {
+                kind: PropertyKind::Family,
+                values: vec![test.into()],
+                ..Default::default()
+            }],
+            edits: vec![Edit {
+                mode,
+                binding,
+                kind: PropertyKind::Family,
+                values: vec![edit.into()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn query() -> Pattern {
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "A");
+        pattern.add(PropertyKind::Family, "B");
+        pattern.add(PropertyKind::Family, "C");
+        pattern
+    }
+
+    #[test]
+    fn edit_modes() {
+        use EditBinding::*;
+
+        let cases = [
+            (
+                EditMode::Assign,
+                vec![("A", Strong), ("X", Weak), ("C", Strong)],
+            ),
+            (EditMode::AssignReplace, vec![("X", Weak)]),
+            (
+                EditMode::Prepend,
+                vec![("A", Strong), ("X", Weak), ("B", Strong), ("C", Strong)],
+            ),
+            (
+                EditMode::PrependFirst,
+                vec![("X", Weak), ("A", Strong), ("B", Strong), ("C", Strong)],
+            ),
+            (
+                EditMode::Append,
+                vec![("A", Strong), ("B", Strong), ("X", Weak), ("C", Strong)],
+            ),
+            (
+                EditMode::AppendLast,
+                vec![("A", Strong), ("B", Strong), ("C", Strong), ("X", Weak)],
+            ),
+            (EditMode::Delete, vec![("A", Strong), ("C", Strong)]),
+            (EditMode::DeleteAll, vec![]),
+        ];
+
+        for (mode, expected) in cases {
+            let mut pattern = query();
+            assert!(family_match("b", mode, Weak, "X").apply(&mut pattern, None));
+
+            let expected = expected
+                .into_iter()
+                .map(|(s, b)| (s.to_string(), b))
+                .collect::<Vec<_>>();
+            k9::assert_equal!(expected, families(&pattern), "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn same_binding() {
+        let mut pattern = query();
+        family_match("B", EditMode::Prepend, EditBinding::Same, "X").apply(&mut pattern, None);
+        family_match("X", EditMode::AppendLast, EditBinding::Same, "Y").apply(&mut pattern, None);
+
+        k9::assert_equal!(
+            families(&pattern)[1..],
+            [
+                ("X".to_string(), EditBinding::Strong),
+                ("B".to_string(), EditBinding::Strong),
+                ("C".to_string(), EditBinding::Strong),
+                ("Y".to_string(), EditBinding::Weak),
+            ]
+        );
+    }
+
+    #[test]
+    fn qual_all() {
+        let mut pattern = query();
+        let mut m = family_match("Z", EditMode::AppendLast, EditBinding::Weak, "X");
+        m.tests[0].qual = TestQual::All;
+        m.tests[0].compare = TestCompare::NotEq;
+
+        assert!(m.apply(&mut pattern, None));
+
+        m.tests[0].values = vec!["A".into()];
+        assert!(!m.apply(&mut pattern, None));
+
+        // missing property always passes `all`
+        m.tests[0].kind = PropertyKind::Style;
+        assert!(m.apply(&mut pattern, None));
+    }
+
+    #[test]
+    fn qual_first() {
+        let mut pattern = query();
+        let mut m = family_match("A", EditMode::AppendLast, EditBinding::Weak, "X");
+        m.tests[0].qual = TestQual::First;
+        assert!(m.apply(&mut pattern, None));
+
+        m.tests[0].qual = TestQual::NotFirst;
+        assert!(!m.apply(&mut pattern, None));
+
+        m.tests[0].values = vec!["B".into()];
+        assert!(m.apply(&mut pattern, None));
+
+        m.tests[0].qual = TestQual::First;
+        assert!(!m.apply(&mut pattern, None));
+
+        // missing property never passes
+        m.tests[0].kind = PropertyKind::Style;
+        assert!(!m.apply(&mut pattern, None));
+    }
+
+    #[test]
+    fn ignore_blanks() {
+        let mut pattern = query();
+        let mut m = family_match("A ", EditMode::AppendLast, EditBinding::Weak, "X");
+
+        assert!(!m.apply(&mut pattern, None));
+
+        m.tests[0].ignore_blanks = true;
+        assert!(m.apply(&mut pattern, None));
+    }
+
+    #[test]
+    fn multiple_values() {
+        let mut pattern = query();
+        let mut m = family_match("Z", EditMode::Prepend, EditBinding::Strong, "X");
+        m.tests[0].values.push("B".into());
+        m.edits[0].values.push("Y".into());
+
+        assert!(m.apply(&mut pattern, None));
+        k9::assert_equal!(
+            families(&pattern)
+                .into_iter()
+                .map(|(s, _)| s)
+                .collect::<Vec<_>>(),
+            vec!["A", "X", "Y", "B", "C"]
+        );
+    }
+}
@@ -1,11 +1,18 @@
 mod alias;
+mod charset;
 mod config;
 mod constant;
+mod diagnostic;
 mod dir;
 mod document;
+mod langset;
 mod match_;
+mod options;
+mod pattern;
 mod property;
+mod provenance;
 mod selectfont;
+mod span;
 mod value;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -17,6 +24,7 @@ pub enum IntOrRange {
 }
 
 pub use self::{
-    alias::*, config::*, constant::*, dir::*, document::*, match_::*, property::*, selectfont::*,
+    alias::*, charset::*, config::*, constant::*, diagnostic::*, dir::*, document::*, langset::*,
+    match_::*, options::*, pattern::*, property::*, provenance::*, selectfont::*, span::*,
     value::*,
 };
@@ -1,3 +1,6 @@
+use crate::eval::str_eq_ignore_blanks_and_case;
+use crate::{EditBinding, Pattern, PatternValue, PropertyKind, Value};
+
 /// Alias elements provide a shorthand notation for the set of common match operations needed to substitute one font family for another. They contain a <family> element followed by optional <prefer>, <accept> and <default> elements. Fonts matching the <family> element are edited to prepend the list of <prefer>ed families before the matching <family>, append the <accept>able families after the matching <family> and append the <default> families to the end of the family list.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11,4 +14,127 @@ pub struct Alias {
     pub accept: Vec<String>,
     /// `<default>`
     pub default: Vec<String>,
+    /// Binding of added families, [`EditBinding::Same`] takes binding of the matched family
+    pub binding: EditBinding,
+}
+
+impl Alias {
+    /// Edit family list of `pattern` like the rule fontconfig generates for `<alias>`
+    ///
+    /// The first family equal to [`alias`](Self::alias) ignoring case and blanks is looked up,
+    /// then [`prefer`](Self::prefer) is inserted before it, [`accept`](Self::accept) after it
+    /// and [`default`](Self::default) at the end of list.
+    /// New families are bound by [`binding`](Self::binding).
+    ///
+    /// Returns `false` when `pattern` doesn't have the family
+    pub fn apply(&self, pattern: &mut Pattern) -> bool {
+        let families = match pattern.get(&PropertyKind::Family) {
+            Some(families) => families,
+            None => return false,
+        };
+
+        let pos = match families.iter().position(|family| match &family.value {
+            Value::String(family) => str_eq_ignore_blanks_and_case(family, &self.alias),
+            _ => false,
+        }) {
+            Some(pos) => pos,
+            None => return false,
+        };
+
+        // `<default>` has no position so `same` falls back to weak like `FcConfigAdd`
+        let same = families[pos].binding;
+        let new_values = |families: &[String], same: EditBinding| {
+            let binding = match self.binding {
+                EditBinding::Same => same,
+                binding => binding,
+            };
+
+            families
+                .iter()
+                .map(|family| PatternValue::new(Value::String(family.clone()), binding))
+                .collect::<Vec<_>>()
+        };
+
+        let families = pattern.values_mut(PropertyKind::Family);
+        let accept_pos = pos + self.prefer.len() + 1;
+        families.splice(pos..pos, new_values(&self.prefer, same));
+        families.splice(accept_pos..accept_pos, new_values(&self.accept, same));
+        families.extend(new_values(&self.default, EditBinding::Weak));
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_alias() {
+        let alias = Alias {
+            alias: "Sans Serif".into(),
+            prefer: vec!["A".into(), "B".into()],
+            accept: vec!["C".into()],
+            default: vec!["D".into()],
+            binding: EditBinding::Weak,
+        };
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "Foo");
+        pattern.add(PropertyKind::Family, "sansserif");
+        pattern.add(PropertyKind::Family, "Bar");
+
+        assert!(alias.apply(&mut pattern));
+
+        let families = pattern
+            .get(&PropertyKind::Family)
+            .unwrap()
+            .iter()
+            .map(|v| (v.value.clone(), v.binding))
+            .collect::<Vec<_>>();
+
+        k9::assert_equal!(
+            families,
+            vec![
+                ("Foo".into(), EditBinding::Strong),
+                ("A".into(), EditBinding::Weak),
+                ("B".into(), EditBinding::Weak),
+                ("sansserif".into(), EditBinding::Strong),
+                ("C".into(), EditBinding::Weak),
+                ("Bar".into(), EditBinding::Strong),
+                ("D".into(), EditBinding::Weak),
+            ]
+        );
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "serif");
+        assert!(!alias.apply(&mut pattern));
+    }
+
+    #[test]
+    fn apply_alias_same_binding() {
+        let alias = Alias {
+            alias: "serif".into(),
+            prefer: vec!["A".into()],
+            default: vec!["B".into()],
+            binding: EditBinding::Same,
+            ..Default::default()
+        };
+
+        let mut pattern = Pattern::new();
+        pattern.add(PropertyKind::Family, "serif");
+        assert!(alias.apply(&mut pattern));
+
+        let bindings = pattern
+            .get(&PropertyKind::Family)
+            .unwrap()
+            .iter()
+            .map(|v| v.binding)
+            .collect::<Vec<_>>();
+
+        k9::assert_equal!(
+            bindings,
+            vec![EditBinding::Strong, EditBinding::Strong, EditBinding::Weak]
+        );
+    }
 }
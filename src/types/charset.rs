@@ -0,0 +1,233 @@
+use crate::{Int, IntOrRange};
+
+use std::iter::FromIterator;
+
+/// Set of Unicode code points like `FcCharSet`
+///
+/// Code points are stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<IntOrRange>", into = "Vec<IntOrRange>")
+)]
+pub struct CharSet {
+    ranges: Vec<(Int, Int)>,
+}
+
+impl CharSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build from ranges in any order, reversed bounds are swapped
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (Int, Int)>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .map(|(start, end)| (start.min(end), start.max(end)))
+            .collect::<Vec<_>>();
+
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(Int, Int)> = Vec::with_capacity(ranges.len());
+
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    /// Sorted and merged inclusive ranges
+    pub fn ranges(&self) -> &[(Int, Int)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of code points like `FcCharSetCount`
+    pub fn count(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| u64::from(end - start) + 1)
+            .sum()
+    }
+
+    pub fn insert(&mut self, c: Int) {
+        self.insert_range(c, c);
+    }
+
+    pub fn insert_range(&mut self, start: Int, end: Int) {
+        let ranges = std::mem::take(&mut self.ranges);
+        *self = Self::from_ranges(ranges.into_iter().chain(Some((start, end))));
+    }
+
+    /// Same as `FcCharSetHasChar`
+    pub fn contains(&self, c: Int) -> bool {
+        // index of the first range which ends at or after `c`
+        let idx = self.ranges.partition_point(|&(_, end)| end < c);
+
+        matches!(self.ranges.get(idx), Some(&(start, _)) if start <= c)
+    }
+
+    /// Whether every code point of `other` is in `self`, same as `FcCharSetIsSuperset`
+    pub fn is_superset(&self, other: &CharSet) -> bool {
+        other.ranges.iter().all(|&(start, end)| {
+            let idx = self.ranges.partition_point(|&(_, e)| e < start);
+            matches!(self.ranges.get(idx), Some(&(s, e)) if s <= start && end <= e)
+        })
+    }
+
+    pub fn is_subset(&self, other: &CharSet) -> bool {
+        other.is_superset(self)
+    }
+
+    /// Same as `FcCharSetUnion`
+    pub fn union(&self, other: &CharSet) -> CharSet {
+        Self::from_ranges(self.ranges.iter().chain(other.ranges.iter()).copied())
+    }
+
+    /// Same as `FcCharSetIntersect`
+    pub fn intersection(&self, other: &CharSet) -> CharSet {
+        let mut ret = Vec::new();
+        let (mut l, mut r) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(&&(ls, le)), Some(&&(rs, re))) = (l.peek(), r.peek()) {
+            let (start, end) = (ls.max(rs), le.min(re));
+
+            if start <= end {
+                ret.push((start, end));
+            }
+
+            // drop the range which ends first, the other one may overlap the next range
+            if le < re {
+                l.next();
+            } else {
+                r.next();
+            }
+        }
+
+        // inputs are merged so the result already is
+        Self { ranges: ret }
+    }
+
+    /// Same as `FcCharSetSubtract`
+    pub fn subtract(&self, other: &CharSet) -> CharSet {
+        let mut ret = Vec::new();
+
+        for &(start, end) in self.ranges.iter() {
+            // start of remaining part, `None` when nothing remains
+            let mut rest = Some(start);
+
+            for &(r_start, r_end) in other.ranges.iter() {
+                let start = match rest {
+                    Some(start) if start <= end => start,
+                    _ => break,
+                };
+
+                if r_end < start || r_start > end {
+                    continue;
+                }
+
+                if r_start > start {
+                    ret.push((start, r_start - 1));
+                }
+
+                rest = r_end.checked_add(1);
+            }
+
+            match rest {
+                Some(start) if start <= end => ret.push((start, end)),
+                _ => {}
+            }
+        }
+
+        Self { ranges: ret }
+    }
+
+    /// Number of code points of `other` covered by `self`, same as `FcCharSetIntersectCount`
+    pub fn coverage(&self, other: &CharSet) -> u64 {
+        self.intersection(other).count()
+    }
+
+    /// Number of code points of `other` missing in `self`, same as `FcCharSetSubtractCount` with swapped arguments
+    pub fn missing(&self, other: &CharSet) -> u64 {
+        other.subtract(self).count()
+    }
+
+    /// Ranges written as [`IntOrRange`], single code points become [`IntOrRange::Int`]
+    pub fn iter(&self) -> impl Iterator<Item = IntOrRange> + '_ {
+        self.ranges.iter().map(|&(start, end)| {
+            if start == end {
+                IntOrRange::Int(start)
+            } else {
+                IntOrRange::Range(start, end)
+            }
+        })
+    }
+}
+
+impl FromIterator<IntOrRange> for CharSet {
+    fn from_iter<T: IntoIterator<Item = IntOrRange>>(iter: T) -> Self {
+        Self::from_ranges(iter.into_iter().map(|c| match c {
+            IntOrRange::Int(i) => (i, i),
+            IntOrRange::Range(start, end) => (start, end),
+        }))
+    }
+}
+
+impl From<Vec<IntOrRange>> for CharSet {
+    fn from(v: Vec<IntOrRange>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl From<CharSet> for Vec<IntOrRange> {
+    fn from(charset: CharSet) -> Self {
+        charset.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_ranges() {
+        let charset = CharSet::from(vec![
+            IntOrRange::Range(20, 10),
+            IntOrRange::Int(21),
+            IntOrRange::Range(30, 40),
+            IntOrRange::Int(35),
+        ]);
+
+        k9::assert_equal!(charset.ranges(), &[(10, 21), (30, 40)]);
+        k9::assert_equal!(charset.count(), 23);
+        assert!(charset.contains(21));
+        assert!(!charset.contains(25));
+        assert!(!charset.contains(41));
+    }
+
+    #[test]
+    fn set_operations() {
+        let l = CharSet::from_ranges(vec![(0, 20), (40, 50)]);
+        let r = CharSet::from_ranges(vec![(5, 5), (10, 45)]);
+
+        k9::assert_equal!(l.union(&r).ranges(), &[(0, 50)]);
+        k9::assert_equal!(l.intersection(&r).ranges(), &[(5, 5), (10, 20), (40, 45)]);
+        k9::assert_equal!(l.subtract(&r).ranges(), &[(0, 4), (6, 9), (46, 50)]);
+        k9::assert_equal!(l.coverage(&r), 18);
+        k9::assert_equal!(l.missing(&r), 19);
+        assert!(l.is_superset(&CharSet::from_ranges(vec![(1, 3), (41, 42)])));
+        assert!(!l.is_superset(&r));
+        assert!(l.intersection(&r).is_subset(&r));
+    }
+}
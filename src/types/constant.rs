@@ -25,10 +25,25 @@ macro_rules! define_constant {
                     _ => None,
                 }
             }
+
+            /// Property this constant is used for, the first one when it applies to several properties
+            pub fn property_kind(self) -> PropertyKind {
+                match self {
+                    $(
+                        Constant::$variant => first_kind!($($ty,)+),
+                    )+
+                }
+            }
         }
     };
 }
 
+macro_rules! first_kind {
+    ($ty:ident, $($rest:ident,)*) => {
+        PropertyKind::$ty
+    };
+}
+
 define_constant! {
     Thin = (Weight, 0),
     Extralight = (Weight, 40),
@@ -131,4 +146,5 @@ parse_enum! {
 #[test]
 fn convert_test() {
     assert_eq!(Constant::Roman.get_value(PropertyKind::Slant).unwrap(), 0,);
+    assert_eq!(Constant::Normal.property_kind(), PropertyKind::Weight);
 }
@@ -0,0 +1,110 @@
+use crate::{Error, Span};
+
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+parse_enum! {
+    Severity,
+    (Warning, "warning"),
+    (Error, "error"),
+}
+
+/// Kind of a [`Diagnostic`], stable enough to match on
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiagnosticCode {
+    /// Element skipped by lenient parsing, see [`ParseOptions::strict`](crate::ParseOptions::strict)
+    UnknownElement,
+    /// Attribute skipped by lenient parsing
+    UnknownAttribute,
+    /// `<patelt>` of `<selectfont>` which is dropped because it can't be parsed
+    InvalidPatternElement,
+    /// `<include>` which can't be loaded
+    IncludeFailed,
+    /// Config file of an included directory which can't be merged
+    MergeFailed,
+    /// Value whose type doesn't match the property, see [`PropertyKind::value_type`](crate::PropertyKind::value_type)
+    TypeMismatch,
+    /// `<const>` which has no value for the property
+    InapplicableConstant,
+    /// `<range>` whose start is after its end
+    InvalidRange,
+    /// `<matrix>` element which isn't a number or which doesn't have 4 elements
+    InvalidMatrix,
+}
+
+parse_enum! {
+    DiagnosticCode,
+    (UnknownElement, "unknown-element"),
+    (UnknownAttribute, "unknown-attribute"),
+    (InvalidPatternElement, "invalid-patelt"),
+    (IncludeFailed, "include-failed"),
+    (MergeFailed, "merge-failed"),
+    (TypeMismatch, "type-mismatch"),
+    (InapplicableConstant, "inapplicable-constant"),
+    (InvalidRange, "invalid-range"),
+    (InvalidMatrix, "invalid-matrix"),
+}
+
+/// Problem which doesn't stop parsing or merging
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: String,
+    /// Element which caused the problem
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// Diagnostic of `err`, `span` is used when the error has no location
+    pub(crate) fn from_error(
+        severity: Severity,
+        code: DiagnosticCode,
+        err: &Error,
+        span: Option<Span>,
+    ) -> Self {
+        Self {
+            severity,
+            code,
+            message: err.inner().to_string(),
+            span: err.span().cloned().or(span),
+        }
+    }
+}
+
+/// `file:line:col: warning[code]: message`
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(span) = &self.span {
+            write!(f, "{}: ", span)?;
+        }
+
+        write!(
+            f,
+            "{}[{}]: {}",
+            self.severity.as_str(),
+            self.code.as_str(),
+            self.message
+        )
+    }
+}
+
+/// Record `diagnostic`, it's also logged with the `log` feature
+pub(crate) fn report(diagnostics: &mut Vec<Diagnostic>, diagnostic: Diagnostic) {
+    #[cfg(feature = "log")]
+    match diagnostic.severity {
+        Severity::Warning => log::warn!("{}", diagnostic),
+        Severity::Error => log::error!("{}", diagnostic),
+    }
+
+    diagnostics.push(diagnostic);
+}
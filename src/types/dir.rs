@@ -18,11 +18,13 @@ pub struct CacheDir {
 pub struct Include {
     pub prefix: DirPrefix,
     pub ignore_missing: bool,
+    /// Marks a legacy location like `~/.fonts.conf`, only informative
+    pub deprecated: bool,
     pub path: String,
 }
 
 /// This element contains a directory name where will be mapped as the path 'as-path' in cached information. This is useful if the directory name is an alias (via a bind mount or symlink) to another directory in the system for which cached font information is likely to exist.
-
+///
 /// 'salt' property affects to determine cache filename as same as [`Dir`] element.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -33,9 +35,10 @@ pub struct RemapDir {
     pub path: String,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DirPrefix {
+    #[default]
     Default,
     Cwd,
     Xdg,
@@ -50,12 +53,6 @@ parse_enum! {
     (Relative, "relative"),
 }
 
-impl Default for DirPrefix {
-    fn default() -> Self {
-        DirPrefix::Default
-    }
-}
-
 /// Get the location to user home directory.
 ///
 /// This implementation follows `FcConfigHome` function of freedesktop.org's
@@ -98,7 +95,7 @@ macro_rules! define_calculate_path {
                     .join(self.path.as_str()),
                 };
 
-                if let Some(stripped_path) = path.strip_prefix("~") {
+                if let Ok(stripped_path) = path.strip_prefix("~") {
                     let home = config_home().unwrap_or("/".to_string());
                     std::path::Path::new(&home).join(stripped_path)
                 } else {
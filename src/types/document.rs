@@ -1,14 +1,14 @@
 use crate::parser::parse_config;
 use crate::*;
 
-use std::collections::BinaryHeap;
-use std::fs;
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConfigPart {
-    Description(String),
+    Description(Description),
     SelectFont(SelectFont),
     Dir(Dir),
     CacheDir(CacheDir),
@@ -20,6 +20,25 @@ pub enum ConfigPart {
     ResetDirs,
 }
 
+/// `<description>` of a config file
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Description {
+    /// `domain` used for translation, `None` means the default `fontconfig-conf`
+    pub domain: Option<String>,
+    pub text: String,
+}
+
+/// A merged config file, like what `FcConfigFileInfoIter` gives
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigFileInfo {
+    pub path: PathBuf,
+    /// Last `<description>` of the file
+    pub description: Option<Description>,
+    /// `false` for templates only parsed by [`FontConfig::parse_templates`]
+    pub enabled: bool,
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontConfig {
@@ -30,44 +49,178 @@ pub struct FontConfig {
     pub matches: Vec<Match>,
     pub config: Config,
     pub aliases: Vec<Alias>,
+    /// Source of merged items
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub provenance: Provenance,
+    /// Problems which didn't stop merging, in the order they're found
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub diagnostics: Vec<Diagnostic>,
+    /// Merged files, each one comes after files it included like libfontconfig lists them
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub config_files: Vec<ConfigFileInfo>,
 }
 
 impl FontConfig {
+    /// Load main config the way libfontconfig does
+    ///
+    /// Config file is `FONTCONFIG_FILE` or [`MergeOptions::DEFAULT_CONFIG_FILE`] found by
+    /// [`MergeOptions::find_config_file`], using options from [`MergeOptions::from_env`]
+    pub fn from_env() -> Result<Self> {
+        let options = MergeOptions::from_env();
+        let name = std::env::var_os("FONTCONFIG_FILE")
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| MergeOptions::DEFAULT_CONFIG_FILE.into());
+
+        let config_path = options.find_config_file(Path::new(&name)).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Can't find config file {}", Path::new(&name).display()),
+            )
+        })?;
+
+        let mut config = Self::default();
+        config.merge_config_with_options(&config_path, &options)?;
+        Ok(config)
+    }
+
     pub fn merge_config<P: AsRef<Path> + ?Sized>(&mut self, config_path: &P) -> Result<()> {
-        let config = fs::read_to_string(config_path.as_ref())?;
+        self.merge_config_with_options(config_path, &MergeOptions::default())
+    }
+
+    /// Same as [`merge_config`](Self::merge_config) but with custom options
+    ///
+    /// Returns [`Error::IncludeCycle`] or [`Error::IncludeTooDeep`] when includes don't terminate,
+    /// these errors are never ignored even with `ignore_missing`.
+    /// Same for unknown elements and attributes with [`ParseOptions::strict`].
+    pub fn merge_config_with_options<P: AsRef<Path> + ?Sized>(
+        &mut self,
+        config_path: &P,
+        options: &MergeOptions,
+    ) -> Result<()> {
+        self.load_config(config_path.as_ref(), options, &mut Vec::new())
+    }
+
+    /// Merge `config_path` while tracking canonical paths of files being loaded in `chain`
+    fn load_config(
+        &mut self,
+        config_path: &Path,
+        options: &MergeOptions,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let canonical_path = options
+            .fs
+            .canonicalize(config_path)
+            .unwrap_or_else(|_| config_path.to_path_buf());
+
+        if chain.contains(&canonical_path) {
+            let mut chain = chain.clone();
+            chain.push(canonical_path);
+            return Err(Error::IncludeCycle(chain));
+        }
+
+        if chain.len() >= options.max_include_depth {
+            let mut chain = chain.clone();
+            chain.push(canonical_path);
+            return Err(Error::IncludeTooDeep(chain));
+        }
+
+        chain.push(canonical_path);
+        let ret = self.merge_parts(config_path, options, chain);
+        chain.pop();
+
+        ret
+    }
+
+    fn merge_parts(
+        &mut self,
+        config_path: &Path,
+        options: &MergeOptions,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let config = options.fs.read_to_string(config_path)?;
         let xml_doc = roxmltree::Document::parse(&config)?;
 
-        for part in parse_config(&xml_doc)? {
-            match part? {
-                ConfigPart::Alias(alias) => self.aliases.push(alias),
+        let file: Arc<Path> = config_path.into();
+        let include_chain: Arc<[PathBuf]> = chain[..chain.len() - 1].into();
+
+        // parse whole file first so its diagnostics come before those of included files
+        let mut diagnostics = Vec::new();
+        let parts = parse_config(
+            &xml_doc,
+            Some(file.clone()),
+            options.parse_options,
+            &mut diagnostics,
+        )?
+        .collect::<Vec<_>>();
+        self.diagnostics.append(&mut diagnostics);
+
+        let mut description = None;
+
+        for part in parts {
+            let Spanned { span, value: part } = part?;
+            // every part takes a position so order follows the document
+            let origin = self.provenance.next_origin(&file, &include_chain, span);
+
+            match part {
+                ConfigPart::Alias(alias) => {
+                    self.provenance.aliases.push(origin);
+                    self.aliases.push(alias);
+                }
                 ConfigPart::Config(mut c) => {
                     self.config.rescans.append(&mut c.rescans);
                     self.config.blanks.append(&mut c.blanks);
                 }
-                ConfigPart::Description(_) => {}
-                ConfigPart::Dir(dir) => self.dirs.push(DirData {
-                    path: dir.calculate_path(config_path),
-                    salt: dir.salt,
-                }),
-                ConfigPart::CacheDir(dir) => self.cache_dirs.push(dir.calculate_path(config_path)),
-                ConfigPart::Match(m) => self.matches.push(m),
-                ConfigPart::ResetDirs => self.dirs.clear(),
-                ConfigPart::SelectFont(s) => self.select_fonts.push(s),
+                ConfigPart::Description(d) => description = Some(d),
+                ConfigPart::Dir(dir) => {
+                    self.provenance.dirs.push(origin);
+                    self.dirs.push(DirData {
+                        path: options.with_sysroot(dir.calculate_path(config_path)),
+                        salt: dir.salt,
+                    });
+                }
+                ConfigPart::CacheDir(dir) => self
+                    .cache_dirs
+                    .push(options.with_sysroot(dir.calculate_path(config_path))),
+                ConfigPart::Match(m) => {
+                    self.provenance.matches.push(origin);
+                    self.matches.push(m);
+                }
+                ConfigPart::ResetDirs => {
+                    self.dirs.clear();
+                    self.provenance.dirs.clear();
+                }
+                ConfigPart::SelectFont(s) => {
+                    self.provenance.select_fonts.push(origin);
+                    self.select_fonts.push(s);
+                }
                 ConfigPart::RemapDir(remap) => self.remap_dirs.push(RemapDirData {
-                    path: remap.calculate_path(config_path),
+                    path: options.with_sysroot(remap.calculate_path(config_path)),
                     salt: remap.salt,
                     as_path: remap.as_path,
                 }),
                 ConfigPart::Include(dir) => {
-                    let include_path = dir.calculate_path(config_path);
+                    let include_path = include_path(&dir, config_path, options);
 
-                    match self.include(&include_path) {
+                    match self.include(&include_path, options, chain) {
                         Ok(_) => {}
-                        #[allow(unused_variables)]
+                        Err(err) if err.is_include_error() || err.is_strict_error() => {
+                            return Err(err)
+                        }
                         Err(err) => {
                             if !dir.ignore_missing {
-                                #[cfg(feature = "log")]
-                                log::warn!("Failed to include {}: {}", include_path.display(), err);
+                                report(
+                                    &mut self.diagnostics,
+                                    Diagnostic {
+                                        severity: Severity::Error,
+                                        code: DiagnosticCode::IncludeFailed,
+                                        message: format!(
+                                            "Failed to include {}: {}",
+                                            include_path.display(),
+                                            err.inner()
+                                        ),
+                                        span: Some(err.span().unwrap_or(&origin.span).clone()),
+                                    },
+                                );
                             }
                         }
                     }
@@ -75,39 +228,99 @@ impl FontConfig {
             }
         }
 
+        self.config_files.push(ConfigFileInfo {
+            path: config_path.into(),
+            description,
+            enabled: true,
+        });
+
         Ok(())
     }
 
-    fn include(&mut self, include_path: &Path) -> Result<()> {
-        let meta = fs::metadata(include_path)?;
-        let ty = meta.file_type();
-
-        // fs::metadata follow symlink so ty is never symlink
-        if ty.is_file() {
-            self.merge_config(include_path)?;
-        } else if ty.is_dir() {
-            let dir = std::fs::read_dir(include_path)?;
-            let config_paths = dir
-                .filter_map(|entry| {
-                    let entry = entry.ok()?;
-                    let ty = entry.file_type().ok()?;
-
-                    if ty.is_file() || ty.is_symlink() {
-                        Some(entry.path())
-                    } else {
-                        None
-                    }
-                })
-                .collect::<BinaryHeap<_>>();
+    /// Parse config files of `dir` only for their description like `FcConfigParseOnly`
+    ///
+    /// libfontconfig does this for [`MergeOptions::TEMPLATE_DIR`] so templates which aren't enabled are
+    /// listed in [`config_files`](Self::config_files) after merged files. Merged files, also through a
+    /// symlink, are skipped and so are files which can't be parsed.
+    pub fn parse_templates<P: AsRef<Path> + ?Sized>(
+        &mut self,
+        dir: &P,
+        options: &MergeOptions,
+    ) -> Result<()> {
+        let canonical = |path: &Path| {
+            options
+                .fs
+                .canonicalize(path)
+                .unwrap_or_else(|_| path.to_path_buf())
+        };
+        let merged = self
+            .config_files
+            .iter()
+            .map(|file| canonical(&file.path))
+            .collect::<Vec<_>>();
+
+        let dir = options.with_sysroot(dir.as_ref().into());
+
+        for config_path in config_files_of(&dir, options)? {
+            if merged.contains(&canonical(&config_path)) {
+                continue;
+            }
+
+            let parts = match options
+                .fs
+                .read_to_string(&config_path)
+                .map_err(Error::from)
+                .and_then(|config| parse_config_parts(&config))
+            {
+                Ok(parts) => parts,
+                Err(_) => continue,
+            };
 
-            for config_path in config_paths {
-                match self.merge_config(&config_path) {
+            let description = parts
+                .into_iter()
+                .fold(None, |description, part| match part {
+                    ConfigPart::Description(d) => Some(d),
+                    _ => description,
+                });
+
+            self.config_files.push(ConfigFileInfo {
+                path: config_path,
+                description,
+                enabled: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn include(
+        &mut self,
+        include_path: &Path,
+        options: &MergeOptions,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let kind = options.fs.metadata(include_path)?;
+
+        if kind == FileKind::File {
+            self.load_config(include_path, options, chain)?;
+        } else if kind == FileKind::Dir {
+            for config_path in config_files_of(include_path, options)? {
+                match self.load_config(&config_path, options, chain) {
                     Ok(_) => {}
-                    #[allow(unused_variables)]
-                    Err(err) => {
-                        #[cfg(feature = "log")]
-                        log::warn!("Failed to merge {}: {}", config_path.display(), err);
-                    }
+                    Err(err) if err.is_include_error() || err.is_strict_error() => return Err(err),
+                    Err(err) => report(
+                        &mut self.diagnostics,
+                        Diagnostic {
+                            severity: Severity::Error,
+                            code: DiagnosticCode::MergeFailed,
+                            message: format!(
+                                "Failed to merge {}: {}",
+                                config_path.display(),
+                                err.inner()
+                            ),
+                            span: err.span().cloned(),
+                        },
+                    ),
                 }
             }
         }
@@ -116,6 +329,55 @@ impl FontConfig {
     }
 }
 
+/// Config files of `dir` in load order
+fn config_files_of(dir: &Path, options: &MergeOptions) -> Result<Vec<PathBuf>> {
+    let mut config_paths = options
+        .fs
+        .read_dir(dir)?
+        .into_iter()
+        .filter_map(|entry| {
+            let name = entry.path.file_name()?;
+
+            if entry.kind == FileKind::File && is_config_file_name(name) {
+                Some(entry.path)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // same as `FcSortCmpStr`, file names are compared byte by byte
+    config_paths.sort_by(|l, r| l.file_name().cmp(&r.file_name()));
+
+    Ok(config_paths)
+}
+
+/// Relative path without prefix is searched in [`MergeOptions::config_dirs`] like `FcConfigGetFilename`
+fn include_path(include: &Include, config_path: &Path, options: &MergeOptions) -> PathBuf {
+    let path = Path::new(&include.path);
+
+    if include.prefix == DirPrefix::Default && path.is_relative() && !include.path.starts_with('~')
+    {
+        if let Some(path) = options.find_config_file(path) {
+            return path;
+        }
+    }
+
+    options.with_sysroot(include.calculate_path(config_path))
+}
+
+/// Only `[0-9]*.conf` files are loaded from an included directory like `FcConfigParseAndLoadDir`
+fn is_config_file_name(name: &OsStr) -> bool {
+    let name = match name.to_str() {
+        Some(name) => name,
+        None => return false,
+    };
+
+    name.len() > ".conf".len()
+        && name.starts_with(|c: char| c.is_ascii_digit())
+        && name.ends_with(".conf")
+}
+
 macro_rules! define_config_part_from {
 	($($f:ident,)+) => {
         $(
@@ -129,6 +391,7 @@ macro_rules! define_config_part_from {
 }
 
 define_config_part_from! {
+    Description,
     SelectFont,
     Dir,
     CacheDir,
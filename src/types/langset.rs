@@ -0,0 +1,296 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::iter::FromIterator;
+
+/// Result of comparing languages like `FcLangResult`, better results are smaller
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LangResult {
+    Equal,
+    /// Same language in another territory, e.g. `en-us` and `en-gb` or `en`
+    DifferentTerritory,
+    DifferentLang,
+}
+
+/// Normalize a RFC-3066 language tag like `FcLangNormalize`
+///
+/// Tags are lowercased with `-` as separator, encoding and modifier of locale names are dropped
+/// and `C` or `POSIX` becomes `en`.
+pub fn normalize_lang(lang: &str) -> String {
+    let lang = lang.split(['.', '@']).next().unwrap_or_default();
+
+    if lang.eq_ignore_ascii_case("c") || lang.eq_ignore_ascii_case("posix") {
+        return "en".into();
+    }
+
+    lang.chars()
+        .map(|c| {
+            if c == '_' {
+                '-'
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+/// Language of a locale name checked against known orthographies like `FcLangNormalize`
+///
+/// `en_US` becomes `en` as there's no orthography for it, while `zh_TW` keeps its territory.
+fn locale_lang(locale: &str) -> String {
+    let lang = normalize_lang(locale);
+    let is_known = |lang: &str| crate::cache::LANGS.contains(&lang);
+
+    if is_known(&lang) {
+        // libfontconfig keeps the case of known languages
+        let tag = locale.split(['.', '@']).next().unwrap_or_default();
+        let tag = tag.replace('_', "-");
+        return if tag.eq_ignore_ascii_case(&lang) {
+            tag
+        } else {
+            lang
+        };
+    }
+
+    match lang.split_once('-') {
+        Some((language, _)) if is_known(language) => language.into(),
+        _ => lang,
+    }
+}
+
+/// Languages of the current locale like `FcGetDefaultLangs`
+///
+/// `FC_LANG` holds a `:` separated list, otherwise the first of `LC_ALL`, `LC_CTYPE` and `LANG` is used.
+/// Falls back to `en` without any locale.
+pub fn default_langs() -> Vec<String> {
+    let var = |name| std::env::var(name).ok().filter(|v| !v.is_empty());
+    let locales = var("FC_LANG")
+        .or_else(|| var("LC_ALL"))
+        .or_else(|| var("LC_CTYPE"))
+        .or_else(|| var("LANG"))
+        .unwrap_or_else(|| "en".into());
+    let mut langs = Vec::new();
+
+    for lang in locales
+        .split(':')
+        .filter(|l| !l.is_empty())
+        .map(locale_lang)
+    {
+        if !langs.contains(&lang) {
+            langs.push(lang);
+        }
+    }
+
+    langs
+}
+
+/// Compare two normalized language tags like `FcLangCompare`
+pub fn compare_lang(l: &str, r: &str) -> LangResult {
+    let mut l = l.bytes().chain(Some(0));
+    let mut r = r.bytes().chain(Some(0));
+    let mut result = LangResult::DifferentLang;
+    let is_end = |c| c == b'-' || c == 0;
+
+    loop {
+        match (l.next(), r.next()) {
+            (Some(lc), Some(rc)) if lc != rc => {
+                if is_end(lc) && is_end(rc) {
+                    result = LangResult::DifferentTerritory;
+                }
+                return result;
+            }
+            (Some(0), _) | (None, _) | (_, None) => return LangResult::Equal,
+            (Some(b'-'), _) => result = LangResult::DifferentTerritory,
+            _ => {}
+        }
+    }
+}
+
+/// Whether `sup` covers `sub`, languages match when one of them has no territory like `FcLangContains`
+fn lang_contains(sup: &str, sub: &str) -> bool {
+    let mut sup = sup.bytes();
+    let mut sub = sub.bytes();
+
+    loop {
+        match (sup.next(), sub.next()) {
+            (None, None) => return true,
+            (Some(b'-'), None) | (None, Some(b'-')) => return true,
+            (l, r) if l == r => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Set of normalized RFC-3066 language tags like `FcLangSet`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<String>", into = "Vec<String>"))]
+pub struct LangSet {
+    langs: BTreeSet<String>,
+}
+
+impl LangSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a tag after [`normalize_lang`], empty tags are ignored
+    pub fn insert(&mut self, lang: &str) {
+        let lang = normalize_lang(lang);
+
+        if !lang.is_empty() {
+            self.langs.insert(lang);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.langs.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.langs.len()
+    }
+
+    /// Normalized tags in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.langs.iter().map(String::as_str)
+    }
+
+    /// Best result of comparing `lang` with each language like `FcLangSetHasLang`
+    pub fn compare_lang(&self, lang: &str) -> LangResult {
+        let lang = normalize_lang(lang);
+
+        self.iter()
+            .map(|l| compare_lang(l, &lang))
+            .min()
+            .unwrap_or(LangResult::DifferentLang)
+    }
+
+    /// Best result of comparing languages of both sets like `FcLangSetCompare`
+    pub fn compare(&self, other: &LangSet) -> LangResult {
+        other
+            .iter()
+            .flat_map(|r| self.iter().map(move |l| compare_lang(l, r)))
+            .min()
+            .unwrap_or(LangResult::DifferentLang)
+    }
+
+    /// Whether every language of `other` is covered like `FcLangSetContains`
+    pub fn contains(&self, other: &LangSet) -> bool {
+        other
+            .iter()
+            .all(|sub| self.iter().any(|sup| lang_contains(sup, sub)))
+    }
+
+    /// Same as `FcLangSetUnion`
+    pub fn union(&self, other: &LangSet) -> LangSet {
+        Self {
+            langs: self.langs.union(&other.langs).cloned().collect(),
+        }
+    }
+
+    /// Same as `FcLangSetSubtract`
+    pub fn subtract(&self, other: &LangSet) -> LangSet {
+        Self {
+            langs: self.langs.difference(&other.langs).cloned().collect(),
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for LangSet {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut langs = Self::new();
+
+        for lang in iter {
+            langs.insert(lang.as_ref());
+        }
+
+        langs
+    }
+}
+
+impl<'a> From<&'a str> for LangSet {
+    fn from(lang: &'a str) -> Self {
+        Some(lang).into_iter().collect()
+    }
+}
+
+impl From<Vec<String>> for LangSet {
+    fn from(langs: Vec<String>) -> Self {
+        langs.into_iter().collect()
+    }
+}
+
+impl From<LangSet> for Vec<String> {
+    fn from(langs: LangSet) -> Self {
+        langs.langs.into_iter().collect()
+    }
+}
+
+/// Languages separated by `|` like `FcNameUnparseLangSet`
+impl fmt::Display for LangSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, lang) in self.iter().enumerate() {
+            if idx != 0 {
+                f.write_str("|")?;
+            }
+            f.write_str(lang)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        k9::assert_equal!(normalize_lang("ko_KR.UTF-8"), "ko-kr");
+        k9::assert_equal!(normalize_lang("sr_RS@latin"), "sr-rs");
+        k9::assert_equal!(normalize_lang("POSIX"), "en");
+        k9::assert_equal!(locale_lang("en_US.UTF-8"), "en");
+        k9::assert_equal!(locale_lang("zh_TW"), "zh-TW");
+        k9::assert_equal!(locale_lang("xx_YY"), "xx-yy");
+        k9::assert_equal!(locale_lang("C"), "en");
+        k9::assert_equal!(
+            LangSet::from_iter(["en-US", "ja", "en_us"]).to_string(),
+            "en-us|ja"
+        );
+    }
+
+    #[test]
+    fn compare() {
+        k9::assert_equal!(compare_lang("en-us", "en-us"), LangResult::Equal);
+        k9::assert_equal!(
+            compare_lang("en-us", "en-gb"),
+            LangResult::DifferentTerritory
+        );
+        k9::assert_equal!(compare_lang("en", "en-gb"), LangResult::DifferentTerritory);
+        k9::assert_equal!(compare_lang("en", "eo"), LangResult::DifferentLang);
+        k9::assert_equal!(
+            compare_lang("zh-tw", "zh-hk"),
+            LangResult::DifferentTerritory
+        );
+
+        let langs = LangSet::from_iter(["ja", "zh-TW"]);
+        k9::assert_equal!(langs.compare_lang("JA"), LangResult::Equal);
+        k9::assert_equal!(langs.compare_lang("zh-cn"), LangResult::DifferentTerritory);
+        k9::assert_equal!(
+            langs.compare(&LangSet::from("ko")),
+            LangResult::DifferentLang
+        );
+        k9::assert_equal!(LangSet::new().compare(&langs), LangResult::DifferentLang);
+    }
+
+    #[test]
+    fn set_operations() {
+        let langs = LangSet::from_iter(["ja", "en-us"]);
+
+        assert!(langs.contains(&LangSet::from_iter(["ja-jp", "en"])));
+        assert!(!langs.contains(&LangSet::from("en-gb")));
+        k9::assert_equal!(langs.union(&LangSet::from("ko")).to_string(), "en-us|ja|ko");
+        k9::assert_equal!(langs.subtract(&LangSet::from("ja")).to_string(), "en-us");
+    }
+}
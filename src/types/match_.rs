@@ -4,17 +4,22 @@ mod test;
 pub use self::edit::*;
 pub use self::test::*;
 
+use crate::Span;
+
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Match {
     pub target: MatchTarget,
     pub tests: Vec<Test>,
     pub edits: Vec<Edit>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub span: Span,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MatchTarget {
+    #[default]
     Pattern,
     Font,
     Scan,
@@ -26,9 +31,3 @@ parse_enum! {
     (Font, "font"),
     (Scan, "scan"),
 }
-
-impl Default for MatchTarget {
-    fn default() -> Self {
-        MatchTarget::Pattern
-    }
-}
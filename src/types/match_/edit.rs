@@ -1,17 +1,23 @@
-use crate::Property;
+use crate::{Expression, PropertyKind, Span};
 
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edit {
     pub mode: EditMode,
     pub binding: EditBinding,
-    pub value: Property,
+    /// `name` of edited property
+    pub kind: PropertyKind,
+    /// Values in order, empty for delete modes
+    pub values: Vec<Expression>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub span: Span,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EditBinding {
     Strong,
+    #[default]
     Weak,
     Same,
 }
@@ -23,15 +29,10 @@ parse_enum! {
     (Same, "same"),
 }
 
-impl Default for EditBinding {
-    fn default() -> Self {
-        EditBinding::Weak
-    }
-}
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EditMode {
+    #[default]
     Assign,
     AssignReplace,
     Prepend,
@@ -53,9 +54,3 @@ parse_enum! {
     (Delete, "delete"),
     (DeleteAll, "delete_all"),
 }
-
-impl Default for EditMode {
-    fn default() -> Self {
-        EditMode::Assign
-    }
-}
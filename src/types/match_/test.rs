@@ -1,6 +1,7 @@
-use crate::Property;
+use crate::{Expression, PropertyKind, Span};
 
-/// This element contains a single value which is compared with the target ('pattern', 'font', 'scan' or 'default') property "property" (substitute any of the property names seen above).
+/// This element contains values which are compared with the target ('pattern', 'font', 'scan' or 'default') property "property" (substitute any of the property names seen above),
+/// the test passes when any of them matches.
 /// 'compare' can be one of "eq", "not_eq", "less", "less_eq", "more", "more_eq", "contains" or "not_contains".
 /// 'qual' may either be the default, "any", in which case the match succeeds if any value associated with the property matches the test value,
 /// or "all", in which case all of the values associated with the property must match the test value. 'ignore-blanks' takes a boolean value.
@@ -13,12 +14,20 @@ pub struct Test {
     pub qual: TestQual,
     pub target: TestTarget,
     pub compare: TestCompare,
-    pub value: Property,
+    /// `ignore-blanks`, blanks in strings are ignored by `eq` and `not_eq`
+    pub ignore_blanks: bool,
+    /// `name` of tested property
+    pub kind: PropertyKind,
+    /// Values in order, the test passes when any of them matches
+    pub values: Vec<Expression>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub span: Span,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TestTarget {
+    #[default]
     Default,
     Pattern,
     Font,
@@ -33,15 +42,10 @@ parse_enum! {
     (Scan, "scan"),
 }
 
-impl Default for TestTarget {
-    fn default() -> Self {
-        TestTarget::Default
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TestCompare {
+    #[default]
     Eq,
     NotEq,
     Less,
@@ -64,27 +68,22 @@ parse_enum! {
     (NotContains, "not_contains"),
 }
 
-impl Default for TestCompare {
-    fn default() -> Self {
-        TestCompare::Eq
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TestQual {
+    #[default]
     Any,
     All,
+    /// The first value of the property matches
+    First,
+    /// The first value of the property doesn't match but another one does
+    NotFirst,
 }
 
 parse_enum! {
     TestQual,
     (Any, "any"),
     (All, "all"),
-}
-
-impl Default for TestQual {
-    fn default() -> Self {
-        TestQual::Any
-    }
+    (First, "first"),
+    (NotFirst, "not_first"),
 }
@@ -0,0 +1,144 @@
+use crate::{FileSystem, StdFileSystem};
+
+use std::ffi::OsString;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// Options for parsing a config file, see [`parse_config_parts_with_options`](crate::parse_config_parts_with_options)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Reject unknown elements and attributes with [`Error::UnknownElement`](crate::Error::UnknownElement)
+    /// and [`Error::UnknownAttribute`](crate::Error::UnknownAttribute)
+    ///
+    /// Otherwise they're skipped with a warning like libfontconfig does
+    pub strict: bool,
+}
+
+/// Options for [`FontConfig::merge_config_with_options`](crate::FontConfig::merge_config_with_options)
+#[derive(Clone, Debug)]
+pub struct MergeOptions {
+    /// Maximum length of include chain, the top level config counts as 1
+    pub max_include_depth: usize,
+    /// Prefix of every absolute path like `FONTCONFIG_SYSROOT`
+    pub sysroot: Option<PathBuf>,
+    /// Directories searched for config files of relative path without `prefix` like `FONTCONFIG_PATH`
+    ///
+    /// When empty, those paths are relative to current directory
+    pub config_dirs: Vec<PathBuf>,
+    /// Where config files are read from, [`StdFileSystem`] by default
+    pub fs: Arc<dyn FileSystem + Send + Sync>,
+    /// Options used for every loaded file
+    pub parse_options: ParseOptions,
+}
+
+impl MergeOptions {
+    pub const DEFAULT_MAX_INCLUDE_DEPTH: usize = 32;
+    /// Main config file name when `FONTCONFIG_FILE` is not set
+    pub const DEFAULT_CONFIG_FILE: &'static str = "fonts.conf";
+    /// Searched after `FONTCONFIG_PATH` like `CONFIGDIR` of libfontconfig
+    pub const DEFAULT_CONFIG_DIR: &'static str = "/etc/fonts";
+    /// Templates of optional config files like `FC_TEMPLATEDIR` of libfontconfig
+    pub const TEMPLATE_DIR: &'static str = "/usr/share/fontconfig/conf.avail";
+
+    /// Options from `FONTCONFIG_SYSROOT` and `FONTCONFIG_PATH`
+    pub fn from_env() -> Self {
+        Self::from_vars(
+            std::env::var_os("FONTCONFIG_SYSROOT"),
+            std::env::var_os("FONTCONFIG_PATH"),
+        )
+    }
+
+    fn from_vars(sysroot: Option<OsString>, path: Option<OsString>) -> Self {
+        let mut config_dirs = path
+            .map(|path| {
+                std::env::split_paths(&path)
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        config_dirs.push(Self::DEFAULT_CONFIG_DIR.into());
+
+        Self {
+            sysroot: sysroot.filter(|s| !s.is_empty()).map(PathBuf::from),
+            config_dirs,
+            ..Default::default()
+        }
+    }
+
+    /// Prefix `path` with [`sysroot`](Self::sysroot)
+    ///
+    /// Relative paths and paths already under sysroot are returned as is
+    pub fn with_sysroot(&self, path: PathBuf) -> PathBuf {
+        match &self.sysroot {
+            Some(sysroot) if path.is_absolute() && !path.starts_with(sysroot) => sysroot.join(
+                path.components()
+                    .filter(|c| !matches!(c, Component::Prefix(_) | Component::RootDir))
+                    .collect::<PathBuf>(),
+            ),
+            _ => path,
+        }
+    }
+
+    /// Find existing config file `name` like `FcConfigGetFilename`
+    ///
+    /// Absolute paths are prefixed with sysroot, relative paths are searched in [`config_dirs`](Self::config_dirs)
+    pub fn find_config_file(&self, name: &Path) -> Option<PathBuf> {
+        if name.is_absolute() {
+            Some(self.with_sysroot(name.into())).filter(|path| self.exists(path))
+        } else if let Ok(name) = name.strip_prefix("~") {
+            let home = std::env::var_os("HOME")?;
+            Some(Path::new(&home).join(name)).filter(|path| self.exists(path))
+        } else {
+            self.config_dirs
+                .iter()
+                .map(|dir| self.with_sysroot(dir.join(name)))
+                .find(|path| self.exists(path))
+        }
+    }
+}
+
+impl MergeOptions {
+    fn exists(&self, path: &Path) -> bool {
+        self.fs.metadata(path).is_ok()
+    }
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            max_include_depth: Self::DEFAULT_MAX_INCLUDE_DEPTH,
+            sysroot: None,
+            config_dirs: Vec::new(),
+            fs: Arc::new(StdFileSystem),
+            parse_options: ParseOptions::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sysroot() {
+        let options = MergeOptions::from_vars(Some("/sysroot".into()), Some("/a:/b:".into()));
+
+        k9::assert_equal!(
+            options.config_dirs,
+            vec![
+                PathBuf::from("/a"),
+                PathBuf::from("/b"),
+                PathBuf::from(MergeOptions::DEFAULT_CONFIG_DIR)
+            ]
+        );
+        k9::assert_equal!(
+            options.with_sysroot("/usr/share/fonts".into()),
+            PathBuf::from("/sysroot/usr/share/fonts")
+        );
+        k9::assert_equal!(
+            options.with_sysroot("/sysroot/etc/fonts".into()),
+            PathBuf::from("/sysroot/etc/fonts")
+        );
+        k9::assert_equal!(options.with_sysroot("fonts".into()), PathBuf::from("fonts"));
+    }
+}
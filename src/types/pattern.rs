@@ -0,0 +1,257 @@
+use crate::{Bool, Double, EditBinding, Int, PropertyKind, Value};
+use std::iter::FromIterator;
+
+/// A value stored in a [`Pattern`] together with its binding
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternValue {
+    pub value: Value,
+    pub binding: EditBinding,
+}
+
+impl PatternValue {
+    pub fn new(value: Value, binding: EditBinding) -> Self {
+        Self { value, binding }
+    }
+}
+
+/// Runtime set of property values, similar to `FcPattern`
+///
+/// Each property holds an ordered list of values, earlier values are preferred over later ones.
+/// Values are checked against [`PropertyKind::value_type`] so a property never holds a value of wrong type.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    elts: Vec<(PropertyKind, Vec<PatternValue>)>,
+}
+
+impl Pattern {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of properties
+    pub fn len(&self) -> usize {
+        self.elts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elts.is_empty()
+    }
+
+    /// Append a value with strong binding like `FcPatternAdd`
+    ///
+    /// Returns `false` and drops `value` when it's not valid for `kind`
+    pub fn add(&mut self, kind: PropertyKind, value: impl Into<Value>) -> bool {
+        self.add_with_binding(kind, value, EditBinding::Strong)
+    }
+
+    /// Append a value with weak binding like `FcPatternAddWeak`
+    pub fn add_weak(&mut self, kind: PropertyKind, value: impl Into<Value>) -> bool {
+        self.add_with_binding(kind, value, EditBinding::Weak)
+    }
+
+    /// Append a value with `binding`, [`EditBinding::Same`] is stored as [`EditBinding::Weak`]
+    pub fn add_with_binding(
+        &mut self,
+        kind: PropertyKind,
+        value: impl Into<Value>,
+        binding: EditBinding,
+    ) -> bool {
+        let value = value.into();
+
+        if !is_valid(&kind, &value) {
+            return false;
+        }
+
+        let binding = match binding {
+            EditBinding::Same => EditBinding::Weak,
+            binding => binding,
+        };
+
+        self.values_mut(kind)
+            .push(PatternValue::new(value, binding));
+
+        true
+    }
+
+    /// Get values of `kind`, the returned slice is never empty
+    pub fn get(&self, kind: &PropertyKind) -> Option<&[PatternValue]> {
+        self.elts
+            .iter()
+            .find(|(k, _)| k == kind)
+            .map(|(_, values)| values.as_slice())
+    }
+
+    /// Get `idx`th value of `kind` like `FcPatternGet`
+    pub fn get_value(&self, kind: &PropertyKind, idx: usize) -> Option<&Value> {
+        self.get(kind)?.get(idx).map(|v| &v.value)
+    }
+
+    pub fn get_int(&self, kind: &PropertyKind, idx: usize) -> Option<Int> {
+        match self.get_value(kind, idx)? {
+            Value::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Get a number, integers are promoted to double
+    pub fn get_double(&self, kind: &PropertyKind, idx: usize) -> Option<Double> {
+        match self.get_value(kind, idx)? {
+            Value::Int(i) => Some(*i as Double),
+            Value::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    pub fn get_string(&self, kind: &PropertyKind, idx: usize) -> Option<&str> {
+        match self.get_value(kind, idx)? {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn get_bool(&self, kind: &PropertyKind, idx: usize) -> Option<Bool> {
+        match self.get_value(kind, idx)? {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Delete all values of `kind` like `FcPatternDel`
+    ///
+    /// Returns `false` when `kind` doesn't exist
+    pub fn delete(&mut self, kind: &PropertyKind) -> bool {
+        let len = self.elts.len();
+        self.elts.retain(|(k, _)| k != kind);
+        len != self.elts.len()
+    }
+
+    /// Remove `idx`th value of `kind` like `FcPatternRemove`
+    pub fn remove(&mut self, kind: &PropertyKind, idx: usize) -> Option<PatternValue> {
+        let pos = self.elts.iter().position(|(k, _)| k == kind)?;
+        let values = &mut self.elts[pos].1;
+
+        if idx >= values.len() {
+            return None;
+        }
+
+        let value = values.remove(idx);
+
+        if values.is_empty() {
+            self.elts.remove(pos);
+        }
+
+        Some(value)
+    }
+
+    /// Copy of the pattern with only properties in `kinds` like `FcPatternFilter`
+    pub fn filter(&self, kinds: &[PropertyKind]) -> Pattern {
+        Self {
+            elts: self
+                .elts
+                .iter()
+                .filter(|(kind, _)| kinds.contains(kind))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Iterate properties in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&PropertyKind, &[PatternValue])> + '_ {
+        self.elts
+            .iter()
+            .map(|(kind, values)| (kind, values.as_slice()))
+    }
+
+    /// Get values of `kind`, inserting an empty list when it doesn't exist yet
+    pub(crate) fn values_mut(&mut self, kind: PropertyKind) -> &mut Vec<PatternValue> {
+        let idx = match self.elts.iter().position(|(k, _)| *k == kind) {
+            Some(idx) => idx,
+            None => {
+                self.elts.push((kind, Vec::new()));
+                self.elts.len() - 1
+            }
+        };
+
+        &mut self.elts[idx].1
+    }
+
+    /// Drop properties which lost all of their values
+    pub(crate) fn remove_empty(&mut self) {
+        self.elts.retain(|(_, values)| !values.is_empty());
+    }
+}
+
+/// Whether `value` can be stored in `kind`, unknown properties accept any evaluated value
+pub(crate) fn is_valid(kind: &PropertyKind, value: &Value) -> bool {
+    if let Value::Constant(_) | Value::Property(..) = value {
+        return false;
+    }
+
+    match kind.value_type() {
+        Some(ty) => ty.accepts(value),
+        None => true,
+    }
+}
+
+impl<V: Into<Value>> Extend<(PropertyKind, V)> for Pattern {
+    fn extend<T: IntoIterator<Item = (PropertyKind, V)>>(&mut self, iter: T) {
+        for (kind, value) in iter {
+            self.add(kind, value);
+        }
+    }
+}
+
+impl<V: Into<Value>> FromIterator<(PropertyKind, V)> for Pattern {
+    fn from_iter<T: IntoIterator<Item = (PropertyKind, V)>>(iter: T) -> Self {
+        let mut pattern = Pattern::new();
+        pattern.extend(iter);
+        pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_values() {
+        let mut pattern = Pattern::new();
+
+        assert!(pattern.add(PropertyKind::Family, "Foo"));
+        assert!(pattern.add_weak(PropertyKind::Family, "Bar"));
+        assert!(pattern.add(PropertyKind::Size, 12));
+        assert!(!pattern.add(PropertyKind::Weight, "bold"));
+        assert!(pattern.add(PropertyKind::Dynamic("custom".into()), "bold"));
+
+        k9::assert_equal!(pattern.len(), 3);
+        k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 1), Some("Bar"));
+        k9::assert_equal!(
+            pattern.get(&PropertyKind::Family).unwrap()[1].binding,
+            EditBinding::Weak
+        );
+        k9::assert_equal!(pattern.get_double(&PropertyKind::Size, 0), Some(12.0));
+        assert!(pattern.get(&PropertyKind::Weight).is_none());
+    }
+
+    #[test]
+    fn delete_and_remove() {
+        let mut pattern = vec![
+            (PropertyKind::Family, "Foo"),
+            (PropertyKind::Family, "Bar"),
+            (PropertyKind::Style, "Bold"),
+        ]
+        .into_iter()
+        .collect::<Pattern>();
+
+        assert!(pattern.remove(&PropertyKind::Family, 0).is_some());
+        k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 0), Some("Bar"));
+        assert!(pattern.remove(&PropertyKind::Family, 0).is_some());
+        assert!(pattern.get(&PropertyKind::Family).is_none());
+
+        assert!(pattern.delete(&PropertyKind::Style));
+        assert!(!pattern.delete(&PropertyKind::Style));
+        assert!(pattern.is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Expression, Value};
+use crate::{Expression, Value, ValueType};
 
 macro_rules! define_property {
     (
@@ -26,9 +26,18 @@ macro_rules! define_property {
                     Property::Dynamic(s, _) => PropertyKind::Dynamic(s.clone()),
                 }
             }
+
+            pub fn expression(&self) -> &Expression {
+                match self {
+                    $(
+                        Property::$variant(expr) => expr,
+                    )+
+                    Property::Dynamic(_, expr) => expr,
+                }
+            }
         }
 
-        #[derive(Clone, Debug, PartialEq, Eq)]
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum PropertyKind {
             $(
@@ -47,6 +56,26 @@ macro_rules! define_property {
         }
 
         impl PropertyKind {
+            /// Name used in config files
+            pub fn name(&self) -> &str {
+                match self {
+                    $(
+                        PropertyKind::$variant => $name,
+                    )+
+                    PropertyKind::Dynamic(name) => name,
+                }
+            }
+
+            /// Type of values this property holds, `None` for [`PropertyKind::Dynamic`]
+            pub fn value_type(&self) -> Option<ValueType> {
+                match self {
+                    $(
+                        PropertyKind::$variant => Some(ValueType::$value_ty),
+                    )+
+                    PropertyKind::Dynamic(_) => None,
+                }
+            }
+
             pub fn make_property(self, expr: Expression) -> Property {
                 match self {
                     $(
@@ -76,11 +105,11 @@ define_property! {
     /// Italic, oblique or roman
     Slant(Int, "slant"),
     /// Light, medium, demibold, bold or black
-    Weight(Int, "weight"),
+    Weight(Range, "weight"),
     /// Point size
-    Size(Double, "size"),
+    Size(Range, "size"),
     /// Condensed, normal or expanded
-    Width(Int, "width"),
+    Width(Range, "width"),
     /// Stretches glyphs horizontally before hinting
     Aspect(Double, "aspect"),
     /// Pixel size
@@ -130,7 +159,7 @@ define_property! {
     /// Unicode chars encoded by the font
     Charset(CharSet, "charset"),
     /// List of RFC-3066-style languages this font supports
-    Lang(String, "lang"),
+    Lang(LangSet, "lang"),
     /// Version number of the font
     Fontversion(Int, "fontversion"),
     /// List of layout capabilities in the font
@@ -155,6 +184,10 @@ define_property! {
     Fonthashint(Bool, "fonthashint"),
     /// Order number of the font
     Order(Int, "order"),
+    /// Whether the font has a symbol cmap
+    Symbol(Bool, "symbol"),
+    /// Whether the font is a variable font rather than one of its instances
+    Variable(Bool, "variable"),
 
     // custom
 
@@ -169,6 +202,7 @@ impl Default for Property {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for PropertyKind {
     fn default() -> Self {
         PropertyKind::Family
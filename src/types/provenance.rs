@@ -0,0 +1,50 @@
+use crate::Span;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Where a merged item came from
+#[derive(Clone, Debug, PartialEq)]
+pub struct Origin {
+    /// Config file which contains the item
+    pub file: Arc<Path>,
+    /// Canonical paths of files which included [`file`](Self::file), starting from the top level config
+    pub include_chain: Arc<[PathBuf]>,
+    /// Position of the item in evaluation order across every merged file, items of all lists share the sequence
+    pub order: usize,
+    /// Location of the item in [`file`](Self::file)
+    pub span: Span,
+}
+
+/// Side table of [`FontConfig`](crate::FontConfig) recording [`Origin`] of each merged item
+///
+/// Each list is parallel to the list of same name in `FontConfig`,
+/// it's only filled by [`FontConfig::merge_config`](crate::FontConfig::merge_config).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Provenance {
+    pub dirs: Vec<Origin>,
+    pub matches: Vec<Origin>,
+    pub aliases: Vec<Origin>,
+    pub select_fonts: Vec<Origin>,
+    next_order: usize,
+}
+
+impl Provenance {
+    /// Origin of next merged item
+    pub(crate) fn next_origin(
+        &mut self,
+        file: &Arc<Path>,
+        include_chain: &Arc<[PathBuf]>,
+        span: Span,
+    ) -> Origin {
+        let order = self.next_order;
+        self.next_order += 1;
+
+        Origin {
+            file: file.clone(),
+            include_chain: include_chain.clone(),
+            order,
+            span,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 1-based line and column in a config file, column counts chars
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Source location of a parsed element
+///
+/// Spans are skipped on serialization.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    /// Config file path, `None` when parsed from a string without a path
+    pub file: Option<Arc<Path>>,
+    /// Position of start tag
+    pub start: Position,
+    /// Position right after end tag
+    pub end: Position,
+}
+
+/// Formatted like `30-metric-aliases.conf:42:5`
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+
+        self.start.fmt(f)
+    }
+}
+
+/// A value with the span it's parsed from
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spanned<T> {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub span: Span,
+    pub value: T,
+}
+
+impl<T> std::ops::Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
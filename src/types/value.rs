@@ -1,8 +1,10 @@
-use crate::{Constant, IntOrRange, PropertyKind};
+use crate::{CharSet, Constant, LangSet, PropertyKind};
 
 pub type Bool = bool;
 pub type Int = u32;
 pub type Double = f64;
+/// `xx`, `xy`, `yx`, `yy` of a 2x2 transform matrix
+pub type Matrix = [Double; 4];
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -40,10 +42,31 @@ pub enum UnaryOp {
 parse_enum! {
     UnaryOp,
     (Not, "not"),
-    (Cecil, "cecil"),
+    (Cecil, "ceil"),
     (Floor, "floor"),
     (Round, "round"),
     (Trunc, "trunc"),
+    // misspelled name which was accepted before
+    |s| match s {
+        "cecil" => Ok(UnaryOp::Cecil),
+        _ => Err(crate::Error::ParseEnumError(
+            core::any::type_name::<UnaryOp>(),
+            s.into(),
+        )),
+    },
+}
+
+impl UnaryOp {
+    /// Name used in config files
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOp::Not => "not",
+            UnaryOp::Cecil => "ceil",
+            UnaryOp::Floor => "floor",
+            UnaryOp::Round => "round",
+            UnaryOp::Trunc => "trunc",
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -90,12 +113,14 @@ pub enum Expression {
     Binary(BinaryOp, Box<[Self; 2]>),
     Ternary(TernaryOp, Box<[Self; 3]>),
     List(ListOp, Vec<Self>),
-    Matrix(Box<[Self; 4]>),
+    /// Elements of `<matrix>`, it's void unless there are 4 of them
+    Matrix(Vec<Self>),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyTarget {
+    #[default]
     Default,
     Font,
     Pattern,
@@ -108,13 +133,46 @@ parse_enum! {
     (Pattern, "pattern"),
 }
 
-impl Default for PropertyTarget {
-    fn default() -> Self {
-        PropertyTarget::Default
-    }
+/// Type of [`Value`] each property accepts, see [`PropertyKind::value_type`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueType {
+    Int,
+    Double,
+    String,
+    Bool,
+    Range,
+    LangSet,
+    CharSet,
+    Matrix,
 }
 
-pub type CharSet = Vec<IntOrRange>;
+impl ValueType {
+    /// Whether `value` can be stored in a property of this type like `FcObjectValidType`
+    ///
+    /// Numbers are interchangeable and a string is accepted as a language set.
+    pub fn accepts(self, value: &Value) -> bool {
+        value.value_type().map_or(false, |ty| self.accepts_type(ty))
+    }
+
+    /// Same as [`accepts`](Self::accepts) for any value of type `ty`
+    pub fn accepts_type(self, ty: ValueType) -> bool {
+        matches!(
+            (self, ty),
+            (
+                ValueType::Int | ValueType::Double,
+                ValueType::Int | ValueType::Double
+            ) | (
+                ValueType::Range,
+                ValueType::Int | ValueType::Double | ValueType::Range
+            ) | (ValueType::String, ValueType::String)
+                | (ValueType::Bool, ValueType::Bool)
+                | (ValueType::LangSet, ValueType::LangSet | ValueType::String)
+                | (ValueType::CharSet, ValueType::CharSet)
+                | (ValueType::Matrix, ValueType::Matrix)
+        )
+    }
+}
 
 /// Runtime typed fontconfig value
 #[derive(Clone, Debug, PartialEq)]
@@ -133,11 +191,30 @@ pub enum Value {
     /// This element holds the two [`Value::Int`] elements of a range representation.
     Range(Int, Int),
     /// This element holds at least one [`Value::String`] element of a RFC-3066-style languages or more.
-    LangSet(String),
+    LangSet(LangSet),
     /// This element holds at least one [`Value::Int`] element of an Unicode code point or more.
     CharSet(CharSet),
     /// `<name target="font">pixelsize</name>`
     Property(PropertyTarget, PropertyKind),
+    /// Evaluated [`Expression::Matrix`]
+    Matrix(Matrix),
+}
+
+impl Value {
+    /// Type of evaluated value, `None` for [`Value::Constant`] and [`Value::Property`]
+    pub fn value_type(&self) -> Option<ValueType> {
+        match self {
+            Value::Int(_) => Some(ValueType::Int),
+            Value::Double(_) => Some(ValueType::Double),
+            Value::String(_) => Some(ValueType::String),
+            Value::Bool(_) => Some(ValueType::Bool),
+            Value::Range(..) => Some(ValueType::Range),
+            Value::LangSet(_) => Some(ValueType::LangSet),
+            Value::CharSet(_) => Some(ValueType::CharSet),
+            Value::Matrix(_) => Some(ValueType::Matrix),
+            Value::Constant(_) | Value::Property(..) => None,
+        }
+    }
 }
 
 macro_rules! from_value {
@@ -157,7 +234,9 @@ from_value! {
     Bool,
     Double,
     Constant,
+    LangSet,
     CharSet,
+    Matrix,
 }
 
 impl<'a> From<&'a str> for Value {
@@ -11,29 +11,13 @@ macro_rules! try_text {
     ($node:expr) => {
         match $node.text() {
             Some(t) => t,
+            // `<string></string>` holds an empty text
+            None if !$node.has_children() => "",
             None => return Err(crate::Error::InvalidFormat("Can't get text".into())),
         }
     };
 }
 
-macro_rules! parse_attrs_opt {
-    ($node:expr, { $($key:expr => $lvalue:expr,)+ } $(, { $($str_key:expr => $str_lvalue:expr,)+ } )?) => {
-        for attr in $node.attributes() {
-            match attr.name() {
-                $(
-                    $key => $lvalue = attr.value().parse().ok()?,
-                )+
-                $(
-                    $(
-                        $str_key => $str_lvalue = attr.value().into(),
-                    )+
-                )?
-                _ => {}
-            }
-        }
-    };
-}
-
 macro_rules! parse_attrs {
     ($node:expr, { $($key:expr => $lvalue:expr,)+ } $(, { $($str_key:expr => $str_lvalue:expr,)+ } )?) => {
         for attr in $node.attributes() {
@@ -88,5 +72,16 @@ macro_rules! parse_enum {
             )+
             |s| Err(crate::Error::ParseEnumError(core::any::type_name::<$ty>(), s.into())),
         }
+
+        impl $ty {
+            /// Name used in config files
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$variant => $text,
+                    )+
+                }
+            }
+        }
     };
 }
@@ -0,0 +1,323 @@
+//! Semantic checks of parsed configs like `FcTypecheckExpr`
+
+use crate::*;
+
+/// Check values of tests, edits and `<selectfont>` patterns against the type of their property
+///
+/// Parsing only checks syntax, this finds values which can never match or be stored,
+/// `<const>` which doesn't apply to the property and reversed `<range>`.
+pub fn validate_config_parts(parts: &[ConfigPart]) -> Vec<Diagnostic> {
+    let mut validator = Validator::default();
+
+    for part in parts {
+        match part {
+            ConfigPart::Match(m) => validator.match_(m),
+            ConfigPart::SelectFont(s) => validator.select_font(s, None),
+            ConfigPart::Config(c) => validator.config(c),
+            _ => {}
+        }
+    }
+
+    validator.diagnostics
+}
+
+impl FontConfig {
+    /// Same as [`validate_config_parts`] for merged items
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+
+        for m in self.matches.iter() {
+            validator.match_(m);
+        }
+
+        for (idx, s) in self.select_fonts.iter().enumerate() {
+            let span = self.provenance.select_fonts.get(idx).map(|o| &o.span);
+            validator.select_font(s, span);
+        }
+
+        validator.config(&self.config);
+
+        validator.diagnostics
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    fn report(
+        &mut self,
+        severity: Severity,
+        code: DiagnosticCode,
+        message: String,
+        span: Option<&Span>,
+    ) {
+        report(
+            &mut self.diagnostics,
+            Diagnostic {
+                severity,
+                code,
+                message,
+                span: span.cloned(),
+            },
+        );
+    }
+
+    fn match_(&mut self, m: &Match) {
+        for test in m.tests.iter() {
+            for value in test.values.iter() {
+                self.property_value(&test.kind, value, Some(&test.span));
+            }
+        }
+
+        for edit in m.edits.iter() {
+            for value in edit.values.iter() {
+                self.property_value(&edit.kind, value, Some(&edit.span));
+            }
+        }
+    }
+
+    fn select_font(&mut self, s: &SelectFont, span: Option<&Span>) {
+        for font_match in s.accepts.iter().chain(s.rejects.iter()) {
+            if let FontMatch::Pattern(props) = font_match {
+                for prop in props {
+                    self.property_value(&prop.kind(), prop.expression(), span);
+                }
+            }
+        }
+    }
+
+    fn config(&mut self, c: &Config) {
+        for blank in c.blanks.iter() {
+            if let IntOrRange::Range(start, end) = *blank {
+                self.range(start, end, None);
+            }
+        }
+    }
+
+    fn property_value(&mut self, kind: &PropertyKind, expr: &Expression, span: Option<&Span>) {
+        let ty = self.expr_type(kind, expr, span);
+
+        match (kind.value_type(), ty) {
+            (Some(expected), Some(ty)) if !expected.accepts_type(ty) => self.report(
+                Severity::Warning,
+                DiagnosticCode::TypeMismatch,
+                format!(
+                    "Expect {:?} value for {}, got {:?}",
+                    expected,
+                    kind.name(),
+                    ty
+                ),
+                span,
+            ),
+            _ => {}
+        }
+    }
+
+    fn range(&mut self, start: Int, end: Int, span: Option<&Span>) {
+        if start > end {
+            self.report(
+                Severity::Error,
+                DiagnosticCode::InvalidRange,
+                format!("Range start {} is after its end {}", start, end),
+                span,
+            );
+        }
+    }
+
+    /// Type of the evaluated `expr`, `None` when it can't be known without evaluation
+    ///
+    /// `<const>` is resolved with `kind` wherever it appears like [`EvalContext::kind`].
+    fn expr_type(
+        &mut self,
+        kind: &PropertyKind,
+        expr: &Expression,
+        span: Option<&Span>,
+    ) -> Option<ValueType> {
+        match expr {
+            Expression::Simple(value) => self.value_type(kind, value, span),
+            Expression::Unary(op, expr) => {
+                self.expr_type(kind, expr, span);
+
+                match op {
+                    UnaryOp::Not => Some(ValueType::Bool),
+                    _ => Some(ValueType::Int),
+                }
+            }
+            Expression::Binary(_, exprs) => {
+                for expr in exprs.iter() {
+                    self.expr_type(kind, expr, span);
+                }
+
+                Some(ValueType::Bool)
+            }
+            Expression::Ternary(TernaryOp::If, exprs) => {
+                let [cond, then, otherwise] = &**exprs;
+                self.expr_type(kind, cond, span);
+                let then = self.expr_type(kind, then, span);
+                let otherwise = self.expr_type(kind, otherwise, span);
+
+                match (then, otherwise) {
+                    (Some(l), Some(r)) if l == r => Some(l),
+                    (Some(l), Some(r)) if is_number(l) && is_number(r) => Some(ValueType::Double),
+                    (Some(_), Some(_)) => None,
+                    (ty, None) | (None, ty) => ty,
+                }
+            }
+            Expression::List(op, exprs) => {
+                // every operand is checked even if a type is unknown
+                let types = exprs
+                    .iter()
+                    .map(|expr| self.expr_type(kind, expr, span))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .collect::<Option<Vec<_>>>()?;
+
+                match op {
+                    ListOp::Or | ListOp::And => Some(ValueType::Bool),
+                    _ if types.iter().all(|ty| *ty == ValueType::Int) => Some(ValueType::Int),
+                    // a range property mostly holds a single number
+                    _ if types.iter().all(|ty| is_number(*ty)) => Some(ValueType::Double),
+                    _ => types
+                        .first()
+                        .copied()
+                        .filter(|ty| types.iter().all(|t| t == ty)),
+                }
+            }
+            Expression::Matrix(exprs) => {
+                if exprs.len() != 4 {
+                    self.report(
+                        Severity::Warning,
+                        DiagnosticCode::InvalidMatrix,
+                        format!("Matrix must have 4 elements, got {}", exprs.len()),
+                        span,
+                    );
+                }
+
+                for expr in exprs.iter() {
+                    match self.expr_type(kind, expr, span) {
+                        Some(ty) if !is_number(ty) => self.report(
+                            Severity::Warning,
+                            DiagnosticCode::InvalidMatrix,
+                            format!("Expect number in matrix, got {:?}", ty),
+                            span,
+                        ),
+                        _ => {}
+                    }
+                }
+
+                Some(ValueType::Matrix)
+            }
+        }
+    }
+
+    fn value_type(
+        &mut self,
+        kind: &PropertyKind,
+        value: &Value,
+        span: Option<&Span>,
+    ) -> Option<ValueType> {
+        match value {
+            Value::Constant(c) => {
+                if c.get_value(kind.clone()).is_none() {
+                    self.report(
+                        Severity::Warning,
+                        DiagnosticCode::InapplicableConstant,
+                        format!("Constant {} doesn't apply to {}", c.as_str(), kind.name()),
+                        span,
+                    );
+                }
+
+                Some(ValueType::Int)
+            }
+            Value::Property(_, kind) => kind.value_type(),
+            Value::Range(start, end) => {
+                self.range(*start, *end, span);
+                Some(ValueType::Range)
+            }
+            value => value.value_type(),
+        }
+    }
+}
+
+fn is_number(ty: ValueType) -> bool {
+    matches!(ty, ValueType::Int | ValueType::Double | ValueType::Range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(xml: &str) -> Vec<DiagnosticCode> {
+        validate_config_parts(&parse_config_parts(xml).unwrap())
+            .into_iter()
+            .map(|d| d.code)
+            .collect()
+    }
+
+    #[test]
+    fn type_mismatch() {
+        k9::assert_equal!(
+            codes(
+                r#"<fontconfig><match>
+                    <test name="weight"><string>bold</string></test>
+                    <edit name="antialias"><int>1</int></edit>
+                    <edit name="lang"><string>ja</string></edit>
+                    <edit name="pixelsize"><times><name>size</name><double>1.5</double></times></edit>
+                    <edit name="foo"><string>any</string></edit>
+                </match></fontconfig>"#
+            ),
+            vec![DiagnosticCode::TypeMismatch, DiagnosticCode::TypeMismatch]
+        );
+    }
+
+    #[test]
+    fn constants_ranges_and_matrices() {
+        k9::assert_equal!(
+            codes(
+                r#"<fontconfig><match>
+                    <test name="slant"><const>bold</const></test>
+                    <edit name="weight"><if><less><name>weight</name><const>medium</const></less><const>medium</const><name>weight</name></if></edit>
+                    <edit name="size"><range><int>12</int><int>8</int></range></edit>
+                    <edit name="matrix"><matrix><double>1</double><string>0</string><double>0</double><double>1</double></matrix></edit>
+                    <edit name="matrix"><matrix><double>1</double><double>0</double><double>1</double></matrix></edit>
+                </match></fontconfig>"#
+            ),
+            vec![
+                DiagnosticCode::InapplicableConstant,
+                DiagnosticCode::InvalidRange,
+                DiagnosticCode::InvalidMatrix,
+                DiagnosticCode::InvalidMatrix
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostic_location() {
+        let parts = parse_config_parts_with_options(
+            "<fontconfig>\n<match>\n<edit name=\"hintstyle\"><const>hintful</const></edit>\n<edit name=\"rgba\"><const>hintfull</const></edit>\n</match>\n</fontconfig>",
+            Some(std::path::Path::new("10-hinting.conf")),
+            &ParseOptions::default(),
+            &mut Vec::new(),
+        );
+        // unknown constant names are parse errors
+        assert!(parts.is_err());
+
+        let parts = parse_config_parts_with_options(
+            "<fontconfig>\n<match>\n<edit name=\"rgba\"><const>hintfull</const></edit>\n</match>\n</fontconfig>",
+            Some(std::path::Path::new("10-hinting.conf")),
+            &ParseOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let parts = parts.into_iter().map(|p| p.value).collect::<Vec<_>>();
+        let diagnostics = validate_config_parts(&parts);
+
+        k9::assert_equal!(
+            diagnostics[0].to_string(),
+            "10-hinting.conf:3:1: warning[inapplicable-constant]: Constant hintfull doesn't apply to rgba"
+        );
+    }
+}
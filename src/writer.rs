@@ -0,0 +1,501 @@
+//! Write parsed config back to fontconfig XML which [`parse_config_parts`](crate::parse_config_parts) reads back to same value
+
+use crate::*;
+
+use std::fmt::{self, Write};
+
+/// XML header of every fontconfig file
+pub const XML_HEADER: &str =
+    "<?xml version=\"1.0\"?>\n<!DOCTYPE fontconfig SYSTEM \"urn:fontconfig:fonts.dtd\">\n";
+
+/// Types which can be written as fontconfig XML elements
+pub trait WriteXml {
+    /// Write element indented by `depth` tabs, ending with a newline
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result;
+
+    /// Element without document header
+    fn to_xml(&self) -> String {
+        let mut s = String::new();
+        self.write_xml(&mut s, 0)
+            .expect("Writing to String never fails");
+        s
+    }
+}
+
+/// Write a complete `<fontconfig>` document with header
+pub fn write_config<'a, T, I>(f: &mut dyn Write, parts: I) -> fmt::Result
+where
+    T: WriteXml + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    f.write_str(XML_HEADER)?;
+    f.write_str("<fontconfig>\n")?;
+
+    for part in parts {
+        part.write_xml(f, 1)?;
+    }
+
+    f.write_str("</fontconfig>\n")
+}
+
+/// Same as [`write_config`] but into a `String`
+pub fn config_to_xml<'a, T, I>(parts: I) -> String
+where
+    T: WriteXml + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut s = String::new();
+    write_config(&mut s, parts).expect("Writing to String never fails");
+    s
+}
+
+fn indent(f: &mut dyn Write, depth: usize) -> fmt::Result {
+    for _ in 0..depth {
+        f.write_char('\t')?;
+    }
+
+    Ok(())
+}
+
+fn write_escaped(f: &mut dyn Write, s: &str) -> fmt::Result {
+    for c in s.chars() {
+        match c {
+            '&' => f.write_str("&amp;")?,
+            '<' => f.write_str("&lt;")?,
+            '>' => f.write_str("&gt;")?,
+            '"' => f.write_str("&quot;")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Start tag with attributes, `None` attributes are skipped
+fn start_tag(f: &mut dyn Write, name: &str, attrs: &[(&str, Option<&str>)]) -> fmt::Result {
+    write!(f, "<{}", name)?;
+
+    for (key, value) in attrs {
+        if let Some(value) = value {
+            write!(f, " {}=\"", key)?;
+            write_escaped(f, value)?;
+            f.write_char('"')?;
+        }
+    }
+
+    f.write_char('>')
+}
+
+/// `<name attrs>text</name>` in a line
+fn text_element(
+    f: &mut dyn Write,
+    depth: usize,
+    name: &str,
+    attrs: &[(&str, Option<&str>)],
+    text: &str,
+) -> fmt::Result {
+    indent(f, depth)?;
+    start_tag(f, name, attrs)?;
+    write_escaped(f, text)?;
+    writeln!(f, "</{}>", name)
+}
+
+/// Element whose children are written by `children` one level deeper
+fn block_element(
+    f: &mut dyn Write,
+    depth: usize,
+    name: &str,
+    attrs: &[(&str, Option<&str>)],
+    children: impl FnOnce(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    indent(f, depth)?;
+    start_tag(f, name, attrs)?;
+    f.write_char('\n')?;
+    children(f)?;
+    indent(f, depth)?;
+    writeln!(f, "</{}>", name)
+}
+
+/// Attribute value which is omitted when it's the default
+fn non_default<T: Default + PartialEq>(value: &T, name: &'static str) -> Option<&'static str> {
+    if *value == T::default() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+fn write_int_or_range(f: &mut dyn Write, value: &IntOrRange) -> fmt::Result {
+    match value {
+        IntOrRange::Int(i) => write!(f, "<int>{}</int>", i),
+        IntOrRange::Range(start, end) => {
+            write!(f, "<range><int>{}</int><int>{}</int></range>", start, end)
+        }
+    }
+}
+
+impl WriteXml for ConfigPart {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        match self {
+            ConfigPart::Description(desc) => desc.write_xml(f, depth),
+            ConfigPart::SelectFont(s) => s.write_xml(f, depth),
+            ConfigPart::Dir(dir) => dir.write_xml(f, depth),
+            ConfigPart::CacheDir(dir) => dir.write_xml(f, depth),
+            ConfigPart::Include(dir) => dir.write_xml(f, depth),
+            ConfigPart::Match(m) => m.write_xml(f, depth),
+            ConfigPart::Config(c) => c.write_xml(f, depth),
+            ConfigPart::Alias(alias) => alias.write_xml(f, depth),
+            ConfigPart::RemapDir(dir) => dir.write_xml(f, depth),
+            ConfigPart::ResetDirs => {
+                indent(f, depth)?;
+                f.write_str("<reset-dirs/>\n")
+            }
+        }
+    }
+}
+
+impl<T: WriteXml> WriteXml for Spanned<T> {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        self.value.write_xml(f, depth)
+    }
+}
+
+impl WriteXml for Description {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [("domain", self.domain.as_deref())];
+        text_element(f, depth, "description", &attrs, &self.text)
+    }
+}
+
+impl WriteXml for Dir {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let salt = Some(self.salt.as_str()).filter(|s| !s.is_empty());
+        let attrs = [
+            ("prefix", non_default(&self.prefix, self.prefix.as_str())),
+            ("salt", salt),
+        ];
+        text_element(f, depth, "dir", &attrs, &self.path)
+    }
+}
+
+impl WriteXml for CacheDir {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [("prefix", non_default(&self.prefix, self.prefix.as_str()))];
+        text_element(f, depth, "cachedir", &attrs, &self.path)
+    }
+}
+
+impl WriteXml for Include {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [
+            ("prefix", non_default(&self.prefix, self.prefix.as_str())),
+            ("ignore_missing", non_default(&self.ignore_missing, "yes")),
+            ("deprecated", non_default(&self.deprecated, "yes")),
+        ];
+        text_element(f, depth, "include", &attrs, &self.path)
+    }
+}
+
+impl WriteXml for RemapDir {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let salt = Some(self.salt.as_str()).filter(|s| !s.is_empty());
+        let attrs = [
+            ("prefix", non_default(&self.prefix, self.prefix.as_str())),
+            ("as-path", Some(self.as_path.as_str())),
+            ("salt", salt),
+        ];
+        text_element(f, depth, "remap-dir", &attrs, &self.path)
+    }
+}
+
+impl WriteXml for Config {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        block_element(f, depth, "config", &[], |f| {
+            // each `<blank>` and `<rescan>` holds single value
+            for blank in self.blanks.iter() {
+                indent(f, depth + 1)?;
+                f.write_str("<blank>")?;
+                write_int_or_range(f, blank)?;
+                f.write_str("</blank>\n")?;
+            }
+
+            for rescan in self.rescans.iter() {
+                indent(f, depth + 1)?;
+                writeln!(f, "<rescan><int>{}</int></rescan>", rescan)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl WriteXml for Alias {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [("binding", non_default(&self.binding, self.binding.as_str()))];
+
+        block_element(f, depth, "alias", &attrs, |f| {
+            text_element(f, depth + 1, "family", &[], &self.alias)?;
+
+            for (name, families) in [
+                ("prefer", &self.prefer),
+                ("accept", &self.accept),
+                ("default", &self.default),
+            ]
+            .iter()
+            {
+                if families.is_empty() {
+                    continue;
+                }
+
+                block_element(f, depth + 1, name, &[], |f| {
+                    for family in families.iter() {
+                        text_element(f, depth + 2, "family", &[], family)?;
+                    }
+                    Ok(())
+                })?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl WriteXml for SelectFont {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        block_element(f, depth, "selectfont", &[], |f| {
+            for (name, matches) in
+                [("rejectfont", &self.rejects), ("acceptfont", &self.accepts)].iter()
+            {
+                if matches.is_empty() {
+                    continue;
+                }
+
+                block_element(f, depth + 1, name, &[], |f| {
+                    for m in matches.iter() {
+                        m.write_xml(f, depth + 2)?;
+                    }
+                    Ok(())
+                })?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl WriteXml for FontMatch {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        match self {
+            FontMatch::Glob(glob) => text_element(f, depth, "glob", &[], glob),
+            FontMatch::Pattern(props) => block_element(f, depth, "pattern", &[], |f| {
+                for prop in props.iter() {
+                    let kind = prop.kind();
+                    block_element(
+                        f,
+                        depth + 1,
+                        "patelt",
+                        &[("name", Some(kind.name()))],
+                        |f| prop.expression().write_xml(f, depth + 2),
+                    )?;
+                }
+                Ok(())
+            }),
+        }
+    }
+}
+
+impl WriteXml for Match {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [("target", non_default(&self.target, self.target.as_str()))];
+
+        block_element(f, depth, "match", &attrs, |f| {
+            for test in self.tests.iter() {
+                test.write_xml(f, depth + 1)?;
+            }
+
+            for edit in self.edits.iter() {
+                edit.write_xml(f, depth + 1)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl WriteXml for Test {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [
+            ("name", Some(self.kind.name())),
+            ("qual", non_default(&self.qual, self.qual.as_str())),
+            ("target", non_default(&self.target, self.target.as_str())),
+            ("compare", non_default(&self.compare, self.compare.as_str())),
+            ("ignore-blanks", non_default(&self.ignore_blanks, "true")),
+        ];
+
+        block_element(f, depth, "test", &attrs, |f| {
+            self.values
+                .iter()
+                .try_for_each(|expr| expr.write_xml(f, depth + 1))
+        })
+    }
+}
+
+impl WriteXml for Edit {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        let attrs = [
+            ("name", Some(self.kind.name())),
+            ("mode", non_default(&self.mode, self.mode.as_str())),
+            ("binding", non_default(&self.binding, self.binding.as_str())),
+        ];
+
+        block_element(f, depth, "edit", &attrs, |f| {
+            self.values
+                .iter()
+                .try_for_each(|expr| expr.write_xml(f, depth + 1))
+        })
+    }
+}
+
+impl WriteXml for Expression {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        match self {
+            Expression::Simple(value) => value.write_xml(f, depth),
+            Expression::Unary(op, expr) => {
+                block_element(f, depth, op.as_str(), &[], |f| expr.write_xml(f, depth + 1))
+            }
+            Expression::Binary(op, exprs) => block_element(f, depth, op.as_str(), &[], |f| {
+                exprs
+                    .iter()
+                    .try_for_each(|expr| expr.write_xml(f, depth + 1))
+            }),
+            Expression::Ternary(op, exprs) => block_element(f, depth, op.as_str(), &[], |f| {
+                exprs
+                    .iter()
+                    .try_for_each(|expr| expr.write_xml(f, depth + 1))
+            }),
+            Expression::List(op, exprs) => block_element(f, depth, op.as_str(), &[], |f| {
+                exprs
+                    .iter()
+                    .try_for_each(|expr| expr.write_xml(f, depth + 1))
+            }),
+            Expression::Matrix(exprs) => block_element(f, depth, "matrix", &[], |f| {
+                exprs
+                    .iter()
+                    .try_for_each(|expr| expr.write_xml(f, depth + 1))
+            }),
+        }
+    }
+}
+
+impl WriteXml for Value {
+    fn write_xml(&self, f: &mut dyn Write, depth: usize) -> fmt::Result {
+        match self {
+            Value::Int(i) => text_element(f, depth, "int", &[], &i.to_string()),
+            Value::Double(d) => text_element(f, depth, "double", &[], &d.to_string()),
+            Value::String(s) => text_element(f, depth, "string", &[], s),
+            Value::Constant(c) => text_element(f, depth, "const", &[], c.as_str()),
+            Value::Bool(b) => text_element(f, depth, "bool", &[], &b.to_string()),
+            Value::LangSet(langs) => block_element(f, depth, "langset", &[], |f| {
+                langs
+                    .iter()
+                    .try_for_each(|lang| text_element(f, depth + 1, "string", &[], lang))
+            }),
+            Value::Property(target, kind) => {
+                let attrs = [("target", non_default(target, target.as_str()))];
+                text_element(f, depth, "name", &attrs, kind.name())
+            }
+            Value::Range(start, end) => {
+                indent(f, depth)?;
+                writeln!(f, "<range><int>{}</int><int>{}</int></range>", start, end)
+            }
+            Value::CharSet(charset) => {
+                indent(f, depth)?;
+                f.write_str("<charset>")?;
+                for range in charset.iter() {
+                    write_int_or_range(f, &range)?;
+                }
+                f.write_str("</charset>\n")
+            }
+            // a matrix of doubles is parsed back as a value
+            Value::Matrix(m) => block_element(f, depth, "matrix", &[], |f| {
+                m.iter()
+                    .try_for_each(|d| text_element(f, depth + 1, "double", &[], &d.to_string()))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_match() {
+        let m = Match {
+            target: MatchTarget::Font,
+            tests: vec![Test {
+                compare: TestCompare::NotEq,
+                kind: PropertyKind::Family,
+                values: vec!["A & B".into()],
+                ..Default::default()
+            }],
+            edits: vec![Edit {
+                mode: EditMode::Prepend,
+                binding: EditBinding::Strong,
+                kind: PropertyKind::PixelSize,
+                values: vec![Expression::Binary(
+                    BinaryOp::Less,
+                    Box::new([
+                        Value::Property(PropertyTarget::Font, PropertyKind::Size).into(),
+                        Value::Double(1.5).into(),
+                    ]),
+                )],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        k9::assert_equal!(
+            m.to_xml(),
+            r#"<match target="font">
+	<test name="family" compare="not_eq">
+		<string>A &amp; B</string>
+	</test>
+	<edit name="pixelsize" mode="prepend" binding="strong">
+		<less>
+			<name target="font">size</name>
+			<double>1.5</double>
+		</less>
+	</edit>
+</match>
+"#
+        );
+    }
+
+    #[test]
+    fn write_document() {
+        let parts = vec![
+            ConfigPart::Description(Description {
+                domain: Some("fontconfig-conf".into()),
+                text: "test".into(),
+            }),
+            ConfigPart::Alias(Alias {
+                alias: "serif".into(),
+                prefer: vec!["Foo".into()],
+                ..Default::default()
+            }),
+            ConfigPart::Include(Include {
+                prefix: DirPrefix::Relative,
+                ignore_missing: true,
+                deprecated: false,
+                path: "conf.d".into(),
+            }),
+            ConfigPart::ResetDirs,
+        ];
+
+        let xml = config_to_xml(&parts);
+
+        assert!(xml.starts_with(XML_HEADER));
+        k9::assert_equal!(parse_config_parts(&xml).unwrap(), parts);
+    }
+}
@@ -0,0 +1,247 @@
+use fontconfig_parser::*;
+
+const CACHE: &str = "./test-conf/cache/175c9d5f623408c887540438d819d0cb-le64.cache-8";
+
+#[test]
+fn read_cache() {
+    let cache = FcCache::read(CACHE).unwrap();
+
+    k9::assert_equal!(cache.version, 8);
+    k9::assert_equal!(cache.dir, std::path::Path::new("/usr/share/fonts/test"));
+    k9::assert_equal!(
+        cache.subdirs,
+        [std::path::Path::new("/usr/share/fonts/test/sub")]
+    );
+    k9::assert_equal!(cache.fonts.len(), 2);
+
+    let font = &cache.fonts[0];
+    let families = font
+        .get(&PropertyKind::Family)
+        .unwrap()
+        .iter()
+        .map(|v| v.value.clone())
+        .collect::<Vec<_>>();
+    k9::assert_equal!(
+        families,
+        [
+            Value::String("Test Sans".into()),
+            Value::String("テスト".into())
+        ]
+    );
+    k9::assert_equal!(font.get_string(&PropertyKind::FamilyLang, 1), Some("ja"));
+    k9::assert_equal!(font.get_int(&PropertyKind::Weight, 0), Some(200));
+    k9::assert_equal!(font.get_int(&PropertyKind::Slant, 0), Some(100));
+    k9::assert_equal!(font.get_int(&PropertyKind::Spacing, 0), Some(100));
+    k9::assert_equal!(font.get_bool(&PropertyKind::Outline, 0), Some(true));
+    k9::assert_equal!(
+        font.get_string(&PropertyKind::File, 0),
+        Some("/usr/share/fonts/test/TestSans-BoldItalic.ttf")
+    );
+
+    match font.get_value(&PropertyKind::Charset, 0) {
+        Some(Value::CharSet(charset)) => {
+            k9::assert_equal!(
+                charset.ranges(),
+                [(0x20, 0x7e), (0xa0, 0xff), (0x4e00, 0x4e01)]
+            );
+        }
+        other => panic!("{:?} isn't a charset", other),
+    }
+
+    match font.get_value(&PropertyKind::Lang, 0) {
+        Some(Value::LangSet(langs)) => {
+            assert!(langs.contains(&"de".into()));
+            assert!(langs.contains(&"pap-aw".into()));
+            assert!(!langs.contains(&"fr".into()));
+        }
+        other => panic!("{:?} isn't a langset", other),
+    }
+
+    k9::assert_equal!(
+        cache.fonts[1].get_string(&PropertyKind::Family, 0),
+        Some("Test Variable")
+    );
+}
+
+#[test]
+fn reject_invalid_cache() {
+    let data = std::fs::read(CACHE).unwrap();
+
+    assert!(FcCache::parse(&data[..data.len() - 8]).is_err());
+    assert!(FcCache::parse(b"not a cache").is_err());
+
+    let mut newer = data.clone();
+    newer[4] = 9;
+    assert!(matches!(
+        FcCache::parse(&newer),
+        Err(Error::InvalidCache(msg)) if msg.contains("version 9")
+    ));
+
+    let mut broken = data;
+    // offset of the font set points past the end
+    broken[40..48].copy_from_slice(&0xffffu64.to_le_bytes());
+    assert!(FcCache::parse(&broken).is_err());
+}
+
+#[test]
+fn reject_corrupt_pattern() {
+    let data = std::fs::read(CACHE).unwrap();
+    let at = |data: &[u8], base: usize, at: usize| {
+        let mut offset = [0; 8];
+        offset.copy_from_slice(&data[at..at + 8]);
+        (base as i64 + i64::from_le_bytes(offset)) as usize & !1
+    };
+
+    let set = at(&data, 0, 40);
+    let pattern = at(&data, set, at(&data, set, set + 8));
+    let elt = at(&data, pattern, pattern + 8);
+    let list = at(&data, elt, elt + 8);
+
+    // the next value of the list points back to itself
+    let mut looping = data.clone();
+    looping[list..list + 8].copy_from_slice(&1i64.to_le_bytes());
+    assert!(matches!(
+        FcCache::parse(&looping),
+        Err(Error::InvalidCache(msg)) if msg.contains("forward")
+    ));
+
+    // unknown objects are skipped
+    let mut unknown = data;
+    unknown[elt..elt + 4].copy_from_slice(&i32::MIN.to_le_bytes());
+    let cache = FcCache::parse(&unknown).unwrap();
+    k9::assert_equal!(cache.fonts.len(), 2);
+}
+
+#[test]
+fn read_fc_caches() {
+    let mut c = FontConfig::default();
+    c.cache_dirs.push("./test-conf/cache".into());
+    c.cache_dirs.push("./test-conf/missing".into());
+
+    let dirs = c
+        .read_fc_caches()
+        .into_iter()
+        .map(|cache| cache.dir)
+        .collect::<Vec<_>>();
+
+    k9::assert_equal!(
+        dirs,
+        [
+            std::path::PathBuf::from("/usr/share/fonts/test"),
+            std::path::PathBuf::from("/usr/share/fonts/test/sub")
+        ]
+    );
+}
+
+#[cfg(feature = "cache")]
+fn dir(path: &str, salt: &str) -> DirData {
+    DirData {
+        path: path.into(),
+        salt: salt.into(),
+    }
+}
+
+#[test]
+#[cfg(feature = "cache")]
+fn cache_basename() {
+    let basename = |dir: DirData, remap_dirs: &[RemapDirData]| {
+        dir.cache_basename(remap_dirs)
+            .trim_end_matches("-le64.cache-8")
+            .to_string()
+    };
+
+    k9::assert_equal!(
+        basename(dir("/usr/share/fonts/test", ""), &[]),
+        "175c9d5f623408c887540438d819d0cb"
+    );
+    k9::assert_equal!(
+        basename(dir("/tmp/hostfonts/", ""), &[]),
+        "c6eed42b91bd0642f33ea2269457be89"
+    );
+    k9::assert_equal!(
+        basename(dir("/usr/share/fonts/test", "abc"), &[]),
+        "230feb86912b4952fafcbb8f7f3e49e0"
+    );
+    k9::assert_equal!(
+        basename(dir("/usr/share/fonts/test/sub", "abc"), &[]),
+        "f2fe06301edef4b8445ae76174f97f51"
+    );
+
+    let mut remap = RemapDirData {
+        path: "/tmp/hostfonts".into(),
+        salt: String::new(),
+        as_path: "/usr/share/fonts/test".into(),
+    };
+
+    k9::assert_equal!(
+        basename(dir("/tmp/hostfonts", ""), &[remap.clone()]),
+        "175c9d5f623408c887540438d819d0cb"
+    );
+    k9::assert_equal!(
+        basename(dir("/tmp/hostfonts/sub", ""), &[remap.clone()]),
+        "a6c21a34f895e05d479050209cecb2b2"
+    );
+
+    remap.salt = "xyz".into();
+    k9::assert_equal!(
+        basename(dir("/tmp/hostfonts", ""), &[remap.clone()]),
+        "ffe435880b8a615ca3415a2869af4f5d"
+    );
+    k9::assert_equal!(
+        basename(dir("/tmp/hostfonts/sub", ""), &[remap]),
+        "23206a421ecf95eaaf85c0dce8fd7170"
+    );
+}
+
+#[test]
+#[cfg(feature = "cache")]
+fn find_cache_files() {
+    let mut c = FontConfig::default();
+    c.cache_dirs.push("/var/cache/fontconfig".into());
+    c.cache_dirs.push("./test-conf/cache".into());
+
+    let test = dir("/usr/share/fonts/test", "");
+
+    k9::assert_equal!(
+        c.cache_files(&test),
+        [
+            std::path::PathBuf::from(
+                "/var/cache/fontconfig/175c9d5f623408c887540438d819d0cb-le64.cache-8"
+            ),
+            std::path::PathBuf::from(
+                "/var/cache/fontconfig/175c9d5f623408c887540438d819d0cb-le64.cache-7"
+            ),
+            std::path::PathBuf::from(
+                "./test-conf/cache/175c9d5f623408c887540438d819d0cb-le64.cache-8"
+            ),
+            std::path::PathBuf::from(
+                "./test-conf/cache/175c9d5f623408c887540438d819d0cb-le64.cache-7"
+            ),
+        ]
+    );
+
+    // the cached directory doesn't exist here, so the cache is outdated
+    assert!(c.read_fc_cache(&test).is_none());
+    assert!(c
+        .read_fc_cache(&dir("/usr/share/fonts/missing", ""))
+        .is_none());
+}
+
+#[test]
+fn cache_up_to_date() {
+    let mut cache = FcCache::read(CACHE).unwrap();
+    assert!(!cache.is_up_to_date());
+
+    cache.dir = "./test-conf/cache".into();
+    let modified = std::fs::metadata(&cache.dir)
+        .and_then(|m| m.modified())
+        .unwrap()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+    cache.checksum = modified.as_secs() as i32;
+    cache.checksum_nano = modified.subsec_nanos() as i64;
+    assert!(cache.is_up_to_date());
+
+    cache.checksum_nano = (cache.checksum_nano + 1) % 1_000_000_000;
+    assert!(!cache.is_up_to_date());
+}
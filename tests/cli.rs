@@ -0,0 +1,277 @@
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::Font;
+use fontconfig_parser::MergeOptions;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Config with generated fonts which includes latin rules of `test-conf`
+fn setup(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!(
+        "fontconfig-parser-cli-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let fonts = root.join("fonts");
+    std::fs::remove_dir_all(&root).ok();
+    std::fs::create_dir_all(&fonts).unwrap();
+
+    let sans = Font {
+        family: "DejaVu Sans",
+        style: "Book",
+        advances: vec![500, 600, 700],
+        ..Default::default()
+    };
+    std::fs::write(fonts.join("DejaVuSans.ttf"), sans.build()).unwrap();
+
+    let bold = Font {
+        style: "Bold",
+        weight: 700,
+        ..sans
+    };
+    let mono = Font {
+        family: "DejaVu Sans Mono",
+        style: "Book",
+        ..Default::default()
+    };
+    std::fs::write(fonts.join("DejaVuSans-Bold.ttf"), bold.build()).unwrap();
+    std::fs::write(fonts.join("DejaVuSansMono.ttf"), mono.build()).unwrap();
+
+    let conf_d = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-conf/conf.d");
+    let includes = ["45-latin.conf", "49-sansserif.conf", "60-latin.conf"]
+        .iter()
+        .map(|name| format!("<include>{}</include>", conf_d.join(name).display()))
+        .collect::<String>();
+    std::fs::write(
+        root.join("fonts.conf"),
+        format!(
+            "<fontconfig><description>CLI test</description><dir>{}</dir><cachedir>{}</cachedir>{}</fontconfig>",
+            fonts.display(),
+            root.join("cache").display(),
+            includes
+        ),
+    )
+    .unwrap();
+
+    root
+}
+
+fn run(root: &Path, bin: &str, args: &[&str]) -> (bool, String) {
+    let output = Command::new(bin)
+        .args(args)
+        .env("FONTCONFIG_FILE", root.join("fonts.conf"))
+        .env_remove("FONTCONFIG_PATH")
+        .env_remove("FONTCONFIG_SYSROOT")
+        .env("FC_LANG", "en")
+        .output()
+        .unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+fn sorted_lines(s: &str) -> Vec<&str> {
+    let mut lines = s.lines().collect::<Vec<_>>();
+    lines.sort_unstable();
+    lines
+}
+
+#[test]
+fn fc_list() {
+    let root = setup("list");
+    let fc_list = env!("CARGO_BIN_EXE_fc-list");
+    let fonts = root.join("fonts");
+
+    let (ok, out) = run(&root, fc_list, &[]);
+    assert!(ok);
+    k9::assert_equal!(
+        sorted_lines(&out),
+        [
+            format!(
+                "{}: DejaVu Sans:style=Bold",
+                fonts.join("DejaVuSans-Bold.ttf").display()
+            ),
+            format!(
+                "{}: DejaVu Sans:style=Book",
+                fonts.join("DejaVuSans.ttf").display()
+            ),
+            format!(
+                "{}: DejaVu Sans Mono:style=Book",
+                fonts.join("DejaVuSansMono.ttf").display()
+            ),
+        ]
+    );
+
+    let (_, out) = run(&root, fc_list, &[":spacing=mono", "family", "spacing"]);
+    k9::assert_equal!(out, "DejaVu Sans Mono:spacing=100\n");
+
+    let (_, out) = run(
+        &root,
+        fc_list,
+        &[
+            "-f",
+            "%{family}|%{weight}|%{file|basename}\n",
+            "DejaVu Sans",
+        ],
+    );
+    k9::assert_equal!(
+        sorted_lines(&out),
+        [
+            "DejaVu Sans|200|DejaVuSans-Bold.ttf",
+            "DejaVu Sans|80|DejaVuSans.ttf"
+        ]
+    );
+
+    let (ok, out) = run(&root, fc_list, &["-q", "Missing"]);
+    assert!(!ok);
+    k9::assert_equal!(out, "");
+
+    std::fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn fc_match() {
+    let root = setup("match");
+    let fc_match = env!("CARGO_BIN_EXE_fc-match");
+
+    let (ok, out) = run(&root, fc_match, &["sans-serif"]);
+    assert!(ok);
+    k9::assert_equal!(out, "DejaVuSans.ttf: \"DejaVu Sans\" \"Book\"\n");
+
+    let (_, out) = run(&root, fc_match, &["monospace:bold", "family", "style"]);
+    k9::assert_equal!(out, "DejaVu Sans Mono:style=Book\n");
+
+    // other fonts don't add coverage to the first one
+    let (_, out) = run(
+        &root,
+        fc_match,
+        &["-s", "sans-serif:bold", "-f", "%{file|basename}\n"],
+    );
+    k9::assert_equal!(out, "DejaVuSans-Bold.ttf\n");
+
+    let (_, out) = run(
+        &root,
+        fc_match,
+        &["-a", "sans-serif:bold", "-f", "%{file|basename}\n"],
+    );
+    k9::assert_equal!(
+        out,
+        "DejaVuSans-Bold.ttf\nDejaVuSans.ttf\nDejaVuSansMono.ttf\n"
+    );
+
+    std::fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn fc_pattern() {
+    let root = setup("pattern");
+    let fc_pattern = env!("CARGO_BIN_EXE_fc-pattern");
+
+    let (ok, out) = run(&root, fc_pattern, &["sans-serif:bold"]);
+    assert!(ok);
+    k9::assert_equal!(
+        out,
+        "Pattern has 2 elts (size 16)\n\tfamily: \"sans\"(s)\n\tweight: 200(i)(s)\n\n"
+    );
+
+    let (_, out) = run(
+        &root,
+        fc_pattern,
+        &[
+            "--config",
+            "-f",
+            "%{family}|%{lang}|%{prgname}\n",
+            "sans-serif",
+        ],
+    );
+    k9::assert_equal!(
+        out,
+        "sans,DejaVu Sans,Verdana,Arial,Albany AMT,Luxi Sans,Nimbus Sans L,Nimbus Sans,Helvetica,\
+         Lucida Sans Unicode,BPG Glaho International,Tahoma,sans-serif|en|fc-pattern\n"
+    );
+
+    // a bad format is reported and gives nothing
+    let (ok, out) = run(&root, fc_pattern, &["-f", "%{family", "sans-serif"]);
+    assert!(ok);
+    k9::assert_equal!(out, "");
+
+    // same as `fc-pattern -d` of fontconfig 2.14
+    let (_, out) = run(
+        &root,
+        fc_pattern,
+        &["-d", "Foo:namelang=en:prgname=fc-pattern"],
+    );
+    k9::assert_equal!(
+        out,
+        "Pattern has 24 elts (size 32)
+\tfamily: \"Foo\"(s)
+\tfamilylang: \"en\"(s) \"en-us\"(w)
+\tstylelang: \"en\"(s) \"en-us\"(w)
+\tfullnamelang: \"en\"(s) \"en-us\"(w)
+\tslant: 0(i)(s)
+\tweight: 80(i)(s)
+\twidth: 100(i)(s)
+\tsize: 12(f)(s)
+\tpixelsize: 12.5(f)(s)
+\thintstyle: 3(i)(s)
+\thinting: True(s)
+\tverticallayout: False(s)
+\tautohint: False(s)
+\tglobaladvance: True(s)
+\tdpi: 75(f)(s)
+\tscale: 1(f)(s)
+\tfontversion: 2147483647(i)(s)
+\tembeddedbitmap: True(s)
+\tdecorative: False(s)
+\tnamelang: \"en\"(s)
+\tprgname: \"fc-pattern\"(s)
+\tsymbol: False(s)
+\tvariable: False(s)
+\torder: 0(i)(s)
+
+"
+    );
+
+    std::fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn fc_conflist() {
+    let root = setup("conflist");
+
+    let (ok, out) = run(&root, env!("CARGO_BIN_EXE_fc-conflist"), &[]);
+    assert!(ok);
+
+    // templates of the system which aren't enabled come last
+    let (enabled, templates): (Vec<_>, Vec<_>) = out.lines().partition(|l| l.starts_with('+'));
+    k9::assert_equal!(out.lines().take(enabled.len()).collect::<Vec<_>>(), enabled);
+    assert!(templates
+        .iter()
+        .all(|l| l.starts_with(&format!("- {}/", MergeOptions::TEMPLATE_DIR))));
+
+    let conf_d = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-conf/conf.d");
+    k9::assert_equal!(
+        enabled,
+        [
+            format!(
+                "+ {}: Set substitutions for Latin fonts",
+                conf_d.join("45-latin.conf").display()
+            ),
+            format!(
+                "+ {}: Add sans-serif to the family when no generic name",
+                conf_d.join("49-sansserif.conf").display()
+            ),
+            format!(
+                "+ {}: Set preferable fonts for Latin",
+                conf_d.join("60-latin.conf").display()
+            ),
+            format!("+ {}: CLI test", root.join("fonts.conf").display()),
+        ]
+    );
+
+    std::fs::remove_dir_all(&root).ok();
+}
@@ -0,0 +1,319 @@
+//! Minimal sfnt fonts for scanning tests
+
+#![allow(dead_code)]
+
+pub struct Font {
+    pub family: &'static str,
+    pub style: &'static str,
+    /// Extra names as `(name id, windows language id, name)`
+    pub names: Vec<(u16, u16, &'static str)>,
+    pub weight: u16,
+    pub width: u16,
+    pub italic: bool,
+    pub vendor: &'static [u8; 4],
+    /// Inclusive ranges of mapped characters
+    pub chars: Vec<(u32, u32)>,
+    /// Advance of each glyph, the last one is used for the rest
+    pub advances: Vec<u16>,
+    /// `(tag, min, default, max)`
+    pub axes: Vec<(&'static [u8; 4], f32, f32, f32)>,
+    /// `(subfamily name id, coordinates)`
+    pub instances: Vec<(u16, Vec<f32>)>,
+    /// Map `chars` with a Windows symbol subtable instead of a unicode one
+    pub symbol: bool,
+    /// Extra tables like `GSUB` or `prep`
+    pub tables: Vec<(&'static [u8; 4], Vec<u8>)>,
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Self {
+            family: "Test Sans",
+            style: "Regular",
+            names: Vec::new(),
+            weight: 400,
+            width: 5,
+            italic: false,
+            vendor: b"TEST",
+            chars: vec![(0x20, 0x7e)],
+            advances: vec![500],
+            axes: Vec::new(),
+            instances: Vec::new(),
+            symbol: false,
+            tables: Vec::new(),
+        }
+    }
+}
+
+fn u16s(data: &mut Vec<u8>, values: &[u16]) {
+    for value in values {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn fixed(value: f32) -> [u8; 4] {
+    ((value * 65536.0) as i32).to_be_bytes()
+}
+
+impl Font {
+    fn glyph_count(&self) -> u16 {
+        let chars: u32 = self.chars.iter().map(|(s, e)| e - s + 1).sum();
+        chars as u16 + 1
+    }
+
+    fn head(&self) -> Vec<u8> {
+        let mut head = Vec::new();
+        head.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        // fontRevision 1.5
+        head.extend_from_slice(&0x0001_8000u32.to_be_bytes());
+        head.extend_from_slice(&0u32.to_be_bytes());
+        head.extend_from_slice(&0x5F0F_3CF5u32.to_be_bytes());
+        u16s(&mut head, &[0, 1000]);
+        head.extend_from_slice(&[0; 16]);
+        u16s(&mut head, &[0, 0, 1000, 1000, 0, 8, 2, 0, 0]);
+        head
+    }
+
+    fn hhea(&self) -> Vec<u8> {
+        let mut hhea = Vec::new();
+        hhea.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        u16s(
+            &mut hhea,
+            &[800, (-200i16) as u16, 0, 1000, 0, 0, 1000, 1, 0, 0],
+        );
+        u16s(&mut hhea, &[0, 0, 0, 0, 0, self.advances.len() as u16]);
+        hhea
+    }
+
+    fn maxp(&self) -> Vec<u8> {
+        let mut maxp = Vec::new();
+        maxp.extend_from_slice(&0x0000_5000u32.to_be_bytes());
+        u16s(&mut maxp, &[self.glyph_count()]);
+        maxp
+    }
+
+    fn hmtx(&self) -> Vec<u8> {
+        let mut hmtx = Vec::new();
+        for advance in &self.advances {
+            u16s(&mut hmtx, &[*advance, 0]);
+        }
+        for _ in self.advances.len()..self.glyph_count() as usize {
+            u16s(&mut hmtx, &[0]);
+        }
+        hmtx
+    }
+
+    /// Format 4 subtable mapping every range to consecutive glyphs from 1
+    fn cmap(&self) -> Vec<u8> {
+        let mut segments = Vec::new();
+        let mut glyph = 1u32;
+        for (start, end) in &self.chars {
+            let delta = (glyph as i64 - *start as i64).rem_euclid(0x10000) as u16;
+            segments.push((*start as u16, *end as u16, delta));
+            glyph += end - start + 1;
+        }
+        segments.push((0xffff, 0xffff, 1));
+
+        let seg_count = segments.len() as u16;
+        let mut subtable = Vec::new();
+        u16s(
+            &mut subtable,
+            &[4, 16 + seg_count * 8, 0, seg_count * 2, 0, 0, 0],
+        );
+        for (_, end, _) in &segments {
+            u16s(&mut subtable, &[*end]);
+        }
+        u16s(&mut subtable, &[0]);
+        for (start, _, _) in &segments {
+            u16s(&mut subtable, &[*start]);
+        }
+        for (_, _, delta) in &segments {
+            u16s(&mut subtable, &[*delta]);
+        }
+        for _ in &segments {
+            u16s(&mut subtable, &[0]);
+        }
+
+        let mut cmap = Vec::new();
+        u16s(&mut cmap, &[0, 1, 3, if self.symbol { 0 } else { 1 }]);
+        cmap.extend_from_slice(&12u32.to_be_bytes());
+        cmap.extend_from_slice(&subtable);
+        cmap
+    }
+
+    fn name(&self) -> Vec<u8> {
+        let full_name = format!("{} {}", self.family, self.style);
+        let postscript = full_name.replace(' ', "");
+        let mut names = vec![
+            (1, 0x409, self.family),
+            (2, 0x409, self.style),
+            (4, 0x409, full_name.as_str()),
+            (6, 0x409, postscript.as_str()),
+        ];
+        names.extend(self.names.iter().copied());
+
+        let mut records = Vec::new();
+        let mut strings = Vec::new();
+        for (id, lang, name) in names {
+            let encoded = name
+                .encode_utf16()
+                .flat_map(|c| c.to_be_bytes())
+                .collect::<Vec<_>>();
+            u16s(
+                &mut records,
+                &[3, 1, lang, id, encoded.len() as u16, strings.len() as u16],
+            );
+            strings.extend_from_slice(&encoded);
+        }
+
+        let count = records.len() as u16 / 12;
+        let mut table = Vec::new();
+        u16s(&mut table, &[0, count, 6 + count * 12]);
+        table.extend_from_slice(&records);
+        table.extend_from_slice(&strings);
+        table
+    }
+
+    fn os2(&self) -> Vec<u8> {
+        let mut os2 = Vec::new();
+        u16s(&mut os2, &[4, 500, self.weight, self.width, 0]);
+        os2.resize(58, 0);
+        os2.extend_from_slice(self.vendor);
+        u16s(&mut os2, &[if self.italic { 1 } else { 0x40 }, 0x20, 0x7e]);
+        os2.resize(96, 0);
+        os2
+    }
+
+    fn post(&self) -> Vec<u8> {
+        let mut post = 0x0003_0000u32.to_be_bytes().to_vec();
+        post.resize(32, 0);
+        post
+    }
+
+    fn fvar(&self) -> Vec<u8> {
+        let axis_count = self.axes.len() as u16;
+        let mut fvar = Vec::new();
+        u16s(&mut fvar, &[1, 0, 16, 2, axis_count, 20]);
+        u16s(
+            &mut fvar,
+            &[self.instances.len() as u16, 4 + axis_count * 4],
+        );
+        for (idx, (tag, min, default, max)) in self.axes.iter().enumerate() {
+            fvar.extend_from_slice(*tag);
+            fvar.extend_from_slice(&fixed(*min));
+            fvar.extend_from_slice(&fixed(*default));
+            fvar.extend_from_slice(&fixed(*max));
+            u16s(&mut fvar, &[0, 256 + idx as u16]);
+        }
+        for (name_id, coords) in &self.instances {
+            u16s(&mut fvar, &[*name_id, 0]);
+            for coord in coords {
+                fvar.extend_from_slice(&fixed(*coord));
+            }
+        }
+        fvar
+    }
+
+    fn tables(&self) -> Vec<(&'static [u8; 4], Vec<u8>)> {
+        let mut tables = vec![
+            (b"OS/2", self.os2()),
+            (b"cmap", self.cmap()),
+            (b"glyf", Vec::new()),
+            (b"head", self.head()),
+            (b"hhea", self.hhea()),
+            (b"hmtx", self.hmtx()),
+            // every glyph is empty
+            (b"loca", vec![0; (self.glyph_count() as usize + 1) * 2]),
+            (b"maxp", self.maxp()),
+            (b"name", self.name()),
+            (b"post", self.post()),
+        ];
+
+        if !self.axes.is_empty() {
+            tables.push((b"fvar", self.fvar()));
+        }
+        tables.extend(self.tables.iter().cloned());
+        tables.sort_by_key(|(tag, _)| **tag);
+
+        tables
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        write_sfnt(&mut data, &self.tables());
+        data
+    }
+}
+
+/// Append a sfnt whose table offsets are relative to the start of `data`
+fn write_sfnt(data: &mut Vec<u8>, tables: &[(&[u8; 4], Vec<u8>)]) {
+    data.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    u16s(data, &[tables.len() as u16, 0, 0, 0]);
+
+    let mut offset = data.len() + tables.len() * 16;
+    for (tag, table) in tables {
+        data.extend_from_slice(*tag);
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&(offset as u32).to_be_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += (table.len() + 3) & !3;
+    }
+
+    for (_, table) in tables {
+        data.extend_from_slice(table);
+        data.resize((data.len() + 3) & !3, 0);
+    }
+}
+
+/// TrueType collection of `fonts`
+pub fn collection(fonts: &[Font]) -> Vec<u8> {
+    let mut data = b"ttcf".to_vec();
+    data.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    data.extend_from_slice(&(fonts.len() as u32).to_be_bytes());
+    let offsets = data.len();
+    data.resize(offsets + fonts.len() * 4, 0);
+
+    for (idx, font) in fonts.iter().enumerate() {
+        let offset = (data.len() as u32).to_be_bytes();
+        data[offsets + idx * 4..offsets + idx * 4 + 4].copy_from_slice(&offset);
+        write_sfnt(&mut data, &font.tables());
+    }
+
+    data
+}
+
+/// WOFF with every table compressed
+pub fn woff(font: &Font) -> Vec<u8> {
+    let tables = font.tables();
+    let mut entries = Vec::new();
+    let mut body = Vec::new();
+    let mut offset = 44 + tables.len() * 20;
+
+    for (tag, table) in &tables {
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(table, 6);
+        let stored = if compressed.len() < table.len() {
+            compressed
+        } else {
+            table.clone()
+        };
+
+        entries.extend_from_slice(*tag);
+        entries.extend_from_slice(&(offset as u32).to_be_bytes());
+        entries.extend_from_slice(&(stored.len() as u32).to_be_bytes());
+        entries.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        entries.extend_from_slice(&0u32.to_be_bytes());
+
+        body.extend_from_slice(&stored);
+        body.resize((body.len() + 3) & !3, 0);
+        offset = 44 + tables.len() * 20 + body.len();
+    }
+
+    let mut data = b"wOFF".to_vec();
+    data.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    data.extend_from_slice(&((44 + entries.len() + body.len()) as u32).to_be_bytes());
+    u16s(&mut data, &[tables.len() as u16, 0]);
+    data.resize(44, 0);
+    data.extend_from_slice(&entries);
+    data.extend_from_slice(&body);
+    data
+}
@@ -0,0 +1,37 @@
+use fontconfig_parser::*;
+use std::path::Path;
+
+// environment variables are process wide, keep every case in one test
+#[test]
+fn load_from_env() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-conf");
+    let sysroot = root.join("sysroot");
+
+    std::env::remove_var("FONTCONFIG_FILE");
+    std::env::remove_var("FONTCONFIG_PATH");
+    std::env::set_var("FONTCONFIG_SYSROOT", &sysroot);
+
+    let c = FontConfig::from_env().unwrap();
+
+    k9::assert_equal!(
+        c.dirs.iter().map(|d| d.path.clone()).collect::<Vec<_>>(),
+        vec![
+            sysroot.join("usr/share/fonts"),
+            sysroot.join("etc/fonts/fonts"),
+        ]
+    );
+    k9::assert_equal!(c.cache_dirs, vec![sysroot.join("var/cache/fontconfig")]);
+    k9::assert_equal!(c.remap_dirs[0].path, sysroot.join("host/fonts"));
+    k9::assert_equal!(c.aliases[0].prefer, vec!["Sysroot Sans".to_string()]);
+
+    // FONTCONFIG_FILE is searched in FONTCONFIG_PATH
+    std::env::remove_var("FONTCONFIG_SYSROOT");
+    std::env::set_var("FONTCONFIG_PATH", &root);
+    std::env::set_var("FONTCONFIG_FILE", "rule-order.conf");
+
+    let c = FontConfig::from_env().unwrap();
+    k9::assert_equal!(c.aliases[0].alias, "foo");
+
+    std::env::set_var("FONTCONFIG_FILE", "missing.conf");
+    assert!(FontConfig::from_env().is_err());
+}
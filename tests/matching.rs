@@ -0,0 +1,86 @@
+use fontconfig_parser::*;
+
+fn font(name: &str) -> Pattern {
+    name.parse().unwrap()
+}
+
+fn fonts() -> Vec<Pattern> {
+    vec![
+        font("DejaVu Serif:style=Book:weight=80:slant=0:lang=en|fr"),
+        font("DejaVu Sans:style=Book:weight=80:slant=0:lang=en|fr"),
+        font("DejaVu Sans:style=Oblique:weight=80:slant=110:lang=en|fr"),
+        font("Noto Sans CJK JP:style=Regular:weight=80:slant=0:lang=ja|zh-tw"),
+    ]
+}
+
+fn query(c: &FontConfig, name: &str) -> Pattern {
+    let mut pattern = font(name);
+    c.substitute(&mut pattern);
+    pattern.default_substitute();
+    pattern
+}
+
+#[test]
+fn match_generic_family() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let matched = c
+        .font_match(&query(&c, "sans\\-serif:italic"), &fonts())
+        .unwrap();
+    k9::assert_equal!(
+        matched.get_string(&PropertyKind::Family, 0),
+        Some("DejaVu Sans")
+    );
+    k9::assert_equal!(matched.get_string(&PropertyKind::Style, 0), Some("Oblique"));
+
+    // 90-synthetic.conf emboldens the regular face
+    let matched = c
+        .font_match(&query(&c, "sans\\-serif:bold"), &fonts())
+        .unwrap();
+    k9::assert_equal!(matched.get_bool(&PropertyKind::Embolden, 0), Some(true));
+}
+
+#[test]
+fn sort_language_fallback() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let fonts = fonts();
+    let families = c
+        .font_sort(&query(&c, "sans\\-serif:lang=ja"), &fonts, false)
+        .into_iter()
+        .map(|font| font.get_string(&PropertyKind::Family, 0).unwrap())
+        .collect::<Vec<_>>();
+
+    // strong `DejaVu Sans` from 52-nixos-default-fonts.conf wins over the language
+    k9::assert_equal!(
+        families,
+        vec![
+            "DejaVu Sans",
+            "DejaVu Sans",
+            "Noto Sans CJK JP",
+            "DejaVu Serif"
+        ]
+    );
+}
+
+#[test]
+fn reject_type1() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let fonts = vec![
+        font("DejaVu Sans:fontformat=TrueType:file=/usr/share/fonts/DejaVuSans.ttf"),
+        font("Nimbus Sans:fontformat=Type 1:file=/usr/share/fonts/type1/n019003l.pfb"),
+    ];
+    let families = c
+        .filter_fonts(&fonts)
+        .into_iter()
+        .map(|font| font.get_string(&PropertyKind::Family, 0).unwrap())
+        .collect::<Vec<_>>();
+
+    // 53-nixos-reject-type1.conf
+    k9::assert_equal!(families, vec!["DejaVu Sans"]);
+    assert!(c.accept_filename("/usr/share/fonts/type1/n019003l.pfb"));
+}
@@ -17,5 +17,354 @@ fn merge_full() {
         prefer: vec!["FreeSerif".into(), "Code2000".into(), "Code2001".into(),],
         accept: vec![],
         default: vec![],
+        binding: EditBinding::Weak,
     }));
 }
+
+#[test]
+fn merge_include_order() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    // only `*.conf` are loaded, each alias is loaded once
+    k9::assert_equal!(c.aliases.len(), 287);
+
+    // first alias of 30-metric-aliases.conf
+    k9::assert_equal!(c.aliases[0].alias, "Nimbus Sans L");
+
+    // first alias of 52-nixos-default-fonts.conf comes after 30, 40 and 45
+    k9::assert_equal!(
+        c.aliases[240],
+        Alias {
+            alias: "sans-serif".into(),
+            prefer: vec!["DejaVu Sans".into()],
+            accept: vec![],
+            default: vec![],
+            binding: EditBinding::Same,
+        }
+    );
+}
+
+#[test]
+fn merge_include_cycle() {
+    let mut c = FontConfig::default();
+    let err = c
+        .merge_config("./test-conf/include-cycle/self.conf")
+        .unwrap_err();
+
+    match err {
+        Error::IncludeCycle(chain) => {
+            k9::assert_equal!(chain.len(), 2);
+            k9::assert_equal!(chain[0], chain[1]);
+            assert!(chain[0].ends_with("include-cycle/self.conf"));
+        }
+        err => panic!("Unexpected error {}", err),
+    }
+
+    // symlink in conf.d which points back to including config
+    let mut c = FontConfig::default();
+    let err = c
+        .merge_config("./test-conf/include-cycle/fonts.conf")
+        .unwrap_err();
+
+    match err {
+        Error::IncludeCycle(chain) => {
+            k9::assert_equal!(chain.len(), 2);
+            assert!(chain[1].ends_with("include-cycle/fonts.conf"));
+        }
+        err => panic!("Unexpected error {}", err),
+    }
+}
+
+#[test]
+fn merge_include_depth() {
+    let mut c = FontConfig::default();
+    let err = c
+        .merge_config_with_options(
+            "./test-conf/fonts.conf",
+            &MergeOptions {
+                max_include_depth: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+
+    match err {
+        Error::IncludeTooDeep(chain) => {
+            k9::assert_equal!(chain.len(), 2);
+            assert!(chain[1].ends_with("conf.d/00-nixos-cache.conf"));
+        }
+        err => panic!("Unexpected error {}", err),
+    }
+}
+
+#[test]
+fn merge_provenance() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let p = &c.provenance;
+    k9::assert_equal!(p.dirs.len(), c.dirs.len());
+    k9::assert_equal!(p.matches.len(), c.matches.len());
+    k9::assert_equal!(p.aliases.len(), c.aliases.len());
+    k9::assert_equal!(p.select_fonts.len(), c.select_fonts.len());
+
+    // first match of fonts.conf itself
+    let origin = &p.matches[0];
+    assert!(origin.file.ends_with("test-conf/fonts.conf"));
+    assert!(origin.include_chain.is_empty());
+
+    // 30-metric-aliases.conf is included from fonts.conf
+    let origin = &p.aliases[0];
+    assert!(origin.file.ends_with("conf.d/30-metric-aliases.conf"));
+    k9::assert_equal!(origin.include_chain.len(), 1);
+    assert!(origin.include_chain[0].ends_with("test-conf/fonts.conf"));
+    k9::assert_equal!(origin.span.start.line, 56);
+
+    let mut orders = p
+        .matches
+        .iter()
+        .chain(p.aliases.iter())
+        .map(|o| o.order)
+        .collect::<Vec<_>>();
+    let len = orders.len();
+    orders.sort_unstable();
+    orders.dedup();
+    k9::assert_equal!(orders.len(), len);
+    assert!(p.matches.windows(2).all(|w| w[0].order < w[1].order));
+}
+
+#[test]
+fn merge_config_files() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let files = &c.config_files;
+    k9::assert_equal!(files.len(), 23);
+
+    // included files come first
+    assert!(files[0].path.ends_with("conf.d/00-nixos-cache.conf"));
+    k9::assert_equal!(files[0].description, None);
+    assert!(files[1].path.ends_with("conf.d/10-hinting-slight.conf"));
+    k9::assert_equal!(
+        files[1].description.as_ref().unwrap().text,
+        "Set hintslight to hintstyle"
+    );
+
+    assert!(files[22].path.ends_with("test-conf/fonts.conf"));
+    k9::assert_equal!(
+        files[22].description.as_ref().unwrap().text,
+        "Default configuration file"
+    );
+    assert!(files.iter().all(|file| file.enabled));
+}
+
+#[test]
+fn merge_templates() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert(
+        "/etc/fonts/fonts.conf",
+        "<fontconfig><include>/usr/share/fontconfig/conf.avail/10-a.conf</include></fontconfig>",
+    );
+    fs.insert(
+        "/usr/share/fontconfig/conf.avail/10-a.conf",
+        "<fontconfig><description>A</description></fontconfig>",
+    );
+    fs.insert(
+        "/usr/share/fontconfig/conf.avail/20-b.conf",
+        "<fontconfig><description>B</description></fontconfig>",
+    );
+    fs.insert(
+        "/usr/share/fontconfig/conf.avail/30-broken.conf",
+        "<fontconfig>",
+    );
+
+    let options = MergeOptions {
+        fs: std::sync::Arc::new(fs),
+        ..Default::default()
+    };
+
+    let mut c = FontConfig::default();
+    c.merge_config_with_options("/etc/fonts/fonts.conf", &options)
+        .unwrap();
+    c.parse_templates(MergeOptions::TEMPLATE_DIR, &options)
+        .unwrap();
+
+    let files = c
+        .config_files
+        .iter()
+        .map(|file| (file.path.to_str().unwrap(), file.enabled))
+        .collect::<Vec<_>>();
+    k9::assert_equal!(
+        files,
+        vec![
+            ("/usr/share/fontconfig/conf.avail/10-a.conf", true),
+            ("/etc/fonts/fonts.conf", true),
+            ("/usr/share/fontconfig/conf.avail/20-b.conf", false),
+        ]
+    );
+    k9::assert_equal!(c.config_files[2].description.as_ref().unwrap().text, "B");
+}
+
+#[test]
+fn merge_memory_fs() {
+    let alias = |family: &str| {
+        format!(
+            "<fontconfig><alias><family>{}</family><prefer><family>A</family></prefer></alias></fontconfig>",
+            family
+        )
+    };
+
+    let mut fs = MemoryFileSystem::new();
+    fs.insert(
+        "/image/etc/fonts/fonts.conf",
+        "<fontconfig><dir>/usr/share/fonts</dir><include>conf.d</include></fontconfig>",
+    );
+    fs.insert("/image/etc/fonts/conf.d/20-b.conf", alias("b"));
+    fs.insert("/image/etc/fonts/conf.d/10-a.conf", alias("a"));
+    fs.insert("/image/etc/fonts/conf.d/README", "not a config");
+    fs.insert("/image/etc/fonts/conf.d/30-c.conf.bak", alias("c"));
+    fs.insert("/image/etc/fonts/conf.d/sub/40-d.conf", alias("d"));
+
+    let options = MergeOptions {
+        sysroot: Some("/image".into()),
+        config_dirs: vec!["/etc/fonts".into()],
+        fs: std::sync::Arc::new(fs),
+        ..Default::default()
+    };
+
+    let mut c = FontConfig::default();
+    // top level path is used as is, use `MergeOptions::find_config_file` to apply sysroot
+    c.merge_config_with_options("/etc/fonts/fonts.conf", &options)
+        .unwrap_err();
+    c.merge_config_with_options("/image/etc/fonts/fonts.conf", &options)
+        .unwrap();
+
+    k9::assert_equal!(
+        c.dirs[0].path,
+        std::path::Path::new("/image/usr/share/fonts")
+    );
+    k9::assert_equal!(
+        c.aliases
+            .iter()
+            .map(|a| a.alias.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+}
+
+#[test]
+fn merge_alias_binding() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    // 30-metric-aliases.conf
+    k9::assert_equal!(c.aliases[0].binding, EditBinding::Same);
+}
+
+#[test]
+fn merge_strict() {
+    let options = MergeOptions {
+        parse_options: ParseOptions { strict: true },
+        ..Default::default()
+    };
+
+    // every shipped config only uses known elements and attributes
+    let mut strict = FontConfig::default();
+    strict
+        .merge_config_with_options("./test-conf/fonts.conf", &options)
+        .unwrap();
+
+    let mut lenient = FontConfig::default();
+    lenient.merge_config("./test-conf/fonts.conf").unwrap();
+
+    k9::assert_equal!(strict, lenient);
+}
+
+#[test]
+fn merge_strict_include() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert(
+        "/etc/fonts/fonts.conf",
+        "<fontconfig><include prefix=\"relative\">conf.d</include></fontconfig>",
+    );
+    fs.insert(
+        "/etc/fonts/conf.d/10-typo.conf",
+        "<fontconfig>\n<dir prefx=\"xdg\">fonts</dir>\n</fontconfig>",
+    );
+
+    let mut options = MergeOptions {
+        fs: std::sync::Arc::new(fs),
+        ..Default::default()
+    };
+
+    let mut c = FontConfig::default();
+    c.merge_config_with_options("/etc/fonts/fonts.conf", &options)
+        .unwrap();
+    k9::assert_equal!(c.dirs.len(), 1);
+
+    options.parse_options.strict = true;
+    let err = FontConfig::default()
+        .merge_config_with_options("/etc/fonts/fonts.conf", &options)
+        .unwrap_err();
+    k9::assert_equal!(
+        err.to_string(),
+        "/etc/fonts/conf.d/10-typo.conf:2:1: Unknown attribute prefx of <dir>"
+    );
+}
+
+#[test]
+fn merge_diagnostics() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert(
+        "/etc/fonts/fonts.conf",
+        "<fontconfig>\n<include prefix=\"relative\">missing.conf</include>\n<include prefix=\"relative\" ignore_missing=\"yes\">optional.conf</include>\n<include prefix=\"relative\">conf.d</include>\n</fontconfig>",
+    );
+    fs.insert(
+        "/etc/fonts/conf.d/10-broken.conf",
+        "<fontconfig>\n<match>\n<edit name=\"weight\"><const>heavyish</const></edit>\n</match>\n</fontconfig>",
+    );
+    fs.insert(
+        "/etc/fonts/conf.d/20-unknown.conf",
+        "<fontconfig><dir salt=\"a\" sal=\"b\">fonts</dir></fontconfig>",
+    );
+
+    let options = MergeOptions {
+        fs: std::sync::Arc::new(fs),
+        ..Default::default()
+    };
+
+    let mut c = FontConfig::default();
+    c.merge_config_with_options("/etc/fonts/fonts.conf", &options)
+        .unwrap();
+
+    let codes = c.diagnostics.iter().map(|d| d.code).collect::<Vec<_>>();
+    k9::assert_equal!(
+        codes,
+        vec![
+            DiagnosticCode::IncludeFailed,
+            DiagnosticCode::MergeFailed,
+            DiagnosticCode::UnknownAttribute,
+        ]
+    );
+    k9::assert_equal!(c.diagnostics[0].severity, Severity::Error);
+    k9::assert_equal!(
+        c.diagnostics[0].span.as_ref().unwrap().to_string(),
+        "/etc/fonts/fonts.conf:2:1"
+    );
+    k9::assert_equal!(
+        c.diagnostics[1].to_string(),
+        "/etc/fonts/conf.d/10-broken.conf:3:21: error[merge-failed]: Failed to merge /etc/fonts/conf.d/10-broken.conf: Unknown variant for fontconfig_parser::types::constant::Constant: heavyish"
+    );
+    k9::assert_equal!(c.diagnostics[2].severity, Severity::Warning);
+    k9::assert_equal!(c.dirs.len(), 1);
+}
+
+#[test]
+fn validate_test_conf() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    // shipped configs are well typed
+    k9::assert_equal!(c.validate(), Vec::<Diagnostic>::new());
+}
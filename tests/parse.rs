@@ -4,6 +4,18 @@ use std::path::PathBuf;
 
 use fontconfig_parser::*;
 
+/// Fixtures write enums as single key maps since YAML tags can't be nested
+fn to_yaml(parts: &[ConfigPart]) -> String {
+    let mut yaml = Vec::new();
+    serde_yaml::with::singleton_map_recursive::serialize(
+        &parts,
+        &mut serde_yaml::Serializer::new(&mut yaml),
+    )
+    .unwrap();
+
+    String::from_utf8(yaml).unwrap()
+}
+
 fn test_single_conf(path: PathBuf) -> Result<()> {
     eprintln!("Test {}", path.display());
 
@@ -12,11 +24,12 @@ fn test_single_conf(path: PathBuf) -> Result<()> {
         path.file_stem().unwrap().to_str().unwrap()
     ));
 
-    let parts = parse_config_parts(std::fs::read_to_string(path)?.as_str())?;
+    let parts = parse_config_parts(std::fs::read_to_string(&path)?.as_str())?;
 
-    let expected = std::fs::read_to_string(yaml_path)?;
-    let actual = serde_yaml::to_string(&parts).unwrap();
-    k9::assert_equal!(expected, actual);
+    let expected: serde_yaml::Value =
+        serde_yaml::from_str(&std::fs::read_to_string(yaml_path)?).unwrap();
+    let actual: serde_yaml::Value = serde_yaml::from_str(&to_yaml(&parts)).unwrap();
+    k9::assert_equal!(expected, actual, "{}", path.display());
 
     Ok(())
 }
@@ -0,0 +1,437 @@
+#![cfg(feature = "scan")]
+
+mod common;
+
+use common::Font;
+use fontconfig_parser::*;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("fontconfig-parser-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn strings(pattern: &Pattern, kind: PropertyKind) -> Vec<String> {
+    pattern
+        .get(&kind)
+        .unwrap_or_default()
+        .iter()
+        .map(|v| match &v.value {
+            Value::String(s) => s.clone(),
+            other => panic!("{:?} isn't a string", other),
+        })
+        .collect()
+}
+
+#[test]
+fn scan_face() {
+    let font = Font {
+        family: "Test Mono",
+        style: "Bold Italic",
+        names: vec![(1, 0x411, "テスト"), (16, 0x409, "Test Mono Family")],
+        weight: 700,
+        width: 3,
+        italic: true,
+        chars: vec![(0x20, 0x7e), (0xa0, 0xff)],
+        ..Default::default()
+    };
+    let fonts = scan_font_data(&font.build(), Some("/fonts/test.ttf".as_ref())).unwrap();
+
+    k9::assert_equal!(fonts.len(), 1);
+    let font = &fonts[0];
+
+    // typographic family comes first, english names before others
+    k9::assert_equal!(
+        strings(font, PropertyKind::Family),
+        ["Test Mono Family", "Test Mono", "テスト"]
+    );
+    k9::assert_equal!(strings(font, PropertyKind::FamilyLang), ["en", "en", "ja"]);
+    k9::assert_equal!(strings(font, PropertyKind::Style), ["Bold Italic"]);
+    k9::assert_equal!(font.get_int(&PropertyKind::Weight, 0), Some(200));
+    k9::assert_equal!(font.get_int(&PropertyKind::Width, 0), Some(75));
+    k9::assert_equal!(font.get_int(&PropertyKind::Slant, 0), Some(100));
+    k9::assert_equal!(font.get_int(&PropertyKind::Spacing, 0), Some(100));
+    k9::assert_equal!(font.get_int(&PropertyKind::Index, 0), Some(0));
+    k9::assert_equal!(font.get_int(&PropertyKind::Fontversion, 0), Some(0x18000));
+    k9::assert_equal!(font.get_string(&PropertyKind::Foundry, 0), Some("TEST"));
+    k9::assert_equal!(
+        font.get_string(&PropertyKind::Postscriptname, 0),
+        Some("TestMonoBoldItalic")
+    );
+    k9::assert_equal!(
+        font.get_string(&PropertyKind::Fontformat, 0),
+        Some("TrueType")
+    );
+    k9::assert_equal!(
+        font.get_string(&PropertyKind::File, 0),
+        Some("/fonts/test.ttf")
+    );
+    for kind in [
+        PropertyKind::Variable,
+        PropertyKind::Decorative,
+        PropertyKind::Symbol,
+        PropertyKind::Fonthashint,
+    ] {
+        k9::assert_equal!(font.get_bool(&kind, 0), Some(false), "{:?}", kind);
+    }
+    k9::assert_equal!(font.get(&PropertyKind::Capability), None);
+
+    match &font.get(&PropertyKind::Lang).unwrap()[0].value {
+        Value::LangSet(langs) => {
+            k9::assert_equal!(langs.len(), 70);
+            for lang in ["de", "en", "nl"] {
+                k9::assert_equal!(langs.compare_lang(lang), LangResult::Equal);
+            }
+            // Finnish needs `š` and `ž`
+            k9::assert_equal!(langs.compare_lang("fi"), LangResult::DifferentLang);
+        }
+        other => panic!("{:?} isn't a langset", other),
+    }
+    match &font.get(&PropertyKind::Charset).unwrap()[0].value {
+        Value::CharSet(charset) => {
+            assert!(charset.contains(0x41));
+            assert!(charset.contains(0xe9));
+            assert!(!charset.contains(0x100));
+        }
+        other => panic!("{:?} isn't a charset", other),
+    }
+}
+
+#[test]
+fn scan_cyrillic() {
+    let font = Font {
+        chars: vec![(0x20, 0x7e), (0x410, 0x44f)],
+        ..Default::default()
+    };
+    let fonts = scan_font_data(&font.build(), None).unwrap();
+
+    match &fonts[0].get(&PropertyKind::Lang).unwrap()[0].value {
+        Value::LangSet(langs) => {
+            k9::assert_equal!(langs.compare_lang("bg"), LangResult::Equal);
+            // a single missing `ё` is enough to drop Russian
+            k9::assert_equal!(langs.compare_lang("ru"), LangResult::DifferentLang);
+        }
+        other => panic!("{:?} isn't a langset", other),
+    }
+}
+
+#[test]
+fn scan_system_font() {
+    let path = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+    if !std::path::Path::new(path).exists() {
+        return;
+    }
+
+    let fonts = scan_font_data(&std::fs::read(path).unwrap(), None).unwrap();
+    match &fonts[0].get(&PropertyKind::Lang).unwrap()[0].value {
+        Value::LangSet(langs) => {
+            for lang in ["fi", "nl", "ga", "bg", "sr"] {
+                k9::assert_equal!(langs.compare_lang(lang), LangResult::Equal);
+            }
+            k9::assert_equal!(langs.compare_lang("ja"), LangResult::DifferentLang);
+        }
+        other => panic!("{:?} isn't a langset", other),
+    }
+    // same as `fc-query`
+    k9::assert_equal!(
+        fonts[0].get_string(&PropertyKind::Capability, 0),
+        Some("otlayout:DFLT otlayout:arab otlayout:armn otlayout:brai otlayout:cans otlayout:cher otlayout:cyrl otlayout:geor otlayout:grek otlayout:hani otlayout:hebr otlayout:kana otlayout:lao  otlayout:latn otlayout:math otlayout:nko  otlayout:ogam otlayout:runr otlayout:tfng otlayout:thai")
+    );
+    k9::assert_equal!(fonts[0].get_bool(&PropertyKind::Fonthashint, 0), Some(true));
+}
+
+/// `GSUB` or `GPOS` with a script list of `scripts`
+fn layout(scripts: &[&[u8; 4]]) -> Vec<u8> {
+    let mut table = [0, 1, 0, 0, 0, 10, 0, 0, 0, 0].to_vec();
+    table.extend_from_slice(&(scripts.len() as u16).to_be_bytes());
+    for script in scripts {
+        table.extend_from_slice(*script);
+        table.extend_from_slice(&[0, 0]);
+    }
+    table
+}
+
+#[test]
+fn scan_tables() {
+    let font = Font {
+        style: "Small Caps",
+        symbol: true,
+        tables: vec![
+            (b"GSUB", layout(&[b"latn", b"cyrl", b"DFLT"])),
+            (b"GPOS", layout(&[b"latn", b"lao "])),
+            (b"prep", vec![0xb0, 0]),
+        ],
+        ..Default::default()
+    };
+    let fonts = scan_font_data(&font.build(), None).unwrap();
+
+    k9::assert_equal!(
+        fonts[0].get_string(&PropertyKind::Capability, 0),
+        Some("otlayout:DFLT otlayout:cyrl otlayout:lao  otlayout:latn")
+    );
+    k9::assert_equal!(fonts[0].get_bool(&PropertyKind::Decorative, 0), Some(true));
+    k9::assert_equal!(fonts[0].get_bool(&PropertyKind::Symbol, 0), Some(true));
+    k9::assert_equal!(fonts[0].get_bool(&PropertyKind::Fonthashint, 0), Some(true));
+}
+
+#[test]
+fn scan_collection_and_woff() {
+    let regular = Font::default();
+    let bold = Font {
+        style: "Bold",
+        weight: 700,
+        // proportional
+        advances: vec![500, 600, 700],
+        ..Default::default()
+    };
+
+    let fonts = scan_font_data(&common::collection(&[regular, bold]), None).unwrap();
+    k9::assert_equal!(fonts.len(), 2);
+    k9::assert_equal!(fonts[1].get_int(&PropertyKind::Index, 0), Some(1));
+    k9::assert_equal!(fonts[1].get_string(&PropertyKind::Style, 0), Some("Bold"));
+    k9::assert_equal!(fonts[1].get(&PropertyKind::Spacing), None);
+
+    let plain = scan_font_data(&Font::default().build(), None).unwrap();
+    let woff = scan_font_data(&common::woff(&Font::default()), None).unwrap();
+    k9::assert_equal!(woff, plain);
+
+    assert!(scan_font_data(b"not a font", None).is_err());
+}
+
+#[test]
+fn scan_malformed() {
+    // doubling the narrower advance doesn't fit in 16 bits
+    let wide = Font {
+        advances: vec![500, 40000, 60000],
+        ..Default::default()
+    };
+    let fonts = scan_font_data(&wide.build(), None).unwrap();
+    k9::assert_equal!(fonts[0].get(&PropertyKind::Spacing), None);
+
+    // a table inflating beyond its declared length is rejected
+    let mut woff = common::woff(&Font::default());
+    let u32_at = |data: &[u8], at: usize| {
+        u32::from_be_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+    };
+    let entry = (0..woff[13] as usize)
+        .map(|idx| 44 + idx * 20)
+        .find(|&entry| u32_at(&woff, entry + 8) + 1 < u32_at(&woff, entry + 12))
+        .unwrap();
+    let comp_len = u32_at(&woff, entry + 8);
+    woff[entry + 12..entry + 16].copy_from_slice(&(comp_len + 1).to_be_bytes());
+    assert!(scan_font_data(&woff, None).is_err());
+}
+
+#[test]
+fn scan_variable() {
+    let font = Font {
+        style: "Regular",
+        names: vec![
+            (256, 0x409, "Weight"),
+            (257, 0x409, "Light"),
+            (258, 0x409, "Black"),
+        ],
+        axes: vec![(b"wght", 300.0, 400.0, 900.0)],
+        instances: vec![(257, vec![300.0]), (258, vec![900.0])],
+        ..Default::default()
+    };
+    let fonts = scan_font_data(&font.build(), None).unwrap();
+
+    k9::assert_equal!(fonts.len(), 3);
+    k9::assert_equal!(fonts[0].get_bool(&PropertyKind::Variable, 0), Some(true));
+    k9::assert_equal!(
+        fonts[0].get_value(&PropertyKind::Weight, 0),
+        Some(&Value::Range(50, 210))
+    );
+
+    k9::assert_equal!(fonts[1].get_int(&PropertyKind::Index, 0), Some(1 << 16));
+    k9::assert_equal!(fonts[1].get_string(&PropertyKind::Style, 0), Some("Light"));
+    k9::assert_equal!(
+        fonts[1].get_string(&PropertyKind::FullName, 0),
+        Some("Test Sans Light")
+    );
+    k9::assert_equal!(fonts[1].get_int(&PropertyKind::Weight, 0), Some(50));
+    k9::assert_equal!(fonts[1].get_bool(&PropertyKind::Variable, 0), Some(false));
+    k9::assert_equal!(fonts[2].get_int(&PropertyKind::Index, 0), Some(2 << 16));
+    k9::assert_equal!(fonts[2].get_int(&PropertyKind::Weight, 0), Some(210));
+}
+
+#[test]
+fn scan_rules() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let dir = temp_dir("scan-rules");
+    let heavy = Font {
+        family: "Delicious",
+        style: "Heavy",
+        weight: 500,
+        ..Default::default()
+    };
+    std::fs::write(dir.join("Delicious-Heavy.otf"), heavy.build()).unwrap();
+
+    let fonts = c.scan_file(&dir.join("Delicious-Heavy.otf")).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    k9::assert_equal!(fonts[0].get_int(&PropertyKind::Weight, 0), Some(210));
+}
+
+#[test]
+fn scan_dirs() {
+    let dir = temp_dir("scan-dirs");
+    std::fs::create_dir_all(dir.join("sub")).unwrap();
+    std::fs::write(dir.join("a.ttf"), Font::default().build()).unwrap();
+    std::fs::write(dir.join("readme.txt"), "not a font").unwrap();
+    std::fs::write(dir.join("rejected.ttf"), Font::default().build()).unwrap();
+    let bold = Font {
+        style: "Bold",
+        weight: 700,
+        ..Default::default()
+    };
+    std::fs::write(dir.join("sub/b.ttf"), bold.build()).unwrap();
+
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+    c.dirs = vec![DirData {
+        path: dir.clone(),
+        salt: String::new(),
+    }];
+    c.select_fonts.extend(
+        parse_config_parts(
+            r#"<fontconfig><selectfont>
+                <rejectfont><glob>*/rejected.ttf</glob></rejectfont>
+            </selectfont></fontconfig>"#,
+        )
+        .unwrap()
+        .into_iter()
+        .filter_map(|part| match part {
+            ConfigPart::SelectFont(s) => Some(s),
+            _ => None,
+        }),
+    );
+
+    let scanned = c.scan_dir(&dir).unwrap();
+    k9::assert_equal!(scanned.fonts.len(), 1);
+    k9::assert_equal!(scanned.subdirs, [dir.join("sub")]);
+
+    let fonts = c.scan_fonts();
+    std::fs::remove_dir_all(&dir).ok();
+
+    let files = fonts
+        .iter()
+        .map(|f| f.get_string(&PropertyKind::File, 0).unwrap().to_string())
+        .collect::<Vec<_>>();
+    k9::assert_equal!(
+        files,
+        [
+            dir.join("a.ttf").to_string_lossy(),
+            dir.join("sub/b.ttf").to_string_lossy()
+        ]
+    );
+}
+
+fn cached_config(name: &str) -> (FontConfig, DirData) {
+    let root = temp_dir(name);
+    let fonts = root.join("fonts");
+    std::fs::create_dir_all(&fonts).unwrap();
+    std::fs::write(fonts.join("a.ttf"), Font::default().build()).unwrap();
+
+    let mut c = FontConfig::default();
+    c.cache_dirs.push(root.join("cache"));
+
+    let dir = DirData {
+        path: fonts,
+        salt: String::new(),
+    };
+
+    (c, dir)
+}
+
+fn families(scanned: &ScannedDir) -> Vec<String> {
+    scanned
+        .fonts
+        .iter()
+        .map(|f| f.get_string(&PropertyKind::Family, 0).unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn scan_dir_cached() {
+    let (c, dir) = cached_config("cached");
+
+    let scanned = c.scan_dir_cached(&dir).unwrap();
+    k9::assert_equal!(families(&scanned), ["Test Sans"]);
+    k9::assert_equal!(std::fs::read_dir(&c.cache_dirs[0]).unwrap().count(), 1);
+
+    // rewriting a file doesn't change modification time of the directory
+    let renamed = Font {
+        family: "Renamed",
+        ..Default::default()
+    };
+    std::fs::write(dir.path.join("a.ttf"), renamed.build()).unwrap();
+    k9::assert_equal!(c.scan_dir_cached(&dir).unwrap(), scanned);
+
+    std::fs::write(dir.path.join("b.ttf"), Font::default().build()).unwrap();
+    k9::assert_equal!(
+        families(&c.scan_dir_cached(&dir).unwrap()),
+        ["Renamed", "Test Sans"]
+    );
+
+    // broken caches are replaced
+    let cache = std::fs::read_dir(&c.cache_dirs[0])
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    std::fs::write(&cache, "broken").unwrap();
+    k9::assert_equal!(c.scan_dir_cached(&dir).unwrap().fonts.len(), 2);
+    assert!(std::fs::read(&cache).unwrap().len() > 6);
+
+    std::fs::remove_dir_all(dir.path.parent().unwrap()).ok();
+}
+
+#[test]
+fn scan_dir_cached_rescan() {
+    let (mut c, dir) = cached_config("cached-rescan");
+    c.config.rescans.push(1);
+
+    c.scan_dir_cached(&dir).unwrap();
+
+    let renamed = Font {
+        family: "Renamed",
+        ..Default::default()
+    };
+    std::fs::write(dir.path.join("a.ttf"), renamed.build()).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+
+    k9::assert_equal!(families(&c.scan_dir_cached(&dir).unwrap()), ["Renamed"]);
+
+    std::fs::remove_dir_all(dir.path.parent().unwrap()).ok();
+}
+
+#[test]
+fn scan_dir_cached_concurrently() {
+    let (c, dir) = cached_config("cached-concurrently");
+
+    let results = std::thread::scope(|s| {
+        let handles = (0..8)
+            .map(|_| s.spawn(|| c.scan_dir_cached(&dir).unwrap()))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert!(results.iter().all(|r| *r == results[0]));
+    // temporary files are renamed
+    k9::assert_equal!(std::fs::read_dir(&c.cache_dirs[0]).unwrap().count(), 1);
+    k9::assert_equal!(c.scan_dir_cached(&dir).unwrap(), results[0]);
+
+    std::fs::remove_dir_all(dir.path.parent().unwrap()).ok();
+}
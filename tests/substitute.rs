@@ -0,0 +1,131 @@
+use fontconfig_parser::*;
+
+fn first(pattern: &Pattern, kind: PropertyKind) -> Option<&Value> {
+    pattern.get(&kind).map(|values| &values[0].value)
+}
+
+fn has_family(pattern: &[PatternValue], family: &str, binding: EditBinding) -> bool {
+    pattern.contains(&PatternValue::new(family.into(), binding))
+}
+
+#[test]
+fn substitute_full() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let mut pattern = Pattern::new();
+    pattern.add(PropertyKind::Family, "mono");
+    c.substitute(&mut pattern);
+
+    let families = pattern.get(&PropertyKind::Family).unwrap();
+    // fonts.conf
+    assert!(has_family(families, "monospace", EditBinding::Strong));
+    assert!(!has_family(families, "mono", EditBinding::Strong));
+    // 10-nixos-rendering.conf
+    k9::assert_equal!(
+        first(&pattern, PropertyKind::HintStyle),
+        Some(&Value::Int(1))
+    );
+    k9::assert_equal!(
+        first(&pattern, PropertyKind::Hinting),
+        Some(&Value::Bool(true))
+    );
+
+    let mut pattern = Pattern::new();
+    pattern.add(PropertyKind::Family, "Foo");
+    c.substitute(&mut pattern);
+
+    let families = pattern.get(&PropertyKind::Family).unwrap();
+    k9::assert_equal!(families[0].value, Value::from("Foo"));
+    // 49-sansserif.conf
+    assert!(has_family(families, "sans-serif", EditBinding::Weak));
+}
+
+#[test]
+fn expand_generic_family() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/fonts.conf").unwrap();
+
+    let families = c.expand_families(vec!["sans-serif"]);
+
+    // 52-nixos-default-fonts.conf, `binding="same"` takes binding of `sans-serif`
+    k9::assert_equal!(
+        families[0],
+        PatternValue::new("DejaVu Sans".into(), EditBinding::Strong)
+    );
+    assert!(has_family(&families, "sans-serif", EditBinding::Strong));
+}
+
+#[test]
+fn substitute_rule_order() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/rule-order.conf").unwrap();
+
+    let mut pattern = Pattern::new();
+    pattern.add(PropertyKind::Family, "foo");
+    c.substitute(&mut pattern);
+
+    // the match sees family added by the alias before it
+    k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 0), Some("Baz"));
+    k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 1), Some("foo"));
+}
+
+#[test]
+fn substitute_without_provenance() {
+    let mut c = FontConfig::default();
+    c.merge_config("./test-conf/rule-order.conf").unwrap();
+    // pushed by hand so it runs after every merged rule
+    c.aliases.push(Alias {
+        alias: "Baz".into(),
+        prefer: vec!["Qux".into()],
+        ..Default::default()
+    });
+
+    let mut pattern = Pattern::new();
+    pattern.add(PropertyKind::Family, "foo");
+    c.substitute(&mut pattern);
+
+    k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 0), Some("Qux"));
+    k9::assert_equal!(pattern.get_string(&PropertyKind::Family, 1), Some("Baz"));
+}
+
+#[test]
+fn config_substitute() {
+    let prgname = std::env::current_exe().unwrap();
+    let prgname = prgname.file_name().unwrap().to_str().unwrap();
+    let mut c = FontConfig::default();
+
+    for part in parse_config_parts(&format!(
+        "<fontconfig><match><test name=\"prgname\"><string>{}</string></test><edit name=\"family\"><string>Program Sans</string></edit></match></fontconfig>",
+        prgname
+    ))
+    .unwrap()
+    {
+        if let ConfigPart::Match(m) = part {
+            c.matches.push(m);
+        }
+    }
+
+    let mut pattern = Pattern::new();
+    c.config_substitute(&mut pattern);
+
+    // rules see the program name
+    k9::assert_equal!(
+        pattern.get_string(&PropertyKind::Family, 0),
+        Some("Program Sans")
+    );
+    let langs = default_langs();
+    k9::assert_equal!(
+        pattern.get(&PropertyKind::Lang).unwrap().to_vec(),
+        langs
+            .iter()
+            .map(|lang| PatternValue::new(lang.as_str().into(), EditBinding::Weak))
+            .collect::<Vec<_>>()
+    );
+
+    // languages of the pattern aren't added again
+    let mut pattern = Pattern::new();
+    pattern.add(PropertyKind::Lang, langs[0].to_uppercase());
+    c.config_substitute(&mut pattern);
+    k9::assert_equal!(pattern.get(&PropertyKind::Lang).unwrap().len(), langs.len());
+}
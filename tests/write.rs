@@ -0,0 +1,149 @@
+use fontconfig_parser::*;
+
+/// Parse `xml` with every span reset, written output is parsed at other locations
+fn parse_without_spans(xml: &str) -> Vec<ConfigPart> {
+    let mut parts = parse_config_parts(xml).unwrap();
+
+    for part in parts.iter_mut() {
+        if let ConfigPart::Match(m) = part {
+            m.span = Span::default();
+            m.tests
+                .iter_mut()
+                .for_each(|test| test.span = Span::default());
+            m.edits
+                .iter_mut()
+                .for_each(|edit| edit.span = Span::default());
+        }
+    }
+
+    parts
+}
+
+fn round_trip(path: &str) {
+    let parts = parse_without_spans(&std::fs::read_to_string(path).unwrap());
+    let xml = config_to_xml(&parts);
+
+    k9::assert_equal!(parse_without_spans(&xml), parts, "{}", path);
+    // written output is stable
+    k9::assert_equal!(config_to_xml(&parse_config_parts(&xml).unwrap()), xml);
+}
+
+#[test]
+fn write_test_conf() {
+    round_trip("./test-conf/fonts.conf");
+
+    for conf in glob::glob("./test-conf/conf.d/*.conf").unwrap() {
+        round_trip(conf.unwrap().to_str().unwrap());
+    }
+}
+
+#[test]
+fn write_generated_snippet() {
+    let alias = Alias {
+        alias: "sans-serif".into(),
+        prefer: vec!["Noto Sans".into(), "DejaVu Sans".into()],
+        ..Default::default()
+    };
+
+    let xml = config_to_xml(std::slice::from_ref(&alias));
+
+    k9::assert_equal!(
+        parse_config_parts(&xml).unwrap(),
+        vec![ConfigPart::Alias(alias)]
+    );
+}
+
+#[test]
+fn write_empty_text_and_matrix() {
+    let parts = vec![
+        ConfigPart::Description(Description::default()),
+        ConfigPart::Dir(Dir::default()),
+        ConfigPart::Alias(Alias::default()),
+        ConfigPart::Match(Match {
+            edits: vec![
+                Edit {
+                    kind: PropertyKind::Family,
+                    values: vec![Value::String(String::new()).into()],
+                    ..Default::default()
+                },
+                Edit {
+                    kind: PropertyKind::Matrix,
+                    values: vec![Value::Matrix([1.0, 0.2, 0.0, 1.0]).into()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+    ];
+
+    let xml = config_to_xml(&parts);
+
+    k9::assert_equal!(parse_without_spans(&xml), parts);
+}
+
+#[test]
+fn write_all_attributes() {
+    let xml = r#"<?xml version="1.0"?>
+<!DOCTYPE fontconfig SYSTEM "urn:fontconfig:fonts.dtd">
+<fontconfig>
+	<description domain="fontconfig-conf">Attributes</description>
+	<include ignore_missing="yes" deprecated="yes">~/.fonts.conf.d</include>
+	<alias binding="strong">
+		<family>serif</family>
+		<accept>
+			<family>Foo</family>
+		</accept>
+	</alias>
+	<match target="scan">
+		<test name="family" qual="all" target="scan" compare="not_eq" ignore-blanks="true">
+			<string>Foo Bar</string>
+			<string>Qux</string>
+		</test>
+		<test name="style" qual="first">
+			<string>Bold</string>
+		</test>
+		<test name="lang" qual="not_first">
+			<string>ja</string>
+		</test>
+		<edit name="family" mode="append_last" binding="same">
+			<string>Baz</string>
+			<string>Quux</string>
+		</edit>
+		<edit name="style" mode="delete_all">
+		</edit>
+		<edit name="lang">
+			<langset>
+				<string>ja</string>
+				<string>zh-tw</string>
+			</langset>
+		</edit>
+		<edit name="charset">
+			<charset><int>32</int><range><int>65</int><int>90</int></range></charset>
+		</edit>
+	</match>
+</fontconfig>
+"#;
+
+    let parts = parse_config_parts(xml).unwrap();
+
+    match &parts[1] {
+        ConfigPart::Include(include) => assert!(include.ignore_missing && include.deprecated),
+        part => panic!("Unexpected part {:?}", part),
+    }
+    match &parts[3] {
+        ConfigPart::Match(m) => {
+            assert!(m.tests[0].ignore_blanks);
+            k9::assert_equal!(m.tests[0].target, TestTarget::Scan);
+            k9::assert_equal!(
+                m.edits[0].values,
+                vec![Expression::from("Baz"), Expression::from("Quux")]
+            );
+            k9::assert_equal!(m.tests[0].values.len(), 2);
+            k9::assert_equal!(m.tests[1].qual, TestQual::First);
+            k9::assert_equal!(m.tests[2].qual, TestQual::NotFirst);
+            assert!(m.edits[1].values.is_empty());
+        }
+        part => panic!("Unexpected part {:?}", part),
+    }
+    k9::assert_equal!(config_to_xml(&parts), xml);
+}